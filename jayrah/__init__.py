@@ -0,0 +1,4 @@
+import logging
+
+# Nothing goes to the terminal until a command sets up the log file
+logging.getLogger(__name__).addHandler(logging.NullHandler())
@@ -0,0 +1,107 @@
+"""Jira Agile API client for boards and sprints (/rest/agile/1.0)."""
+
+from typing import Any
+
+from ..utils import log
+from . import request_handler
+
+AGILE_API_PATH = "rest/agile/1.0"
+
+
+class JiraAgile:
+    """Client for the Jira Software boards and sprints endpoints."""
+
+    def __init__(
+        self, handler: request_handler.JiraRequestHandler, verbose: bool = False
+    ):
+        self.request_handler = handler
+        self.verbose = verbose
+
+    def _request(
+        self,
+        method: str,
+        endpoint: str,
+        params: dict[str, Any] | None = None,
+        jeez: dict[str, Any] | None = None,
+        use_cache: bool = True,
+    ) -> dict[str, Any]:
+        return self.request_handler.request(
+            method=method,
+            endpoint=endpoint,
+            params=params,
+            json_data=jeez,
+            use_cache=use_cache,
+        )
+
+    def _get_all_values(
+        self, endpoint: str, params: dict[str, Any], use_cache: bool = True
+    ) -> list[dict[str, Any]]:
+        """Follow the agile API pagination and collect every value."""
+        values: list[dict[str, Any]] = []
+        start_at = 0
+        while True:
+            response = self._request(
+                "GET",
+                endpoint,
+                params={**params, "startAt": start_at},
+                use_cache=use_cache,
+            )
+            batch = response.get("values", [])
+            values.extend(batch)
+            if response.get("isLast", True) or not batch:
+                return values
+            start_at += len(batch)
+
+    def list_boards(
+        self, project_key: str | None = None, board_type: str | None = None
+    ) -> list[dict[str, Any]]:
+        """List agile boards, optionally for a project and of a type (scrum, kanban)."""
+        params: dict[str, Any] = {"maxResults": 50}
+        if project_key:
+            params["projectKeyOrId"] = project_key
+        if board_type:
+            params["type"] = board_type
+        return self._get_all_values("board", params)
+
+    def get_board_sprints(
+        self, board_id: int, state: str | None = "active,future"
+    ) -> list[dict[str, Any]]:
+        """List the sprints of a board, state is a comma separated filter."""
+        params: dict[str, Any] = {"maxResults": 50}
+        if state:
+            params["state"] = state
+        return self._get_all_values(f"board/{board_id}/sprint", params, use_cache=False)
+
+    def get_sprint_issues(
+        self,
+        sprint_id: int,
+        fields: list[str] | None = None,
+        max_results: int = 100,
+    ) -> list[dict[str, Any]]:
+        """List every issue in a sprint."""
+        issues: list[dict[str, Any]] = []
+        params: dict[str, Any] = {"maxResults": max_results}
+        if fields:
+            params["fields"] = ",".join(fields)
+        while True:
+            response = self._request(
+                "GET",
+                f"sprint/{sprint_id}/issue",
+                params={**params, "startAt": len(issues)},
+                use_cache=False,
+            )
+            batch = response.get("issues", [])
+            issues.extend(batch)
+            if not batch or len(issues) >= response.get("total", 0):
+                return issues
+
+    def move_issues_to_sprint(
+        self, sprint_id: int, issue_keys: list[str]
+    ) -> dict[str, Any]:
+        """Move issues to a sprint, at most 50 issues per call on Jira side."""
+        if self.verbose:
+            log(f"Moving {', '.join(issue_keys)} to sprint {sprint_id}")
+
+        return self._request(
+            "POST", f"sprint/{sprint_id}/issue", jeez={"issues": issue_keys}
+        )
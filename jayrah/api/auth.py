@@ -1,10 +1,21 @@
 """Authentication handlers for Jira API."""
 
 import base64
+import json
+import threading
+import urllib.error
+import urllib.request
 from abc import ABC, abstractmethod
+from collections.abc import Callable
+from typing import Any
 
 import click
 
+from .oauth import OAuthSession
+
+# Login endpoint of the session cookies, only on Jira Server and Data Center
+SESSION_PATH = "rest/auth/1/session"
+
 
 class AuthenticatorBase(ABC):
     """Base class for authentication handlers."""
@@ -13,6 +24,14 @@ class AuthenticatorBase(ABC):
     def get_headers(self) -> dict[str, str]:
         """Get authentication headers."""
 
+    def renew(self, failed: dict[str, str]) -> bool:
+        """
+        Get new credentials after a 401, whether the request can be retried.
+
+        failed holds the headers of the request that got the 401.
+        """
+        return False
+
 
 class BearerAuthenticator(AuthenticatorBase):
     """Bearer token authentication."""
@@ -43,6 +62,89 @@ class BasicAuthenticator(AuthenticatorBase):
         return {"Authorization": f"Basic {encoded_auth}"}
 
 
+class OAuthAuthenticator(AuthenticatorBase):
+    """OAuth 2.0 (3LO) authentication, the access token is refreshed as needed."""
+
+    def __init__(self, session: OAuthSession):
+        self.session = session
+
+    def get_headers(self) -> dict[str, str]:
+        return {"Authorization": f"Bearer {self.session.access_token()}"}
+
+
+class CookieAuthenticator(AuthenticatorBase):
+    """
+    Session cookie authentication, for Jira Server installs without basic auth.
+
+    Logs in with the user and password on the first request, unless a
+    JSESSIONID is given, and again when Jira answers that the session
+    expired. The requests of the worker threads share the session.
+    """
+
+    def __init__(
+        self,
+        server: str,
+        username: str | None = None,
+        password: str | None = None,
+        session_id: str | None = None,
+    ):
+        if not session_id and not (username and password):
+            raise click.ClickException(
+                "Cookie authentication requires jira_user and jira_password, "
+                "or jira_session"
+            )
+        self.server = server.rstrip("/")
+        self.username = username
+        self.password = password
+        self.cookie = f"JSESSIONID={session_id}" if session_id else None
+        self._lock = threading.Lock()
+        # Set by the client to the send of its request handler, the login goes
+        # through the same proxy, certificates and timeouts as the requests
+        self.send: (
+            Callable[[urllib.request.Request, bytes | None], tuple[int, Any]] | None
+        ) = None
+
+    def login(self) -> str:
+        """Open a session, gives its cookie."""
+        if self.send is None:
+            raise RuntimeError("The cookie authenticator is not attached to a client")
+        request = urllib.request.Request(
+            f"{self.server}/{SESSION_PATH}",
+            headers={"Content-Type": "application/json", "Accept": "application/json"},
+            method="POST",
+        )
+        data = json.dumps({"username": self.username, "password": self.password})
+        try:
+            _, answer = self.send(request, data.encode("utf-8"))
+        except urllib.error.HTTPError as e:
+            raise click.ClickException(
+                f"Jira session login failed: HTTP {e.code} {e.reason}"
+            ) from e
+        except urllib.error.URLError as e:
+            raise click.ClickException(f"Jira session login failed: {e.reason}") from e
+        except TimeoutError as e:
+            raise click.ClickException("Jira session login timed out") from e
+        session = answer.get("session") if isinstance(answer, dict) else None
+        if not session or not session.get("value"):
+            raise click.ClickException("Jira session login answered without a session")
+        return f"{session.get('name') or 'JSESSIONID'}={session['value']}"
+
+    def get_headers(self) -> dict[str, str]:
+        with self._lock:
+            if self.cookie is None:
+                self.cookie = self.login()
+            return {"Cookie": self.cookie}
+
+    def renew(self, failed: dict[str, str]) -> bool:
+        if not (self.username and self.password):
+            return False
+        with self._lock:
+            # Another thread that got a 401 with the same cookie logged in already
+            if self.cookie is None or self.cookie == failed.get("Cookie"):
+                self.cookie = self.login()
+        return True
+
+
 def create_authenticator(config: dict, auth_method: str) -> AuthenticatorBase:
     """Factory function to create appropriate authenticator."""
     if auth_method == "basic":
@@ -60,4 +162,13 @@ def create_authenticator(config: dict, auth_method: str) -> AuthenticatorBase:
                 "Bearer authentication requires a token in config"
             )
         return BearerAuthenticator(token)
+    if auth_method == "oauth":
+        return OAuthAuthenticator(OAuthSession.from_config(config))
+    if auth_method == "cookie":
+        return CookieAuthenticator(
+            config.get("jira_server") or "",
+            config.get("jira_user"),
+            config.get("jira_password"),
+            config.get("jira_session"),
+        )
     raise click.ClickException(f"Unknown authentication method: {auth_method}")
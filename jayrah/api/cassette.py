@@ -0,0 +1,147 @@
+"""
+Recording and replay of the requests sent to Jira, for tests.
+
+A cassette is a JSON file of requests and the responses they got. In record
+mode the requests go to the server and are written to the file, in replay
+mode the responses come from the file and nothing is sent, so the parsing of
+real Jira answers can be tested without a server. Only the method, the path
+and query of the URL and the body of a request are recorded, never its
+headers and credentials.
+"""
+
+import io
+import json
+import pathlib
+import urllib.error
+import urllib.parse
+import urllib.request
+from collections.abc import Callable
+from email.message import Message
+from typing import Any
+
+MODES = ("record", "replay")
+# Fields of the login and token requests and their answers, masked in the
+# cassettes
+SECRET_FIELDS = frozenset(
+    {
+        "password",
+        "session",
+        "client_secret",
+        "code",
+        "access_token",
+        "refresh_token",
+    }
+)
+REDACTED = "REDACTED"
+
+
+class CassetteError(Exception):
+    """A request without a recorded response in the cassette."""
+
+
+def request_key(request: urllib.request.Request, data: bytes | None) -> dict:
+    """What identifies a request in a cassette, whatever the server."""
+    url = urllib.parse.urlsplit(request.full_url)
+    return {
+        "method": request.get_method(),
+        "path": f"{url.path}?{url.query}" if url.query else url.path,
+        "body": json.loads(data) if data else None,
+    }
+
+
+def redact(value: Any, secret: bool = False) -> Any:
+    """The value with the strings of its SECRET_FIELDS masked."""
+    if isinstance(value, dict):
+        return {k: redact(v, secret or k in SECRET_FIELDS) for k, v in value.items()}
+    if isinstance(value, list):
+        return [redact(x, secret) for x in value]
+    return REDACTED if secret and isinstance(value, str) else value
+
+
+class Cassette:
+    """The recorded requests and responses of a cassette file."""
+
+    def __init__(self, path: str | pathlib.Path, mode: str = "replay"):
+        if mode not in MODES:
+            raise ValueError(f"Invalid cassette mode '{mode}', record or replay")
+        self.path = pathlib.Path(path)
+        self.mode = mode
+        # Recording starts a new cassette rather than adding to an old one
+        self.interactions: list[dict[str, Any]] = (
+            []
+            if mode == "record"
+            else json.loads(self.path.read_text(encoding="utf-8"))
+        )
+        self.played: set[int] = set()
+
+    @classmethod
+    def from_config(cls, config: dict[str, Any]) -> "Cassette | None":
+        """The cassette of the cassette and cassette_mode options, if any."""
+        if not config.get("cassette"):
+            return None
+        return cls(config["cassette"], config.get("cassette_mode") or "replay")
+
+    def play(
+        self,
+        request: urllib.request.Request,
+        data: bytes | None,
+        send: Callable[[urllib.request.Request, bytes | None], tuple[int, Any]],
+        secret: bool = False,
+    ) -> tuple[int, Any]:
+        """
+        The status and parsed body of the response to a request.
+
+        Error responses are raised as the HTTPError urlopen raises, so they
+        go through the same error handling when replayed. The credentials of
+        a secret request, like a login, are masked in the cassette and in its
+        replayed answer.
+        """
+        key = request_key(request, data)
+        if secret:
+            key = redact(key)
+        if self.mode == "record":
+            return self._record(key, request, data, send, secret)
+        return self._replay(key, request)
+
+    def _record(self, key, request, data, send, secret) -> tuple[int, Any]:
+        try:
+            status, body = send(request, data)
+        except urllib.error.HTTPError as e:
+            text = e.read().decode("utf-8")
+            self._save(key, {"status": e.code, "reason": str(e.reason), "body": text})
+            raise _http_error(request, e.code, str(e.reason), text) from e
+        self._save(key, {"status": status, "body": redact(body) if secret else body})
+        return status, body
+
+    def _save(self, key: dict, response: dict[str, Any]) -> None:
+        self.interactions.append({"request": key, "response": response})
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        self.path.write_text(
+            json.dumps(self.interactions, indent=2, ensure_ascii=False) + "\n",
+            encoding="utf-8",
+        )
+
+    def _replay(self, key: dict, request) -> tuple[int, Any]:
+        # The same request may be recorded several times, played in order
+        for index, interaction in enumerate(self.interactions):
+            if index not in self.played and interaction["request"] == key:
+                self.played.add(index)
+                response = interaction["response"]
+                if response["status"] >= 400:
+                    raise _http_error(
+                        request,
+                        response["status"],
+                        response.get("reason", ""),
+                        response["body"],
+                    )
+                return response["status"], response["body"]
+        raise CassetteError(
+            f"No recorded response in {self.path} for {key['method']} {key['path']}"
+        )
+
+
+def _http_error(request, status: int, reason: str, body: str):
+    """An HTTPError as raised by urlopen, with a body that can still be read."""
+    return urllib.error.HTTPError(
+        request.full_url, status, reason, Message(), io.BytesIO(body.encode("utf-8"))
+    )
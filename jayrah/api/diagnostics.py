@@ -0,0 +1,152 @@
+"""Check that jayrah can talk to Jira and explain how to fix it when it cannot."""
+
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from typing import Any
+
+import click
+
+from . import exceptions
+
+# Beyond that the OAuth token expiry and the cache freshness get unreliable
+MAX_CLOCK_SKEW = 300
+CLOUD_TOKEN_URL = "https://id.atlassian.com/manage-profile/security/api-tokens"
+SERVER_TOKEN_PATH = (
+    "/secure/ViewProfile.jspa"
+    "?selectedTab=com.atlassian.pats.pats-plugin:jira-user-personal-access-tokens"
+)
+
+
+@dataclass
+class Check:
+    """Outcome of one diagnostic, hint tells how to fix a failed one."""
+
+    name: str
+    ok: bool
+    message: str
+    hint: str = ""
+
+
+def token_creation_url(server: str, deployment: str | None) -> str:
+    """Page where the user creates an API token or a personal access token."""
+    if deployment == "Cloud" or server.rstrip("/").endswith(".atlassian.net"):
+        return CLOUD_TOKEN_URL
+    return server.rstrip("/") + SERVER_TOKEN_PATH
+
+
+def _parse_server_time(value: str) -> datetime | None:
+    """Jira sends times like 2024-01-02T10:00:00.000+0000."""
+    try:
+        return datetime.strptime(value, "%Y-%m-%dT%H:%M:%S.%f%z")
+    except (TypeError, ValueError):
+        return None
+
+
+def check_reachability(jira) -> tuple[Check, dict[str, Any]]:
+    """Fetch the server info, which also shows whether the API version exists."""
+    server = jira.config.get("jira_server", "")
+    try:
+        info = jira.get_server_info()
+    except exceptions.JiraNotFoundError:
+        return (
+            Check(
+                "API version",
+                False,
+                f"API v{jira.api_version} is not available on {server}",
+                "Jira Server and Data Center only have API v2, set api_version: 2",
+            ),
+            {},
+        )
+    except exceptions.JiraAPIError as e:
+        # The server answered, the credentials are checked next
+        message = f"{server} answered with HTTP {e.status_code}"
+        return Check("Server", True, message), {}
+    except click.ClickException as e:
+        return (
+            Check(
+                "Server",
+                False,
+                f"Cannot reach {server}: {e.message}",
+                "Check jira_server in the config, your network or VPN, set proxy "
+                "for a corporate proxy and ca_bundle for an internal CA",
+            ),
+            {},
+        )
+    version = info.get("version", "unknown version")
+    deployment = info.get("deploymentType", "Server")
+    return Check("Server", True, f"{server} is Jira {deployment} {version}"), info
+
+
+def check_api_version(jira, info: dict[str, Any]) -> Check:
+    """Jira Cloud works better with API v3, Server and Data Center only have v2."""
+    deployment = info.get("deploymentType")
+    if deployment == "Cloud" and jira.api_version == "2":
+        return Check(
+            "API version",
+            True,
+            "API v2 works on Jira Cloud but some features need v3",
+            "Set api_version: 3 for Jira Cloud",
+        )
+    return Check("API version", True, f"API v{jira.api_version}")
+
+
+def check_auth(jira, info: dict[str, Any]) -> Check:
+    """Call /myself and explain the usual basic versus bearer mix-ups."""
+    server = jira.config.get("jira_server", "")
+    deployment = info.get("deploymentType")
+    try:
+        user = jira.verify_auth()
+    except exceptions.JiraAuthenticationError:
+        token_url = token_creation_url(server, deployment)
+        if deployment == "Cloud" and jira.auth_method == "bearer":
+            hint = (
+                "Jira Cloud API tokens go with your email as basic authentication, "
+                "set auth_method: basic and jira_user to your email"
+            )
+        elif deployment != "Cloud" and jira.auth_method == "basic":
+            hint = (
+                "Jira Server and Data Center personal access tokens use bearer "
+                "authentication, set auth_method: bearer"
+            )
+        else:
+            hint = "Check jira_user and jira_password or create a new token"
+        return Check(
+            "Authentication",
+            False,
+            f"{jira.auth_method} authentication was rejected",
+            f"{hint} (tokens are created at {token_url})",
+        )
+    except (exceptions.JiraAPIError, click.ClickException) as e:
+        return Check("Authentication", False, f"Cannot check the credentials: {e}")
+    name = user.get("displayName") or user.get("name") or user.get("emailAddress")
+    return Check("Authentication", True, f"Logged in as {name} ({jira.auth_method})")
+
+
+def check_clock_skew(info: dict[str, Any]) -> Check:
+    """Compare the server clock with the local one."""
+    server_time = _parse_server_time(info.get("serverTime", ""))
+    if server_time is None:
+        return Check("Clock", True, "The server did not report its time")
+    skew = abs((datetime.now(timezone.utc) - server_time).total_seconds())
+    if skew > MAX_CLOCK_SKEW:
+        return Check(
+            "Clock",
+            False,
+            f"The local clock is {int(skew)} seconds off the server one",
+            "Synchronize your clock, e.g. enable NTP with timedatectl set-ntp true",
+        )
+    return Check("Clock", True, f"In sync with the server ({int(skew)}s)")
+
+
+def run_checks(jira) -> list[Check]:
+    """Run every diagnostic, stopping early when the server cannot be reached."""
+    reachability, info = check_reachability(jira)
+    checks = [reachability]
+    if not reachability.ok:
+        return checks
+    if info:
+        checks.append(check_api_version(jira, info))
+    checks.append(check_auth(jira, info))
+    if info:
+        checks.append(check_clock_skew(info))
+    return checks
@@ -3,6 +3,8 @@
 from abc import ABC, abstractmethod
 from typing import Any
 
+from ..utils import markdown_to_jira
+
 
 class FormatterBase(ABC):
     """Base class for API-specific data formatters."""
@@ -74,14 +76,14 @@ class V3Formatter(FormatterBase):
         return f"/issue/createmeta?projectKeys={projectkey}&expand=projects.issuetypes"
 
     def _convert_to_adf(self, text: str) -> dict[str, Any]:
-        """Convert plain text to Atlassian Document Format (ADF)."""
-        return {
-            "version": 1,
-            "type": "doc",
-            "content": [
+        """Convert Markdown text to Atlassian Document Format (ADF)."""
+        document = markdown_to_jira.convert_v3(text)
+        if not document["content"]:
+            # Jira refuses empty documents, keep the text as a paragraph
+            document["content"] = [
                 {"type": "paragraph", "content": [{"type": "text", "text": text}]}
-            ],
-        }
+            ]
+        return document
 
     def _is_adf_format(self, obj: Any) -> bool:
         """Check if an object is already in ADF format."""
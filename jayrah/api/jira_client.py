@@ -2,12 +2,35 @@
 
 import sqlite3
 import time
+from collections.abc import Iterator
+from datetime import datetime
 from typing import Any
 
 import click
 
-from ..utils import cache, log
-from . import auth, exceptions, formatters, request_handler
+from .. import config as jayrah_config
+from ..config import defaults
+from ..utils import cache, git, log, package_version
+from ..utils import jql as jql_parser
+from . import agile, auth, cassette, exceptions, formatters, models, request_handler
+
+# Fields copied to the clone of an issue
+CLONED_FIELDS = (
+    "summary",
+    "description",
+    "issuetype",
+    "priority",
+    "labels",
+    "components",
+    "fixVersions",
+)
+# The link type Jira itself uses between a clone and its original
+CLONE_LINK_TYPE = "Cloners"
+
+
+def user_agent() -> str:
+    """The User-Agent of the requests, for the Jira admins reading their logs."""
+    return f"jayrah/{package_version()} (+https://github.com/chmouel/jayrah)"
 
 
 class JiraHTTP:
@@ -24,7 +47,7 @@ class JiraHTTP:
         Args:
             config: Configuration dictionary with Jira settings
             api_version: Jira API version ("2" or "3")
-            auth_method: Authentication method ("basic" or "bearer")
+            auth_method: Authentication method ("basic", "bearer" or "oauth")
         """
         self.config = config
         self.api_version = api_version
@@ -36,8 +59,6 @@ class JiraHTTP:
             raise click.ClickException("jira_server not configured")
         server = server.rstrip("/")
 
-        self.base_url = f"{server}/rest/api/{api_version}"
-
         # Set up authentication
         if not auth_method:
             # Default to Bearer for v2, Basic for v3
@@ -45,17 +66,34 @@ class JiraHTTP:
 
         self.auth_method = auth_method  # Store for backward compatibility
         self.authenticator = auth.create_authenticator(config, auth_method)
+        refreshing_authenticator = None
+        if isinstance(
+            self.authenticator, (auth.OAuthAuthenticator, auth.CookieAuthenticator)
+        ):
+            # They get their credentials through the request handler, below
+            refreshing_authenticator = self.authenticator
+
+        self.base_url = f"{server}/rest/api/{api_version}"
         self.formatter = formatters.create_formatter(api_version)
 
         # Set up headers
         self.headers = {
             "Content-Type": "application/json",
             "Accept": "application/json",
+            "User-Agent": user_agent(),
         }
-        self.headers.update(self.authenticator.get_headers())
+        # Extra headers of gateways, the credentials take precedence over them
+        self.headers.update(
+            {k: str(v) for k, v in (config.get("headers") or {}).items() if v}
+        )
+        if refreshing_authenticator is None:
+            self.headers.update(self.authenticator.get_headers())
 
         # Set up cache and request handler
         cache_instance = cache.JiraCache(config)
+        retry_policy = request_handler.RetryPolicy.from_config(config)
+        recording = cassette.Cassette.from_config(config)
+        timeouts = request_handler.Timeouts.from_config(config)
         self.request_handler = request_handler.JiraRequestHandler(
             base_url=self.base_url,
             headers=self.headers,
@@ -63,7 +101,45 @@ class JiraHTTP:
             verbose=self.verbose,
             insecure=config.get("insecure", False),
             quiet=config.get("quiet", False),
+            retry_policy=retry_policy,
+            authenticator=refreshing_authenticator,
+            cassette=recording,
+            proxy=config.get("proxy"),
+            ca_bundle=config.get("ca_bundle"),
+            timeouts=timeouts,
         )
+        # The login and token requests go with the User-Agent and headers of
+        # the requests, through their proxy, certificates, timeouts and recording
+        if isinstance(self.authenticator, auth.CookieAuthenticator):
+            self.authenticator.send = self.request_handler.send
+        elif isinstance(self.authenticator, auth.OAuthAuthenticator):
+            self.authenticator.session.send = self.request_handler.send
+            # OAuth tokens only work through the Atlassian API gateway
+            server = self.authenticator.session.api_url
+            self.base_url = f"{server}/rest/api/{api_version}"
+            self.request_handler.base_url = self.base_url
+            self.headers.update(self.authenticator.get_headers())
+
+        # Boards and sprints live under their own API root
+        self.agile = agile.JiraAgile(
+            request_handler.JiraRequestHandler(
+                base_url=f"{server}/{agile.AGILE_API_PATH}",
+                headers=self.headers,
+                cache_instance=cache_instance,
+                verbose=self.verbose,
+                insecure=config.get("insecure", False),
+                quiet=config.get("quiet", False),
+                retry_policy=retry_policy,
+                authenticator=refreshing_authenticator,
+                cassette=recording,
+                proxy=config.get("proxy"),
+                ca_bundle=config.get("ca_bundle"),
+                timeouts=timeouts,
+            ),
+            verbose=self.verbose,
+        )
+        # The myself answer, looked up once for the watcher requests
+        self._myself: dict[str, Any] | None = None
 
         if self.verbose:
             log(
@@ -72,6 +148,16 @@ class JiraHTTP:
                 f"no_cache={config.get('no_cache')}, insecure={config.get('insecure', False)}"
             )
 
+    @classmethod
+    def from_profile(cls, config: dict[str, Any], name: str | None) -> "JiraHTTP":
+        """Create a client for a profile of the config, or without profile for None."""
+        config = jayrah_config.apply_profile(config, name)
+        return cls(
+            config,
+            api_version=config.get("api_version") or defaults.API_VERSION,
+            auth_method=config.get("auth_method") or None,
+        )
+
     @property
     def cache(self):
         """Backward compatibility property to access the cache instance."""
@@ -82,7 +168,7 @@ class JiraHTTP:
         method: str,
         endpoint: str,
         params: dict[str, Any] | None = None,
-        jeez: dict[str, Any] | None = None,
+        jeez: dict[str, Any] | str | None = None,
         label: str | None = None,
         use_cache: bool = True,
     ) -> dict[str, Any]:
@@ -115,6 +201,7 @@ class JiraHTTP:
         use_cache: bool = True,
     ) -> dict[str, Any]:
         """Search for issues using JQL."""
+        jql = self._resolve_current_user(jql)
         params = {"jql": jql, "startAt": start_at, "maxResults": max_results}
         if fields:
             params["fields"] = ",".join(fields)
@@ -138,10 +225,48 @@ class JiraHTTP:
             use_cache=use_cache,
         )
 
+    def search_issues_iter(
+        self,
+        jql: str,
+        page_size: int = 100,
+        fields: list[str] | None = None,
+        start_at: int = 0,
+        use_cache: bool = True,
+    ) -> Iterator[dict[str, Any]]:
+        """
+        Yield the search results of a JQL query one page at a time.
+
+        The next page is only requested once the previous one has been
+        consumed, so callers can show the first issues while the rest loads.
+        """
+        while True:
+            result = self.search_issues(
+                jql,
+                start_at=start_at,
+                max_results=page_size,
+                fields=fields,
+                use_cache=use_cache,
+            )
+            yield result
+
+            if not result.get("issues") or start_at + page_size >= result.get(
+                "total", 0
+            ):
+                return
+            start_at += page_size
+
     def get_fields(self) -> Any:
         """Get all available fields."""
         return self._request("GET", "field", label="Fetching fields")
 
+    def get_server_info(self) -> dict[str, Any]:
+        """Get the server version, deployment type and clock."""
+        return self._request("GET", "serverInfo", use_cache=False)
+
+    def verify_auth(self) -> dict[str, Any]:
+        """Return the logged in user, JiraAuthenticationError on bad credentials."""
+        return self._request("GET", "myself", use_cache=False)
+
     def create_issue(
         self,
         issuetype: str,
@@ -226,6 +351,86 @@ class JiraHTTP:
             "GET", f"issue/{issue_key}", params=params, use_cache=use_cache
         )
 
+    def clone_issue(
+        self,
+        issue_key: str,
+        overrides: dict[str, Any] | None = None,
+        link_type: str = CLONE_LINK_TYPE,
+    ) -> dict[str, Any]:
+        """Create a copy of an issue and link it to the original as cloned.
+
+        overrides replaces fields of the copy, like {"project": {"key": "NEW"}}.
+        Components and fix versions belong to a project, they are only kept
+        when the copy stays in the same one.
+        """
+        issue = self.get_issue(
+            issue_key, fields=[*CLONED_FIELDS, "project"], use_cache=False
+        )
+        fields = issue.get("fields") or {}
+        project = fields.get("project", {}).get("key")
+        new_fields: dict[str, Any] = {"project": {"key": project}}
+        for name in CLONED_FIELDS:
+            value = fields.get(name)
+            if not value:
+                continue
+            if name in ("issuetype", "priority"):
+                value = {"name": value["name"]}
+            elif name in ("components", "fixVersions"):
+                value = [{"name": x["name"]} for x in value]
+            new_fields[name] = value
+        new_fields.update(overrides or {})
+        if new_fields["project"].get("key") != project:
+            for name in ("components", "fixVersions"):
+                if name not in (overrides or {}):
+                    new_fields.pop(name, None)
+
+        if self.verbose:
+            log(f"Cloning {issue_key} into {new_fields['project'].get('key')}")
+
+        created = self._request("POST", "issue", jeez={"fields": new_fields})
+        try:
+            # The copy clones the original, inward is the side of the link verb
+            self.link_issues(created["key"], issue_key, link_type)
+        except exceptions.JiraAPIError as exc:
+            # The copy exists, a server without the link type should not fail it
+            if self.verbose:
+                log(f"Could not link {created['key']} to {issue_key}: {exc}", "WARNING")
+        return created
+
+    def move_issue_to_project(
+        self, issue_key: str, project: str, issue_type_id: str | None = None
+    ) -> dict[str, Any]:
+        """Move an issue to another project, only Jira Cloud (API v3) can.
+
+        The issue keeps its type unless issue_type_id is given, Jira picks the
+        defaults of the target project for the fields and the status. Returns
+        the task the move runs in.
+        """
+        if self.api_version != "3":
+            raise click.ClickException(
+                "Moving issues between projects needs API v3 (Jira Cloud)"
+            )
+        if not issue_type_id:
+            issue = self.get_issue(issue_key, fields=["issuetype"], use_cache=False)
+            issue_type_id = issue["fields"]["issuetype"]["id"]
+        payload = {
+            "sendBulkNotification": False,
+            "targetToSourcesMapping": {
+                f"{project},{issue_type_id}": {
+                    "issueIdsOrKeys": [issue_key],
+                    "inferClassificationDefaults": True,
+                    "inferFieldDefaults": True,
+                    "inferStatusDefaults": True,
+                    "inferSubtaskTypeDefault": True,
+                }
+            },
+        }
+
+        if self.verbose:
+            log(f"Moving {issue_key} to project {project}")
+
+        return self._request("POST", "bulk/issues/move", jeez=payload)
+
     def update_issue(self, issue_key: str, fields: dict[str, Any]) -> dict[str, Any]:
         """Update an existing issue's fields."""
         # Handle description formatting if present
@@ -243,22 +448,87 @@ class JiraHTTP:
 
         return self._request("PUT", f"issue/{issue_key}", jeez=payload)
 
-    def get_transitions(self, issue_key: str) -> dict[str, Any]:
-        """Get available transitions for an issue."""
+    def update_issue_priority(self, issue_key: str, name: str) -> dict[str, Any]:
+        """Change the priority of an issue, by priority name."""
+        return self.update_issue(issue_key, {"priority": {"name": name}})
+
+    def update_issue_type(self, issue_key: str, name: str) -> dict[str, Any]:
+        """Change the type of an issue, Jira refuses types with another workflow."""
+        return self.update_issue(issue_key, {"issuetype": {"name": name}})
+
+    def update_issue_duedate(
+        self, issue_key: str, due_date: str | None
+    ) -> dict[str, Any]:
+        """Set the due date of an issue (YYYY-MM-DD), None clears it."""
+        return self.update_issue(issue_key, {"duedate": due_date})
+
+    def update_issue_fix_versions(
+        self, issue_key: str, versions: list[str], mode: str = "set"
+    ) -> dict[str, Any]:
+        """Set the fix versions of an issue, or add or remove some of them.
+
+        Adding and removing leave the other versions alone, so they do not
+        undo a change made on the server since the issue was fetched.
+        """
+        if mode == "set":
+            payload: dict[str, Any] = {
+                "fields": {"fixVersions": [{"name": x} for x in versions]}
+            }
+        elif mode in ("add", "remove"):
+            payload = {
+                "update": {"fixVersions": [{mode: {"name": x}} for x in versions]}
+            }
+        else:
+            raise ValueError(f"Unknown fix versions mode: {mode}")
+
+        if self.verbose:
+            log(f"Updating fix versions of {issue_key} ({mode}): {versions}")
+
+        return self._request("PUT", f"issue/{issue_key}", jeez=payload)
+
+    def get_transitions(
+        self, issue_key: str, expand_fields: bool = False, use_cache: bool = True
+    ) -> dict[str, Any]:
+        """Get available transitions for an issue, with their screen fields."""
+        endpoint = f"issue/{issue_key}/transitions"
+        if expand_fields:
+            return self._request(
+                "GET",
+                endpoint,
+                params={"expand": "transitions.fields"},
+                label="All transitions",
+                use_cache=use_cache,
+            )
         return self._request(
-            "GET", f"issue/{issue_key}/transitions", label="All transitions"
+            "GET", endpoint, label="All transitions", use_cache=use_cache
         )
 
-    def transition_issue(self, issue_key: str, transition_id: str) -> dict[str, Any]:
-        """Transition an issue to a new status."""
-        payload = {"transition": {"id": transition_id}}
+    def transition_issue(
+        self,
+        issue_key: str,
+        transition_id: str,
+        transition_fields: models.TransitionFields | None = None,
+    ) -> dict[str, Any]:
+        """Transition an issue to a new status, filling its transition screen."""
+        payload: dict[str, Any] = {"transition": {"id": transition_id}}
+        if transition_fields:
+            fields = dict(transition_fields.fields)
+            if transition_fields.resolution:
+                fields["resolution"] = {"name": transition_fields.resolution}
+            if fields:
+                payload["fields"] = fields
+            if transition_fields.comment:
+                body = self.formatter.format_comment(transition_fields.comment)["body"]
+                payload["update"] = {"comment": [{"add": {"body": body}}]}
 
         if self.verbose:
             log(f"Transitioning issue: {issue_key} with transition ID: {transition_id}")
 
         return self._request("POST", f"issue/{issue_key}/transitions", jeez=payload)
 
-    def add_comment(self, issue_key: str, comment: str) -> dict[str, Any]:
+    def add_comment(
+        self, issue_key: str, comment: str | dict[str, Any]
+    ) -> dict[str, Any]:
         """Add a comment to an issue."""
         payload = self.formatter.format_comment(comment)
 
@@ -267,6 +537,184 @@ class JiraHTTP:
 
         return self._request("POST", f"issue/{issue_key}/comment", jeez=payload)
 
+    def get_issue_attachments(self, issue_key: str) -> list[models.Attachment]:
+        """Get the files attached to an issue."""
+        issue = self.get_issue(issue_key, fields=["attachment"], use_cache=False)
+        return models.issue_attachments(issue.get("fields", {}))
+
+    def download_attachment(self, attachment: models.Attachment) -> bytes:
+        """The content of an attachment, up to ATTACHMENT_PREVIEW_SIZE."""
+        if self.verbose:
+            log(f"Downloading attachment: {attachment.filename}")
+        return self.request_handler.download(
+            attachment.content, defaults.ATTACHMENT_PREVIEW_SIZE
+        )
+
+    def get_issue_comments(
+        self,
+        issue_key: str,
+        start_at: int = 0,
+        max_results: int = defaults.COMMENTS_PAGE_SIZE,
+        newest_first: bool = False,
+        use_cache: bool = True,
+    ) -> dict[str, Any]:
+        """Get a page of the comments of an issue, with the total to page further."""
+        params = {
+            "startAt": start_at,
+            "maxResults": max_results,
+            "orderBy": "-created" if newest_first else "created",
+        }
+        return self._request(
+            "GET", f"issue/{issue_key}/comment", params=params, use_cache=use_cache
+        )
+
+    def get_issue_changelog(
+        self, issue_key: str, use_cache: bool = True
+    ) -> list[dict[str, Any]]:
+        """The change histories of an issue, oldest first."""
+        if self.api_version != "3":
+            # API v2 only sends the changelog expanded in the issue
+            issue = self._request(
+                "GET",
+                f"issue/{issue_key}",
+                params={"expand": "changelog", "fields": "summary"},
+                use_cache=use_cache,
+            )
+            return (issue.get("changelog") or {}).get("histories") or []
+
+        histories: list[dict[str, Any]] = []
+        while True:
+            page = self._request(
+                "GET",
+                f"issue/{issue_key}/changelog",
+                params={"startAt": len(histories), "maxResults": 100},
+                use_cache=use_cache,
+            )
+            values = page.get("values") or []
+            histories += values
+            if page.get("isLast", True) or not values:
+                return histories
+
+    def get_all_issue_comments(
+        self, issue_key: str, newest_first: bool = False, use_cache: bool = True
+    ) -> list[dict[str, Any]]:
+        """Get the whole comment thread of an issue, one page after the other."""
+        comments: list[dict[str, Any]] = []
+        while True:
+            page = self.get_issue_comments(
+                issue_key,
+                start_at=len(comments),
+                newest_first=newest_first,
+                use_cache=use_cache,
+            )
+            batch = page.get("comments") or []
+            comments.extend(batch)
+            if not batch or len(comments) >= page.get("total", 0):
+                return comments
+
+    def get_issue_links(self, issue_key: str) -> list[dict[str, Any]]:
+        """Get the links of an issue to other issues."""
+        issue = self.get_issue(issue_key, fields=["issuelinks"], use_cache=False)
+        return issue.get("fields", {}).get("issuelinks", [])
+
+    def link_issues(
+        self, inward_issue: str, outward_issue: str, link_type: str
+    ) -> dict[str, Any]:
+        """Link two issues, link_type is the link type name (e.g. Blocks)."""
+        payload = {
+            "type": {"name": link_type},
+            "inwardIssue": {"key": inward_issue},
+            "outwardIssue": {"key": outward_issue},
+        }
+
+        if self.verbose:
+            log(f"Linking {inward_issue} to {outward_issue} with {link_type}")
+
+        return self._request("POST", "issueLink", jeez=payload)
+
+    def get_remote_links(self, issue_key: str) -> list[dict[str, Any]]:
+        """Get the web links (pull requests, documents...) attached to an issue."""
+        return self._request("GET", f"issue/{issue_key}/remotelink", use_cache=False)
+
+    def add_remote_link(
+        self, issue_key: str, url: str, title: str | None = None
+    ) -> dict[str, Any]:
+        """Attach a web link to an issue, titled with its URL by default."""
+        payload = {"object": {"url": url, "title": title or url}}
+
+        if self.verbose:
+            log(f"Adding remote link {url} to issue: {issue_key}")
+
+        return self._request("POST", f"issue/{issue_key}/remotelink", jeez=payload)
+
+    def add_pull_request_link(
+        self, issue_key: str, url: str, title: str | None = None, merged: bool = False
+    ) -> dict[str, Any]:
+        """
+        Attach a pull or merge request to an issue with the icon of its forge.
+
+        The URL is the global id of the link, attaching it again updates the
+        link, like its merged status, instead of adding a second one. Raises
+        ValueError for a URL that isn't a GitHub, GitLab or Bitbucket request.
+        """
+        info = git.pull_request_info(url)
+        if info is None:
+            raise ValueError(f"Not a pull or merge request URL: {url}")
+        icon = {
+            "url16x16": f"https://{info['host']}/favicon.ico",
+            "title": info["forge"],
+        }
+        payload = {
+            "globalId": url,
+            "application": {"type": info["forge"].lower(), "name": info["forge"]},
+            "relationship": "pull request",
+            "object": {
+                "url": url,
+                "title": title or f"{info['repo']}#{info['number']}",
+                "icon": icon,
+                "status": {"resolved": merged},
+            },
+        }
+
+        if self.verbose:
+            log(f"Adding pull request {url} to issue: {issue_key}")
+
+        return self._request("POST", f"issue/{issue_key}/remotelink", jeez=payload)
+
+    def get_link_types(self) -> list[dict[str, Any]]:
+        """Get the issue link types available on the server."""
+        response = self._request("GET", "issueLinkType")
+        return response.get("issueLinkTypes", [])
+
+    def get_issue_worklogs(
+        self, issue_key: str, use_cache: bool = True
+    ) -> list[dict[str, Any]]:
+        """Get the worklogs logged against an issue."""
+        response = self._request(
+            "GET", f"issue/{issue_key}/worklog", use_cache=use_cache
+        )
+        return response.get("worklogs", [])
+
+    def add_worklog(
+        self,
+        issue_key: str,
+        time_spent: str,
+        started: str | None = None,
+        comment: str | None = None,
+    ) -> dict[str, Any]:
+        """Log time (Jira duration like 2h or 30m) against an issue."""
+        if not started:
+            # Jira wants milliseconds and a numeric timezone offset
+            started = datetime.now().astimezone().strftime("%Y-%m-%dT%H:%M:%S.000%z")
+        payload: dict[str, Any] = {"timeSpent": time_spent, "started": started}
+        if comment:
+            payload["comment"] = self.formatter.format_comment(comment)["body"]
+
+        if self.verbose:
+            log(f"Adding worklog of {time_spent} to issue: {issue_key}")
+
+        return self._request("POST", f"issue/{issue_key}/worklog", jeez=payload)
+
     def get_issue_types(self, use_cache: bool = True) -> dict[str, str]:
         """Get all available issue types for the project.
 
@@ -537,13 +985,7 @@ class JiraHTTP:
     def _get_global_priorities(self) -> list[str]:
         """Get global priorities as a fallback."""
         try:
-            priorities = self.get_priorities()
-            if isinstance(priorities, list):
-                return [
-                    str(p.get("name"))
-                    for p in priorities
-                    if isinstance(p, dict) and p.get("name")
-                ]
+            return self.list_priorities()
         except Exception:
             pass
         return []
@@ -552,12 +994,148 @@ class JiraHTTP:
         """Get all available priorities."""
         return self._request("GET", "priority", label="Fetching priorities")
 
+    def list_priorities(self) -> list[str]:
+        """Names of the priorities of the server, highest first."""
+        priorities = self.get_priorities()
+        if not isinstance(priorities, list):
+            return []
+        return [
+            str(p.get("name"))
+            for p in priorities
+            if isinstance(p, dict) and p.get("name")
+        ]
+
+    def list_projects(self) -> list[dict[str, Any]]:
+        """The projects the user can browse, sorted by key."""
+        if self.api_version != "3":
+            projects = self._request("GET", "project", label="Fetching projects")
+        else:
+            # API v3 only pages through them with project/search
+            projects = []
+            start_at = 0
+            while True:
+                response = self._request(
+                    "GET",
+                    "project/search",
+                    params={"startAt": start_at, "maxResults": 50},
+                    label="Fetching projects",
+                )
+                values = response.get("values") or []
+                projects += values
+                if response.get("isLast", True) or not values:
+                    break
+                start_at += len(values)
+        return sorted(projects or [], key=lambda x: x.get("key", ""))
+
+    def list_project_components(self, project: str) -> list[str]:
+        """Names of the components of a project."""
+        response = self._request(
+            "GET", f"project/{project}/components", label="Fetching components"
+        )
+        return sorted(x["name"] for x in response or [] if x.get("name"))
+
+    def list_project_versions(
+        self, project: str, include_archived: bool = False
+    ) -> list[str]:
+        """Names of the versions of a project, unreleased ones first."""
+        response = self._request(
+            "GET", f"project/{project}/versions", label="Fetching versions"
+        )
+        versions = [
+            x
+            for x in response or []
+            if x.get("name") and (include_archived or not x.get("archived"))
+        ]
+        # Jira sorts them oldest first, the ones still to ship matter more
+        versions.sort(key=lambda x: bool(x.get("released")))
+        return [x["name"] for x in versions]
+
     def get_users(self) -> dict[str, Any]:
         """Get all available users."""
         return self._request(
             "GET", "user/search", params={"maxResults": 1000}, label="Fetching users"
         )
 
+    def search_users(self, query: str, max_results: int = 20) -> list[dict[str, Any]]:
+        """Search users by name, username or email."""
+        # Jira Cloud dropped the username parameter in favour of query
+        param = "query" if self.api_version == "3" else "username"
+        return self._request(
+            "GET", "user/search", params={param: query, "maxResults": max_results}
+        )
+
+    def assign_issue(self, issue_key: str, assignee: str | None) -> dict[str, Any]:
+        """Assign an issue to a user (accountId or username), None unassigns it."""
+        if assignee:
+            payload: dict[str, Any] = self.formatter.format_assignee(assignee)
+        elif self.api_version == "3":
+            payload = {"accountId": None}
+        else:
+            payload = {"name": None}
+
+        if self.verbose:
+            log(f"Assigning issue: {issue_key} to {assignee or 'nobody'}")
+
+        return self._request("PUT", f"issue/{issue_key}/assignee", jeez=payload)
+
+    def _resolve_current_user(self, jql: str) -> str:
+        """
+        The JQL with currentUser() replaced by the user /myself gives.
+
+        Only with the resolve_current_user option, the cached searches of the
+        users of a server are then told apart. Queries that cannot be parsed
+        are left for Jira to report.
+        """
+        if not self.config.get("resolve_current_user"):
+            return jql
+        if "currentuser" not in jql.lower():
+            return jql
+        try:
+            return jql_parser.replace_current_user(jql, self._current_user())
+        except jql_parser.JqlSyntaxError:
+            return jql
+
+    def _current_user(self) -> str:
+        """accountId (API v3) or username (API v2) of the logged in user."""
+        if self._myself is None:
+            self._myself = self.verify_auth()
+        key = "accountId" if self.api_version == "3" else "name"
+        return self._myself.get(key, "")
+
+    def get_watchers(self, issue_key: str) -> dict[str, Any]:
+        """Get the watchers of an issue with watchCount and isWatching."""
+        return self._request("GET", f"issue/{issue_key}/watchers", use_cache=False)
+
+    def add_watcher(self, issue_key: str, user: str | None = None) -> dict[str, Any]:
+        """Make a user (accountId or username) watch an issue, by default myself."""
+        user = user or self._current_user()
+        if self.verbose:
+            log(f"Adding watcher {user} to issue: {issue_key}")
+        # Jira wants the user alone as a JSON string
+        return self._request("POST", f"issue/{issue_key}/watchers", jeez=user)
+
+    def remove_watcher(self, issue_key: str, user: str | None = None) -> dict[str, Any]:
+        """Stop a user (accountId or username) watching an issue, by default myself."""
+        user = user or self._current_user()
+        param = "accountId" if self.api_version == "3" else "username"
+        if self.verbose:
+            log(f"Removing watcher {user} from issue: {issue_key}")
+        return self._request(
+            "DELETE", f"issue/{issue_key}/watchers", params={param: user}
+        )
+
+    def get_votes(self, issue_key: str) -> dict[str, Any]:
+        """Get the votes of an issue with the vote count and hasVoted."""
+        return self._request("GET", f"issue/{issue_key}/votes", use_cache=False)
+
+    def vote(self, issue_key: str) -> dict[str, Any]:
+        """Vote for an issue."""
+        return self._request("POST", f"issue/{issue_key}/votes")
+
+    def unvote(self, issue_key: str) -> dict[str, Any]:
+        """Remove my vote for an issue."""
+        return self._request("DELETE", f"issue/{issue_key}/votes")
+
     def get_labels(self, max_results: int = 100) -> list[str]:
         """Get all available labels."""
         jql = f"project = {self.config.get('jira_project')}"
@@ -574,6 +1152,28 @@ class JiraHTTP:
 
         return sorted(labels)
 
+    def suggest_labels(
+        self, prefix: str, max_results: int = defaults.LABEL_SUGGESTIONS
+    ) -> list[str]:
+        """
+        Labels starting with a prefix, from the JQL autocomplete of Jira.
+
+        Servers without the autocomplete get the labels of the project issues.
+        """
+        try:
+            response = self._request(
+                "GET",
+                "jql/autocompletedata/suggestions",
+                params={"fieldName": "labels", "fieldValue": prefix},
+            )
+        except exceptions.JiraNotFoundError:
+            folded = prefix.casefold()
+            labels = [x for x in self.get_labels() if x.casefold().startswith(folded)]
+        else:
+            results = response.get("results", [])
+            labels = [x["value"] for x in results if x.get("value")]
+        return labels[:max_results]
+
     def get_components(self, max_results: int = 100) -> list[str]:
         """Get all available components."""
         jql = f"project = {self.config.get('jira_project')}"
@@ -601,6 +1201,42 @@ class JiraHTTP:
         }
         return self._request("GET", "issue/createmeta", params=params)
 
+    def get_create_meta(self, project: str, issue_type: str) -> dict[str, Any]:
+        """Fields of the create screen of an issue type, by field id.
+
+        Jira 9 and Cloud list them per issue type, older servers only have the
+        legacy createmeta endpoint so it is used as a fallback.
+        """
+        issue_type_id = self.get_issue_types(use_cache=True).get(issue_type)
+        if issue_type_id:
+            try:
+                response = self._request(
+                    "GET",
+                    f"issue/createmeta/{project}/issuetypes/{issue_type_id}",
+                    label=f"Fetching {issue_type} metadata",
+                )
+                values = response.get("values") or response.get("fields") or []
+                if values:
+                    return {x["fieldId"]: x for x in values if x.get("fieldId")}
+            except exceptions.JiraNotFoundError:
+                pass
+
+        meta = self.get_createmeta(project, issue_type)
+        for project_meta in meta.get("projects") or []:
+            if project_meta.get("key") != project:
+                continue
+            for issuetype_meta in project_meta.get("issuetypes") or []:
+                if issuetype_meta.get("name") == issue_type:
+                    return issuetype_meta.get("fields") or {}
+        return {}
+
+    def get_edit_meta(self, issue_key: str) -> dict[str, Any]:
+        """Fields that can be edited on an issue with their allowed values."""
+        response = self._request(
+            "GET", f"issue/{issue_key}/editmeta", label="Fetching edit metadata"
+        )
+        return response.get("fields") or {}
+
     def get_cache_stats(self) -> dict[str, Any]:
         """Get statistics about the SQLite cache usage."""
         if self.verbose:
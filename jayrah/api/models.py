@@ -0,0 +1,454 @@
+"""Plain data views of the Jira issues, comments and lists for scripts."""
+
+import datetime
+import json
+from dataclasses import asdict, dataclass, field, fields
+from typing import Any
+
+import yaml
+
+from ..utils import adf
+
+
+def parse_time(text: str) -> datetime.datetime:
+    """A time sent by Jira, the oldest time when it cannot be parsed."""
+    try:
+        return datetime.datetime.strptime(text, "%Y-%m-%dT%H:%M:%S.%f%z")
+    except ValueError:
+        return datetime.datetime.min.replace(tzinfo=datetime.timezone.utc)
+
+
+def _name(value: dict | None, key: str = "name") -> str | None:
+    return value.get(key) if isinstance(value, dict) else None
+
+
+def _text(value: Any) -> str:
+    """Text of a description or comment body, API v3 sends them as ADF."""
+    if isinstance(value, dict):
+        return adf.extract_text_from_adf(value)
+    return value or ""
+
+
+@dataclass
+class IssueComment:
+    id: str
+    author: str | None
+    created: str | None
+    updated: str | None
+    body: str
+
+    @classmethod
+    def from_api(cls, comment: dict[str, Any]) -> "IssueComment":
+        return cls(
+            id=str(comment.get("id", "")),
+            author=_name(comment.get("author"), "displayName"),
+            created=comment.get("created"),
+            updated=comment.get("updated"),
+            body=_text(comment.get("body")),
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+@dataclass
+class ListIssue:
+    """The fields shown in the issue list."""
+
+    key: str
+    summary: str
+    issuetype: str | None
+    status: str | None
+    status_category: str | None
+    priority: str | None
+    assignee: str | None
+    reporter: str | None
+    labels: list[str]
+    created: str | None
+    updated: str | None
+
+    @classmethod
+    def _fields_from_api(cls, issue: dict[str, Any]) -> dict[str, Any]:
+        fields = issue.get("fields") or {}
+        return {
+            "key": issue.get("key", ""),
+            "summary": fields.get("summary", ""),
+            "issuetype": _name(fields.get("issuetype")),
+            "status": _name(fields.get("status")),
+            "status_category": status_category(fields),
+            "priority": _name(fields.get("priority")),
+            "assignee": _name(fields.get("assignee"), "displayName"),
+            "reporter": _name(fields.get("reporter"), "displayName"),
+            "labels": list(fields.get("labels") or []),
+            "created": fields.get("created"),
+            "updated": fields.get("updated"),
+        }
+
+    @classmethod
+    def from_api(cls, issue: dict[str, Any]) -> "ListIssue":
+        return cls(**cls._fields_from_api(issue))
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+@dataclass
+class DetailIssue(ListIssue):
+    """An issue with its description and comments."""
+
+    url: str = ""
+    description: str = ""
+    due_date: str | None = None
+    components: list[str] = field(default_factory=list)
+    fix_versions: list[str] = field(default_factory=list)
+    comments: list[IssueComment] = field(default_factory=list)
+
+    @classmethod
+    def from_api(cls, issue: dict[str, Any], server: str = "") -> "DetailIssue":
+        fields = issue.get("fields") or {}
+        comments = (fields.get("comment") or {}).get("comments") or []
+        url = f"{server.rstrip('/')}/browse/{issue.get('key', '')}" if server else ""
+        return cls(
+            **cls._fields_from_api(issue),
+            url=url,
+            description=_text(fields.get("description")),
+            due_date=fields.get("duedate"),
+            components=[x["name"] for x in fields.get("components") or []],
+            fix_versions=[x["name"] for x in fields.get("fixVersions") or []],
+            comments=[IssueComment.from_api(x) for x in comments],
+        )
+
+
+def status_category(fields: dict[str, Any]) -> str | None:
+    """
+    Category of the status of an issue: new, indeterminate or done.
+
+    Unlike the status names the categories don't change with the workflow.
+    """
+    return _name((fields.get("status") or {}).get("statusCategory"), "key")
+
+
+def is_overdue(fields: dict[str, Any], today: datetime.date | None = None) -> bool:
+    """Whether an unresolved issue is past its due date."""
+    due_date = fields.get("duedate")
+    if not due_date or fields.get("resolution"):
+        return False
+    today = today or datetime.date.today()
+    return datetime.date.fromisoformat(due_date[:10]) < today
+
+
+@dataclass
+class ActivityEvent:
+    """A comment, status change or assignment of an issue."""
+
+    issue_key: str
+    kind: str  # comment, transition or assignment
+    created: str
+    author: str | None
+    text: str
+
+    @property
+    def created_at(self) -> datetime.datetime:
+        """When the event happened, the oldest time when it cannot be parsed."""
+        return parse_time(self.created)
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+def issue_activity(
+    issue_key: str, histories: list[dict[str, Any]], comments: list[dict[str, Any]]
+) -> list[ActivityEvent]:
+    """The comments, transitions and assignments of an issue, newest first."""
+    events = [
+        ActivityEvent(
+            issue_key=issue_key,
+            kind="comment",
+            created=comment.get("created") or "",
+            author=_name(comment.get("author"), "displayName"),
+            # The first line is enough to tell what the comment is about
+            text=next(iter(_text(comment.get("body")).strip().splitlines()), ""),
+        )
+        for comment in comments
+    ]
+    for history in histories:
+        for item in history.get("items") or []:
+            if item.get("field") == "status":
+                kind = "transition"
+                text = f"{item.get('fromString')} → {item.get('toString')}"
+            elif item.get("field") == "assignee":
+                kind = "assignment"
+                text = f"assigned to {item.get('toString') or 'nobody'}"
+            else:
+                continue
+            events.append(
+                ActivityEvent(
+                    issue_key=issue_key,
+                    kind=kind,
+                    created=history.get("created") or "",
+                    author=_name(history.get("author"), "displayName"),
+                    text=text,
+                )
+            )
+    return sort_activity(events)
+
+
+def sort_activity(events: list[ActivityEvent]) -> list[ActivityEvent]:
+    """Events of one or more issues, newest first."""
+    return sorted(events, key=lambda x: x.created_at, reverse=True)
+
+
+def time_in_status(
+    fields: dict[str, Any],
+    histories: list[dict[str, Any]],
+    now: datetime.datetime | None = None,
+) -> dict[str, datetime.timedelta]:
+    """
+    How long an issue spent in each of its statuses, from its changelog.
+
+    The statuses come in the order the issue first got into them, the time
+    in the current status counts until now. Empty when the creation time of
+    the issue is unknown.
+    """
+    if not fields.get("created"):
+        return {}
+    now = now or datetime.datetime.now().astimezone()
+    changes = sorted(
+        (
+            (parse_time(history.get("created") or ""), item)
+            for history in histories
+            for item in history.get("items") or []
+            if item.get("field") == "status"
+        ),
+        key=lambda x: x[0],
+    )
+    # Before its first transition the issue was in the status it moved from
+    status = changes[0][1].get("fromString") if changes else None
+    status = status or _name(fields.get("status")) or ""
+    since = parse_time(fields["created"])
+    durations: dict[str, datetime.timedelta] = {}
+    for when, item in changes:
+        durations[status] = durations.get(status, datetime.timedelta()) + max(
+            when - since, datetime.timedelta()
+        )
+        status, since = item.get("toString") or "", when
+    durations[status] = durations.get(status, datetime.timedelta()) + max(
+        now - since, datetime.timedelta()
+    )
+    return durations
+
+
+@dataclass
+class TransitionFields:
+    """What the transition screen of a workflow asks for, fields by field id."""
+
+    resolution: str | None = None
+    comment: str | None = None
+    fields: dict[str, Any] = field(default_factory=dict)
+
+
+def required_transition_fields(transition: dict[str, Any]) -> dict[str, Any]:
+    """
+    Fields a transition cannot be done without.
+
+    Only known when the transitions were fetched with their fields, the
+    fields with a default value are left out as Jira fills them itself.
+    """
+    return {
+        field_id: meta
+        for field_id, meta in (transition.get("fields") or {}).items()
+        if meta.get("required") and not meta.get("hasDefaultValue")
+    }
+
+
+def allowed_values(meta: dict[str, Any]) -> list[str]:
+    """Names of the values a field accepts, empty when it takes free text."""
+    return [
+        value.get("name") or value.get("value") or str(value.get("id", ""))
+        for value in meta.get("allowedValues") or []
+    ]
+
+
+def match_allowed_value(meta: dict[str, Any], text: str) -> str | None:
+    """The allowed value matching text case insensitively, None if none does."""
+    return next(
+        (x for x in allowed_values(meta) if x.lower() == text.strip().lower()), None
+    )
+
+
+@dataclass
+class Transition:
+    """A transition of an issue with the status it goes to."""
+
+    id: str
+    name: str
+    to_status: str | None
+    to_category: str | None
+    required_fields: list[str]
+
+    @classmethod
+    def from_api(cls, transition: dict[str, Any]) -> "Transition":
+        to = transition.get("to") or {}
+        return cls(
+            id=str(transition.get("id", "")),
+            name=transition.get("name", ""),
+            to_status=_name(to),
+            to_category=status_category({"status": to}),
+            required_fields=list(required_transition_fields(transition)),
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+@dataclass
+class Attachment:
+    """A file attached to an issue, content is the URL to download it from."""
+
+    id: str
+    filename: str
+    mime_type: str
+    size: int
+    author: str | None
+    created: str | None
+    content: str
+
+    @classmethod
+    def from_api(cls, attachment: dict[str, Any]) -> "Attachment":
+        return cls(
+            id=str(attachment.get("id", "")),
+            filename=attachment.get("filename", ""),
+            mime_type=attachment.get("mimeType", ""),
+            size=int(attachment.get("size") or 0),
+            author=_name(attachment.get("author"), "displayName"),
+            created=attachment.get("created"),
+            content=attachment.get("content", ""),
+        )
+
+    @property
+    def is_image(self) -> bool:
+        return self.mime_type.startswith("image/")
+
+    @property
+    def human_size(self) -> str:
+        size = float(self.size)
+        for unit in ("B", "KB", "MB"):
+            if size < 1024:
+                return f"{size:.0f} {unit}" if unit == "B" else f"{size:.1f} {unit}"
+            size /= 1024
+        return f"{size:.1f} GB"
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+def issue_attachments(fields: dict[str, Any]) -> list[Attachment]:
+    """The attachments of an issue, the oldest first as Jira lists them."""
+    return [Attachment.from_api(x) for x in fields.get("attachment") or []]
+
+
+@dataclass
+class Board:
+    """A board of the config file."""
+
+    name: str
+    description: str
+    jql: str
+    order_by: str | None
+
+    @classmethod
+    def from_config(cls, board: dict[str, Any]) -> "Board":
+        return cls(
+            name=board.get("name", ""),
+            description=board.get("description", ""),
+            jql=(board.get("jql") or "").strip(),
+            order_by=board.get("order_by"),
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+@dataclass
+class CustomField:
+    """A custom field of the config file."""
+
+    name: str
+    field: str
+    type: str
+    description: str
+
+    @classmethod
+    def from_config(cls, custom_field: dict[str, Any]) -> "CustomField":
+        return cls(
+            name=custom_field.get("name", ""),
+            field=custom_field.get("field", ""),
+            type=custom_field.get("type", "string"),
+            description=custom_field.get("description", ""),
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+# Version of the payloads below, bumped when a key is renamed or removed so
+# the editor plugins can tell they talk to an incompatible jayrah
+PAYLOAD_VERSION = 1
+PAYLOADS: dict[str, type] = {
+    "list": ListIssue,
+    "show": DetailIssue,
+    "comments": IssueComment,
+    "transitions": Transition,
+    "boards": Board,
+    "custom-fields": CustomField,
+}
+
+
+def validate_payload(kind: str, data: dict[str, Any] | list[dict[str, Any]]) -> None:
+    """
+    Check a record, or a list of records, has the keys of its payload kind.
+
+    Raises ValueError naming the missing or unknown keys of the first record
+    that doesn't fit, so a plugin or a test catches a drifting contract early.
+    """
+    if kind not in PAYLOADS:
+        raise ValueError(f"Unknown payload: {kind}")
+    expected = {x.name for x in fields(PAYLOADS[kind])}
+    for record in data if isinstance(data, list) else [data]:
+        if not isinstance(record, dict):
+            raise ValueError(f"A {kind} payload must be an object")
+        if missing := expected - record.keys():
+            raise ValueError(f"Missing keys in {kind}: {', '.join(sorted(missing))}")
+        if unknown := record.keys() - expected:
+            raise ValueError(f"Unknown keys in {kind}: {', '.join(sorted(unknown))}")
+
+
+OUTPUT_FORMATS = ("json", "yaml", "tsv")
+
+
+def _tsv_value(value: Any) -> str:
+    """A TSV cell, lists of records are counted and lists of names joined."""
+    if value is None:
+        return ""
+    if isinstance(value, list):
+        if any(isinstance(x, dict) for x in value):
+            return str(len(value))
+        return ",".join(str(x) for x in value)
+    return " ".join(str(value).split())
+
+
+def serialize(data: dict[str, Any] | list[dict[str, Any]], output: str) -> str:
+    """Format a record or a list of records as json, yaml or tsv."""
+    if output == "json":
+        return json.dumps(data, indent=2, ensure_ascii=False)
+    if output == "yaml":
+        return yaml.safe_dump(data, sort_keys=False, allow_unicode=True).rstrip("\n")
+    if output == "tsv":
+        rows = data if isinstance(data, list) else [data]
+        if not rows:
+            return ""
+        columns = list(rows[0])
+        lines = ["\t".join(columns)]
+        lines += ["\t".join(_tsv_value(row.get(x)) for x in columns) for row in rows]
+        return "\n".join(lines)
+    raise ValueError(f"Unknown output format: {output}")
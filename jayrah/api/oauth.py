@@ -0,0 +1,297 @@
+"""OAuth 2.0 (3LO) authorization for Jira Cloud."""
+
+import http.server
+import json
+import os
+import pathlib
+import secrets
+import threading
+import time
+import urllib.error
+import urllib.parse
+import urllib.request
+import webbrowser
+from collections.abc import Callable
+from typing import Any
+
+import click
+
+from ..utils import log
+
+AUTHORIZE_URL = "https://auth.atlassian.com/authorize"
+TOKEN_URL = "https://auth.atlassian.com/oauth/token"
+RESOURCES_URL = "https://api.atlassian.com/oauth/token/accessible-resources"
+# With OAuth the REST API is reached through the Atlassian gateway, not the site
+API_GATEWAY_URL = "https://api.atlassian.com/ex/jira/{cloud_id}"
+
+DEFAULT_SCOPES = "read:jira-work write:jira-work read:jira-user offline_access"
+DEFAULT_CALLBACK_PORT = 8976
+# The callback server only listens on the loopback, Atlassian redirects there
+CALLBACK_HOST = "127.0.0.1"
+DEFAULT_TOKEN_FILE = pathlib.Path.home() / ".config" / "jayrah" / "oauth_token.json"
+# Refresh the access token when it expires in less than that many seconds
+EXPIRY_MARGIN = 60
+AUTHORIZE_TIMEOUT = 300
+
+
+class OAuthError(click.ClickException):
+    """The OAuth authorization or a token refresh failed."""
+
+
+class _CallbackHandler(http.server.BaseHTTPRequestHandler):
+    """
+    Catch the redirect of the authorization page on the loopback.
+
+    Other requests, like the /favicon.ico of the browser, get a 404 and leave
+    the server waiting for the callback with the state of the flow.
+    """
+
+    def do_GET(self):  # pylint: disable=invalid-name
+        url = urllib.parse.urlparse(self.path)
+        params = {k: v[0] for k, v in urllib.parse.parse_qs(url.query).items()}
+        state = self.server.state  # type: ignore[attr-defined]
+        if url.path != "/callback" or params.get("state") != state:
+            self.send_response(404)
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+            return
+        self.server.callback_params = params  # type: ignore[attr-defined]
+        self.send_response(200)
+        self.send_header("Content-Type", "text/plain; charset=utf-8")
+        self.end_headers()
+        self.wfile.write("Jayrah is authorized, you can close this page.".encode())
+
+    def log_message(self, format, *args):  # pylint: disable=redefined-builtin
+        """Keep the terminal quiet."""
+
+
+class OAuthSession:
+    """
+    Get, store and refresh the OAuth tokens of an Atlassian OAuth 2.0 app.
+
+    Atlassian has no device flow, the first authorization opens the consent
+    page in a browser and catches the redirect on a loopback port. The app
+    callback URL has to be set to http://127.0.0.1:<port>/callback. Tokens are
+    kept in a file only readable by the user and refreshed when they expire.
+    """
+
+    def __init__(
+        self,
+        client_id: str,
+        client_secret: str,
+        server: str,
+        scopes: str = DEFAULT_SCOPES,
+        callback_port: int = DEFAULT_CALLBACK_PORT,
+        token_file: pathlib.Path = DEFAULT_TOKEN_FILE,
+        verbose: bool = False,
+    ):
+        if not client_id or not client_secret:
+            raise OAuthError(
+                "OAuth authentication requires oauth_client_id and oauth_client_secret"
+            )
+        self.client_id = client_id
+        self.client_secret = client_secret
+        self.server = server.rstrip("/")
+        self.scopes = scopes
+        self.callback_port = callback_port
+        self.token_file = pathlib.Path(token_file)
+        self.verbose = verbose
+        self._lock = threading.Lock()
+        self.token: dict[str, Any] = self._load_token()
+        # Set by the client to the send of its request handler, the token
+        # requests go through the same proxy, certificates and timeouts
+        self.send: (
+            Callable[[urllib.request.Request, bytes | None], tuple[int, Any]] | None
+        ) = None
+
+    @classmethod
+    def from_config(cls, config: dict[str, Any]) -> "OAuthSession":
+        return cls(
+            client_id=config.get("oauth_client_id", ""),
+            client_secret=config.get("oauth_client_secret", ""),
+            server=config.get("jira_server", ""),
+            scopes=config.get("oauth_scopes") or DEFAULT_SCOPES,
+            callback_port=int(
+                config.get("oauth_callback_port") or DEFAULT_CALLBACK_PORT
+            ),
+            token_file=config.get("oauth_token_file") or DEFAULT_TOKEN_FILE,
+            verbose=config.get("verbose", False),
+        )
+
+    @property
+    def redirect_uri(self) -> str:
+        return f"http://{CALLBACK_HOST}:{self.callback_port}/callback"
+
+    def _load_token(self) -> dict[str, Any]:
+        """Tokens saved for this app and site, empty when there are none."""
+        try:
+            tokens = json.loads(self.token_file.read_text())
+        except (OSError, ValueError):
+            return {}
+        return tokens.get(f"{self.client_id}|{self.server}", {})
+
+    def _save_token(self) -> None:
+        try:
+            tokens = json.loads(self.token_file.read_text())
+        except (OSError, ValueError):
+            tokens = {}
+        tokens[f"{self.client_id}|{self.server}"] = self.token
+        self.token_file.parent.mkdir(parents=True, exist_ok=True)
+        fd = os.open(self.token_file, os.O_WRONLY | os.O_CREAT | os.O_TRUNC, 0o600)
+        with os.fdopen(fd, "w") as file:
+            json.dump(tokens, file)
+
+    def authorization_url(self, state: str) -> str:
+        params = {
+            "audience": "api.atlassian.com",
+            "client_id": self.client_id,
+            "scope": self.scopes,
+            "redirect_uri": self.redirect_uri,
+            "state": state,
+            "response_type": "code",
+            "prompt": "consent",
+        }
+        return f"{AUTHORIZE_URL}?{urllib.parse.urlencode(params)}"
+
+    def _send(self, request: urllib.request.Request, data: bytes | None) -> Any:
+        if self.send is None:
+            raise RuntimeError("The OAuth session is not attached to a client")
+        return self.send(request, data)[1]
+
+    def _post(self, url: str, payload: dict[str, Any]) -> dict[str, Any]:
+        request = urllib.request.Request(
+            url, headers={"Content-Type": "application/json"}, method="POST"
+        )
+        try:
+            return self._send(request, json.dumps(payload).encode("utf-8"))
+        except urllib.error.HTTPError as e:
+            raise OAuthError(
+                f"OAuth token request failed: HTTP {e.code} {e.read().decode('utf-8')}"
+            ) from e
+        except urllib.error.URLError as e:
+            raise OAuthError(f"OAuth token request failed: {e}") from e
+        except TimeoutError as e:
+            raise OAuthError("OAuth token request timed out") from e
+
+    def _get(self, url: str, access_token: str) -> Any:
+        request = urllib.request.Request(
+            url,
+            headers={
+                "Authorization": f"Bearer {access_token}",
+                "Accept": "application/json",
+            },
+        )
+        try:
+            return self._send(request, None)
+        except urllib.error.URLError as e:
+            raise OAuthError(f"Cannot list the sites of the OAuth token: {e}") from e
+        except TimeoutError as e:
+            raise OAuthError("Listing the sites of the OAuth token timed out") from e
+
+    def _set_token(self, response: dict[str, Any]) -> None:
+        """Keep a token response, refresh tokens rotate but may not be resent."""
+        self.token = {
+            **self.token,
+            "access_token": response["access_token"],
+            "expires_at": time.time() + int(response.get("expires_in", 3600)),
+        }
+        if response.get("refresh_token"):
+            self.token["refresh_token"] = response["refresh_token"]
+        if not self.token.get("cloud_id"):
+            self.token["cloud_id"] = self._find_cloud_id(response["access_token"])
+        self._save_token()
+
+    def _find_cloud_id(self, access_token: str) -> str:
+        """Id of the Jira site of the configured server among the authorized ones."""
+        for resource in self._get(RESOURCES_URL, access_token):
+            if resource.get("url", "").rstrip("/") == self.server:
+                return resource["id"]
+        raise OAuthError(
+            f"The OAuth app has not been granted access to {self.server}, "
+            "select that site on the consent page"
+        )
+
+    def exchange_code(self, code: str) -> None:
+        """Trade the authorization code for tokens."""
+        self._set_token(
+            self._post(
+                TOKEN_URL,
+                {
+                    "grant_type": "authorization_code",
+                    "client_id": self.client_id,
+                    "client_secret": self.client_secret,
+                    "code": code,
+                    "redirect_uri": self.redirect_uri,
+                },
+            )
+        )
+
+    def refresh(self) -> None:
+        """Get a new access token with the refresh token."""
+        if not self.token.get("refresh_token"):
+            raise OAuthError("The OAuth token cannot be refreshed, authorize again")
+        if self.verbose:
+            log("Refreshing the OAuth access token")
+        self._set_token(
+            self._post(
+                TOKEN_URL,
+                {
+                    "grant_type": "refresh_token",
+                    "client_id": self.client_id,
+                    "client_secret": self.client_secret,
+                    "refresh_token": self.token["refresh_token"],
+                },
+            )
+        )
+
+    def authorize(self) -> None:
+        """Run the browser consent flow and catch the code on the loopback."""
+        state = secrets.token_urlsafe(16)
+        server = http.server.HTTPServer(
+            (CALLBACK_HOST, self.callback_port), _CallbackHandler
+        )
+        server.callback_params = {}  # type: ignore[attr-defined]
+        server.state = state  # type: ignore[attr-defined]
+
+        url = self.authorization_url(state)
+        click.echo(f"Opening the Jira authorization page, or visit:\n{url}")
+        webbrowser.open(url)
+        deadline = time.monotonic() + AUTHORIZE_TIMEOUT
+        try:
+            while not server.callback_params:  # type: ignore[attr-defined]
+                server.timeout = deadline - time.monotonic()
+                if server.timeout <= 0:
+                    break
+                server.handle_request()
+        finally:
+            server.server_close()
+
+        params = server.callback_params  # type: ignore[attr-defined]
+        if params.get("state") != state or not params.get("code"):
+            reason = params.get("error_description") or params.get("error")
+            raise OAuthError(
+                f"OAuth authorization failed: {reason or 'no code received'}"
+            )
+        self.exchange_code(params["code"])
+
+    def access_token(self) -> str:
+        """A valid access token, authorizing or refreshing first when needed."""
+        with self._lock:
+            if not self.token.get("access_token"):
+                # The consent flow prompts and waits on the terminal, a worker
+                # thread of the TUI cannot run it
+                if threading.current_thread() is not threading.main_thread():
+                    raise OAuthError(
+                        "Jira needs to be authorized again, quit and run a jayrah "
+                        "command like jayrah cli list to open the consent page"
+                    )
+                self.authorize()
+            elif self.token.get("expires_at", 0) - EXPIRY_MARGIN < time.time():
+                self.refresh()
+            return self.token["access_token"]
+
+    @property
+    def api_url(self) -> str:
+        """Root of the site through the API gateway, authorizing first if needed."""
+        self.access_token()
+        return API_GATEWAY_URL.format(cloud_id=self.token["cloud_id"])
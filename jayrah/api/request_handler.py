@@ -1,18 +1,200 @@
 """HTTP request handler for Jira API."""
 
+import collections
+import http.client
 import json
+import logging
+import os
+import random
 import ssl
 import sys
 import time
 import urllib.error
 import urllib.request
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from email.utils import parsedate_to_datetime
+from functools import partial
 from typing import Any
-from urllib.parse import urlencode
+from urllib.parse import urlencode, urljoin, urlsplit
 
 import click
 
+from ..config import defaults
 from ..utils import cache, log
 from . import exceptions
+from .auth import AuthenticatorBase
+from .cassette import Cassette
+
+logger = logging.getLogger(__name__)
+
+RETRYABLE_STATUS_CODES = {429, 500, 502, 503, 504}
+# A POST failing with a server error may still have been applied (e.g. an issue
+# created twice), those are only retried when rate limited.
+IDEMPOTENT_METHODS = {"GET", "PUT", "DELETE"}
+REDIRECT_STATUS_CODES = {301, 302, 303, 307, 308}
+# Redirects followed by a download before giving up
+MAX_DOWNLOAD_REDIRECTS = 5
+
+
+@dataclass
+class RetryPolicy:
+    """How requests failing with a transient HTTP error get retried."""
+
+    max_retries: int = defaults.MAX_RETRIES
+    backoff: float = defaults.RETRY_BACKOFF
+    jitter: float = defaults.RETRY_JITTER
+    max_delay: float = defaults.RETRY_MAX_DELAY
+
+    @classmethod
+    def from_config(cls, config: dict[str, Any]) -> "RetryPolicy":
+        """Build the policy from the max_retries and retry_* config options."""
+        policy = cls()
+        if config.get("max_retries") is not None:
+            policy.max_retries = int(config["max_retries"])
+        if config.get("retry_backoff") is not None:
+            policy.backoff = float(config["retry_backoff"])
+        if config.get("retry_jitter") is not None:
+            policy.jitter = float(config["retry_jitter"])
+        return policy
+
+    def should_retry(self, method: str, status_code: int, attempt: int) -> bool:
+        """Whether a request failing with status_code is worth another attempt."""
+        if attempt >= self.max_retries or status_code not in RETRYABLE_STATUS_CODES:
+            return False
+        return status_code == 429 or method.upper() in IDEMPOTENT_METHODS
+
+    def get_delay(self, attempt: int, retry_after: str | None = None) -> float:
+        """Seconds to wait before the next attempt, honoring a Retry-After header."""
+        if retry_after:
+            delay = parse_retry_after(retry_after)
+            if delay is not None:
+                return min(delay, self.max_delay)
+        delay = self.backoff * (2**attempt) + random.uniform(0, self.jitter)
+        return min(delay, self.max_delay)
+
+
+@dataclass
+class Timeouts:
+    """Seconds a request may take to connect, and to answer once connected."""
+
+    connect: float = defaults.CONNECT_TIMEOUT
+    read: float = defaults.READ_TIMEOUT
+    search: float = defaults.SEARCH_TIMEOUT
+
+    @classmethod
+    def from_config(cls, config: dict[str, Any]) -> "Timeouts":
+        """Build the timeouts from the timeout, *_timeout config options."""
+        timeouts = cls()
+        if config.get("timeout"):
+            timeouts.read = float(config["timeout"])
+        if config.get("connect_timeout"):
+            timeouts.connect = float(config["connect_timeout"])
+        if config.get("search_timeout"):
+            timeouts.search = float(config["search_timeout"])
+        return timeouts
+
+    def read_timeout(self, endpoint: str) -> float:
+        """The read timeout of a request, searches get at least the default one."""
+        if endpoint.split("/")[0] == "search":
+            return max(self.search, self.read)
+        return self.read
+
+
+class _ReadTimeoutMixin:
+    """A connection switching to the read timeout once it is connected."""
+
+    def __init__(self, *args, read_timeout: float | None = None, **kwargs):
+        super().__init__(*args, **kwargs)
+        self.read_timeout = read_timeout
+
+    def connect(self):
+        super().connect()  # type: ignore[misc]
+        if self.read_timeout is not None:
+            self.sock.settimeout(self.read_timeout)  # type: ignore[attr-defined]
+
+
+class _HTTPConnection(_ReadTimeoutMixin, http.client.HTTPConnection):
+    pass
+
+
+class _HTTPSConnection(_ReadTimeoutMixin, http.client.HTTPSConnection):
+    pass
+
+
+class _HTTPHandler(urllib.request.HTTPHandler):
+    def http_open(self, req):
+        read_timeout = getattr(req, "read_timeout", None)
+        return self.do_open(partial(_HTTPConnection, read_timeout=read_timeout), req)
+
+
+class _HTTPSHandler(urllib.request.HTTPSHandler):
+    def https_open(self, req):
+        read_timeout = getattr(req, "read_timeout", None)
+        return self.do_open(
+            partial(_HTTPSConnection, read_timeout=read_timeout),
+            req,
+            context=self._context,
+        )
+
+
+class _NoRedirectHandler(urllib.request.HTTPRedirectHandler):
+    """Answer the redirects as errors, for the caller to decide where to go."""
+
+    def redirect_request(self, req, fp, code, msg, headers, newurl):
+        return None
+
+
+@dataclass
+class RequestRecord:
+    """A request sent to Jira, as listed by the debug pane of the TUI."""
+
+    at: datetime
+    method: str
+    endpoint: str
+    status: int | None  # None when the server could not be reached
+    duration: float  # in milliseconds
+    error: str = ""
+
+
+# The latest requests of the session, appended from the worker threads as well
+RECENT_REQUESTS: collections.deque[RequestRecord] = collections.deque(
+    maxlen=defaults.RECENT_REQUESTS
+)
+
+
+def recent_requests() -> list[RequestRecord]:
+    """The latest requests sent to Jira, newest first."""
+    return list(reversed(RECENT_REQUESTS))
+
+
+def error_message(response_body: str, reason: str) -> str:
+    """The messages of a Jira error response, the HTTP reason without them."""
+    try:
+        body = json.loads(response_body)
+    except ValueError:
+        return reason
+    if not isinstance(body, dict):
+        return reason
+    messages = [
+        *(body.get("errorMessages") or []),
+        *(body.get("errors") or {}).values(),
+    ]
+    return "; ".join(str(x) for x in messages) or reason
+
+
+def parse_retry_after(value: str) -> float | None:
+    """Parse a Retry-After header, given either in seconds or as an HTTP date."""
+    value = value.strip()
+    if value.isdigit():
+        return float(value)
+    try:
+        retry_at = parsedate_to_datetime(value)
+    except (TypeError, ValueError):
+        return None
+    if retry_at.tzinfo is None:
+        retry_at = retry_at.replace(tzinfo=timezone.utc)
+    return max((retry_at - datetime.now(timezone.utc)).total_seconds(), 0.0)
 
 
 class JiraRequestHandler:
@@ -26,30 +208,70 @@ class JiraRequestHandler:
         verbose: bool = False,
         insecure: bool = False,
         quiet: bool = False,
+        retry_policy: RetryPolicy | None = None,
+        authenticator: AuthenticatorBase | None = None,
+        cassette: Cassette | None = None,
+        proxy: str | None = None,
+        ca_bundle: str | None = None,
+        timeouts: Timeouts | None = None,
     ):
         self.base_url = base_url
         self.headers = headers
+        # Set for credentials expiring, like OAuth tokens, to refresh the headers
+        self.authenticator = authenticator
         self.cache = cache_instance
         self.verbose = verbose
         self.insecure = insecure
+        # Without a proxy the HTTPS_PROXY and NO_PROXY variables are honored
+        self.proxy = proxy
+        self.ca_bundle = os.path.expanduser(ca_bundle) if ca_bundle else None
         self.quiet = quiet
+        self.retry_policy = retry_policy or RetryPolicy()
+        self.timeouts = timeouts or Timeouts()
+        # Records the requests to a file, or answers them from it, in tests
+        self.cassette = cassette
 
-        if self.insecure:
-            self._setup_insecure_ssl()
-
-    def _setup_insecure_ssl(self):
-        """Setup SSL context that doesn't verify certificates."""
-        context = ssl.create_default_context()
-        context.check_hostname = False
-        context.verify_mode = ssl.CERT_NONE
-
-        opener = urllib.request.build_opener(
-            urllib.request.HTTPSHandler(context=context)
-        )
-        urllib.request.install_opener(opener)
+        self._opener = self._build_opener()
+        self._download_opener = self._build_opener(_NoRedirectHandler())
 
-        if self.verbose:
-            log("WARNING: SSL certificate verification disabled")
+    def _ssl_context(self) -> ssl.SSLContext | None:
+        """The certificates checks of the requests, None for the default ones."""
+        if self.insecure:
+            context = ssl.create_default_context()
+            context.check_hostname = False
+            context.verify_mode = ssl.CERT_NONE
+            if self.verbose:
+                log("WARNING: SSL certificate verification disabled")
+            return context
+        if self.ca_bundle:
+            try:
+                return ssl.create_default_context(cafile=self.ca_bundle)
+            except (OSError, ssl.SSLError) as e:
+                raise click.ClickException(
+                    f"Cannot load the ca_bundle {self.ca_bundle}: {e}"
+                ) from e
+        return None
+
+    def _build_opener(
+        self, *extra: urllib.request.BaseHandler
+    ) -> urllib.request.OpenerDirector:
+        """
+        The opener sending the requests through the proxy, trusting the CA bundle.
+
+        It is kept by the handler rather than installed for the whole process,
+        each client has its own settings. The connections of the opener give
+        the read timeout of a request to its socket once connected, urllib
+        only has a single timeout.
+        """
+        handlers: list[urllib.request.BaseHandler] = [
+            _HTTPHandler(),
+            _HTTPSHandler(context=self._ssl_context()),
+        ]
+        if self.proxy:
+            handlers.append(
+                urllib.request.ProxyHandler({"http": self.proxy, "https": self.proxy})
+            )
+        return urllib.request.build_opener(*handlers, *extra)
 
     def _get_curl_command(
         self,
@@ -57,13 +279,17 @@ class JiraRequestHandler:
         url: str,
         headers: dict[str, str],
         params: dict[str, Any] | None = None,
-        json_data: dict[str, Any] | None = None,
+        json_data: dict[str, Any] | str | None = None,
     ) -> str:
         """Generate an equivalent curl command for debugging purposes."""
         curl_parts = [f"curl -X {method}"]
 
         if self.insecure:
             curl_parts.append("-k")
+        elif self.ca_bundle:
+            curl_parts.append(f"--cacert '{self.ca_bundle}'")
+        if self.proxy:
+            curl_parts.append(f"--proxy '{self.proxy}'")
 
         for key, value in headers.items():
             if key == "Authorization":
@@ -90,7 +316,7 @@ class JiraRequestHandler:
         method: str,
         endpoint: str,
         params: dict[str, Any] | None = None,
-        json_data: dict[str, Any] | None = None,
+        json_data: dict[str, Any] | str | None = None,
         label: str | None = None,
         use_cache: bool = True,
     ) -> dict[str, Any]:
@@ -115,12 +341,17 @@ class JiraRequestHandler:
             if cached_response:
                 if self.verbose:
                     log("Using cached response from SQLite database")
+                logger.debug("%s %s from the cache", method, endpoint)
                 return cached_response
 
             if self.verbose:
                 log(f"No cache found for: {url}")
 
+        started = time.monotonic()
         try:
+            if self.authenticator is not None:
+                self.headers.update(self.authenticator.get_headers())
+
             if self.verbose:
                 log(f"Sending request to {url}...")
                 curl_cmd = self._get_curl_command(
@@ -137,6 +368,9 @@ class JiraRequestHandler:
 
             # Prepare the request
             request = urllib.request.Request(full_url, method=method)
+            # Given to the socket by the connections of the opener
+            read_timeout = self.timeouts.read_timeout(endpoint)
+            request.read_timeout = read_timeout  # type: ignore[attr-defined]
 
             # Add headers
             for key, value in self.headers.items():
@@ -148,7 +382,9 @@ class JiraRequestHandler:
                 data = json.dumps(json_data).encode("utf-8")
 
             # Send the request
-            response_data = self._send_request(request, data, label)
+            status_code, response_data = self._send_with_retries(request, data, label)
+            duration = self._record(method, endpoint, status_code, started)
+            logger.info("%s %s: %s in %.0fms", method, endpoint, status_code, duration)
 
             # Cache the response for GET requests
             if method.upper() == "GET":
@@ -165,9 +401,21 @@ class JiraRequestHandler:
             if self.verbose:
                 log(f"HTTP error occurred: {status_code} {e.reason}")
                 log(f"Response: {response_body}")
-
-            # Add delay before potentially retrying to avoid rate limiting
-            time.sleep(0.5)
+            self._record(
+                method,
+                endpoint,
+                status_code,
+                started,
+                error_message(response_body, str(e.reason)),
+            )
+            logger.warning(
+                "%s %s: %s %s %s",
+                method,
+                endpoint,
+                status_code,
+                e.reason,
+                response_body[:500],
+            )
 
             # Raise specific exceptions based on status code
             if status_code == 429:
@@ -187,15 +435,190 @@ class JiraRequestHandler:
             )
         except urllib.error.URLError as e:
             log(f"URL error occurred: {e}")
+            self._record(method, endpoint, None, started, str(e.reason))
+            logger.error("%s %s: %s", method, endpoint, e.reason)
             raise click.ClickException(f"URL error: {e}") from e
+        except TimeoutError as e:
+            # Connecting in time but answering too late, connect timeouts
+            # come as an URLError
+            self._record(method, endpoint, None, started, "timed out")
+            logger.error("%s %s: timed out", method, endpoint)
+            raise click.ClickException(
+                f"Timed out waiting for {method} {endpoint}, raise the timeout "
+                "or search_timeout option if Jira is slow"
+            ) from e
+
+    def download(self, url: str, max_size: int) -> bytes:
+        """
+        The content of a file of the server, like an attachment.
+
+        Attachment URLs are absolute and redirect to where the file is stored,
+        often another host: the credentials are only sent to the host of the
+        URL, the redirects are followed without them. Files bigger than
+        max_size are refused.
+        """
+        if self.authenticator is not None:
+            self.headers.update(self.authenticator.get_headers())
+        host = urlsplit(url).netloc
+        credentials = True
+        started = time.monotonic()
+        for _ in range(MAX_DOWNLOAD_REDIRECTS + 1):
+            request = urllib.request.Request(url)
+            # Once off the host of the URL, the credentials are never sent again
+            credentials = credentials and urlsplit(url).netloc == host
+            if credentials:
+                for key, value in self.headers.items():
+                    if key not in ("Accept", "Content-Type"):
+                        request.add_header(key, value)
+            try:
+                with self._download_opener.open(
+                    request, timeout=self.timeouts.connect
+                ) as response:
+                    content = response.read(max_size + 1)
+                break
+            except urllib.error.HTTPError as e:
+                location = e.headers.get("Location") if e.headers else None
+                if e.code in REDIRECT_STATUS_CODES and location:
+                    url = urljoin(url, location)
+                    continue
+                self._record("GET", url, e.code, started, str(e.reason))
+                raise exceptions.JiraAPIError(
+                    f"HTTP {e.code}: {e.reason}", url, e.code, ""
+                ) from e
+            except urllib.error.URLError as e:
+                self._record("GET", url, None, started, str(e.reason))
+                raise click.ClickException(f"URL error: {e}") from e
+        else:
+            self._record("GET", url, None, started, "too many redirects")
+            raise click.ClickException(f"Too many redirects downloading {url}")
+        self._record("GET", url, response.status, started)
+        if len(content) > max_size:
+            raise click.ClickException(
+                f"{url} is bigger than {max_size // 1024 // 1024} MB"
+            )
+        return content
+
+    def send(
+        self, request: urllib.request.Request, data: bytes | None
+    ) -> tuple[int, Any]:
+        """
+        Send a request built by the caller, like the login of a session cookie.
+
+        The request gets the headers of the handler it does not have, except
+        the credentials, and is neither cached nor retried. Its credentials
+        are masked when recorded to a cassette. Errors are raised as urlopen
+        raises them.
+        """
+        for key, value in self.headers.items():
+            if key not in ("Authorization", "Cookie") and not request.has_header(
+                key.capitalize()
+            ):
+                request.add_header(key, value)
+        method = request.get_method()
+        endpoint = urlsplit(request.full_url).path.lstrip("/")
+        read_timeout = self.timeouts.read_timeout(endpoint)
+        request.read_timeout = read_timeout  # type: ignore[attr-defined]
+        started = time.monotonic()
+        try:
+            if self.cassette is not None:
+                status, body = self.cassette.play(
+                    request, data, self._urlopen, secret=True
+                )
+            else:
+                status, body = self._urlopen(request, data)
+        except urllib.error.HTTPError as e:
+            self._record(method, endpoint, e.code, started, str(e.reason))
+            raise
+        except urllib.error.URLError as e:
+            self._record(method, endpoint, None, started, str(e.reason))
+            raise
+        except TimeoutError:
+            self._record(method, endpoint, None, started, "timed out")
+            raise
+        self._record(method, endpoint, status, started)
+        return status, body
+
+    def _record(
+        self,
+        method: str,
+        endpoint: str,
+        status: int | None,
+        started: float,
+        error: str = "",
+    ) -> float:
+        """Keep a request for the debug pane, gives its duration in ms."""
+        duration = (time.monotonic() - started) * 1000
+        RECENT_REQUESTS.append(
+            RequestRecord(
+                datetime.now().astimezone(),
+                method.upper(),
+                endpoint,
+                status,
+                duration,
+                error,
+            )
+        )
+        return duration
+
+    def _send_with_retries(
+        self,
+        request: urllib.request.Request,
+        data: bytes | None,
+        label: str | None,
+    ) -> tuple[int, dict[str, Any]]:
+        """Send the request, retrying on rate limiting and server errors."""
+        attempt = 0
+        renewed = False
+        while True:
+            try:
+                return self._send_request(request, data, label)
+            except urllib.error.HTTPError as e:
+                if e.code == 401 and not renewed and self._renew_credentials(request):
+                    renewed = True
+                    continue
+                if not self.retry_policy.should_retry(
+                    request.get_method(), e.code, attempt
+                ):
+                    raise
+                retry_after = e.headers.get("Retry-After") if e.headers else None
+                delay = self.retry_policy.get_delay(attempt, retry_after)
+                attempt += 1
+                logger.warning(
+                    "%s %s: %s, retrying in %.1fs (%s/%s)",
+                    request.get_method(),
+                    request.full_url,
+                    e.code,
+                    delay,
+                    attempt,
+                    self.retry_policy.max_retries,
+                )
+                if self.verbose:
+                    log(
+                        f"HTTP {e.code}, retrying in {delay:.1f}s "
+                        f"({attempt}/{self.retry_policy.max_retries})"
+                    )
+                time.sleep(delay)
+
+    def _renew_credentials(self, request: urllib.request.Request) -> bool:
+        """Renew expired credentials, like a session cookie, for another try."""
+        if self.authenticator is None or not self.authenticator.renew(
+            dict(request.header_items())
+        ):
+            return False
+        logger.info(
+            "%s %s: 401, credentials renewed", request.get_method(), request.full_url
+        )
+        for key, value in self.authenticator.get_headers().items():
+            request.add_header(key, value)
+        return True
 
     def _send_request(
         self,
         request: urllib.request.Request,
         data: bytes | None,
         label: str | None,
-    ) -> dict[str, Any]:
-        """Send the actual HTTP request."""
+    ) -> tuple[int, dict[str, Any]]:
+        """Send the actual HTTP request, gives its status and parsed body."""
         if (not self.verbose and not self.quiet) and label:
             with click.progressbar(
                 length=1,
@@ -206,18 +629,27 @@ class JiraRequestHandler:
                 fill_char="⣾⣷⣯⣟⡿⢿⣻⣽"[0],
                 empty_char=" ",
             ) as bar:
-                response_data = self._execute_request(request, data)
+                response = self._execute_request(request, data)
                 bar.update(1)
         else:
-            response_data = self._execute_request(request, data)
+            response = self._execute_request(request, data)
 
-        return response_data
+        return response
 
     def _execute_request(
         self, request: urllib.request.Request, data: bytes | None
-    ) -> dict[str, Any]:
+    ) -> tuple[int, dict[str, Any]]:
         """Execute the HTTP request and parse response."""
-        with urllib.request.urlopen(request, data=data) as response:
+        if self.cassette is not None:
+            return self.cassette.play(request, data, self._urlopen)
+        return self._urlopen(request, data)
+
+    def _urlopen(
+        self, request: urllib.request.Request, data: bytes | None
+    ) -> tuple[int, dict[str, Any]]:
+        with self._opener.open(
+            request, data=data, timeout=self.timeouts.connect
+        ) as response:
             status_code = response.status
             response_text = response.read().decode("utf-8")
             response_data = json.loads(response_text) if response_text else {}
@@ -225,4 +657,4 @@ class JiraRequestHandler:
         if self.verbose:
             log(f"Response status: {status_code}")
 
-        return response_data
+        return status_code, response_data
@@ -2,7 +2,18 @@
 
 from jayrah.ui.web import cli as web_cli
 
-from . import browse, cache, create, mcli, mcp
+from . import browse, cache, completions, create, doctor, mcli, mcp, serve
 from .common import cli as cli
 
-__all__ = ["browse", "cache", "cli", "create", "mcli", "mcp", "web_cli"]
+__all__ = [
+    "browse",
+    "cache",
+    "cli",
+    "completions",
+    "create",
+    "doctor",
+    "mcli",
+    "mcp",
+    "serve",
+    "web_cli",
+]
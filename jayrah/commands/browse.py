@@ -1,14 +1,40 @@
 """Browse command for Jayrah Jira CLI."""
 
+import functools
+import string
+
 import click
 
 from .. import utils
+from ..api import models
 from ..config import defaults
 from ..ui import boards
 from .common import cli
 from .completions import BoardType
 
 
+def format_choice(template: str, issue: dict, server: str) -> str:
+    """
+    A chosen issue formatted with a template like `{key} {summary}`.
+
+    The template takes the fields of `jayrah cli list` plus the url.
+    """
+    values = models.ListIssue.from_api(issue).to_dict()
+    values["url"] = utils.make_full_url(values["key"], server)
+    try:
+        return template.format_map(
+            {k: "" if v is None else v for k, v in values.items()}
+        )
+    except (KeyError, ValueError) as e:
+        raise click.BadParameter(
+            f"{e}, the fields are {', '.join(values)}", param_hint="--choose-format"
+        ) from e
+
+
+def _template_fields(template: str) -> set[str]:
+    return {x[1] for x in string.Formatter().parse(template) if x[1] is not None}
+
+
 @cli.command("browse")
 @click.argument("board", required=False, type=BoardType())
 @click.argument("search_terms", nargs=-1)
@@ -31,9 +57,66 @@ from .completions import BoardType
     is_flag=True,
     help="Automatically select the first matching issue and print its URL",
 )
+@click.option(
+    "--choose-multi",
+    is_flag=True,
+    help="Like --choose, the issues marked with space are printed",
+)
+@click.option(
+    "--choose-format",
+    help="Template of the chosen issues, like '{key} {summary}'",
+)
+@click.option(
+    "--no-mouse",
+    "no_mouse",
+    is_flag=True,
+    help="Disable mouse capture so the terminal can select and copy text",
+)
+@click.option(
+    "--offline",
+    is_flag=True,
+    help="Browse the issues saved the last time the board was browsed online",
+)
+@click.option(
+    "--layout",
+    type=click.Choice(defaults.LAYOUTS),
+    help="Show the details below the issues (vertical) or beside them",
+)
+@click.option(
+    "--zoom",
+    type=click.Choice(defaults.ZOOMS),
+    help="Start with only the issues or only the details on screen",
+)
+@click.option("--theme", help="Textual theme of the browser, like nord")
+@click.option(
+    "--refresh",
+    type=click.IntRange(min=0),
+    help="Reload the issues every that many seconds, 0 to never reload",
+)
+@click.option(
+    "--fresh",
+    is_flag=True,
+    help="Start from the default board instead of restoring the last session",
+)
 @click.pass_obj
 def browse(
-    jayrah_obj, board, search_terms, use_or, filters, list_boards, choose, jql_query
+    jayrah_obj,
+    board,
+    search_terms,
+    use_or,
+    filters,
+    list_boards,
+    choose,
+    choose_multi,
+    choose_format,
+    jql_query,
+    no_mouse,
+    offline,
+    layout,
+    zoom,
+    theme,
+    refresh,
+    fresh,
 ):
     """
     Browse boards
@@ -44,12 +127,51 @@ def browse(
     Example: jayrah browse my-board term1 term2   # Searches for term1 AND term2
     Example: jayrah browse my-board --or term1 term2   # Searches for term1 OR term2
     Example: jayrah browse my-board --filter status="Code Review"   # Filter by status
+
+    BOARD can be an issue key or the URL of an issue to open it directly.
+
+    Example: jayrah browse JAY-123
+    Example: jayrah browse https://jira.example.com/browse/JAY-123
+
+    Without arguments the board or query of the last session comes back with
+    its filter, sort and selected issue, unless --fresh is given.
     """
     if list_boards:
         boards.show(jayrah_obj.config)
         return
 
-    if jql_query:
+    choose = choose or choose_multi or bool(choose_format)
+
+    if no_mouse:
+        jayrah_obj.config["no_mouse"] = True
+
+    if offline:
+        jayrah_obj.config["offline"] = True
+
+    for name, value in (("layout", layout), ("zoom", zoom), ("theme", theme)):
+        if value:
+            jayrah_obj.config[name] = value
+    jayrah_obj.refresh_override = refresh
+
+    board_names = [x.get("name") for x in jayrah_obj.config.get("boards", [])]
+    session = {}
+    if not (board or jql_query or search_terms or filters or choose or fresh):
+        session = jayrah_obj.state_store.session()
+        # Boards are looked up again in case their query was changed since
+        if session.get("command") in board_names:
+            board = session["command"]
+        elif session.get("jql"):
+            jql_query = session["jql"]
+    issue_key = None
+    if board and not jql_query and board not in board_names:
+        issue_key = utils.issue_key_from_text(board)
+
+    if issue_key:
+        # A ticket pasted in a chat, its list only has the issue itself
+        jql = f"key = {issue_key}"
+        order_by = defaults.ORDER_BY
+        jayrah_obj.command = issue_key
+    elif jql_query:
         jql = jql_query
         order_by = defaults.ORDER_BY
         if board:
@@ -60,33 +182,46 @@ def browse(
         jql, order_by = boards.check(board, jayrah_obj.config)
         if not jql or not order_by:
             return
+        jayrah_obj.board_overrides = boards.overrides(board, jayrah_obj.config)
 
     # Use the common function to build the search JQL
     jql = boards.build_search_jql(
         jql, search_terms, use_or, jayrah_obj.verbose, filters
     )
 
-    issues = jayrah_obj.issues_client.list_issues(jql, order_by=order_by)
-
-    if not issues:
-        boards.show_no_issues_message(search_terms, use_or, filters)
-        return
-
+    if jql_query and session.get("order_by"):
+        order_by = session["order_by"]
     jayrah_obj.jql = jql
     jayrah_obj.order_by = order_by
+    jayrah_obj.session = session
 
-    selected_key = jayrah_obj.fuzzy_search(issues, auto_choose=choose)
+    # The UI shows up right away and loads the issues in the background
+    on_no_issues = functools.partial(
+        boards.show_no_issues_message, search_terms, use_or, filters
+    )
+    if choose_multi:
+        selected_keys = jayrah_obj.fuzzy_search(
+            None, auto_choose=True, on_no_issues=on_no_issues, choose_multi=True
+        )
+    else:
+        selected_key = jayrah_obj.fuzzy_search(
+            None, auto_choose=choose, on_no_issues=on_no_issues
+        )
+        selected_keys = [selected_key] if selected_key else []
 
     if choose:
-        if not selected_key:
+        if not selected_keys:
             return
 
         server = jayrah_obj.config.get("jira_server")
         if not server:
             raise click.ClickException("jira_server not configured")
 
-        url = utils.make_full_url(selected_key, server)
-        if jayrah_obj.config.get("quiet"):
-            click.echo(url)
-        else:
-            click.echo(f"{selected_key} {url}")
+        template = choose_format
+        if not template:
+            template = "{url}" if jayrah_obj.config.get("quiet") else "{key} {url}"
+        # The key and url are known, the other fields need the issue
+        fetch = bool(_template_fields(template) - {"key", "url"})
+        for key in selected_keys:
+            issue = jayrah_obj.jira.get_issue(key) if fetch else {"key": key}
+            click.echo(format_choice(template, issue, server))
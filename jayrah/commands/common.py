@@ -8,9 +8,17 @@ import click
 
 from .. import config, utils
 from ..ui import boards
+from ..utils import logs
 
+# Commands run without reading the config, it may not even exist yet
+NO_CONFIG_COMMANDS = ("completion",)
 
-@click.group()
+
+@click.group(
+    invoke_without_command=True,
+    context_settings={"help_option_names": ["-h", "--help"]},
+)
+@click.version_option(utils.package_version(), prog_name="jayrah")
 @click.option("--no-cache", "-n", is_flag=True, help="Disable caching of API responses")
 @click.option("--verbose", "-v", is_flag=True, help="Enable verbose output")
 @click.option("--insecure", is_flag=True, help="Disable SSL verification for requests")
@@ -41,7 +49,26 @@ from ..ui import boards
     default=config.defaults.CONFIG_FILE,
     help="Config file to use",
 )
+@click.option(
+    "--profile",
+    default=os.environ.get("JAYRAH_PROFILE"),
+    help="Profile of the config file to use, for another Jira instance",
+)
 @click.option("--quiet", is_flag=True, help="Suppress non-error output")
+@click.option(
+    "--log-level",
+    type=click.Choice(config.defaults.LOG_FILE_LEVELS, case_sensitive=False),
+    help="Level of the log file (default: log_level of the config or info)",
+)
+# Records the Jira requests to a file or replays them from it, to write tests
+@click.option("--cassette", envvar="JAYRAH_CASSETTE", hidden=True)
+@click.option(
+    "--cassette-mode",
+    envvar="JAYRAH_CASSETTE_MODE",
+    type=click.Choice(["record", "replay"]),
+    default="replay",
+    hidden=True,
+)
 @click.pass_context
 def cli(
     ctx,
@@ -54,9 +81,19 @@ def cli(
     jira_server,
     cache_ttl,
     config_file,
+    profile,
     quiet,
+    log_level,
+    cassette,
+    cassette_mode,
 ):
-    """Jira Helper Tool"""
+    """
+    Jira Helper Tool
+
+    Without a command the issues of the default board are browsed.
+    """
+    if ctx.invoked_subcommand in NO_CONFIG_COMMANDS:
+        return
 
     flag_config = {
         "jira_server": jira_server,
@@ -69,8 +106,23 @@ def cli(
         "quiet": quiet,
         "insecure": insecure,
         "jayrah_path": os.path.abspath(sys.argv[0]),
+        "config_file": pathlib.Path(config_file),
+        "profile": profile,
+        "cassette": cassette,
+        "cassette_mode": cassette_mode,
         "ctx": ctx,
     }
-    wconfig = config.make_config(flag_config, pathlib.Path(config_file))
+    try:
+        wconfig = config.make_config(flag_config, pathlib.Path(config_file))
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
+    try:
+        logs.setup_logging(
+            log_level or wconfig.get("log_level") or config.defaults.LOG_LEVEL
+        )
+    except ValueError as e:
+        raise click.ClickException(str(e)) from e
     utils.log(f"Using config: {wconfig}", verbose=verbose, verbose_only=True)
     ctx.obj = boards.Boards(wconfig)
+    if ctx.invoked_subcommand is None:
+        ctx.invoke(cli.commands["browse"])
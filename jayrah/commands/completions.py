@@ -3,18 +3,29 @@
 import pathlib
 
 import click
+from click.shell_completion import get_completion_class
 
 from .. import config
 from ..api import jira_client as jirahttp
 from ..config import defaults, read_config
+from .common import cli
+
+SHELLS = ("bash", "zsh", "fish")
+
+
+def completion_config(ctx) -> dict:
+    """The config of the config file and profile given before the command."""
+    params = ctx.find_root().params
+    config_file = pathlib.Path(params.get("config_file") or defaults.CONFIG_FILE)
+    cfg = read_config({}, config_file)
+    if params.get("profile"):
+        return config.apply_profile(cfg, params["profile"])
+    return cfg
 
 
 def setup_jira_http(ctx):
     """Initializes and returns a JiraHTTP client from a configuration file."""
-    config_file = defaults.CONFIG_FILE
-    if custom_config := ctx.parent.params.get("config_file"):
-        config_file = pathlib.Path(custom_config)
-    cfg = read_config({}, config_file)
+    cfg = completion_config(ctx)
     # Get API version from config or use default
     api_version = cfg.get("api_version", defaults.API_VERSION)
     auth_method = cfg.get("auth_method", defaults.AUTH_METHOD)
@@ -22,17 +33,22 @@ def setup_jira_http(ctx):
 
 
 class BoardType(click.ParamType):
+    """A board of the config file, completed from the names it lists."""
+
     name = "board"
 
     def shell_complete(self, ctx, _, incomplete):
-        config_file = defaults.CONFIG_FILE
-        if ctx.parent.params.get("config_file"):
-            config_file = pathlib.Path(ctx.parent.params.get("config_file"))
-        cfg = config.read_config({}, config_file)
+        try:
+            boards = completion_config(ctx).get("boards") or []
+        except Exception:
+            # A broken config must not break the shell, it gets no completion
+            return []
         return [
-            click.shell_completion.CompletionItem(x)
-            for x in [x.get("name") for x in cfg.get("boards", [])]
-            if x.startswith(incomplete)
+            click.shell_completion.CompletionItem(
+                board["name"], help=board.get("description")
+            )
+            for board in boards
+            if board.get("name", "").startswith(incomplete)
         ]
 
 
@@ -108,3 +124,15 @@ class ComponentType(JiraObjectCompletion):
 
     def __init__(self):
         super().__init__("get_components")
+
+
+@cli.command("completion")
+@click.argument("shell", type=click.Choice(SHELLS))
+def completion(shell):
+    """
+    Print the completion script of a shell.
+
+    Example: eval "$(jayrah completion bash)" in ~/.bashrc
+    """
+    complete = get_completion_class(shell)(cli, {}, "jayrah", "_JAYRAH_COMPLETE")
+    click.echo(complete.source())
@@ -0,0 +1,24 @@
+"""Doctor command checking the connection and the credentials to Jira."""
+
+import sys
+
+import click
+
+from ..api import diagnostics
+from .common import cli
+
+
+@cli.command("doctor")
+@click.pass_obj
+def doctor(jayrah_obj):
+    """Check the server, the credentials, the API version and the clock."""
+    checks = diagnostics.run_checks(jayrah_obj.jira)
+    for check in checks:
+        icon = "✅" if check.ok else "❌"
+        click.secho(
+            f"{icon} {check.name}: {check.message}", fg="green" if check.ok else "red"
+        )
+        if check.hint:
+            click.echo(f"   💡 {check.hint}")
+    if not all(check.ok for check in checks):
+        sys.exit(1)
@@ -1,11 +1,17 @@
 """Manage command for Jayrah Jira CLI."""
 
+import json
 import os
+import pathlib
+import sys
+from typing import NoReturn
 
 import click
 
-from jayrah.utils import issue_view
+from jayrah import utils
+from jayrah.utils import custom_fields, git, issue_view
 
+from ..api import exceptions, models
 from ..ui import boards
 from .common import cli as ccli
 from .completions import BoardType
@@ -106,8 +112,6 @@ def view(jayrah_obj, ticket_number, as_json):
     try:
         issue = jayrah_obj.jira.get_issue(ticket_number, fields=["*all"])
         if as_json:
-            import json
-
             click.echo(json.dumps(issue.raw, indent=2))
         else:
             header, body = issue_view.build_issue(issue, jayrah_obj.config, 5)
@@ -122,15 +126,13 @@ def view(jayrah_obj, ticket_number, as_json):
 @click.pass_obj
 def bopen(jayrah_obj, ticket_number):
     """Open a specific ticket in the default web browser."""
-    import webbrowser
-
     try:
         base_url = jayrah_obj.config.get("jira_server")
         if not base_url:
             click.secho("Jira base URL not found in config.", fg="red")
             return
         url = f"{base_url}/browse/{ticket_number}"
-        webbrowser.open(url)
+        utils.browser_open(url, jayrah_obj.config)
         click.secho(f"Opened {url} in your browser.", fg="green")
     except Exception as e:
         click.secho(f"Error opening ticket {ticket_number}: {e}", fg="red")
@@ -139,8 +141,10 @@ def bopen(jayrah_obj, ticket_number):
 @cli.command("status")
 @click.argument("ticket_number")
 @click.argument("status_or_transition_id", required=False)
+@click.option("--resolution", help="Resolution asked by the transition, e.g. Done")
+@click.option("--comment", help="Comment added with the transition")
 @click.pass_obj
-def status(jayrah_obj, ticket_number, status_or_transition_id):
+def status(jayrah_obj, ticket_number, status_or_transition_id, resolution, comment):
     """Set status or list available transitions for a ticket."""
     try:
         # Get available transitions for the issue
@@ -154,7 +158,6 @@ def status(jayrah_obj, ticket_number, status_or_transition_id):
         # If no status/transition provided, list available transitions in CSV format
         if not status_or_transition_id:
             import csv
-            import sys
 
             # Get current issue status
             issue = jayrah_obj.jira.get_issue(ticket_number, fields=["status"])
@@ -191,7 +194,6 @@ def status(jayrah_obj, ticket_number, status_or_transition_id):
 
         if not target_transition_id:
             import csv
-            import sys
 
             click.secho(
                 f"No transition found for '{status_or_transition_id}' on {ticket_number}",
@@ -214,7 +216,12 @@ def status(jayrah_obj, ticket_number, status_or_transition_id):
             return
 
         # Apply the transition
-        jayrah_obj.jira.transition_issue(ticket_number, target_transition_id)
+        transition_fields = None
+        if resolution or comment:
+            transition_fields = models.TransitionFields(resolution, comment)
+        jayrah_obj.jira.transition_issue(
+            ticket_number, target_transition_id, transition_fields
+        )
 
         # Get the transition name for confirmation
         transition_name = next(
@@ -275,8 +282,6 @@ def show(jayrah_obj, ticket_number, output):
         issue = jayrah_obj.jira.get_issue(ticket_number, fields=base_fields)
 
         if output == "json":
-            import json
-
             click.echo(json.dumps(issue, indent=2))
         else:
             # Friendly output similar to TUI display
@@ -415,12 +420,11 @@ def show(jayrah_obj, ticket_number, output):
 
 
 @cli.command("browse")
-@click.argument("board_name")
+@click.argument("board_name", type=BoardType())
 @click.pass_obj
 def browse(jayrah_obj, board_name):
     """List all issues for a specific board in CSV format."""
     import csv
-    import sys
 
     try:
         boards = jayrah_obj.config.get("boards", [])
@@ -467,6 +471,224 @@ def browse(jayrah_obj, board_name):
         sys.exit(1)
 
 
+def _fail(message: str) -> NoReturn:
+    click.secho(message, fg="red", err=True)
+    sys.exit(1)
+
+
+output_option = click.option(
+    "--output",
+    "-o",
+    type=click.Choice(models.OUTPUT_FORMATS),
+    default="json",
+    help="Output format (default: json)",
+)
+
+
+@cli.command("list")
+@click.argument("board", type=BoardType(), required=False)
+@click.option("--query", "-q", "jql_query", help="JQL query to use directly")
+@output_option
+@click.pass_obj
+def list_issues(jayrah_obj, board, jql_query, output):
+    """List the issues of a board or a JQL query."""
+    from ..config import defaults
+
+    overrides = {}
+    if jql_query:
+        jql, order_by = jql_query, defaults.ORDER_BY
+    elif board:
+        jql, order_by = boards.check(board, jayrah_obj.config)
+        if not jql:
+            _fail(f"Cannot list the issues of board '{board}'")
+        overrides = boards.overrides(board, jayrah_obj.config)
+    else:
+        _fail("You must specify a board or a JQL query via -q/--query")
+
+    try:
+        issues = jayrah_obj.issues_client.list_issues(
+            jql, order_by=order_by, board_overrides=overrides
+        )
+    except Exception as e:
+        _fail(f"Error fetching issues: {e}")
+    rows = [models.ListIssue.from_api(x).to_dict() for x in issues]
+    click.echo(models.serialize(rows, output))
+
+
+@cli.command("detail")
+@click.argument("ticket_number")
+@output_option
+@click.pass_obj
+def detail(jayrah_obj, ticket_number, output):
+    """Show an issue with its description and comments."""
+    ticket_number = os.path.basename(ticket_number)
+    try:
+        issue = jayrah_obj.jira.get_issue(ticket_number)
+    except Exception as e:
+        _fail(f"Error fetching ticket {ticket_number}: {e}")
+    issue_detail = models.DetailIssue.from_api(
+        issue, jayrah_obj.config.get("jira_server", "")
+    )
+    click.echo(models.serialize(issue_detail.to_dict(), output))
+
+
+@cli.command("comment")
+@click.argument("ticket_number")
+@click.argument("text", required=False)
+@click.option("--newest-first", is_flag=True, help="List the newest comments first")
+@output_option
+@click.pass_obj
+def comment(jayrah_obj, ticket_number, text, newest_first, output):
+    """
+    List all the comments of an issue, or add one.
+
+    Use - as the text to read the comment from the standard input.
+    """
+    ticket_number = os.path.basename(ticket_number)
+    try:
+        if text is None:
+            comments = jayrah_obj.jira.get_all_issue_comments(
+                ticket_number, newest_first=newest_first, use_cache=False
+            )
+            result = [models.IssueComment.from_api(x).to_dict() for x in comments]
+        else:
+            if text == "-":
+                text = sys.stdin.read()
+            if not text.strip():
+                _fail("The comment is empty")
+            added = jayrah_obj.jira.add_comment(ticket_number, text)
+            result = models.IssueComment.from_api(added).to_dict()
+    except Exception as e:
+        _fail(f"Error with the comments of {ticket_number}: {e}")
+    click.echo(models.serialize(result, output))
+
+
+@cli.command("edit")
+@click.argument("ticket_number")
+@click.option("--summary", help="New title of the issue")
+@click.option("--description", help="New description, - reads it from stdin")
+@click.option(
+    "--label", "labels", multiple=True, help="Label replacing the current ones"
+)
+@click.option("--assignee", help="User to assign, an empty value unassigns")
+@click.option(
+    "--due", help="Due date (YYYY-MM-DD, today, tomorrow, +3d), empty clears it"
+)
+@output_option
+@click.pass_obj
+def edit(
+    jayrah_obj, ticket_number, summary, description, labels, assignee, due, output
+):
+    """Edit the summary, description, labels, assignee or due date of an issue."""
+    ticket_number = os.path.basename(ticket_number)
+    fields: dict = {}
+    if summary is not None:
+        fields["summary"] = summary
+    if description is not None:
+        fields["description"] = (
+            sys.stdin.read() if description == "-" else description
+        )
+    if labels:
+        fields["labels"] = list(labels)
+    if due is not None:
+        try:
+            fields["duedate"] = custom_fields.parse_custom_field_value("date", due)
+        except ValueError as e:
+            _fail(str(e))
+    if not fields and assignee is None:
+        _fail(
+            "Nothing to edit, pass --summary, --description, --label, --assignee"
+            " or --due"
+        )
+
+    try:
+        if fields:
+            jayrah_obj.jira.update_issue(ticket_number, fields)
+        if assignee is not None:
+            jayrah_obj.jira.assign_issue(ticket_number, assignee or None)
+    except Exception as e:
+        _fail(f"Error editing {ticket_number}: {e}")
+    edited = sorted([*fields, *(["assignee"] if assignee is not None else [])])
+    click.echo(models.serialize({"key": ticket_number, "updated": edited}, output))
+
+
+# Same command as status, under the name the other tools use
+cli.add_command(status, "transition")
+
+
+@cli.command("commit-msg")
+@click.argument("message_file", required=False, type=click.Path(dir_okay=False))
+@click.option("--branch", help="Branch to read the issue from (default: current)")
+@click.pass_obj
+def commit_msg(jayrah_obj, message_file, branch):
+    """
+    Commit message line for the issue of the current git branch.
+
+    With MESSAGE_FILE the line is put first in that file unless it already
+    mentions the issue, so it can be used as a prepare-commit-msg hook. A
+    hook never blocks the commit: when the issue cannot be found it warns
+    and leaves the file alone.
+    """
+
+    def give_up(message: str) -> None:
+        if not message_file:
+            _fail(message)
+        click.secho(
+            f"Warning: {message}, commit message left alone", fg="yellow", err=True
+        )
+
+    branch = branch or git.current_branch()
+    if not branch:
+        give_up("Not on a git branch")
+        return
+    issue_key = git.issue_key_from_branch(
+        branch, jayrah_obj.config.get("jira_project")
+    )
+    if not issue_key:
+        give_up(f"No issue key in branch '{branch}'")
+        return
+    try:
+        issue = jayrah_obj.jira.get_issue(issue_key, fields=["summary"])
+    except Exception as e:
+        give_up(f"Error fetching ticket {issue_key}: {e}")
+        return
+    line = git.commit_message(issue_key, issue["fields"]["summary"])
+    if message_file:
+        git.prepare_commit_message(pathlib.Path(message_file), line, issue_key)
+    else:
+        click.echo(line)
+
+
+@cli.command("standup")
+@click.option(
+    "--since",
+    help="Window of the report: yesterday, week, or like 3d or 2w "
+    "(default: standup_since of the config or yesterday)",
+)
+@click.pass_obj
+def standup(jayrah_obj, since):
+    """Markdown summary of the issues I commented, moved or assigned lately."""
+    from ..config import defaults
+    from ..utils import report
+
+    try:
+        days = report.parse_window(
+            since or jayrah_obj.config.get("standup_since") or defaults.STANDUP_SINCE
+        )
+    except ValueError as e:
+        raise click.BadParameter(str(e), param_hint="--since") from e
+    try:
+        text = report.standup_report(
+            jayrah_obj.jira,
+            jayrah_obj.issues_client,
+            days,
+            jayrah_obj.config.get("jira_server", ""),
+        )
+    except Exception as e:
+        _fail(f"Error building the standup: {e}")
+    click.echo(text, nl=False)
+
+
 class CustomCommands(click.MultiCommand):
     def list_commands(self, ctx):
         """Read subcommand groups from `plugins_dir`."""
@@ -495,9 +717,6 @@ class CustomCommands(click.MultiCommand):
         @click.pass_obj
         def callback(jayrah_obj, issue_key, value):
             """Custom command to update a custom field for an issue."""
-            import re
-            import sys
-
             issue_key = os.path.basename(issue_key)
 
             field_id = command.get("field")
@@ -508,23 +727,23 @@ class CustomCommands(click.MultiCommand):
                     f"Custom field ID missing for command '{field_name}'", fg="red"
                 )
                 sys.exit(1)
-            # Type validation and conversion
+            # Type validation and conversion, choices are checked with the editmeta
+            meta = None
+            if field_type in custom_fields.CHOICE_TYPES:
+                try:
+                    meta = jayrah_obj.jira.get_edit_meta(issue_key).get(field_id)
+                except exceptions.JiraAPIError as e:
+                    click.secho(f"Error fetching {issue_key} metadata: {e}", fg="red")
+                    sys.exit(1)
             try:
-                if field_type == "number":
-                    if value is None:
-                        value = 0
-                    value_str = str(value)
-                    value = float(value_str) if "." in value_str else int(value_str)
-                elif field_type == "url":
-                    if value and not re.match(
-                        r"^(https?|ftp)://[^\s/$.?#].[^\s]*$", str(value)
-                    ):
-                        click.secho("Invalid URL format", fg="red")
-                        sys.exit(1)
-                elif field_type in ["string", "text"]:
-                    value = str(value) if value is not None else ""
-            except (ValueError, TypeError):
-                click.secho(f"Invalid value for type '{field_type}'", fg="red")
+                value = custom_fields.parse_custom_field_value(
+                    field_type,
+                    value,
+                    meta=meta,
+                    format_user=jayrah_obj.jira.formatter.format_assignee,
+                )
+            except ValueError as e:
+                click.secho(f"Invalid value for type '{field_type}': {e}", fg="red")
                 sys.exit(1)
             # Update the issue
             try:
@@ -0,0 +1,224 @@
+"""
+JSON-RPC daemon for editor integrations.
+
+Serves the JSON of the `jayrah cli` commands over a persistent connection so
+editor plugins reuse one authenticated client instead of spawning a process
+per request. Messages are JSON-RPC 2.0 objects, one per line.
+"""
+
+import json
+import os
+import sys
+from collections.abc import Callable
+from typing import IO, Any
+
+import click
+
+from ..api import models
+from ..config import defaults
+from .common import cli
+
+PARSE_ERROR = -32700
+INVALID_REQUEST = -32600
+METHOD_NOT_FOUND = -32601
+INVALID_PARAMS = -32602
+# Errors of the Jira requests, in the range JSON-RPC leaves to servers
+SERVER_ERROR = -32000
+
+
+def _param(params: dict[str, Any], name: str) -> Any:
+    if not params.get(name):
+        raise ValueError(f"Missing parameter: {name}")
+    return params[name]
+
+
+def find_transition(
+    transitions: list[dict[str, Any]], status_or_transition_id: str
+) -> dict[str, Any] | None:
+    """A transition by its id, or by a part of its name or target status."""
+    for transition in transitions:
+        if transition["id"] == status_or_transition_id:
+            return transition
+    status_name = status_or_transition_id.lower()
+    for transition in transitions:
+        if (
+            status_name in transition["to"]["name"].lower()
+            or status_name in transition["name"].lower()
+        ):
+            return transition
+    return None
+
+
+def browse_list(jayrah_obj, params: dict[str, Any]) -> list[dict[str, Any]]:
+    """The issues of a board, the first one by default, or of a JQL query."""
+    if params.get("jql"):
+        jql, order_by = params["jql"], defaults.ORDER_BY
+    else:
+        name = params.get("board")
+        board = next(
+            (
+                x
+                for x in jayrah_obj.config.get("boards", [])
+                if not name or x.get("name") == name
+            ),
+            None,
+        )
+        if not board or not board.get("jql"):
+            raise ValueError(f"Unknown board: {name}" if name else "No boards setup")
+        jql, order_by = board["jql"], board.get("order_by", defaults.ORDER_BY)
+    issues = jayrah_obj.issues_client.list_issues(jql, order_by=order_by)
+    return [models.ListIssue.from_api(x).to_dict() for x in issues]
+
+
+def issue_show(jayrah_obj, params: dict[str, Any]) -> dict[str, Any]:
+    """An issue with its description and comments."""
+    key = os.path.basename(_param(params, "key"))
+    issue = jayrah_obj.jira.get_issue(key)
+    server = jayrah_obj.config.get("jira_server", "")
+    return models.DetailIssue.from_api(issue, server).to_dict()
+
+
+def comments_list(jayrah_obj, params: dict[str, Any]) -> list[dict[str, Any]]:
+    """All the comments of an issue, oldest first."""
+    key = os.path.basename(_param(params, "key"))
+    comments = jayrah_obj.jira.get_all_issue_comments(key, use_cache=False)
+    return [models.IssueComment.from_api(x).to_dict() for x in comments]
+
+
+def comment_add(jayrah_obj, params: dict[str, Any]) -> dict[str, Any]:
+    """Add a comment to an issue."""
+    key = os.path.basename(_param(params, "key"))
+    body = _param(params, "body")
+    if not body.strip():
+        raise ValueError("The comment is empty")
+    added = jayrah_obj.jira.add_comment(key, body)
+    return models.IssueComment.from_api(added).to_dict()
+
+
+def transitions_list(jayrah_obj, params: dict[str, Any]) -> list[dict[str, Any]]:
+    """The transitions of an issue, with the fields they require."""
+    key = os.path.basename(_param(params, "key"))
+    transitions = jayrah_obj.jira.get_transitions(key, expand_fields=True)
+    return [
+        models.Transition.from_api(x).to_dict()
+        for x in transitions.get("transitions", [])
+    ]
+
+
+def transition_apply(jayrah_obj, params: dict[str, Any]) -> dict[str, Any]:
+    """Move an issue by the id or the target status of a transition."""
+    key = os.path.basename(_param(params, "key"))
+    wanted = str(_param(params, "transition"))
+    transitions = jayrah_obj.jira.get_transitions(key).get("transitions", [])
+    transition = find_transition(transitions, wanted)
+    if transition is None:
+        names = ", ".join(x["to"]["name"] for x in transitions)
+        raise ValueError(f"No transition '{wanted}' on {key}, one of: {names}")
+    fields = None
+    if params.get("resolution") or params.get("comment"):
+        fields = models.TransitionFields(
+            params.get("resolution"), params.get("comment")
+        )
+    jayrah_obj.jira.transition_issue(key, transition["id"], fields)
+    return {
+        "key": key,
+        "transition": transition["name"],
+        "status": transition["to"]["name"],
+    }
+
+
+def boards_list(jayrah_obj, params: dict[str, Any]) -> list[dict[str, Any]]:
+    """The boards of the config file."""
+    return [
+        models.Board.from_config(x).to_dict()
+        for x in jayrah_obj.config.get("boards", [])
+    ]
+
+
+def custom_fields_list(jayrah_obj, params: dict[str, Any]) -> list[dict[str, Any]]:
+    """The custom fields of the config file."""
+    return [
+        models.CustomField.from_config(x).to_dict()
+        for x in jayrah_obj.config.get("custom_fields", [])
+    ]
+
+
+def version(jayrah_obj, params: dict[str, Any]) -> dict[str, Any]:
+    """The version of the payloads, to check a plugin understands them."""
+    return {"version": models.PAYLOAD_VERSION}
+
+
+METHODS: dict[str, Callable[[Any, dict[str, Any]], Any]] = {
+    "browse-list": browse_list,
+    "issue-show": issue_show,
+    "comments-list": comments_list,
+    "comment-add": comment_add,
+    "transitions-list": transitions_list,
+    "transition-apply": transition_apply,
+    "boards-list": boards_list,
+    "custom-fields-list": custom_fields_list,
+    "version": version,
+}
+
+
+def _error(request_id: Any, code: int, message: str) -> dict[str, Any]:
+    return {
+        "jsonrpc": "2.0",
+        "id": request_id,
+        "error": {"code": code, "message": message},
+    }
+
+
+def handle_request(jayrah_obj, request: Any) -> dict[str, Any] | None:
+    """The response to a JSON-RPC request, None for a notification."""
+    if not isinstance(request, dict) or not isinstance(request.get("method"), str):
+        return _error(None, INVALID_REQUEST, "Invalid request")
+    request_id = request.get("id")
+    method = METHODS.get(request["method"])
+    params = request.get("params") or {}
+    if method is None:
+        response = _error(
+            request_id, METHOD_NOT_FOUND, f"Unknown method: {request['method']}"
+        )
+    elif not isinstance(params, dict):
+        response = _error(request_id, INVALID_PARAMS, "Params must be an object")
+    else:
+        try:
+            result = method(jayrah_obj, params)
+            response = {"jsonrpc": "2.0", "id": request_id, "result": result}
+        except ValueError as e:
+            response = _error(request_id, INVALID_PARAMS, str(e))
+        except Exception as e:
+            response = _error(request_id, SERVER_ERROR, str(e))
+    return response if "id" in request else None
+
+
+def serve(jayrah_obj, stdin: IO[str], stdout: IO[str]) -> None:
+    """Answer the requests read from stdin until it is closed."""
+    for line in stdin:
+        if not line.strip():
+            continue
+        try:
+            request = json.loads(line)
+        except json.JSONDecodeError:
+            response = _error(None, PARSE_ERROR, "Parse error")
+        else:
+            response = handle_request(jayrah_obj, request)
+        if response is not None:
+            stdout.write(json.dumps(response, ensure_ascii=False) + "\n")
+            stdout.flush()
+
+
+@cli.command("serve")
+@click.option(
+    "--stdio", is_flag=True, help="Serve JSON-RPC on the standard input and output"
+)
+@click.pass_obj
+def serve_cmd(jayrah_obj, stdio):
+    """Serve the issues, comments, transitions and boards as JSON-RPC."""
+    if not stdio:
+        raise click.UsageError("Only --stdio is supported")
+    try:
+        serve(jayrah_obj, sys.stdin, sys.stdout)
+    except KeyboardInterrupt:
+        pass
@@ -8,13 +8,77 @@ from rich.prompt import Prompt
 
 from jayrah import utils
 
-from . import defaults
+from . import defaults, secrets
+from .store import ConfigStore
+
+RETRY_OPTIONS = ("max_retries", "retry_backoff", "retry_jitter")
+OAUTH_OPTIONS = (
+    "oauth_client_id",
+    "oauth_client_secret",
+    "oauth_scopes",
+    "oauth_callback_port",
+)
+GENERAL_OPTIONS = (
+    "jira_server",
+    "jira_user",
+    "jira_password",
+    "jira_session",
+    "jira_component",
+    "jira_project",
+    "cache_ttl",
+    "insecure",
+    "proxy",
+    "ca_bundle",
+    "timeout",
+    "connect_timeout",
+    "search_timeout",
+    "headers",
+    "label_excludes",
+    "auth_method",
+    "api_version",
+    "no_mouse",
+    "epic_link_field",
+    "export_columns",
+    "comments_order",
+    "kanban_columns",
+    "auto_reload",
+    "workers",
+    "detail_cache_size",
+    "detail_cache_ttl",
+    "filter_mode",
+    "browser",
+    "standup_since",
+    "log_level",
+    "resolve_current_user",
+    "image_preview",
+    "image_protocol",
+    "layout",
+    "zoom",
+    "theme",
+    *OAUTH_OPTIONS,
+)
+# Written to the general section by write_config, every option and a few more
+WRITE_OPTIONS = (*GENERAL_OPTIONS, "create", "custom_fields", "secret_providers")
+# ${name} references of the boards, replaced by the variables section
+VARIABLE_RE = re.compile(r"\$\{(\w+)\}")
+# Options of a board overriding how its issues are fetched and shown
+BOARD_OVERRIDES = ("limit", "fields", "columns", "refresh_interval")
+# A profile can override any general option, the boards and the create defaults
+PROFILE_OPTIONS = (
+    *GENERAL_OPTIONS,
+    *RETRY_OPTIONS,
+    "custom_fields",
+    "boards",
+    "variables",
+    "create",
+)
 
 
 def make_config(config: dict, config_file: pathlib.Path) -> dict:
     config = read_config(config, pathlib.Path(config_file))
-    # Check for missing credentials and prompt if needed
-    config_modified = False
+    # Check for missing credentials and prompt if needed, the answers are
+    # written back as typed: a pass:: reference must not be saved resolved
+    prompted: dict = {}
     config_path = pathlib.Path(config_file)
 
     if not config["jira_server"]:
@@ -26,54 +90,69 @@ def make_config(config: dict, config_file: pathlib.Path) -> dict:
             if len(server_url.split(".")) == 1:
                 server_url = f"{server_url}.atlassian.net"
                 if not config.get("api_version"):
-                    config["api_version"] = "3"
-                    config["auth_method"] = "basic"
+                    config["api_version"] = prompted["api_version"] = "3"
+                    config["auth_method"] = prompted["auth_method"] = "basic"
             server_url = "https://" + server_url
 
-        config["jira_server"] = server_url
-        config_modified = True
+        config["jira_server"] = prompted["jira_server"] = server_url
 
     if not config["api_version"]:
-        config["api_version"] = Prompt.ask(
+        config["api_version"] = prompted["api_version"] = Prompt.ask(
             "Select authentication version", choices=["2", "3"], default="3"
         )
-        config_modified = True
 
     if not config["auth_method"]:
         default_auth_method = "basic" if config.get("api_version") == "3" else "bearer"
-        config["auth_method"] = Prompt.ask(
+        config["auth_method"] = prompted["auth_method"] = Prompt.ask(
             "Select authentication method",
-            choices=["basic", "bearer"],
+            choices=["basic", "bearer", "cookie", "oauth"],
             default=default_auth_method,
         )
-        config_modified = True
 
-    if not config["jira_user"]:
-        config["jira_user"] = Prompt.ask("Enter Jira username")
-        config_modified = True
+    if config["auth_method"] == "oauth":
+        # The OAuth app credentials replace the user and password
+        for key, label in (
+            ("oauth_client_id", "client id"),
+            ("oauth_client_secret", "client secret"),
+        ):
+            if not config.get(key):
+                config[key] = prompted[key] = Prompt.ask(
+                    f"Enter the {label} of your Atlassian OAuth app",
+                    password=key == "oauth_client_secret",
+                )
+
+    # A session cookie replaces the user and password
+    needs_login = config["auth_method"] != "oauth" and not config.get("jira_session")
+    if not config["jira_user"] and needs_login:
+        config["jira_user"] = prompted["jira_user"] = Prompt.ask("Enter Jira username")
 
     if "jira_project" not in config or not config["jira_project"]:
-        config["jira_project"] = Prompt.ask("Enter your Jira Project (ie: SRVKP)")
-        config_modified = True
+        config["jira_project"] = prompted["jira_project"] = Prompt.ask(
+            "Enter your Jira Project (ie: SRVKP)"
+        )
 
-    if not config["jira_password"]:
-        config["jira_password"] = Prompt.ask(
+    if not config["jira_password"] and needs_login:
+        config["jira_password"] = prompted["jira_password"] = Prompt.ask(
             "Enter your Jira password (or pass key prefixed by pass:)", password=True
         )
-        config_modified = True
 
     # Ensure server URL has https:// prefix
     if config["jira_server"] and not config["jira_server"].startswith("https://"):
-        config["jira_server"] = "https://" + config["jira_server"]
-        config_modified = True
+        config["jira_server"] = prompted["jira_server"] = (
+            "https://" + config["jira_server"]
+        )
 
     if "boards" not in config:
         config["boards"] = defaults.BOARDS
 
-    # Save the config if modified
-    if config_modified:
-        write_config(config, config_path)
+    # Save the answers as typed, then resolve the secret references among them
+    if prompted:
+        write_config({**prompted, "boards": defaults.BOARDS}, config_path)
         utils.log(f"Configuration saved to {config_file}")
+        _normalize_credentials(config)
+
+    if config.get("profile"):
+        config = apply_profile(config, config["profile"])
 
     return config
 
@@ -82,22 +161,7 @@ def read_config(ret: dict, config_file: pathlib.Path) -> dict:
     """Read configuration from yaml file"""
 
     def checks():
-        if (
-            "jira_server" in ret
-            and ret["jira_server"]
-            and not ret["jira_server"].startswith("https://")
-        ):
-            ret["jira_server"] = "https://" + ret["jira_server"]
-
-        if (
-            "jira_password" in ret
-            and ret["jira_password"]
-            and re.match(r"(pass|passage)::", ret["jira_password"])
-        ):
-            ret["jira_password"] = utils.get_pass_key(
-                ret["jira_password"].split("::")[0],
-                ret["jira_password"].split("::")[-1],
-            )
+        _normalize_credentials(ret)
 
         if "cache_ttl" not in ret or ret["cache_ttl"] is None:
             ret["cache_ttl"] = defaults.CACHE_DURATION
@@ -111,6 +175,9 @@ def read_config(ret: dict, config_file: pathlib.Path) -> dict:
         if "insecure" not in ret:
             ret["insecure"] = False
 
+        if "no_mouse" not in ret:
+            ret["no_mouse"] = False
+
         if "auth_method" not in ret:
             ret["auth_method"] = ""
 
@@ -120,6 +187,15 @@ def read_config(ret: dict, config_file: pathlib.Path) -> dict:
         if "custom_fields" not in ret:
             ret["custom_fields"] = []
 
+        if ret.get("max_retries") is None:
+            ret["max_retries"] = defaults.MAX_RETRIES
+
+        if ret.get("retry_backoff") is None:
+            ret["retry_backoff"] = defaults.RETRY_BACKOFF
+
+        if ret.get("retry_jitter") is None:
+            ret["retry_jitter"] = defaults.RETRY_JITTER
+
     checks()
     if not config_file.exists():
         return ret
@@ -132,19 +208,14 @@ def read_config(ret: dict, config_file: pathlib.Path) -> dict:
             def set_general(x):
                 return general.get(x) if x in general and general.get(x) else None
 
-            for x in [
-                "jira_server",
-                "jira_user",
-                "jira_password",
-                "jira_component",
-                "jira_project",
-                "cache_ttl",
-                "insecure",
-                "label_excludes",
-                "auth_method",
-                "api_version",
-            ]:
+            for x in GENERAL_OPTIONS:
                 ret[x] = set_general(x) if set_general(x) is not None else ret.get(x)
+            # Zero is a valid value for the retry options, e.g. to disable retries
+            for x in RETRY_OPTIONS:
+                if general.get(x) is not None:
+                    ret[x] = general[x]
+            if general.get("secret_providers"):
+                ret["secret_providers"] = general["secret_providers"]
             # Add support for custom_fields in general
             if general.get("custom_fields"):
                 ret["custom_fields"] = general["custom_fields"]
@@ -154,37 +225,177 @@ def read_config(ret: dict, config_file: pathlib.Path) -> dict:
             ret["boards"] = config["boards"]
         if config.get("create"):
             ret["create"] = config["create"]
+        if config.get("profiles"):
+            ret["profiles"] = config["profiles"]
+        if config.get("variables"):
+            ret["variables"] = config["variables"]
     checks()
+    # The boards as written, with their extends and ${name}, to write them back
+    ret["raw_boards"] = ret["boards"]
+    ret["boards"] = resolve_boards(ret["boards"], ret.get("variables"))
+    check_boards(ret["boards"])
     return ret
 
 
-def write_config(config, config_file: pathlib.Path):
-    """Write configuration to yaml file"""
-    # Create config directory if it doesn't exist
-    config_file.parent.mkdir(parents=True, exist_ok=True)
-
-    # Prepare the config structure
-    yaml_config: dict[str, dict] = {"general": {}}
-    for key in [
-        "jira_server",
-        "jira_user",
-        "jira_password",
-        "jira_project",
-        "cache_ttl",
-        "auth_method",
-        "api_version",
-        "jira_component",
-        "label_excludes",
-        "create",
-        "insecure",
-        "custom_fields",
-    ]:
-        if config.get(key):
-            yaml_config["general"][key] = config[key]
+def resolve_boards(boards: list[dict], variables: dict | None = None) -> list[dict]:
+    """
+    Return the boards with their extends and ${name} variables resolved.
+
+    A board extending another one gets its options, its extra_jql added to
+    the JQL of the other board. Variables come from the variables section,
+    a ValueError is raised for unknown ones and loops of extends.
+    """
+    by_name = {x.get("name"): x for x in boards}
+    variables = {str(k): str(v) for k, v in (variables or {}).items()}
+
+    def substitute(name, value):
+        if not isinstance(value, str):
+            return value
+
+        def replace(match):
+            if match.group(1) not in variables:
+                raise ValueError(f"Board {name}: unknown variable {match.group(0)}")
+            return variables[match.group(1)]
+
+        return VARIABLE_RE.sub(replace, value)
+
+    def resolve(board, seen):
+        name, parent_name = board.get("name"), board.get("extends")
+        if not parent_name:
+            return dict(board)
+        if parent_name in seen:
+            raise ValueError(f"Board {name}: extends loop through {parent_name}")
+        if parent_name not in by_name:
+            raise ValueError(f"Board {name}: extends unknown board {parent_name}")
+        parent = resolve(by_name[parent_name], seen | {parent_name})
+        ret = {k: v for k, v in parent.items() if k not in ("name", "description")}
+        ret.update(
+            {k: v for k, v in board.items() if k not in ("extends", "extra_jql")}
+        )
+        if board.get("extra_jql") and not board.get("jql"):
+            ret["jql"] = f"({parent.get('jql', '').strip()}) {board['extra_jql']}"
+        return ret
 
-    if config.get("boards"):
-        yaml_config["boards"] = config["boards"]
+    resolved = [resolve(x, {x.get("name")}) for x in boards]
+    return [{k: substitute(x.get("name"), v) for k, v in x.items()} for x in resolved]
 
-    # Write to file
-    with config_file.open("w") as file:
-        yaml.safe_dump(yaml_config, file)
+
+def check_boards(boards: list[dict]) -> None:
+    """Raise a ValueError for invalid board overrides, before the UI starts."""
+    for board in boards:
+        name = board.get("name")
+        limit = board.get("limit")
+        if limit is not None and (
+            isinstance(limit, bool) or not isinstance(limit, int) or limit < 1
+        ):
+            raise ValueError(f"Board {name}: limit must be above 0")
+        for key in ("fields", "columns"):
+            value = board.get(key)
+            if value is not None and not (
+                isinstance(value, list) and all(isinstance(x, str) for x in value)
+            ):
+                raise ValueError(f"Board {name}: {key} must be a list of names")
+        if unknown := set(board.get("columns") or ()) - set(defaults.ISSUE_COLUMNS):
+            raise ValueError(
+                f"Board {name}: unknown columns {', '.join(sorted(unknown))}, "
+                f"one of {', '.join(defaults.ISSUE_COLUMNS)}"
+            )
+        interval = board.get("refresh_interval")
+        if interval is not None and (
+            isinstance(interval, bool)
+            or not isinstance(interval, (int, float))
+            or interval <= 0
+        ):
+            raise ValueError(f"Board {name}: refresh_interval must be seconds above 0")
+
+
+def _normalize_credentials(ret: dict) -> None:
+    """Add the https:// prefix to the server and resolve secret references."""
+    if ret.get("jira_server") and not ret["jira_server"].startswith("https://"):
+        ret["jira_server"] = "https://" + ret["jira_server"]
+
+    if ret.get("jira_password"):
+        ret["jira_password"] = secrets.resolve_secret(ret["jira_password"], ret)
+
+    if ret.get("jira_session"):
+        ret["jira_session"] = secrets.resolve_secret(ret["jira_session"], ret)
+
+    if ret.get("oauth_client_secret"):
+        ret["oauth_client_secret"] = secrets.resolve_secret(
+            ret["oauth_client_secret"], ret
+        )
+
+    # Gateway tokens, like the Cloudflare Access ones, can be references too
+    if ret.get("headers"):
+        ret["headers"] = {
+            str(name): secrets.resolve_secret(value, ret)
+            for name, value in ret["headers"].items()
+        }
+
+
+def apply_profile(config: dict, name: str | None) -> dict:
+    """
+    Return the config of a profile, its options applied over the general ones.
+
+    Profiles are applied over the config without any profile, kept in
+    base_config, so switching between them does not mix their options. No
+    name gives back the config without profile.
+    """
+    base = config.get("base_config", config)
+    if not name:
+        return {**base, "profile": None}
+
+    profiles = base.get("profiles") or {}
+    if name not in profiles:
+        raise ValueError(
+            f"Unknown profile {name}, available profiles: {', '.join(profiles)}"
+        )
+    options = {k: v for k, v in (profiles[name] or {}).items() if k in PROFILE_OPTIONS}
+    ret = {**base, **options, "profile": name, "base_config": base}
+    _normalize_credentials(ret)
+    # The boards of the base are resolved already, the profile variables
+    # apply to them as they were written
+    ret["raw_boards"] = options.get("boards", base.get("raw_boards") or [])
+    ret["boards"] = resolve_boards(ret["raw_boards"], ret.get("variables"))
+    check_boards(ret["boards"])
+    return ret
+
+
+def write_config(config, config_file: pathlib.Path):
+    """
+    Write options to the configuration yaml file.
+
+    The options are set over the file as written, through a ConfigStore, so
+    the secret references and the boards it has are kept as they are. The
+    boards, profiles and variables of the config are only written to a file
+    without them, the boards as they were read rather than resolved.
+    """
+    store = ConfigStore(config_file)
+    for key in WRITE_OPTIONS:
+        if config.get(key):
+            store.set_general(key, config[key])
+    for key in RETRY_OPTIONS:
+        if config.get(key) is not None:
+            store.set_general(key, config[key])
+
+    sections = {
+        "boards": config.get("raw_boards", config.get("boards")),
+        "profiles": config.get("profiles"),
+        "variables": config.get("variables"),
+    }
+    for key, value in sections.items():
+        if value and not store.data.get(key):
+            store.data[key] = value
+    store.save()
+
+
+def save_board(config_file: pathlib.Path, board: dict) -> None:
+    """
+    Add a board to the config file, replacing any board with the same name.
+
+    The file is updated in place rather than regenerated from the loaded
+    config, so password lookups like pass:: are kept as they were written.
+    """
+    store = ConfigStore(config_file)
+    store.add_board(board)
+    store.save()
@@ -1,5 +1,6 @@
 """Default configuration values and constants for Jayrah."""
 
+import os
 import pathlib
 
 ORDER_BY = "updated"
@@ -12,6 +13,68 @@ BOARDS = [
     }
 ]
 
+# Columns of the TUI issue table after the type and the key, the columns
+# option of a board shows some of them only or in another order
+ISSUE_COLUMNS = {
+    "summary": "Summary",
+    "status": "Status",
+    "priority": "Priority",
+    "assignee": "Assignee",
+    "reporter": "Reporter",
+    "created": "Created",
+    "updated": "Updated",
+}
+# Width the columns need on screen, the summary gets what is left over
+COLUMN_WIDTHS = {
+    "summary": 30,
+    "status": 12,
+    "priority": 10,
+    "assignee": 17,
+    "reporter": 17,
+    "created": 12,
+    "updated": 12,
+}
+# The type and ticket columns, always shown
+KEY_COLUMNS_WIDTH = 18
+# Columns hidden first when the terminal is too narrow for all of them
+COLUMN_DROP_ORDER = ["reporter", "created", "priority", "updated", "assignee"]
+# Below this width each issue is one line of ticket, summary and status
+COMPACT_WIDTH = 70
+# Layouts of the browser, details below or beside the issues, and the panes
+# it can start zoomed on
+LAYOUTS = ("vertical", "horizontal")
+ZOOMS = ("issues", "detail")
+# Percent of the width the issues take beside the details
+HORIZONTAL_ISSUES_WIDTH = 45
+
+# Board offered for a project picked from the projects list
+PROJECT_BOARD_JQL = "project = {key} AND resolution = Unresolved"
+# Query of the pinned issues entry of the boards popup
+PINNED_JQL = "key in ({keys})"
+# Number of recently viewed issues kept for the history popup
+HISTORY_SIZE = 100
+# Labels the labels popup remembers, they are suggested before the Jira ones
+RECENT_LABELS = 20
+# Labels asked from the Jira autocomplete for what is being typed
+LABEL_SUGGESTIONS = 20
+# Users offered for the @mention being typed in a comment
+MENTION_SUGGESTIONS = 8
+# Changes made from the browser that can be undone
+UNDO_SIZE = 20
+# Issues left by jumping to the keys referenced in the details, to go back to
+REFERENCE_STACK_SIZE = 50
+# Tabs of the browser, switched to with the number keys, and their title length
+MAX_TABS = 9
+TAB_TITLE_LENGTH = 20
+# Notifications the messages popup keeps, and seconds they are shown by severity
+MESSAGES_SIZE = 100
+NOTIFY_TIMEOUTS = {"information": 3.0, "warning": 6.0, "error": 12.0}
+# Frames of the spinner of the pane titles while requests run, and its speed
+SPINNER_FRAMES = "⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏"
+SPINNER_INTERVAL = 0.1
+# Biggest attachment downloaded for a preview
+ATTACHMENT_PREVIEW_SIZE = 10 * 1024 * 1024
+
 FIELDS = [
     "key",
     "summary",
@@ -22,8 +85,10 @@ FIELDS = [
     "resolution",
     "issuetype",
     "fixVersion",
+    "duedate",
     "status",
     "priority",
+    "parent",
 ]
 FZFOPTS = [
     "--highlight-line",
@@ -189,6 +254,74 @@ LOG_LEVELS = {
 
 CACHE_DURATION = 60 * 60 * 24 * 1  # 1 day
 
+# The detail pane keeps the last DETAIL_CACHE_SIZE issues it showed, for
+# DETAIL_CACHE_TTL seconds before fetching them again from the server
+DETAIL_CACHE_SIZE = 200
+DETAIL_CACHE_TTL = 300
+
+# The TUI loads this many issues upfront, the next pages of bigger boards are
+# only fetched once the selection gets within LOAD_MORE_THRESHOLD rows of the end
+PRELOAD_ISSUES = 500
+LOAD_MORE_THRESHOLD = 10
+
+# How the TUI filter matches, substring or fuzzy (letters in order, like fzf)
+FILTER_MODE = "substring"
+# Columns the TUI sorts the loaded issues by, z cycles through them
+SORT_COLUMNS = ("key", "status", "updated", "priority", "assignee")
+# What the TUI groups the issues by, g cycles through them
+GROUP_BY_FIELDS = ("status", "category", "assignee", "epic")
+# Order of the status groups, from the category of the status
+STATUS_CATEGORY_ORDER = ("new", "indeterminate", "done")
+# Color of the statuses in the TUI table by their category
+STATUS_CATEGORY_STYLES = {
+    "new": "bright_blue",
+    "indeterminate": "yellow",
+    "done": "green",
+}
+# Longest summary slug of the branch names made from issues
+BRANCH_SLUG_LENGTH = 50
+
+# The activity stream of the TUI covers the most recently updated issues
+# of the list, with their latest comments
+ACTIVITY_ISSUES = 30
+ACTIVITY_COMMENTS = 10
+
+# The standup covers the last day by default, of at most STANDUP_ISSUES
+# issues I may have worked on
+STANDUP_SINCE = "yesterday"
+STANDUP_ISSUES = 50
+
+# Columns of the TUI kanban view by status category, the kanban_columns
+# option of the config moves statuses to other columns
+KANBAN_COLUMNS = (("new", "To Do"), ("indeterminate", "In Progress"), ("done", "Done"))
+
+# Most issues the TUI opens at once in the browser, O asks to confirm first
+OPEN_ISSUES_LIMIT = 20
+
+# Width of the longest bar of the TUI stats popup
+STATS_BAR_WIDTH = 30
+
+# Comments fetched at once by the comments popup, L loads the next page
+COMMENTS_PAGE_SIZE = 50
+
+# Columns of the CSV and Markdown exports of the issue list, the general
+# export_columns option takes any field of `jayrah cli list` plus url
+EXPORT_COLUMNS = ["key", "issuetype", "status", "assignee", "summary"]
+
+# Retries of requests failing with HTTP 429 or 5xx, the delay doubles on every
+# attempt starting from RETRY_BACKOFF seconds with up to RETRY_JITTER seconds of
+# random jitter added, Retry-After headers sent by Jira take precedence.
+MAX_RETRIES = 3
+RETRY_BACKOFF = 1.0
+RETRY_JITTER = 0.5
+RETRY_MAX_DELAY = 60
+
+# Seconds a request may take to connect and then to get each part of the
+# answer, searches may take SEARCH_TIMEOUT as they are the slowest requests
+CONNECT_TIMEOUT = 10.0
+READ_TIMEOUT = 30.0
+SEARCH_TIMEOUT = 60.0
+
 # API version to use (2 or 3)
 API_VERSION = "2"
 
@@ -197,3 +330,16 @@ API_VERSION = "2"
 AUTH_METHOD = "basic"
 
 CONFIG_FILE = pathlib.Path.home() / ".config" / "jayrah" / "config.yaml"
+
+# The log file rotates at LOG_MAX_BYTES, keeping LOG_BACKUPS older files
+STATE_HOME = os.environ.get("XDG_STATE_HOME") or pathlib.Path.home() / ".local/state"
+LOG_FILE = pathlib.Path(STATE_HOME) / "jayrah" / "jayrah.log"
+LOG_LEVEL = "info"
+LOG_FILE_LEVELS = ("debug", "info", "warning", "error")
+LOG_MAX_BYTES = 1_000_000
+LOG_BACKUPS = 3
+# Lines of the log file shown by the log viewer of the TUI
+LOG_VIEW_LINES = 200
+# Requests listed by the debug pane of the TUI, refreshed every second
+RECENT_REQUESTS = 50
+DEBUG_PANE_REFRESH = 1.0
@@ -0,0 +1,53 @@
+"""Look up secrets like the Jira password from password managers."""
+
+import re
+import subprocess
+
+import click
+
+# Command run for each secret reference prefix, {item} is replaced by what comes
+# after the `prefix::`. The general secret_providers option adds or overrides
+# entries, e.g. to read a different field of a 1Password item.
+SECRET_PROVIDERS: dict[str, list[str]] = {
+    "pass": ["pass", "show", "{item}"],
+    "passage": ["passage", "show", "{item}"],
+    "op": ["op", "item", "get", "{item}", "--fields", "password", "--reveal"],
+    "bw": ["bw", "get", "password", "{item}"],
+}
+
+SECRET_REFERENCE_RE = re.compile(r"(?P<provider>[\w-]+)::(?P<item>.+)", re.DOTALL)
+
+
+def get_providers(config: dict | None = None) -> dict[str, list[str]]:
+    """The default providers with the ones of the config applied over them."""
+    return {**SECRET_PROVIDERS, **((config or {}).get("secret_providers") or {})}
+
+
+def parse_secret_reference(
+    value: str, providers: dict[str, list[str]]
+) -> tuple[str, str] | None:
+    """Split a `provider::item` reference, None when it is a plain value."""
+    match = SECRET_REFERENCE_RE.fullmatch(value)
+    if not match or match["provider"] not in providers:
+        return None
+    return match["provider"], match["item"]
+
+
+def resolve_secret(value, config: dict | None = None):
+    """Return the secret a reference points to, plain values are kept as is."""
+    if not isinstance(value, str):
+        return value
+    providers = get_providers(config)
+    reference = parse_secret_reference(value, providers)
+    if reference is None:
+        return value
+
+    provider, item = reference
+    cmd = [arg.replace("{item}", item) for arg in providers[provider]]
+    try:
+        return subprocess.check_output(cmd, text=True).strip()
+    except (OSError, subprocess.CalledProcessError) as e:
+        click.secho(
+            f"Failed to retrieve password for {item} with {provider}: {e}", fg="red"
+        )
+        return None
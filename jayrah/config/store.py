@@ -0,0 +1,83 @@
+"""Read, update and write back the YAML config file."""
+
+import os
+import pathlib
+import tempfile
+
+import yaml
+
+from . import defaults
+
+
+class ConfigStore:
+    """
+    Edit the config file as written by the user.
+
+    Unlike the loaded config, where secret references are resolved and boards
+    extended, the store keeps unknown keys, the key order and values like
+    pass:: lookups untouched. PyYAML drops comments, only the comment block at
+    the top of the file is carried over.
+    """
+
+    def __init__(self, config_file: pathlib.Path):
+        self.config_file = pathlib.Path(config_file)
+        self.data: dict = {}
+        self.header = ""
+        if self.config_file.exists():
+            text = self.config_file.read_text()
+            self.data = yaml.safe_load(text) or {}
+            header = []
+            for line in text.splitlines(keepends=True):
+                if not line.startswith("#") and line.strip():
+                    break
+                header.append(line)
+            self.header = "".join(header)
+
+    @property
+    def boards(self) -> list[dict]:
+        """Boards of the file, the defaults when it has none since those are used."""
+        if not self.data.get("boards"):
+            self.data["boards"] = [dict(board) for board in defaults.BOARDS]
+        return self.data["boards"]
+
+    def add_board(self, board: dict) -> None:
+        """Add a board, a board with the same name is replaced where it stands."""
+        for index, existing in enumerate(self.boards):
+            if existing.get("name") == board["name"]:
+                self.boards[index] = board
+                return
+        self.boards.append(board)
+
+    def remove_board(self, name: str) -> bool:
+        """Remove a board by name, returns whether it existed."""
+        remaining = [x for x in self.boards if x.get("name") != name]
+        removed = len(remaining) != len(self.boards)
+        self.data["boards"] = remaining
+        return removed
+
+    def set_general(self, key: str, value) -> None:
+        """Set an option of the general section, None removes it."""
+        general = self.data.setdefault("general", {})
+        if value is None:
+            general.pop(key, None)
+        else:
+            general[key] = value
+
+    def save(self) -> None:
+        """Write the file, through a temporary file so it is never left half written."""
+        self.config_file.parent.mkdir(parents=True, exist_ok=True)
+        content = self.header + yaml.safe_dump(
+            self.data, sort_keys=False, allow_unicode=True
+        )
+        fd, tmp_path = tempfile.mkstemp(
+            dir=self.config_file.parent, prefix=f".{self.config_file.name}."
+        )
+        try:
+            with os.fdopen(fd, "w") as file:
+                file.write(content)
+            if self.config_file.exists():
+                os.chmod(tmp_path, self.config_file.stat().st_mode)
+            os.replace(tmp_path, self.config_file)
+        except BaseException:
+            os.unlink(tmp_path)
+            raise
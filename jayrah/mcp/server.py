@@ -1,7 +1,9 @@
 # pylint: disable=too-many-lines,too-many-branches,too-many-statements,too-many-locals
 """MCP server implementation for Jayrah AI integration."""
 
+import asyncio
 import json
+import sys
 from collections.abc import Sequence
 from typing import TypeVar, Union
 
@@ -15,6 +17,7 @@ from jayrah import config
 from jayrah.ui import boards
 
 from .. import utils
+from ..utils import markdown_to_jira
 
 # Type definition for content return types
 ContentType = Union[types.TextContent, types.ImageContent, types.EmbeddedResource]
@@ -196,6 +199,30 @@ def _format_search_results(
     return summary
 
 
+def _edit_fields(arguments: dict, api_version: str = "2") -> dict:
+    """
+    The fields to update from the edit-issue arguments, unset ones are kept.
+
+    The description is Markdown, converted to the wiki markup of API v2 or
+    the ADF of API v3.
+    """
+    fields: dict = {}
+    if arguments.get("summary") is not None:
+        fields["summary"] = arguments["summary"]
+    if arguments.get("description") is not None:
+        if api_version == "3":
+            fields["description"] = markdown_to_jira.convert_v3(
+                arguments["description"]
+            )
+        else:
+            fields["description"] = markdown_to_jira.convert(arguments["description"])
+    if arguments.get("priority"):
+        fields["priority"] = {"name": arguments["priority"]}
+    if arguments.get("labels") is not None:
+        fields["labels"] = list(arguments["labels"])
+    return fields
+
+
 def create_server(context: ServerContext) -> Server:
     """Create and configure the MCP server with handlers that use the context."""
     server = Server("jayrah")
@@ -591,6 +618,38 @@ def create_server(context: ServerContext) -> Server:
                     "required": ["ticket", "comment"],
                 },
             ),
+            # Edit fields of Jira issue
+            types.Tool(
+                name="edit-issue",
+                description="Edit the summary, description, priority or labels of a Jira issue",
+                inputSchema={
+                    "type": "object",
+                    "properties": {
+                        "ticket": {
+                            "type": "string",
+                            "description": "Issue key (e.g., PROJ-123)",
+                        },
+                        "summary": {
+                            "type": "string",
+                            "description": "New summary",
+                        },
+                        "description": {
+                            "type": "string",
+                            "description": "New description in Markdown",
+                        },
+                        "priority": {
+                            "type": "string",
+                            "description": "Priority name (e.g., Major)",
+                        },
+                        "labels": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Labels replacing the current ones",
+                        },
+                    },
+                    "required": ["ticket"],
+                },
+            ),
             # Get raw issue JSON
             types.Tool(
                 name="get-issue-json",
@@ -638,6 +697,7 @@ def create_server(context: ServerContext) -> Server:
             "list-boards": _handle_list_boards,
             "search": _handle_search,
             "add-comment": _handle_add_comment,
+            "edit-issue": _handle_edit_issue,
             "get-issue-json": _handle_get_issue_json,
             "aggregate-story-points": _handle_aggregate_story_points,
         }
@@ -977,6 +1037,27 @@ def create_server(context: ServerContext) -> Server:
             )
         ]
 
+    async def _handle_edit_issue(arguments: dict) -> Sequence[ContentType]:
+        """Handle the edit-issue tool to update fields of a Jira issue."""
+        ticket = arguments.get("ticket")
+        if not ticket:
+            raise ValueError("Ticket key is required")
+
+        fields = _edit_fields(arguments, context.boards_obj.jira.api_version)
+        if not fields:
+            raise ValueError(
+                "Nothing to edit, give a summary, description, priority or labels"
+            )
+
+        context.boards_obj.jira.update_issue(ticket, fields)
+
+        return [
+            types.TextContent(
+                type="text",
+                text=f"Successfully updated {', '.join(fields)} of issue {ticket}",
+            )
+        ]
+
     async def _handle_get_issue_json(arguments: dict) -> Sequence[ContentType]:
         """Handle the get-issue-json tool to get raw JSON for a Jira issue."""
         ticket = arguments.get("ticket")
@@ -1115,3 +1196,11 @@ async def main(config_file=None):
     # Run the server using stdin/stdout streams
     async with mcp.server.stdio.stdio_server() as (read_stream, write_stream):
         await server.run(read_stream, write_stream, initialization_options)
+
+
+def run():
+    """Entry point of the jayrah-mcp binary, takes an optional config file."""
+    try:
+        asyncio.run(main(sys.argv[1] if len(sys.argv) > 1 else None))
+    except KeyboardInterrupt:
+        pass
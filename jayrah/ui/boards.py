@@ -4,7 +4,9 @@ import click
 
 from .. import utils
 from ..api import jira_client as jirahttp
-from ..config import defaults
+from ..config import BOARD_OVERRIDES, defaults
+from ..utils import jql as jql_parser
+from ..utils import offline, state
 from . import issues
 from .tui import run_textual_browser
 
@@ -40,12 +42,28 @@ def check(board, config) -> tuple[str, str]:
     if not jql:
         click.secho(f"Board {board} has no JQL defined", fg="red")
         return "", ""
+    try:
+        jql_parser.validate(jql)
+    except jql_parser.JqlSyntaxError as e:
+        click.secho(f"Board {board} has an invalid JQL: {e}", fg="red")
+        return "", ""
     order_by = chosen_boards[0].get("order_by", defaults.ORDER_BY)
     if config.get("verbose"):
         print(f"Running query: {jql} ORDER BY: {order_by}")
     return jql, order_by
 
 
+def overrides(board, config) -> dict:
+    """The limit, fields, columns and refresh_interval set on a board."""
+    boards = config.get("boards") or []
+    chosen = (
+        next((x for x in boards if x.get("name") == board), None)
+        if board
+        else next(iter(boards), None)
+    )
+    return {k: chosen[k] for k in BOARD_OVERRIDES if chosen and chosen.get(k)}
+
+
 class Boards:
     ctx: click.Context
     command: str = ""
@@ -67,18 +85,39 @@ class Boards:
             config, api_version=api_version, auth_method=auth_method
         )
         self.verbose = self.config.get("verbose", False)
+        # Overrides of the board being browsed, see overrides()
+        self.board_overrides: dict = {}
+        # Filter, sort and issue of the last session, restored by the browser
+        self.session: dict = {}
+        # Auto reload seconds of the --refresh flag, over the ones of the boards
+        self.refresh_override: int | None = None
 
         if self.verbose:
             print("Jayrah initialized with verbose logging enabled")
             print(f"Using Jira API version: {api_version}")
 
         self.issues_client = issues.Issues(self.config, self.jira)
-
-    def fuzzy_search(self, issues, auto_choose: bool = False):
-        """Use interactive UI to select an issue."""
+        self.offline_store = offline.OfflineStore(self.config)
+        self.state_store = state.StateStore(self.config)
+
+    def fuzzy_search(
+        self,
+        issues,
+        auto_choose: bool = False,
+        on_no_issues=None,
+        choose_multi: bool = False,
+    ):
+        """
+        Use interactive UI to select an issue.
+
+        When issues is None the UI starts right away and fetches the issues for
+        the current JQL itself, on_no_issues is called if there are none. With
+        choose_multi the list of the chosen issue keys is returned.
+        """
         if self.verbose:
+            count = "" if issues is None else f" for {len(issues)} issues"
             utils.log(
-                f"Preparing UI interface for {len(issues)} issues",
+                f"Preparing UI interface{count}",
                 "DEBUG",
                 verbose_only=True,
                 verbose=self.verbose,
@@ -92,6 +131,11 @@ class Boards:
                 self.jql,
                 self.order_by,
                 auto_choose=auto_choose,
+                on_no_issues=on_no_issues,
+                choose_multi=choose_multi,
+                board_overrides=self.board_overrides,
+                session=self.session,
+                refresh=self.refresh_override,
             )
         except Exception as e:
             click.secho(f"Error occurred with Textual UI: {e}", fg="red")
@@ -10,6 +10,55 @@ class Issues:
         self.config = config
         self.verbose = self.config.get("verbose", False)
 
+    def _get_fields(self, fields=None, extra_fields=None) -> list:
+        """Fields to fetch for an issue list, the defaults plus the epic field."""
+        if fields is not None:
+            return fields
+        fields = list(defaults.FIELDS)  # Create a copy of the default list
+        if self.config.get("epic_link_field"):
+            fields.append(self.config["epic_link_field"])
+        # The fields of a board come on top of the defaults the UI shows
+        fields.extend(x for x in extra_fields or () if x not in fields)
+        return fields
+
+    # pylint: disable=too-many-positional-arguments
+    def iter_issue_pages(
+        self,
+        jql,
+        page_size=100,
+        fields=None,
+        start_at=None,
+        use_cache=True,
+        board_overrides=None,
+    ):
+        """
+        Yield the (issues, total) of a JQL query page by page.
+
+        The limit of the board overrides caps the issues and the total, its
+        fields are fetched along with the default ones.
+        """
+        board_overrides = board_overrides or {}
+        fields = self._get_fields(fields, board_overrides.get("fields"))
+        limit = board_overrides.get("limit")
+        pages = self.jira.search_issues_iter(
+            jql,
+            page_size=min(page_size, limit) if limit else page_size,
+            fields=fields,
+            start_at=0 if start_at is None else start_at,
+            use_cache=use_cache,
+        )
+        count = 0
+        for result in pages:
+            issues = result.get("issues", [])
+            total = result.get("total", 0)
+            if limit:
+                issues = issues[: limit - count]
+                total = min(total, limit)
+            count += len(issues)
+            yield issues, total
+            if limit and count >= limit:
+                return
+
     # pylint: disable=too-many-positional-arguments
     def list_issues(
         self,
@@ -20,11 +69,10 @@ class Issues:
         fields=None,
         start_at=None,
         use_cache=True,
+        board_overrides=None,
     ):
         """List issues using JQL query."""
-        # Handle the dangerous default value
-        if fields is None:
-            fields = list(defaults.FIELDS)  # Create a copy of the default list
+        fields = self._get_fields(fields, (board_overrides or {}).get("fields"))
 
         if self.verbose:
             utils.log(f"Listing issues with JQL: {jql}")
@@ -34,20 +82,9 @@ class Issues:
             utils.log(f"Fields: {fields}")
 
         issues = []
-        current_start_at = 0 if start_at is None else start_at
-        while True:
-            if self.verbose:
-                utils.log(f"Fetching batch starting at {current_start_at}")
-
-            result = self.jira.search_issues(
-                jql,
-                start_at=current_start_at,
-                max_results=limit,
-                fields=fields,
-                use_cache=use_cache,
-            )
-
-            batch_issues = result.get("issues", [])
+        for batch_issues, _total in self.iter_issue_pages(
+            jql, limit, fields, start_at, use_cache, board_overrides
+        ):
             issues.extend(batch_issues)
 
             if self.verbose:
@@ -58,10 +95,7 @@ class Issues:
                     verbose=self.verbose,
                 )
 
-            total = result.get("total", 0)
-            if not all_pages or current_start_at + limit >= total:
+            if not all_pages:
                 break
 
-            current_start_at += limit
-
         return issues
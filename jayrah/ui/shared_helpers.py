@@ -1,6 +1,12 @@
 """Helpers shared between TUI and Web UI, with no TUI dependencies."""
 
+import datetime
+import shlex
+import sys
+from collections import Counter
+
 from jayrah import utils
+from jayrah.api import models
 from jayrah.config import defaults
 
 
@@ -35,25 +41,351 @@ def get_row_data_for_issue(issue: dict) -> tuple:
     )
 
 
-def filter_issues_by_text(issues: list, search_text: str) -> list:
+def label_completion(value: str, labels: list[str]) -> str | None:
+    """
+    The value with the label typed after its last comma completed, or None.
+
+    The first label starting with what was typed wins, whatever the case,
+    the labels already in the value are skipped.
+    """
+    *chosen, current = value.split(",")
+    typed = current.lstrip().casefold()
+    if not typed:
+        return None
+    chosen_labels = {x.strip().casefold() for x in chosen}
+    for label in labels:
+        folded = label.casefold()
+        if folded.startswith(typed) and folded != typed and folded not in chosen_labels:
+            return value + label[len(typed) :]
+    return None
+
+
+def get_issue_epic(issue: dict, epic_link_field: str | None = None) -> tuple:
+    """
+    Return the (key, name) of the epic an issue belongs to, or ("", "").
+
+    Jira Cloud exposes the epic as the parent issue while Jira Server keeps
+    it in the Epic Link custom field, which only gives us the epic key.
+    """
+    fields = issue.get("fields", {})
+    parent = fields.get("parent") or {}
+    parent_fields = parent.get("fields", {})
+    if parent_fields.get("issuetype", {}).get("name") == "Epic":
+        return parent.get("key", ""), parent_fields.get("summary", "")
+    if epic_link_field and fields.get(epic_link_field):
+        return str(fields[epic_link_field]), ""
+    return "", ""
+
+
+def group_issues(
+    issues: list, group_by: str, epic_link_field: str | None = None
+) -> list[tuple[str, str, list]]:
+    """
+    Split issues in groups of the same status, status category, assignee or epic.
+
+    Gives the key, label and issues of each group in the order to show them,
+    statuses by their category from To Do to Done and the issues without an
+    assignee or an epic last.
+    """
+    summaries = {issue["key"]: issue["fields"]["summary"] for issue in issues}
+    groups: dict[str, tuple[str, list]] = {}
+    ranks: dict[str, tuple] = {}
+    for issue in issues:
+        fields = issue.get("fields", {})
+        if group_by == "status":
+            status = fields.get("status") or {}
+            key = label = status.get("name", "")
+            category = (status.get("statusCategory") or {}).get("key", "")
+            order = defaults.STATUS_CATEGORY_ORDER
+            rank: tuple = (
+                order.index(category) if category in order else len(order),
+                key.lower(),
+            )
+        elif group_by == "category":
+            category = (fields.get("status") or {}).get("statusCategory") or {}
+            key = category.get("key", "")
+            label = category.get("name") or key
+            order = defaults.STATUS_CATEGORY_ORDER
+            rank = (order.index(key) if key in order else len(order), key)
+        elif group_by == "assignee":
+            assignee = fields.get("assignee")
+            key = utils.parse_email(assignee) if assignee else ""
+            label = (assignee or {}).get("displayName") or key
+            rank = (key == "", label.lower())
+        elif group_by == "epic":
+            key, epic_name = get_issue_epic(issue, epic_link_field)
+            label = f"{key} {epic_name or summaries.get(key, '')}".strip()
+            # Epics keep the order of their first issue
+            rank = (key == "", len(ranks))
+        else:
+            raise ValueError(f"Unknown group: {group_by}")
+        groups.setdefault(key, (label, []))[1].append(issue)
+        ranks.setdefault(key, rank)
+
+    empty_labels = {
+        "status": "No status",
+        "category": "No status",
+        "assignee": "Unassigned",
+        "epic": "No epic",
+    }
+    return [
+        (key, groups[key][0] or empty_labels[group_by], groups[key][1])
+        for key in sorted(groups, key=lambda x: ranks[x])
+    ]
+
+
+def kanban_columns(status_columns: dict | None = None) -> list[str]:
+    """Names of the kanban columns, the ones of the config go before Done."""
+    names = [name for _, name in defaults.KANBAN_COLUMNS]
+    configured = dict.fromkeys((status_columns or {}).values())
+    extra = [x for x in configured if x not in names]
+    return names[:-1] + extra + names[-1:]
+
+
+def kanban_column(status: dict, status_columns: dict | None = None) -> str:
+    """The kanban column of a status, from the config or its status category."""
+    if (name := status.get("name", "")) in (status_columns or {}):
+        return (status_columns or {})[name]
+    category = (status.get("statusCategory") or {}).get("key", "")
+    return dict(defaults.KANBAN_COLUMNS).get(category, defaults.KANBAN_COLUMNS[0][1])
+
+
+def visible_columns(columns: list[str], width: int | None) -> list[str]:
+    """
+    The columns fitting in a terminal width, in their order.
+
+    The low priority columns are hidden first, under the compact width only
+    the summary is left, and the browser adds the status to it.
+    """
+    if width is None:
+        return list(columns)
+    if width < defaults.COMPACT_WIDTH:
+        return ["summary"]
+    shown = list(columns)
+    needed = defaults.KEY_COLUMNS_WIDTH + sum(
+        defaults.COLUMN_WIDTHS.get(x, 0) for x in shown
+    )
+    for column in defaults.COLUMN_DROP_ORDER:
+        if needed <= width:
+            break
+        if column in shown:
+            shown.remove(column)
+            needed -= defaults.COLUMN_WIDTHS.get(column, 0)
+    return shown
+
+
+def _sort_value(issue: dict, column: str):
+    fields = issue.get("fields", {})
+    if column == "key":
+        project, _, number = issue["key"].partition("-")
+        return project, int(number) if number.isdigit() else 0
+    if column == "status":
+        return (fields.get("status") or {}).get("name", "").lower()
+    if column == "updated":
+        return fields.get("updated") or ""
+    if column == "priority":
+        # Jira numbers the priorities of its default scheme from the highest
+        priority_id = str((fields.get("priority") or {}).get("id", ""))
+        return int(priority_id) if priority_id.isdigit() else sys.maxsize
+    if column == "assignee":
+        # Unassigned issues go last
+        assignee = fields.get("assignee")
+        return (0, utils.parse_email(assignee).lower()) if assignee else (1, "")
+    raise ValueError(f"Unknown sort column: {column}")
+
+
+def sort_issues(issues: list, column: str | None, reverse: bool = False) -> list:
+    """Sort loaded issues by a column, None keeps the order of the server."""
+    if column is None:
+        return list(reversed(issues)) if reverse else list(issues)
+    return sorted(issues, key=lambda x: _sort_value(x, column), reverse=reverse)
+
+
+def board_stats(issues: list, now: datetime.datetime | None = None) -> dict:
+    """
+    Counts of the issues by status, assignee and priority, most common first.
+
+    The oldest unresolved issue and the average age of the unresolved issues
+    tell how long the work stays open, they are None when all are resolved.
+    """
+    now = now or datetime.datetime.now().astimezone()
+    counters: dict[str, Counter] = {
+        "status": Counter(),
+        "assignee": Counter(),
+        "priority": Counter(),
+    }
+    unresolved = []
+    for issue in issues:
+        fields = issue.get("fields", {})
+        counters["status"][(fields.get("status") or {}).get("name") or "None"] += 1
+        assignee = fields.get("assignee") or {}
+        counters["assignee"][assignee.get("displayName") or "Unassigned"] += 1
+        counters["priority"][(fields.get("priority") or {}).get("name") or "None"] += 1
+        done = models.status_category(fields) == "done" or fields.get("resolution")
+        if not done and fields.get("created"):
+            unresolved.append((models.parse_time(fields["created"]), issue))
+    unresolved.sort(key=lambda x: x[0])
+    ages = [now - created for created, _ in unresolved]
+    return {
+        "total": len(issues),
+        **{name: counter.most_common() for name, counter in counters.items()},
+        "oldest": unresolved[0][1] if unresolved else None,
+        "oldest_age": ages[0] if ages else None,
+        "average_age": sum(ages, datetime.timedelta()) / len(ages) if ages else None,
+    }
+
+
+def fuzzy_match(pattern: str, text: str) -> tuple[int, list[int]] | None:
+    """
+    Score and positions of the letters of pattern found in order in text.
+
+    Like fzf the shortest window holding the letters is kept, consecutive
+    letters and letters starting a word score more, gaps score less. None
+    when some letter is missing, spaces of the pattern are ignored.
+    """
+    pattern = "".join(pattern.lower().split())
+    lowered = text.lower()
+    if not pattern:
+        return None
+    end = -1
+    for char in pattern:
+        end = lowered.find(char, end + 1)
+        if end < 0:
+            return None
+    # Walk back from the last letter to the latest start of a match
+    start = end + 1
+    for char in reversed(pattern):
+        start = lowered.rfind(char, 0, start)
+    positions = []
+    index = start - 1
+    for char in pattern:
+        index = lowered.find(char, index + 1)
+        positions.append(index)
+
+    score = 0
+    for i, position in enumerate(positions):
+        score += 16
+        if position == 0 or not lowered[position - 1].isalnum():
+            score += 8
+        if i and position == positions[i - 1] + 1:
+            score += 8
+        elif i:
+            score -= min(position - positions[i - 1] - 1, 8)
+    return score, positions
+
+
+def _people(user: dict | None) -> list[str]:
+    """Names a user is filtered by, its display name and its username."""
+    if not user:
+        return ["none"]
+    return [user.get("displayName") or "", utils.parse_email(user)]
+
+
+def _names(values: list | None) -> list[str]:
+    return [x.get("name", "") for x in values or []]
+
+
+# Fields of the field:value filter expressions, with the values they match
+FILTER_FIELDS = {
+    "key": lambda issue: [issue["key"]],
+    "summary": lambda issue: [issue["fields"].get("summary") or ""],
+    "status": lambda issue: [(issue["fields"].get("status") or {}).get("name", "")],
+    "assignee": lambda issue: _people(issue["fields"].get("assignee")),
+    "reporter": lambda issue: _people(issue["fields"].get("reporter")),
+    "label": lambda issue: list(issue["fields"].get("labels") or []),
+    "type": lambda issue: [(issue["fields"].get("issuetype") or {}).get("name", "")],
+    "priority": lambda issue: [
+        (issue["fields"].get("priority") or {}).get("name", "")
+    ],
+    "component": lambda issue: _names(issue["fields"].get("components")),
+}
+FILTER_FIELD_ALIASES = {"labels": "label", "components": "component"}
+
+
+def parse_filter(text: str) -> tuple[list[tuple[str, str]], str]:
+    """
+    Split a filter into its field:value expressions and the free text left.
+
+    Values with spaces are quoted, like status:"in progress". Words with an
+    unknown field before the colon stay in the free text.
+    """
+    try:
+        words = shlex.split(text)
+    except ValueError:
+        # An unbalanced quote, still filter by what was typed so far
+        words = text.split()
+    predicates, free = [], []
+    for word in words:
+        name, sep, value = word.partition(":")
+        name = FILTER_FIELD_ALIASES.get(name.lower(), name.lower())
+        if sep and value and name in FILTER_FIELDS:
+            predicates.append((name, value.lower()))
+        else:
+            free.append(word)
+    return predicates, " ".join(free)
+
+
+def _matches_fields(issue: dict, predicates: list[tuple[str, str]]) -> bool:
+    """Whether every field:value expression has a value of the issue containing it."""
+    return all(
+        any(value in x.lower() for x in FILTER_FIELDS[name](issue))
+        for name, value in predicates
+    )
+
+
+def match_positions(pattern: str, text: str, mode: str | None = None) -> list[int]:
+    """Positions of text matching the free text of the filter, to highlight them."""
+    pattern = parse_filter(pattern)[1]
+    if not pattern.strip():
+        return []
+    if (mode or defaults.FILTER_MODE) == "fuzzy":
+        match = fuzzy_match(pattern, text)
+        return match[1] if match else []
+    start = text.lower().find(pattern.lower())
+    return list(range(start, start + len(pattern))) if start >= 0 else []
+
+
+def _filter_fields(issue: dict) -> list[str]:
+    fields = issue["fields"]
+    assignee = "none"
+    if assignee_field := fields.get("assignee"):
+        assignee = utils.parse_email(assignee_field)
+    return [
+        issue["key"],
+        fields["summary"],
+        assignee,
+        utils.parse_email(fields.get("reporter", "")),
+        fields["status"]["name"],
+    ]
+
+
+def filter_issues_by_text(
+    issues: list, search_text: str, mode: str | None = None
+) -> list:
+    """
+    The issues with a key, summary, assignee, reporter or status matching.
+
+    field:value expressions of the filter, like status:progress, only keep
+    the issues with that field containing the value. In fuzzy mode the best
+    matches of the rest of the text come first, the substring mode keeps the
+    order of the issues.
+    """
+    predicates, search_text = parse_filter(search_text)
+    if predicates:
+        issues = [x for x in issues if _matches_fields(x, predicates)]
     if not search_text.strip():
         return issues
-    filtered_issues = []
+    if (mode or defaults.FILTER_MODE) == "fuzzy":
+        scored = []
+        for issue in issues:
+            matches = [fuzzy_match(search_text, x) for x in _filter_fields(issue)]
+            scores = [x[0] for x in matches if x]
+            if scores:
+                scored.append((max(scores), issue))
+        return [issue for _, issue in sorted(scored, key=lambda x: -x[0])]
     search_text = search_text.lower()
-    for issue in issues:
-        issue_key = issue["key"].lower()
-        summary = issue["fields"]["summary"].lower()
-        assignee = "none"
-        if assignee_field := issue["fields"].get("assignee"):
-            assignee = utils.parse_email(assignee_field).lower()
-        reporter = utils.parse_email(issue["fields"].get("reporter", "")).lower()
-        status = issue["fields"]["status"]["name"].lower()
-        if (
-            search_text in issue_key
-            or search_text in summary
-            or search_text in assignee
-            or search_text in reporter
-            or search_text in status
-        ):
-            filtered_issues.append(issue)
-    return filtered_issues
+    return [
+        issue
+        for issue in issues
+        if any(search_text in x.lower() for x in _filter_fields(issue))
+    ]
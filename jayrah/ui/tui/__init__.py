@@ -5,35 +5,101 @@ from .app import IssueBrowserApp, run_textual_browser
 from .base import BaseModalScreen, JayrahAppMixin
 from .helpers import filter_issues_by_text, get_row_data_for_issue
 from .views import (
+    ActivityScreen,
     AddCommentScreen,
+    AssigneeEditScreen,
+    AttachmentsScreen,
     BoardSelectionScreen,
+    BulkActionsScreen,
+    CloneIssueScreen,
     CommentsViewScreen,
     ComponentsEditScreen,
+    CreateIssueScreen,
+    DebugPanel,
     DescriptionEditScreen,
     EditSelectionScreen,
+    ExportScreen,
+    FollowLinkScreen,
+    FixVersionsEditScreen,
     FuzzyFilterScreen,
+    HistoryScreen,
     IssueDetailPanel,
+    IssueTypeEditScreen,
+    JqlQueryScreen,
+    KanbanScreen,
     LabelsEditScreen,
+    LinksViewScreen,
+    LogsScreen,
+    MessagesScreen,
+    MultiSelectScreen,
+    PriorityEditScreen,
+    ProfileSelectionScreen,
+    ProjectsScreen,
+    PullRequestScreen,
+    ReferencesScreen,
+    RemoteLinksScreen,
+    SaveBoardScreen,
+    SingleSelectScreen,
+    SprintSelectionScreen,
+    StandupScreen,
+    StatsScreen,
+    SubtasksTreeScreen,
     TitleEditScreen,
+    TransitionFieldsScreen,
     TransitionSelectionScreen,
+    WorklogsViewScreen,
+    YankScreen,
 )
 
 __all__ = [
+    "ActivityScreen",
     "AddCommentScreen",
+    "AssigneeEditScreen",
+    "AttachmentsScreen",
     "BaseModalScreen",
     "BoardSelectionScreen",
+    "BulkActionsScreen",
+    "CloneIssueScreen",
     "CommentsViewScreen",
     "ComponentsEditScreen",
+    "CreateIssueScreen",
+    "DebugPanel",
     "DescriptionEditScreen",
     "EditSelectionScreen",
+    "ExportScreen",
+    "FollowLinkScreen",
+    "FixVersionsEditScreen",
     "FuzzyFilterScreen",
+    "HistoryScreen",
     "IssueBrowserActions",
     "IssueBrowserApp",
     "IssueDetailPanel",
+    "IssueTypeEditScreen",
     "JayrahAppMixin",
+    "JqlQueryScreen",
+    "KanbanScreen",
     "LabelsEditScreen",
+    "LinksViewScreen",
+    "LogsScreen",
+    "MessagesScreen",
+    "MultiSelectScreen",
+    "PriorityEditScreen",
+    "ProfileSelectionScreen",
+    "ProjectsScreen",
+    "PullRequestScreen",
+    "ReferencesScreen",
+    "RemoteLinksScreen",
+    "SaveBoardScreen",
+    "SingleSelectScreen",
+    "SprintSelectionScreen",
+    "StandupScreen",
+    "StatsScreen",
+    "SubtasksTreeScreen",
     "TitleEditScreen",
+    "TransitionFieldsScreen",
     "TransitionSelectionScreen",
+    "WorklogsViewScreen",
+    "YankScreen",
     "filter_issues_by_text",
     "get_row_data_for_issue",
     "run_textual_browser",
@@ -3,20 +3,58 @@
 
 from __future__ import annotations
 
+import pathlib
+from datetime import datetime
 from typing import Any, cast
 
+import yaml
+from rich.markup import escape
+from textual.widgets.data_table import RowDoesNotExist
+
+from jayrah import config as jayrah_config
 from jayrah import utils
+from jayrah.api import diagnostics
+from jayrah.config import defaults
+from jayrah.utils import clipboard, export, git, issue_view, logs, report
 
+from . import undo
+from .helpers import filter_issues_by_text
 from .views import (
     ActionsPanel,
+    ActivityScreen,
+    AttachmentsScreen,
     BoardSelectionScreen,
+    BulkActionsScreen,
+    CloneIssueScreen,
     CommentsViewScreen,
     ComponentsEditScreen,
+    CreateIssueScreen,
+    DebugPanel,
     EditSelectionScreen,
+    ExportScreen,
+    FollowLinkScreen,
     FuzzyFilterScreen,
+    HistoryScreen,
     IssueDetailPanel,
+    JqlQueryScreen,
+    KanbanScreen,
     LabelsEditScreen,
+    LinksViewScreen,
+    LogsScreen,
+    MessagesScreen,
+    ProfileSelectionScreen,
+    ProjectsScreen,
+    PullRequestScreen,
+    ReferencesScreen,
+    RemoteLinksScreen,
+    SaveBoardScreen,
+    SprintSelectionScreen,
+    StandupScreen,
+    StatsScreen,
+    SubtasksTreeScreen,
     TransitionSelectionScreen,
+    WorklogsViewScreen,
+    YankScreen,
 )
 
 
@@ -36,18 +74,355 @@ class IssueBrowserActions:
     order_by: str = ""  # Order by clause for fetching issues
     issues: list = []  # List of issues fetched from JIRA
     selected_issue: str | None = None  # Currently selected issue
+    issues_generation: int = 0  # Bumped on every reload to drop stale results
+    offline_saved_at: float | None = None  # When the offline issues were saved
+    issue_pages: Any = None  # Pages of the current list not fetched yet
+    issues_total: int = 0  # Total number of issues matching the current list
+    loading_more: bool = False  # Whether the next page is being fetched
+    query_history: list[str] = []  # JQL queries run from the query prompt
+    sort_column: str | None = None  # Column the loaded issues are sorted by
+    sort_reverse: bool = False  # Whether that sort is reversed
+    group_by: str | None = None  # What the issue rows are grouped by
+    updated_issues: set[str] = set()  # Changed since the last auto reload
+    board_overrides: dict = {}  # Limit, fields, columns and refresh of the board
 
     def action_reload(self) -> None:
         """Reload issues asynchronously with loading state."""
         # Show loading state
         cast(Any, self).notify("🔄 Reloading issues...")
 
-        # Run the reload in a worker thread
-        # pylint: disable=unnecessary-lambda
-        cast(Any, self).run_worker(
-            lambda: self._reload_issues(),
-            exclusive=True,
-            thread=True,
+        # Run the reload on the shared worker pool
+        self._submit_issues_request(
+            self._reload_issues,
+            self._update_issues_after_reload,
+            "Error reloading issues",
+        )
+
+    def action_refresh_detail(self) -> None:
+        """Fetch the selected issue again, skipping every cache."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="warning")
+            return
+        detail_panel = app.query_one(IssueDetailPanel)
+        for detail_cache in (
+            detail_panel.ticket_cache,
+            detail_panel.issue_data_cache,
+            detail_panel.transitions_cache,
+        ):
+            detail_cache.pop(app.selected_issue, None)
+        detail_panel.update_issue(app.selected_issue, app.config, use_cache=False)
+
+    def fetch_issues(
+        self,
+        jql: str,
+        order_by: str | None,
+        use_cache: bool = True,
+        on_page=None,
+        board_overrides: dict | None = None,
+    ) -> list:
+        """
+        Worker method listing the issues of a query.
+
+        Every page but the last is handed to `on_page(issues, total)` with the
+        issues loaded so far. Once PRELOAD_ISSUES are loaded, the remaining
+        pages are handed over as a third argument for `load_more_issues` and
+        the issues loaded so far are returned. Without `on_page` the complete
+        list is returned.
+
+        Online the result is saved to the offline store. In offline mode the
+        last saved result is returned instead of asking Jira. Without
+        `board_overrides` the ones of the board browsed apply.
+        """
+        app = cast(Any, self)
+        store = app.jayrah_obj.offline_store
+        if app.config.get("offline"):
+            saved = store.load_issues(jql, order_by)
+            if saved is None:
+                raise ValueError("No offline data for this query, browse it online")
+            issues, app.offline_saved_at = saved
+            return issues
+
+        issues: list = []
+        if board_overrides is None:
+            board_overrides = app.board_overrides
+        pages = app.jayrah_obj.issues_client.iter_issue_pages(
+            jql, use_cache=use_cache, board_overrides=board_overrides
+        )
+        for batch, total in pages:
+            issues.extend(batch)
+            if on_page is None or not batch or len(issues) >= total:
+                continue
+            # Past the preload the next pages are only fetched on demand
+            if len(issues) >= defaults.PRELOAD_ISSUES:
+                on_page(list(issues), total, pages)
+                break
+            on_page(list(issues), total)
+        store.save_issues(jql, order_by, issues)
+        return issues
+
+    def _show_offline_indicator(self) -> None:
+        """Show in the header how old the offline data is."""
+        app = cast(Any, self)
+        if app.config.get("offline") and app.offline_saved_at:
+            saved_at = datetime.fromtimestamp(app.offline_saved_at)
+            app.sub_title = f"⚠️ Offline, data from {saved_at:%Y-%m-%d %H:%M}"
+
+    def _show_partial_issues(self, issues: list, total: int) -> None:
+        """Show the issues loaded so far while the next pages are fetched."""
+        app = cast(Any, self)
+        app.issues = issues
+        app.issues_total = total
+        app.apply_fuzzy_filter(app.filter_text, notify=False)
+        app.sub_title = f"⏳ Loading more… {len(issues)}/{total}"
+        self._update_issues_title()
+
+    def _update_issues_title(self) -> None:
+        """Show how many issues are loaded out of the total in the table border."""
+        app = cast(Any, self)
+        table = app.query_one("#issues-table")
+        if app.issue_pages is not None:
+            title = f"Issues ({len(app.issues)}/{app.issues_total})"
+        else:
+            title = f"Issues ({len(app.issues)})"
+        if app.sort_column:
+            title += f" by {app.sort_column} {'↓' if app.sort_reverse else '↑'}"
+        elif app.sort_reverse:
+            title += " reversed"
+        if app.group_by:
+            title += f", grouped by {app.group_by}"
+        if app.spinner:
+            title += f" {app.spinner}"
+        table.border_title = title
+        table.border_subtitle = self._tabs_title()
+
+    def _tabs_title(self) -> str:
+        """The tabs in the table border once there are several, active reversed."""
+        app = cast(Any, self)
+        if len(app.tabs) < 2:
+            return ""
+        return " ".join(
+            f"[reverse] {index + 1} {title} [/]"
+            if index == app.tabs.active
+            else f" {index + 1} {title} "
+            for index, title in enumerate(map(escape, app.tabs.titles(app)))
+        )
+
+    def action_new_tab(self) -> None:
+        """Open a tab with the current query, to change it with b or /."""
+        app = cast(Any, self)
+        if len(app.tabs) >= defaults.MAX_TABS:
+            app.notify(f"No more than {defaults.MAX_TABS} tabs", severity="warning")
+            return
+        index = app.tabs.open(app)
+        self.show_tab()
+        app.notify(f"Opened tab {index + 1}, press b or / to pick its issues")
+
+    def action_switch_tab(self, index: int) -> None:
+        """Show the tab of a number key."""
+        if cast(Any, self).tabs.switch(self, index):
+            self.show_tab()
+
+    def action_next_tab(self) -> None:
+        app = cast(Any, self)
+        self.action_switch_tab((app.tabs.active + 1) % len(app.tabs))
+
+    def action_previous_tab(self) -> None:
+        app = cast(Any, self)
+        self.action_switch_tab((app.tabs.active - 1) % len(app.tabs))
+
+    def action_close_tab(self) -> None:
+        """Close the active tab, the last one stays open."""
+        app = cast(Any, self)
+        if not app.tabs.close(app):
+            app.notify("The last tab can't be closed", severity="warning")
+            return
+        self.show_tab()
+
+    def show_tab(self) -> None:
+        """Show the issues of the tab the app switched to."""
+        app = cast(Any, self)
+        # A list still loading for the previous tab must not land in this one
+        app.issues_generation += 1
+        app.query_one("#issues-table").loading = False
+        app.start_auto_reload()
+        app.relayout_table()
+        app.query_one(IssueDetailPanel).update_issue(app.selected_issue, app.config)
+        self._update_issues_title()
+        if not app.issues:
+            # Left before its issues were loaded
+            self.action_reload()
+
+    def action_cycle_sort(self) -> None:
+        """Sort the loaded issues by the next column, or back to the server order."""
+        app = cast(Any, self)
+        columns = (None, *defaults.SORT_COLUMNS)
+        app.sort_column = columns[(columns.index(app.sort_column) + 1) % len(columns)]
+        self._apply_sort()
+
+    def action_reverse_sort(self) -> None:
+        """Reverse the order of the loaded issues."""
+        app = cast(Any, self)
+        app.sort_reverse = not app.sort_reverse
+        self._apply_sort()
+
+    def _apply_sort(self) -> None:
+        app = cast(Any, self)
+        app.query_one("#issues-table").clear()
+        app.apply_fuzzy_filter(app.filter_text, notify=False)
+        self._update_issues_title()
+        app.notify(
+            f"↕️ Sorted by {app.sort_column}"
+            if app.sort_column
+            else "↕️ Back to the order of the server"
+        )
+
+    def load_more_issues(self, cursor_row: int) -> None:
+        """Fetch the next page once the cursor gets close to the last loaded row."""
+        app = cast(Any, self)
+        table = app.query_one("#issues-table")
+        if app.issue_pages is None or app.loading_more:
+            return
+        if cursor_row < table.row_count - defaults.LOAD_MORE_THRESHOLD:
+            return
+
+        app.loading_more = True
+        app.sub_title = "⏳ Loading more…"
+        generation = self.issues_generation
+
+        def is_stale() -> bool:
+            return generation != self.issues_generation
+
+        def handle_result(page) -> None:
+            if is_stale():
+                return
+            app.loading_more = False
+            app.sub_title = ""
+            batch, total = page or ([], 0)
+            app.issues = app.issues + batch
+            app.issues_total = max(total, len(app.issues))
+            if not batch or len(app.issues) >= total:
+                app.issue_pages = None
+            app.apply_fuzzy_filter(app.filter_text, notify=False)
+            self._update_issues_title()
+            app.jayrah_obj.offline_store.save_issues(app.jql, app.order_by, app.issues)
+
+        def handle_error(exc: Exception) -> None:
+            if not is_stale():
+                app.loading_more = False
+                app.sub_title = ""
+                app.notify(f"Error loading more issues: {exc}", severity="error")
+
+        app.worker_pool.submit(
+            next,
+            app.issue_pages,
+            None,
+            on_result=handle_result,
+            on_error=handle_error,
+            is_stale=is_stale,
+        )
+
+    def auto_reload_issues(self) -> None:
+        """
+        Run the query again in the background and highlight what changed.
+
+        Skipped offline and while a list is loading, lists too big to be
+        loaded at once are not reloaded either.
+        """
+        app = cast(Any, self)
+        if app.config.get("offline") or app.issues_loading:
+            return
+        if app.issue_pages is not None or app.loading_more:
+            return
+        generation = self.issues_generation
+
+        def is_stale() -> bool:
+            return generation != self.issues_generation
+
+        def handle_result(new_issues: list) -> None:
+            if not is_stale():
+                self._show_changed_issues(new_issues)
+
+        app.worker_pool.submit(
+            self.fetch_issues,
+            app.jql,
+            app.order_by,
+            False,
+            on_result=handle_result,
+            on_error=lambda exc: app.log(f"Auto reload failed: {exc}"),
+            is_stale=is_stale,
+        )
+
+    def _show_changed_issues(self, new_issues: list) -> None:
+        """Show an auto reloaded list, new issues and status changes highlighted."""
+        app = cast(Any, self)
+        old_status = {
+            x["key"]: (x["fields"].get("status") or {}).get("name")
+            for x in app.issues
+        }
+        changed = {
+            x["key"]
+            for x in new_issues
+            if x["key"] not in old_status
+            or old_status[x["key"]] != (x["fields"].get("status") or {}).get("name")
+        }
+        app.updated_issues = (app.updated_issues | changed) & {
+            x["key"] for x in new_issues
+        }
+        app.issues = new_issues
+        app.apply_fuzzy_filter(app.filter_text, notify=False)
+        self._update_issues_title()
+        if changed:
+            app.notify(f"🔔 {len(changed)} issues updated since last view")
+
+    def _submit_issues_request(self, fetch, on_result, error_prefix: str) -> None:
+        """
+        Fetch a new issue list on the worker pool.
+
+        `fetch` is called with a page callback so the first pages of a long
+        list are shown while the rest loads. Only the latest request gets to
+        update the table, responses from earlier reloads or board switches
+        are dropped.
+        """
+        self.issues_generation += 1
+        generation = self.issues_generation
+        cast(Any, self).issue_pages = None
+        cast(Any, self).loading_more = False
+        table = cast(Any, self).query_one("#issues-table")
+        table.loading = True
+
+        def is_stale() -> bool:
+            return generation != self.issues_generation
+
+        def handle_page(issues: list, total: int, pages=None) -> None:
+            if not is_stale():
+                table.loading = False
+                cast(Any, self).issue_pages = pages
+                self._show_partial_issues(issues, total)
+
+        def on_page(issues: list, total: int, pages=None) -> None:
+            cast(Any, self).call_from_thread(handle_page, issues, total, pages)
+
+        def handle_result(result) -> None:
+            if not is_stale():
+                table.loading = False
+                cast(Any, self).sub_title = ""
+                self._show_offline_indicator()
+                on_result(result)
+                self._update_issues_title()
+
+        def handle_error(exc: Exception) -> None:
+            if not is_stale():
+                table.loading = False
+                cast(Any, self).sub_title = ""
+                cast(Any, self).notify(f"{error_prefix}: {exc}", severity="error")
+
+        cast(Any, self).worker_pool.submit(
+            fetch,
+            on_page,
+            on_result=handle_result,
+            on_error=handle_error,
+            is_stale=is_stale,
         )
 
     def action_add_labels(self) -> None:
@@ -56,24 +431,27 @@ class IssueBrowserActions:
             cast(Any, self).notify("No issue selected", severity="warning")
             return
 
-        # Get the current issue data to retrieve existing labels
-        try:
-            issue_data = cast(Any, self).jayrah_obj.jira.get_issue(
-                cast(Any, self).selected_issue
-            )
+        issue_key = cast(Any, self).selected_issue
+
+        def show_labels(issue_data: dict) -> None:
             current_labels = issue_data.get("fields", {}).get("labels", [])
 
             # Show the labels edit screen
             cast(Any, self).push_screen(
                 LabelsEditScreen(
-                    self,
-                    cast(Any, self).selected_issue,
-                    current_labels,
-                    cast(Any, self).config,
+                    self, issue_key, current_labels, cast(Any, self).config
                 )
             )
-        except Exception as exc:
-            cast(Any, self).notify(f"Error loading issue data: {exc}", severity="error")
+
+        # Get the current issue data to retrieve existing labels
+        cast(Any, self).worker_pool.submit(
+            cast(Any, self).jayrah_obj.jira.get_issue,
+            issue_key,
+            on_result=show_labels,
+            on_error=lambda exc: cast(Any, self).notify(
+                f"Error loading issue data: {exc}", severity="error"
+            ),
+        )
 
     def action_edit_components(self) -> None:
         """Open modal to edit components for the selected issue."""
@@ -81,11 +459,9 @@ class IssueBrowserActions:
             cast(Any, self).notify("No issue selected", severity="warning")
             return
 
-        # Get the current issue data to retrieve existing components
-        try:
-            issue_data = cast(Any, self).jayrah_obj.jira.get_issue(
-                cast(Any, self).selected_issue
-            )
+        issue_key = cast(Any, self).selected_issue
+
+        def show_components(issue_data: dict) -> None:
             current_components_data = issue_data.get("fields", {}).get("components", [])
             current_components = [
                 comp.get("name", "") for comp in current_components_data
@@ -94,14 +470,19 @@ class IssueBrowserActions:
             # Show the components edit screen
             cast(Any, self).push_screen(
                 ComponentsEditScreen(
-                    self,
-                    cast(Any, self).selected_issue,
-                    current_components,
-                    cast(Any, self).config,
+                    self, issue_key, current_components, cast(Any, self).config
                 )
             )
-        except Exception as exc:
-            cast(Any, self).notify(f"Error loading issue data: {exc}", severity="error")
+
+        # Get the current issue data to retrieve existing components
+        cast(Any, self).worker_pool.submit(
+            cast(Any, self).jayrah_obj.jira.get_issue,
+            issue_key,
+            on_result=show_components,
+            on_error=lambda exc: cast(Any, self).notify(
+                f"Error loading issue data: {exc}", severity="error"
+            ),
+        )
 
     def action_transition_issue(self) -> None:
         """Open modal to transition the selected issue to a new status."""
@@ -161,16 +542,382 @@ class IssueBrowserActions:
         except Exception as exc:
             cast(Any, self).notify(f"Error loading comments: {exc}", severity="error")
 
+    def action_view_worklogs(self) -> None:
+        """Open modal to view and log work on the selected issue."""
+        if not cast(Any, self).selected_issue:
+            cast(Any, self).notify("No issue selected", severity="warning")
+            return
+
+        cast(Any, self).push_screen(
+            WorklogsViewScreen(
+                self,
+                cast(Any, self).selected_issue,
+                cast(Any, self).config,
+            )
+        )
+
+    def action_view_links(self) -> None:
+        """Open modal to browse the issues linked to the selected issue."""
+        if not cast(Any, self).selected_issue:
+            cast(Any, self).notify("No issue selected", severity="warning")
+            return
+
+        cast(Any, self).push_screen(
+            LinksViewScreen(
+                self,
+                cast(Any, self).selected_issue,
+                cast(Any, self).config,
+            )
+        )
+
+    def action_view_attachments(self) -> None:
+        """Open modal listing the attachments of the selected issue."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="warning")
+            return
+        app.push_screen(AttachmentsScreen(self, app.selected_issue, app.config))
+
+    def action_attach_pull_request(self) -> None:
+        """Open the prompt attaching a pull request to the selected issue."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="warning")
+            return
+        app.push_screen(PullRequestScreen(self, app.selected_issue))
+
+    def action_follow_link(self) -> None:
+        """Open modal with the links of the details to open one in the browser."""
+        app = cast(Any, self)
+        detail_panel = app.query_one(IssueDetailPanel)
+        content = detail_panel.ticket_cache.get(detail_panel.ticket) or ""
+        links = issue_view.markdown_links(content)
+        if not links:
+            app.notify("No links in the issue details", severity="warning")
+            return
+        app.push_screen(FollowLinkScreen(self, detail_panel.ticket, links))
+
+    def action_jump_to_reference(self) -> None:
+        """Jump to an issue key of the details, picked when there are several."""
+        app = cast(Any, self)
+        detail_panel = app.query_one(IssueDetailPanel)
+        content = detail_panel.ticket_cache.get(detail_panel.ticket) or ""
+        keys = issue_view.issue_keys(content, exclude=detail_panel.ticket)
+        if not keys:
+            app.notify("No other issue referenced in the details", severity="warning")
+        elif len(keys) == 1:
+            self.jump_to_reference(keys[0])
+        else:
+            app.push_screen(ReferencesScreen(self, detail_panel.ticket, keys))
+
+    def jump_to_reference(self, issue_key: str) -> None:
+        """Show a referenced issue, keeping the current one to come back to it."""
+        app = cast(Any, self)
+        if app.selected_issue:
+            app.reference_stack.append(app.selected_issue)
+        self.jump_to_issue(issue_key)
+
+    def action_reference_back(self) -> None:
+        """Go back to the issue a referenced issue was jumped to from."""
+        app = cast(Any, self)
+        if not app.reference_stack:
+            app.notify("No issue to go back to", severity="warning")
+            return
+        self.jump_to_issue(app.reference_stack.pop())
+
+    def action_view_remote_links(self) -> None:
+        """Open modal listing the web links of the selected issue."""
+        if not cast(Any, self).selected_issue:
+            cast(Any, self).notify("No issue selected", severity="warning")
+            return
+
+        cast(Any, self).push_screen(
+            RemoteLinksScreen(
+                self,
+                cast(Any, self).selected_issue,
+                cast(Any, self).config,
+            )
+        )
+
+    def action_clone_issue(self) -> None:
+        """Open modal to clone the selected issue, in its project or another."""
+        if not cast(Any, self).selected_issue:
+            cast(Any, self).notify("No issue selected", severity="warning")
+            return
+
+        issue = next(
+            (
+                x
+                for x in cast(Any, self).issues
+                if x["key"] == cast(Any, self).selected_issue
+            ),
+            None,
+        )
+        summary = issue["fields"].get("summary", "") if issue else ""
+        cast(Any, self).push_screen(
+            CloneIssueScreen(
+                self,
+                cast(Any, self).selected_issue,
+                summary,
+                cast(Any, self).config,
+            )
+        )
+
+    def action_view_subtasks(self) -> None:
+        """Open modal showing the parent/subtasks tree of the selected issue."""
+        if not cast(Any, self).selected_issue:
+            cast(Any, self).notify("No issue selected", severity="warning")
+            return
+
+        cast(Any, self).push_screen(
+            SubtasksTreeScreen(
+                self,
+                cast(Any, self).selected_issue,
+                cast(Any, self).config,
+            )
+        )
+
+    def jump_to_issue(self, issue_key: str) -> None:
+        """Select an issue in the table, or show it alone if it isn't listed."""
+        table = cast(Any, self).query_one("#issues-table")
+        try:
+            row_index = table.get_row_index(issue_key)
+        except RowDoesNotExist:
+            # Not part of the current query, only load it in the detail pane
+            cast(Any, self).selected_issue = issue_key
+            detail_panel = cast(Any, self).query_one(IssueDetailPanel)
+            detail_panel.update_issue(issue_key, cast(Any, self).config)
+            cast(Any, self).notify(f"{issue_key} is not in the current list")
+            return
+
+        table.move_cursor(row=row_index)
+
     def action_filter(self) -> None:
         """Open a simple filter dialog to search across all visible fields."""
         # Show the filter screen
         cast(Any, self).push_screen(FuzzyFilterScreen(self))
 
+    def action_cycle_group(self) -> None:
+        """Group the issues table by the next of status, assignee and epic."""
+        app = cast(Any, self)
+        groups = (None, *defaults.GROUP_BY_FIELDS)
+        app.group_by = groups[(groups.index(app.group_by) + 1) % len(groups)]
+        app.folded_groups = set()
+        # Start from an empty table so rows come back in the right order
+        app.query_one("#issues-table").clear()
+        app.apply_fuzzy_filter(app.filter_text)
+        self._update_issues_title()
+
+    def action_view_activity(self) -> None:
+        """Open the latest comments and changes of the visible issues."""
+        app = cast(Any, self)
+        if app.config.get("offline"):
+            app.notify("The activity is not available offline", severity="warning")
+            return
+        issues = filter_issues_by_text(
+            app.issues, app.filter_text, app.config.get("filter_mode")
+        )
+        app.push_screen(ActivityScreen(self, issues))
+
+    def action_view_stats(self) -> None:
+        """Open the counts and ages of the visible issues."""
+        app = cast(Any, self)
+        issues = filter_issues_by_text(
+            app.issues, app.filter_text, app.config.get("filter_mode")
+        )
+        app.push_screen(StatsScreen(self, issues))
+
+    def action_view_standup(self) -> None:
+        """Open the Markdown standup of what I did on the issues lately."""
+        app = cast(Any, self)
+        if app.config.get("offline"):
+            app.notify("The standup is not available offline", severity="warning")
+            return
+        since = app.config.get("standup_since") or defaults.STANDUP_SINCE
+        try:
+            days = report.parse_window(since)
+        except ValueError as e:
+            app.notify(f"Invalid standup_since: {e}", severity="error")
+            return
+        app.push_screen(StandupScreen(self, days))
+
+    def action_view_messages(self) -> None:
+        """Open the latest notifications, the ones already gone included."""
+        app = cast(Any, self)
+        if not app.messages:
+            app.notify("No messages yet", severity="warning")
+            return
+        app.push_screen(MessagesScreen(self, app.messages.recent()))
+
+    def action_view_logs(self) -> None:
+        """Open the end of the log file of the Jira requests."""
+        app = cast(Any, self)
+        path = logs.log_file()
+        if path is None:
+            app.notify("No log file, it could not be created", severity="warning")
+            return
+        app.push_screen(LogsScreen(self, path))
+
+    def action_toggle_debug(self) -> None:
+        """Show or hide the pane of the latest requests sent to Jira."""
+        cast(Any, self).query_one(DebugPanel).toggle()
+
+    def action_kanban_view(self) -> None:
+        """Open the visible issues in columns by status."""
+        app = cast(Any, self)
+        issues = filter_issues_by_text(
+            app.issues, app.filter_text, app.config.get("filter_mode")
+        )
+        app.push_screen(KanbanScreen(self, issues, app.config))
+
     def action_change_board(self) -> None:
         """Open modal to select a different board."""
         # Show the board selection screen
         cast(Any, self).push_screen(BoardSelectionScreen(self, cast(Any, self).config))
 
+    def action_view_history(self) -> None:
+        """Open the recently viewed issues to jump back to one of them."""
+        app = cast(Any, self)
+        history = app.jayrah_obj.state_store.history()
+        if not history:
+            app.notify("No issue viewed yet", severity="warning")
+            return
+        app.push_screen(HistoryScreen(self, history))
+
+    def action_toggle_pin(self) -> None:
+        """Pin or unpin the selected issue, the boards popup lists the pinned."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="warning")
+            return
+        if app.jayrah_obj.state_store.toggle_pin(app.selected_issue):
+            app.notify(f"📌 Pinned {app.selected_issue}")
+        else:
+            app.notify(f"Unpinned {app.selected_issue}")
+
+    def show_pinned_issues(self) -> None:
+        """Show the pinned issues instead of the current board."""
+        app = cast(Any, self)
+        pinned = app.jayrah_obj.state_store.pinned_issues()
+        if not pinned:
+            app.notify("No pinned issues, press i to pin one", severity="warning")
+            return
+        jql = defaults.PINNED_JQL.format(keys=", ".join(pinned))
+        app.notify(f"📌 Showing {len(pinned)} pinned issues")
+        self._submit_issues_request(
+            lambda on_page: self._run_query_worker(jql, on_page),
+            lambda new_issues: self._update_issues_after_board_change(
+                new_issues, "Pinned issues"
+            ),
+            "Error loading the pinned issues",
+        )
+
+    def action_change_sprint(self) -> None:
+        """Open modal to select a sprint of the project boards."""
+        cast(Any, self).push_screen(SprintSelectionScreen(self, cast(Any, self).config))
+
+    def action_change_profile(self) -> None:
+        """Open modal to switch to another profile of the config."""
+        app = cast(Any, self)
+        app.push_screen(ProfileSelectionScreen(self, app.config))
+
+    def action_create_issue(self) -> None:
+        """Open the form to create a new issue."""
+        cast(Any, self).push_screen(CreateIssueScreen(self, cast(Any, self).config))
+
+    def action_toggle_mark(self) -> None:
+        """Mark or unmark the selected issue for bulk actions."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="warning")
+            return
+        app.set_marked_issues(app.marked_issues ^ {app.selected_issue})
+
+    def action_visual_mode(self) -> None:
+        """Start or stop marking every issue the cursor moves over."""
+        app = cast(Any, self)
+        if app.visual_anchor is not None:
+            app.visual_anchor = None
+            app.notify(f"{len(app.marked_issues)} issues marked")
+            return
+
+        table = app.query_one("#issues-table")
+        app.visual_anchor = table.cursor_row
+        app.visual_base = set(app.marked_issues)
+        app.mark_visual_range(table.cursor_row)
+        app.notify("Visual mode: move the cursor to mark issues, v to stop")
+
+    def action_bulk_actions(self) -> None:
+        """Open the bulk actions for the marked issues."""
+        app = cast(Any, self)
+        app.visual_anchor = None
+        issue_keys = [
+            issue["key"] for issue in app.issues if issue["key"] in app.marked_issues
+        ]
+        if not issue_keys:
+            app.notify("Mark issues with space or v first", severity="warning")
+            return
+        app.push_screen(BulkActionsScreen(self, issue_keys, app.config))
+
+    def run_bulk_action(self, label: str, issue_keys: list, func, *args) -> None:
+        """
+        Run `func(issue_key, *args)` for every issue on the worker pool.
+
+        The progress is shown in the header and once every request is done a
+        summary of the failures is shown, failed issues stay marked.
+        """
+        app = cast(Any, self)
+        failures: dict[str, str] = {}
+        done: list[str] = []
+
+        def finish(issue_key: str, error: Exception | None = None) -> None:
+            done.append(issue_key)
+            if error is not None:
+                failures[issue_key] = str(error)
+            app.sub_title = f"{label}: {len(done)}/{len(issue_keys)}"
+            if len(done) < len(issue_keys):
+                return
+
+            app.sub_title = ""
+            message = f"{label}: ✅ {len(issue_keys) - len(failures)} succeeded"
+            if failures:
+                message += f", ❌ {len(failures)} failed\n" + "\n".join(
+                    f"{key}: {error}" for key, error in failures.items()
+                )
+            app.notify(message, severity="warning" if failures else "information")
+            app.set_marked_issues(set(failures))
+            self.action_reload()
+
+        app.sub_title = f"{label}: 0/{len(issue_keys)}"
+        for issue_key in issue_keys:
+            app.worker_pool.submit(
+                func,
+                issue_key,
+                *args,
+                on_result=lambda _result, issue_key=issue_key: finish(issue_key),
+                on_error=lambda exc, issue_key=issue_key: finish(issue_key, exc),
+            )
+
+    def bulk_transition(self, issue_key: str, transition_name: str) -> None:
+        """Worker method moving an issue through the transition with that name."""
+        jira = cast(Any, self).jayrah_obj.jira
+        transitions = jira.get_transitions(issue_key).get("transitions", [])
+        transition = next(
+            (t for t in transitions if t["name"].lower() == transition_name.lower()),
+            None,
+        )
+        if transition is None:
+            raise ValueError(f"no '{transition_name}' transition")
+        jira.transition_issue(issue_key, transition["id"])
+
+    def bulk_add_label(self, issue_key: str, label: str) -> None:
+        """Worker method adding a label to an issue, keeping its other labels."""
+        jira = cast(Any, self).jayrah_obj.jira
+        issue = jira.get_issue(issue_key, fields=["labels"], use_cache=False)
+        labels = issue.get("fields", {}).get("labels", [])
+        if label not in labels:
+            jira.update_issue(issue_key, {"labels": [*labels, label]})
+
     def action_show_actions(self) -> None:
         """Show a panel with all available actions."""
         # Show the actions panel
@@ -222,61 +969,156 @@ class IssueBrowserActions:
             cast(Any, self).notify(
                 f"Opening {cast(Any, self).selected_issue} in browser"
             )
-        except Exception as exc:
+        except (OSError, ValueError) as exc:
             cast(Any, self).notify(f"Error opening issue: {exc}", severity="error")
 
-    def action_copy_url(self) -> None:
-        """Copy the URL of the selected issue to clipboard."""
-        if not cast(Any, self).selected_issue:
-            cast(Any, self).notify("No issue selected", severity="error")
+    def action_open_visible_issues(self) -> None:
+        """Open the issues left by the filter in the browser, once confirmed."""
+        app = cast(Any, self)
+        issues = filter_issues_by_text(
+            app.issues, app.filter_text, app.config.get("filter_mode")
+        )
+        if not issues:
+            app.notify("No issues to open", severity="warning")
+            return
+        if not app.config.get("jira_server"):
+            app.notify("Jira server not configured", severity="error")
+            return
+        keys = [x["key"] for x in issues[: defaults.OPEN_ISSUES_LIMIT]]
+        if app.pending_open != keys:
+            # A browser tab per issue is a lot to undo, ask first
+            app.pending_open = keys
+            count = f"the first {len(keys)} of {len(issues)}"
+            if len(keys) == len(issues):
+                count = f"{len(keys)}"
+            app.notify(f"Open {count} issues in the browser? Press O again to confirm")
             return
+        app.pending_open = None
+        try:
+            for key in keys:
+                utils.browser_open_ticket(key, app.config)
+        except (OSError, ValueError) as exc:
+            app.notify(f"Error opening the issues: {exc}", severity="error")
+            return
+        app.notify(f"Opening {len(keys)} issues in browser")
 
+    def action_open_search(self) -> None:
+        """Open the query of the list as a Jira issue search in the browser."""
+        app = cast(Any, self)
+        if not app.jql:
+            app.notify("No query to open", severity="warning")
+            return
+        server = app.config.get("jira_server")
+        if not server:
+            app.notify("Jira server not configured", severity="error")
+            return
+        jql = app.jql.strip()
+        if app.order_by:
+            jql = f"{jql} ORDER BY {app.order_by}"
         try:
-            from jayrah.utils.clipboard import copy_to_clipboard, get_clipboard_command
+            utils.browser_open(utils.make_search_url(jql, server), app.config)
+            app.notify("Opening the query in browser")
+        except (OSError, ValueError) as exc:
+            app.notify(f"Error opening the query: {exc}", severity="error")
 
-            # Get the Jira server URL from config
-            server = cast(Any, self).config.get("jira_server")
-            if not server:
-                cast(Any, self).notify("Jira server not configured", severity="error")
-                return
+    def copy_text(self, label: str, text: str) -> None:
+        """Copy text to the clipboard and show what was copied."""
+        app = cast(Any, self)
+        if not clipboard.copy_to_clipboard(text):
+            # Without a clipboard tool, over SSH too, the terminal gets it by OSC 52
+            app.copy_to_clipboard(text)
+        app.notify(f"📋 Copied {label}: {text}")
 
-            # Construct the full URL
-            url = utils.make_full_url(cast(Any, self).selected_issue, server)
+    def action_yank(self) -> None:
+        """Open the menu of what to copy from the selected issue."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="error")
+            return
+        app.push_screen(YankScreen(self))
 
-            # Check if clipboard is available for the platform
-            clipboard_cmd = get_clipboard_command()
-            if not clipboard_cmd:
-                cast(Any, self).notify(
-                    "Clipboard not supported on this platform",
-                    severity="warning",
-                )
+    def action_copy_key(self) -> None:
+        """Copy the key of the selected issue to clipboard."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="error")
+            return
+        self.copy_text("key", app.selected_issue)
+
+    def action_copy_url(self) -> None:
+        """Copy the URL of the selected issue to clipboard."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="error")
+            return
+
+        server = app.config.get("jira_server")
+        if not server:
+            app.notify("Jira server not configured", severity="error")
+            return
+        self.copy_text("URL", utils.make_full_url(app.selected_issue, server))
+
+    def _with_selected_summary(self, callback) -> None:
+        """Call `callback` with the selected summary, fetched when it isn't listed."""
+        app = cast(Any, self)
+        for issue in app.issues:
+            if issue["key"] == app.selected_issue:
+                callback(issue["fields"].get("summary", ""))
                 return
+        app.worker_pool.submit(
+            app.jayrah_obj.jira.get_issue,
+            app.selected_issue,
+            ["summary"],
+            on_result=lambda issue: callback(issue["fields"].get("summary", "")),
+            on_error=lambda exc: app.notify(
+                f"Error loading issue data: {exc}", severity="error"
+            ),
+        )
 
-            # Copy to clipboard
-            if copy_to_clipboard(url):
-                cast(Any, self).notify(
-                    f"✅ Copied URL to clipboard: {cast(Any, self).selected_issue}"
-                )
-            else:
-                cast(Any, self).notify(
-                    f"Failed to copy URL (clipboard command: {clipboard_cmd})",
-                    severity="error",
-                )
+    def action_copy_summary(self) -> None:
+        """Copy the summary of the selected issue to clipboard."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="error")
+            return
+        self._with_selected_summary(lambda summary: self.copy_text("summary", summary))
 
-        except Exception as exc:
-            cast(Any, self).notify(f"Error copying URL: {exc}", severity="error")
+    def action_copy_branch(self) -> None:
+        """Copy a git branch name made of the key and summary of the issue."""
+        app = cast(Any, self)
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="error")
+            return
+        issue_key = app.selected_issue
+        self._with_selected_summary(
+            lambda summary: self.copy_text(
+                "branch", git.branch_name(issue_key, summary)
+            )
+        )
 
     def action_confirm_selection(self) -> None:
-        """Confirm the currently highlighted issue when auto choose is enabled."""
+        """
+        Confirm the currently highlighted issue when auto choose is enabled.
+
+        In choose multi mode the marked issues are confirmed, or the
+        highlighted one when none is marked.
+        """
         if not getattr(self, "auto_choose", False):
             self.action_open_issue()
             return
 
-        if not cast(Any, self).selected_issue:
-            cast(Any, self).notify("No issue selected", severity="warning")
+        app = cast(Any, self)
+        multi = getattr(app, "choose_multi", False)
+        if multi and app.marked_issues:
+            # In the order of the list rather than the order they were marked
+            app.exit([x["key"] for x in app.issues if x["key"] in app.marked_issues])
+            return
+
+        if not app.selected_issue:
+            app.notify("No issue selected", severity="warning")
             return
 
-        cast(Any, self).exit(cast(Any, self).selected_issue)
+        app.exit([app.selected_issue] if multi else app.selected_issue)
 
     def action_quit(self) -> None:
         """Quit the application."""
@@ -299,52 +1141,307 @@ class IssueBrowserActions:
         """Change to a different board and reload issues."""
         cast(Any, self).notify(f"🔄 Switching to board: {board_name}...")
 
-        # Run the board change in a worker thread
-        cast(Any, self).run_worker(
-            lambda: self._change_board_worker(board_name),
-            exclusive=True,
-            thread=True,
+        def show_board(result) -> None:
+            # Set once the list is known to be the latest, a board switched to
+            # later must not get the query of this one
+            (jql, order_by, overrides), new_issues = result
+            app = cast(Any, self)
+            app.command, app.jql, app.order_by = board_name, jql, order_by
+            app.board_overrides = overrides
+            self._update_issues_after_board_change(new_issues, board_name)
+
+        # Run the board change on the shared worker pool
+        self._submit_issues_request(
+            lambda on_page: self._change_board_worker(board_name, on_page),
+            show_board,
+            "Error changing board",
         )
 
-    def _change_board_worker(self, board_name: str) -> None:
-        """Worker method to change board and reload issues."""
+    def change_profile(self, name: str | None) -> None:
+        """Switch to another Jira profile and show its first board."""
+        # Import here to avoid circular imports
+        from jayrah.ui import boards
+
+        app = cast(Any, self)
         try:
-            # Import here to avoid circular imports
-            from jayrah.ui import boards
-
-            # Get the new board's JQL and order_by
-            jql, order_by = boards.check(board_name, cast(Any, self).config)
-            if not jql or not order_by:
-                cast(Any, self).app.call_from_thread(
-                    lambda: cast(Any, self).notify(
-                        f"Error: Invalid board or missing JQL: {board_name}",
-                        severity="error",
-                    )
-                )
-                return
+            new_config = jayrah_config.apply_profile(app.config, name)
+        except ValueError as e:
+            app.notify(str(e), severity="error")
+            return
+        # Options set from the command line for this run carry over
+        for key in ("offline", "no_mouse"):
+            if key in app.config:
+                new_config[key] = app.config[key]
 
-            # Update the command for this app instance
-            cast(Any, self).command = board_name
-            cast(Any, self).jql = jql
-            cast(Any, self).order_by = order_by
+        app.config = new_config
+        app.jayrah_obj = boards.Boards(new_config)
+        detail_panel = app.query_one(IssueDetailPanel)
+        detail_panel.config = new_config
+        detail_panel.jayrah_obj = app.jayrah_obj
+        app.notify(f"👤 Switched to profile: {name or 'default'}")
 
-            # Clear cache and fetch new issues
-            cast(Any, self).jayrah_obj.jira.cache.clear()
-            new_issues = cast(Any, self).jayrah_obj.issues_client.list_issues(
-                jql, order_by=order_by, use_cache=False
-            )
+        if new_config.get("boards"):
+            self.change_board(new_config["boards"][0]["name"])
+
+    def action_jql_query(self) -> None:
+        """Open a prompt to browse the issues of a JQL query."""
+        app = cast(Any, self)
+        app.push_screen(JqlQueryScreen(self, app.jql, history=app.query_history))
+
+    def run_query(self, jql: str) -> None:
+        """Show the issues of a JQL query instead of the current board."""
+        app = cast(Any, self)
+        # Most recent last, a query run again moves to the end
+        if jql in app.query_history:
+            app.query_history.remove(jql)
+        app.query_history.append(jql)
+        app.notify(f"🔎 Running query: {jql}")
+
+        self._submit_issues_request(
+            lambda on_page: self._run_query_worker(jql, on_page),
+            lambda new_issues: self._update_issues_after_board_change(
+                new_issues, "JQL query"
+            ),
+            "Error running query",
+        )
+
+    def _run_query_worker(self, jql: str, on_page=None) -> list:
+        """Worker method fetching the issues of a JQL query."""
+        cast(Any, self).command = "query"
+        cast(Any, self).jql = jql
+        cast(Any, self).board_overrides = {}
+        return self.fetch_issues(
+            jql, cast(Any, self).order_by, use_cache=False, on_page=on_page
+        )
+
+    def action_save_board(self) -> None:
+        """Open a prompt to save the current query as a board."""
+        app = cast(Any, self)
+        # Ad-hoc queries have no name yet, boards are offered to be updated
+        name = "" if app.command == "query" else app.command
+        app.push_screen(SaveBoardScreen(self, app.jql, name=name))
+
+    def save_board(self, name: str, jql: str | None = None) -> None:
+        """Write a query, the current one by default, to the config as a board."""
+        app = cast(Any, self)
+        board = {"name": name, "jql": jql or app.jql}
+        if app.order_by:
+            board["order_by"] = app.order_by
+
+        config_file = pathlib.Path(
+            app.config.get("config_file") or defaults.CONFIG_FILE
+        )
+        try:
+            jayrah_config.save_board(config_file, board)
+        except (OSError, yaml.YAMLError) as e:
+            app.notify(f"Error saving board: {e}", severity="error")
+            return
+
+        app.config["boards"] = [
+            x for x in app.config.get("boards", []) if x.get("name") != name
+        ] + [board]
+        app.command = name
+        app.notify(f"💾 Saved board {name} to {config_file}")
 
-            # Update the UI in the main thread
-            cast(Any, self).app.call_from_thread(
-                lambda: self._update_issues_after_board_change(new_issues, board_name)
+    def action_browse_projects(self) -> None:
+        """Open modal listing the projects to browse their unresolved issues."""
+        app = cast(Any, self)
+        app.push_screen(ProjectsScreen(self, app.config))
+
+    def action_export_issues(self) -> None:
+        """Open a prompt to export the visible issues to a file."""
+        app = cast(Any, self)
+        issues = filter_issues_by_text(
+            app.issues, app.filter_text, app.config.get("filter_mode")
+        )
+        name = "query" if app.command == "query" else app.command or "issues"
+        path = f"jayrah-{name}-{datetime.now().strftime('%Y%m%d')}.md"
+        app.push_screen(ExportScreen(self, path, len(issues)))
+
+    def export_issues(self, path: str) -> None:
+        """Write the issues left by the filter to a CSV or Markdown file."""
+        app = cast(Any, self)
+        issues = filter_issues_by_text(
+            app.issues, app.filter_text, app.config.get("filter_mode")
+        )
+        columns = app.config.get("export_columns") or defaults.EXPORT_COLUMNS
+        try:
+            count = export.export_issues(
+                issues, pathlib.Path(path), columns, app.config.get("jira_server", "")
             )
-        except Exception as e:
-            err = str(e)
-            cast(Any, self).app.call_from_thread(
-                lambda err=err: cast(Any, self).notify(
-                    f"Error changing board: {err}", severity="error"
+        except (OSError, ValueError) as e:
+            app.notify(f"Error exporting issues: {e}", severity="error")
+            return
+        app.notify(f"📤 Exported {count} issues to {path}")
+
+    def action_doctor(self) -> None:
+        """Check the connection to Jira and show the failed checks with their hint."""
+        app = cast(Any, self)
+        app.notify("🩺 Checking the connection to Jira...")
+
+        def handle_result(checks: list) -> None:
+            failed = [check for check in checks if not check.ok]
+            for check in failed:
+                hint = f"\n💡 {check.hint}" if check.hint else ""
+                app.notify(
+                    f"{check.message}{hint}",
+                    title=f"❌ {check.name}",
+                    severity="error",
+                    timeout=30,
                 )
-            )
+            if not failed:
+                app.notify(
+                    "\n".join(f"✅ {check.message}" for check in checks),
+                    title="Everything looks fine",
+                )
+
+        app.worker_pool.submit(
+            diagnostics.run_checks,
+            app.jayrah_obj.jira,
+            on_result=handle_result,
+            on_error=lambda exc: app.notify(
+                f"Error running the checks: {exc}", severity="error"
+            ),
+        )
+
+    def action_toggle_watch(self) -> None:
+        """Start or stop watching the selected issue."""
+        app = cast(Any, self)
+        issue_key = app.selected_issue
+        if not issue_key:
+            app.notify("No issue selected", severity="warning")
+            return
+        jira = app.jayrah_obj.jira
+
+        def toggle() -> bool:
+            if jira.get_watchers(issue_key).get("isWatching"):
+                jira.remove_watcher(issue_key)
+                return False
+            jira.add_watcher(issue_key)
+            return True
+
+        self._submit_issue_toggle(
+            issue_key, toggle, ("👀 Watching", "🙈 Stopped watching"), "watching"
+        )
+
+    def action_toggle_vote(self) -> None:
+        """Vote for the selected issue, or remove the vote."""
+        app = cast(Any, self)
+        issue_key = app.selected_issue
+        if not issue_key:
+            app.notify("No issue selected", severity="warning")
+            return
+        jira = app.jayrah_obj.jira
+
+        def toggle() -> bool:
+            if jira.get_votes(issue_key).get("hasVoted"):
+                jira.unvote(issue_key)
+                return False
+            jira.vote(issue_key)
+            return True
+
+        self._submit_issue_toggle(
+            issue_key, toggle, ("👍 Voted for", "Removed the vote for"), "voting for"
+        )
+
+    def action_undo(self) -> None:
+        """Revert the latest change, once confirmed by pressing again."""
+        app = cast(Any, self)
+        change = app.undo_stack.last()
+        if change is None:
+            app.notify("Nothing to undo", severity="warning")
+            return
+        if app.undo_pending is not change:
+            app.undo_pending = change
+            app.notify(f"Undo the {change.description}? Press Ctrl+Z again")
+            return
+        app.undo_pending = None
+        app.undo_stack.pop()
+
+        def handle_result(_) -> None:
+            app.notify(f"↩️ Reverted the {change.description}")
+            detail_panel = app.query_one(IssueDetailPanel)
+            if detail_panel.ticket == change.issue_key:
+                detail_panel.update_issue(change.issue_key, app.config, use_cache=False)
+            app.action_reload()
+
+        app.worker_pool.submit(
+            undo.revert,
+            app.jayrah_obj.jira,
+            change,
+            on_result=handle_result,
+            on_error=lambda exc: app.notify(
+                f"Could not revert the {change.description}: {exc}", severity="error"
+            ),
+        )
+
+    def _submit_issue_toggle(
+        self, issue_key: str, toggle, messages: tuple[str, str], doing: str
+    ) -> None:
+        """Run a watch or vote toggle and refresh the details of the issue."""
+        app = cast(Any, self)
+
+        def handle_result(enabled: bool) -> None:
+            app.notify(f"{messages[0] if enabled else messages[1]} {issue_key}")
+            detail_panel = app.query_one(IssueDetailPanel)
+            if detail_panel.ticket == issue_key:
+                detail_panel.update_issue(issue_key, app.config, use_cache=False)
+
+        app.worker_pool.submit(
+            toggle,
+            on_result=handle_result,
+            on_error=lambda exc: app.notify(
+                f"Error {doing} {issue_key}: {exc}", severity="error"
+            ),
+        )
+
+    def _change_board_worker(self, board_name: str, on_page=None) -> tuple:
+        """Worker method fetching the issues of a board, with its query."""
+        # Import here to avoid circular imports
+        from jayrah.ui import boards
+
+        # Get the new board's JQL and order_by
+        jql, order_by = boards.check(board_name, cast(Any, self).config)
+        if not jql or not order_by:
+            raise ValueError(f"Invalid board or missing JQL: {board_name}")
+        overrides = boards.overrides(board_name, cast(Any, self).config)
+
+        # Clear cache and fetch new issues
+        cast(Any, self).jayrah_obj.jira.cache.clear()
+        issues = self.fetch_issues(
+            jql,
+            order_by,
+            use_cache=False,
+            on_page=on_page,
+            board_overrides=overrides,
+        )
+        return (jql, order_by, overrides), issues
+
+    def change_sprint(self, sprint_id: str, sprint_name: str) -> None:
+        """Show the issues of a sprint instead of the current board."""
+        cast(Any, self).notify(f"🔄 Switching to sprint: {sprint_name}...")
+
+        def show_sprint(new_issues: list) -> None:
+            # Reloading goes through the search API, keep a matching JQL around
+            app = cast(Any, self)
+            app.command, app.jql = sprint_name, f"sprint = {sprint_id}"
+            app.board_overrides = {}
+            self._update_issues_after_board_change(new_issues, sprint_name)
+
+        self._submit_issues_request(
+            lambda _on_page: self._change_sprint_worker(sprint_id),
+            show_sprint,
+            "Error changing sprint",
+        )
+
+    def _change_sprint_worker(self, sprint_id: str) -> list:
+        """Worker method fetching the issues of a sprint."""
+        fields = list(defaults.FIELDS)
+        if cast(Any, self).config.get("epic_link_field"):
+            fields.append(cast(Any, self).config["epic_link_field"])
+        return cast(Any, self).jayrah_obj.jira.agile.get_sprint_issues(
+            int(sprint_id), fields=fields
+        )
 
     def _update_issues_after_board_change(
         self, new_issues: list, board_name: str
@@ -356,11 +1453,12 @@ class IssueBrowserActions:
             )
 
         cast(Any, self).issues = new_issues
+        cast(Any, self).apply_board_overrides()
         cast(Any, self).apply_fuzzy_filter("", msg=f"Switched to board: {board_name}")
 
         # Clear the detail panel
         detail_panel = cast(Any, self).query_one(IssueDetailPanel)
-        detail_panel.ticket_cache = {}
+        detail_panel.ticket_cache.clear()
         detail_panel.update_issue(None, cast(Any, self).config)
         cast(Any, self).selected_issue = None
 
@@ -368,26 +1466,16 @@ class IssueBrowserActions:
             f"✅ Switched to board: {board_name} ({len(new_issues)} issues)"
         )
 
-    def _reload_issues(self) -> None:
+    def _reload_issues(self, on_page=None) -> list:
         """Worker method to reload issues."""
-        try:
-            # Clear cache and fetch new issues
-            cast(Any, self).jayrah_obj.jira.cache.clear()
-            new_issues = cast(Any, self).jayrah_obj.issues_client.list_issues(
-                cast(Any, self).jql, order_by=cast(Any, self).order_by, use_cache=False
-            )
-
-            # Update the UI in the main thread
-            cast(Any, self).app.call_from_thread(
-                lambda: self._update_issues_after_reload(new_issues)
-            )
-        except Exception as e:
-            err = str(e)
-            cast(Any, self).app.call_from_thread(
-                lambda err=err: cast(Any, self).notify(
-                    f"Error reloading issues: {err}", severity="error"
-                )
-            )
+        # Clear cache and fetch new issues
+        cast(Any, self).jayrah_obj.jira.cache.clear()
+        return self.fetch_issues(
+            cast(Any, self).jql,
+            cast(Any, self).order_by,
+            use_cache=False,
+            on_page=on_page,
+        )
 
     def _update_issues_after_reload(self, new_issues: list) -> None:
         """Update the UI after reloading issues."""
@@ -400,7 +1488,7 @@ class IssueBrowserActions:
         )
 
         detail_panel = cast(Any, self).query_one(IssueDetailPanel)
-        detail_panel.ticket_cache = {}
+        detail_panel.ticket_cache.clear()
         detail_panel.update_issue(
             detail_panel.ticket, cast(Any, self).config, use_cache=False
         )
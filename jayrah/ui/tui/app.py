@@ -1,15 +1,48 @@
 """Main issue browser application combining all components."""
 
+from collections import deque
+
+from rich.text import Text
 from textual import on
 from textual.app import App, ComposeResult
 from textual.binding import Binding
 from textual.containers import Container, Vertical
 from textual.widgets import DataTable, Footer, Header
 
+from jayrah import utils
+from jayrah.api import models
+from jayrah.config import defaults
+
 from .actions import IssueBrowserActions
 from .base import JayrahAppMixin
-from .helpers import filter_issues_by_text, get_row_data_for_issue
-from .views import IssueDetailPanel
+from .helpers import (
+    filter_issues_by_text,
+    get_row_data_for_issue,
+    group_issues,
+    match_positions,
+    sort_issues,
+    visible_columns,
+)
+from .messages import MessageLog, notify_timeout
+from .tabs import TabSet
+from .undo import Change, UndoStack
+from .views import DebugPanel, IssueDetailPanel
+from .workers import DEFAULT_MAX_WORKERS, JiraWorkerPool
+
+# What the browser remembers of a session, restored unless --fresh is given
+SESSION_STATE = (
+    "command",
+    "jql",
+    "order_by",
+    "filter_text",
+    "sort_column",
+    "sort_reverse",
+    "group_by",
+    "selected_issue",
+)
+
+# Row key prefix of the header rows when grouping issues
+GROUP_ROW_PREFIX = "group:"
 
 
 class IssueBrowserApp(App, JayrahAppMixin, IssueBrowserActions):
@@ -48,6 +81,36 @@ class IssueBrowserApp(App, JayrahAppMixin, IssueBrowserActions):
         overflow-x: auto;
     }
 
+    /* Issues and details side by side, HORIZONTAL_ISSUES_WIDTH for the issues */
+    .horizontal #panes {
+        layout: horizontal;
+    }
+    .horizontal #main-panel {
+        width: {issues_width}%;
+        height: 100%;
+    }
+    .horizontal #bottom-panel {
+        width: {detail_width}%;
+        height: 100%;
+        border-top: none;
+        border-left: solid $primary;
+    }
+
+    /* One pane taking the whole screen */
+    .zoom-issues #bottom-panel, .zoom-detail #main-panel {
+        display: none;
+    }
+    .zoom-issues #main-panel, .zoom-detail #bottom-panel {
+        width: 100%;
+        height: 100%;
+    }
+
+    /* Small terminals give the details all the room they can */
+    .compact #bottom-panel {
+        border-top: none;
+        padding: 0;
+    }
+
     /* Issue detail panel */
     #detail-label {
         padding: 0;
@@ -60,22 +123,75 @@ class IssueBrowserApp(App, JayrahAppMixin, IssueBrowserActions):
         color: $text-muted;
         text-align: center;
     }
-    """
+    """.replace(
+        "{issues_width}", str(defaults.HORIZONTAL_ISSUES_WIDTH)
+    ).replace(
+        "{detail_width}", str(100 - defaults.HORIZONTAL_ISSUES_WIDTH)
+    )
 
     # ─────────────────────────  Key bindings  ──────────────────────────
     BINDINGS = [
         Binding("escape", "quit", "Quit", show=False),
         Binding("o", "open_issue", "Open"),
-        Binding("y", "copy_url", "Copy URL", show=False),
+        Binding("O", "open_visible_issues", "Open all", show=False),
+        Binding("ctrl+b", "open_search", "Open search", show=False),
+        Binding("y", "yank", "Yank", show=False),
+        Binding("Y", "copy_url", "Copy URL", show=False),
         Binding("r", "reload", "Reload"),
+        Binding("ctrl+r", "refresh_detail", "Refresh issue", show=False),
         Binding("a", "show_actions", "Actions"),
         Binding("c", "view_comments", "Comments"),
         Binding("l", "add_labels", "Labels", show=False),
         Binding("C", "edit_components", "Components", show=False),
         Binding("t", "transition_issue", "Transition", show=False),
+        Binding("w", "view_worklogs", "Worklogs", show=False),
+        Binding("W", "toggle_watch", "Watch", show=False),
+        Binding("V", "toggle_vote", "Vote", show=False),
+        Binding("L", "view_links", "Links", show=False),
+        Binding("U", "view_remote_links", "Web links", show=False),
+        Binding("F", "follow_link", "Follow link", show=False),
+        Binding("ctrl+g", "jump_to_reference", "Go to referenced issue", show=False),
+        Binding("ctrl+t", "reference_back", "Back from reference", show=False),
+        Binding("m", "view_attachments", "Attachments", show=False),
+        Binding("R", "attach_pull_request", "Pull request", show=False),
+        Binding("S", "view_subtasks", "Subtasks", show=False),
+        Binding("d", "clone_issue", "Clone", show=False),
         Binding("e", "edit_issue", "Edit", show=False),
         Binding("f", "filter", "Fuzzy Filter", show=False),
+        Binding("g", "cycle_group", "Group by", show=False),
+        Binding("z", "cycle_sort", "Sort", show=False),
+        Binding("Z", "reverse_sort", "Reverse sort", show=False),
+        Binding("ctrl+k", "kanban_view", "Kanban", show=False),
+        Binding("A", "view_activity", "Activity", show=False),
+        Binding("u", "view_standup", "Standup", show=False),
+        Binding("ctrl+z", "undo", "Undo", show=False),
+        Binding("G", "view_stats", "Stats", show=False),
+        Binding("ctrl+l", "view_logs", "Logs", show=False),
+        Binding("M", "view_messages", "Messages", show=False),
+        Binding("f12", "toggle_debug", "Debug requests", show=False),
         Binding("b", "change_board", "Boards", show=False),
+        Binding("i", "toggle_pin", "Pin", show=False),
+        Binding("ctrl+o", "view_history", "History", show=False),
+        Binding("s", "change_sprint", "Sprints", show=False),
+        Binding("P", "change_profile", "Profiles", show=False),
+        Binding("p", "browse_projects", "Projects", show=False),
+        Binding("/", "jql_query", "JQL query", show=False),
+        Binding(":", "jql_query", "JQL query", show=False),
+        Binding("ctrl+s", "save_board", "Save as board", show=False),
+        Binding("D", "doctor", "Doctor", show=False),
+        Binding("E", "export_issues", "Export", show=False),
+        Binding("n", "create_issue", "New issue", show=False),
+        Binding("space", "toggle_mark", "Mark", show=False),
+        Binding("v", "visual_mode", "Visual mark", show=False),
+        Binding("B", "bulk_actions", "Bulk actions", show=False),
+        Binding("T", "new_tab", "New tab", show=False),
+        Binding("X", "close_tab", "Close tab", show=False),
+        Binding("]", "next_tab", "Next tab", show=False),
+        Binding("[", "previous_tab", "Previous tab", show=False),
+        *(
+            Binding(str(x), f"switch_tab({x - 1})", f"Tab {x}", show=False)
+            for x in range(1, defaults.MAX_TABS + 1)
+        ),
         Binding("j", "cursor_down", "Down"),
         Binding("k", "cursor_up", "Up"),
         Binding("J", "scroll_down", "PrevDown"),
@@ -94,60 +210,321 @@ class IssueBrowserApp(App, JayrahAppMixin, IssueBrowserActions):
         jql: str | None = None,
         order_by: str | None = None,
         auto_choose: bool = False,
+        choose_multi: bool = False,
+        board_overrides: dict | None = None,
+        session: dict | None = None,
+        refresh: int | None = None,
     ):
         # Initialize mixins first
         JayrahAppMixin.__init__(self, config)
         App.__init__(self)
 
+        # Without issues given upfront they are fetched once the UI is up
+        self.issues_loading = issues is None
+        self.no_issues_found = False
         self.issues = issues or []
         self.command = command or ""
         self.selected_issue: str | None = None
         self.jql = jql
         self.order_by: str | None = order_by
+        # Limit, fields, columns and refresh interval of the board browsed
+        self.board_overrides = board_overrides or {}
+        # Seconds of the --refresh flag, over the interval of every board
+        self.refresh_override = refresh
+        self.auto_reload_timer = None
         self.auto_choose = auto_choose
+        # Enter returns the marked issues instead of the highlighted one
+        self.choose_multi = choose_multi
+        # The view of the last session and the issue to select once loaded
+        session = session or {}
+        self.restored_issue: str | None = session.get("selected_issue")
+        self.filter_text = session.get("filter_text") or ""
+        self.query_history: list[str] = []
+        # Group of the issue rows, none when group_by is None
+        self.group_by: str | None = session.get("group_by")
+        self.folded_groups: set[str] = set()
+        # New or moved issues of the last auto reloads, until they get selected
+        self.updated_issues: set[str] = set()
+        # Sort of the loaded issues, the server order when no column is set
+        self.sort_column: str | None = session.get("sort_column")
+        self.sort_reverse = bool(session.get("sort_reverse"))
+        # Issues marked for bulk actions, visual mode marks from an anchor row
+        self.marked_issues: set[str] = set()
+        # Label, component, title and status changes that can be reverted
+        self.undo_stack = UndoStack()
+        self.undo_pending: Change | None = None
+        # Issues left by jumping to a referenced key, Ctrl+T goes back to them
+        self.reference_stack: deque[str] = deque(maxlen=defaults.REFERENCE_STACK_SIZE)
+        # Every notification, to read again the ones that went away too fast
+        self.messages = MessageLog()
+        # Frame of the spinner shown in the pane titles, empty when idle
+        self.spinner = ""
+        self.spinner_tick = 0
+        # Width of the terminal, the columns that do not fit are hidden
+        self.terminal_width: int | None = None
+        self.visual_anchor: int | None = None
+        self.visual_base: set[str] = set()
+        # Issues O opens in the browser once pressed again to confirm
+        self.pending_open: list[str] | None = None
+        # Queries open side by side, the app shows the active one
+        self.tabs = TabSet(self)
+        self.worker_pool = JiraWorkerPool(
+            self.call_from_thread,
+            int(self.config.get("workers") or DEFAULT_MAX_WORKERS),
+        )
 
         if not self.config.get("no_cache"):
             self.jayrah_obj.jira.cache.preload_cache()
             if self.verbose:
                 self.log("Preloaded Jira cache into memory for fast access.")
 
+    def notify(  # type: ignore[override]
+        self,
+        message,
+        *,
+        title: str = "",
+        severity="information",
+        timeout: float | None = None,
+        markup: bool = True,
+    ) -> None:
+        """Show a notification and keep it for the messages popup."""
+        self.messages.add(str(message), severity)
+        super().notify(
+            message,
+            title=title,
+            severity=severity,
+            timeout=notify_timeout(severity) if timeout is None else timeout,
+            markup=markup,
+        )
+
+    def open_url(self, url: str, *, new_tab: bool = True) -> None:
+        """Open the links clicked in the Markdown panes with the browser option."""
+        try:
+            utils.browser_open(url, self.config)
+        except (OSError, ValueError) as exc:
+            self.notify(f"Error opening {url}: {exc}", severity="error")
+
     def compose(self) -> ComposeResult:  # type: ignore[override]
         """Create the widget tree."""
         yield Header(show_clock=True)
-        with Container(), Vertical():
+        with Container(), Vertical(id="panes"):
             # Main content area
             with Vertical(id="main-panel"):
                 yield self._create_datatable()
             # Detail panel at the bottom
             with Vertical(id="bottom-panel"):
                 yield IssueDetailPanel(config=self.config)
+        yield DebugPanel()
         yield Footer()
 
     # ─────────────────────────  Helpers  ──────────────────────────
-    def _create_datatable(self) -> DataTable:
-        table = DataTable(id="issues-table")
-        table.cursor_type = "row"  # Highlights whole rows
-
+    def _row_data(self, issue: dict) -> tuple:
+        """Row values of an issue, marked issues show a check instead of their type."""
+        row_data = get_row_data_for_issue(issue)
+        fields = issue.get("fields", {})
+        if models.is_overdue(fields):
+            # Overdue issues get their key in red
+            row_data = (row_data[0], Text(row_data[1], style="bold red"), *row_data[2:])
+        if style := defaults.STATUS_CATEGORY_STYLES.get(models.status_category(fields)):
+            # Colored by category as status names change with the workflow
+            row_data = (*row_data[:3], Text(row_data[3], style=style), *row_data[4:])
+        if issue["key"] in self.updated_issues:
+            # Issues changed by an auto reload get their key in reverse video
+            key = Text.assemble(row_data[1], style="reverse")
+            row_data = (row_data[0], key, *row_data[2:])
+        if self.filter_text.strip():
+            row_data = tuple(
+                self._highlight_match(cell) if index in (1, 2, 3, 5, 6) else cell
+                for index, cell in enumerate(row_data)
+            )
+        if issue["key"] in self.marked_issues:
+            row_data = ("✔", *row_data[1:])
+        columns = self._shown_columns()
+        if columns != list(defaults.ISSUE_COLUMNS):
+            # The type and ticket always come first, the board picks the others
+            names = list(defaults.ISSUE_COLUMNS)
+            cells = [row_data[2 + names.index(x)] for x in columns]
+            if self.compact:
+                status = row_data[2 + names.index("status")]
+                cells = [Text.assemble(cells[0], "  ", status)]
+            row_data = (*row_data[:2], *cells)
+        return row_data
+
+    def _board_columns(self) -> list[str]:
+        """The columns shown after the type and the ticket."""
+        return self.board_overrides.get("columns") or list(defaults.ISSUE_COLUMNS)
+
+    def _shown_columns(self) -> list[str]:
+        """The columns of the board fitting in the width of the issues pane."""
+        width = self.terminal_width
+        if (
+            width is not None
+            and self.config.get("layout") == "horizontal"
+            and self.config.get("zoom") != "issues"
+        ):
+            width = width * defaults.HORIZONTAL_ISSUES_WIDTH // 100
+        return visible_columns(self._board_columns(), width)
+
+    @property
+    def compact(self) -> bool:
+        """Whether the terminal is too narrow for more than ticket and summary."""
+        width = self.terminal_width
+        return width is not None and width < defaults.COMPACT_WIDTH
+
+    def _add_columns(self, table: DataTable) -> None:
         table.add_columns(
             "",
             "Ticket",
-            "Summary",
-            "Status",
-            "Priority",
-            "Assignee",
-            "Reporter",
-            "Created",
-            "Updated",
+            *(defaults.ISSUE_COLUMNS[x] for x in self._shown_columns()),
         )
 
+    def _highlight_match(self, cell):
+        """The cell with the letters matching the filter underlined."""
+        text = cell.copy() if isinstance(cell, Text) else Text(str(cell))
+        positions = match_positions(
+            self.filter_text, text.plain, self.config.get("filter_mode")
+        )
+        if not positions:
+            return cell
+        for position in positions:
+            text.stylize("bold underline", position, position + 1)
+        return text
+
+    def _create_datatable(self) -> DataTable:
+        table = DataTable(id="issues-table")
+        table.cursor_type = "row"  # Highlights whole rows
+        self._add_columns(table)
+
         for issue in self.issues:
-            row_data = get_row_data_for_issue(issue)
-            table.add_row(*row_data)
+            row_data = self._row_data(issue)
+            table.add_row(*row_data, key=issue["key"])
         return table
 
     def on_mount(self) -> None:
         self.title = "Jayrah – Your friendly Jira browser"
+        self.apply_startup_layout()
+        self.start_auto_reload()
+        self.set_interval(defaults.SPINNER_INTERVAL, self.tick_spinner)
+
+        if self.issues_loading:
+            self.notify("🔄 Loading issues...")
+            self._submit_issues_request(
+                self._load_issues,
+                self._on_issues_loaded,
+                "Error loading issues",
+            )
+            return
+
+        self._select_first_issue()
 
+    def apply_startup_layout(self) -> None:
+        """The layout, zoomed pane and theme of the config or browse flags."""
+        self.set_class(self.config.get("layout") == "horizontal", "horizontal")
+        zoom = self.config.get("zoom")
+        self.set_class(zoom == "issues", "zoom-issues")
+        self.set_class(zoom == "detail", "zoom-detail")
+        theme = self.config.get("theme")
+        if not theme:
+            return
+        if theme in self.available_themes:
+            self.theme = theme
+        else:
+            self.notify(
+                f"Unknown theme {theme}, the themes are "
+                f"{', '.join(sorted(self.available_themes))}",
+                severity="warning",
+            )
+
+    def tick_spinner(self) -> None:
+        """Turn the spinner of the pane titles while requests are running."""
+        if not self.worker_pool.pending:
+            if self.spinner:
+                self.spinner = ""
+                self.update_pane_titles()
+            return
+        frames = defaults.SPINNER_FRAMES
+        self.spinner_tick += 1
+        self.spinner = frames[self.spinner_tick % len(frames)]
+        self.update_pane_titles()
+
+    def update_pane_titles(self) -> None:
+        self._update_issues_title()
+        detail_panel = self.query_one(IssueDetailPanel)
+        title = ""
+        if self.spinner and detail_panel.loading:
+            title = f"{self.spinner} Loading {detail_panel.ticket}"
+        elif self.spinner:
+            title = f"{self.spinner} {self.worker_pool.pending} requests running"
+        self.query_one("#bottom-panel").border_title = title
+
+    def start_auto_reload(self) -> None:
+        """Reload the issues every --refresh, board refresh_interval or auto_reload."""
+        if self.auto_reload_timer is not None:
+            self.auto_reload_timer.stop()
+            self.auto_reload_timer = None
+        interval = self.refresh_override
+        if interval is None:
+            interval = self.board_overrides.get("refresh_interval")
+        if interval is None:
+            interval = self.config.get("auto_reload")
+        if interval:
+            self.auto_reload_timer = self.set_interval(
+                float(interval), self.auto_reload_issues
+            )
+
+    def on_resize(self, event) -> None:
+        """Show the columns fitting in the new width of the terminal."""
+        shown = self._shown_columns()
+        self.terminal_width = event.size.width
+        self.set_class(self.compact, "compact")
+        if self._shown_columns() != shown and self.is_mounted:
+            self.relayout_table()
+
+    def relayout_table(self) -> None:
+        """Rebuild the columns and rows of the table, keeping the cursor."""
+        table = self.query_one("#issues-table", DataTable)
+        table.clear(columns=True)
+        self._add_columns(table)
+        self.apply_fuzzy_filter(self.filter_text, notify=False)
+        if self.selected_issue and self.selected_issue in table.rows:
+            table.move_cursor(row=table.get_row_index(self.selected_issue))
+
+    def apply_board_overrides(self) -> None:
+        """Show the columns and refresh of the board the issues now come from."""
+        table = self.query_one("#issues-table", DataTable)
+        table.clear(columns=True)
+        self._add_columns(table)
+        self.start_auto_reload()
+
+    def _load_issues(self, on_page=None) -> list:
+        """Worker method fetching the initial list of issues."""
+        return self.fetch_issues(self.jql, self.order_by, on_page=on_page)
+
+    def _on_issues_loaded(self, issues: list) -> None:
+        """Fill the table once the initial issues have been fetched."""
+        self.issues_loading = False
+        if not issues:
+            self.no_issues_found = True
+            self.exit()
+            return
+
+        self.issues = issues
+        self.apply_fuzzy_filter(self.filter_text)
+        self._select_first_issue()
+        self._select_restored_issue()
+
+    def _select_restored_issue(self) -> None:
+        """Put the cursor back on the issue selected in the last session."""
+        table = self.query_one("#issues-table", DataTable)
+        if self.restored_issue and self.restored_issue in table.rows:
+            table.move_cursor(row=table.get_row_index(self.restored_issue))
+        self.restored_issue = None
+
+    def session_state(self) -> dict:
+        return {name: getattr(self, name) for name in SESSION_STATE}
+
+    def _select_first_issue(self) -> None:
+        """Preselect the first issue when running in auto choose mode."""
         if self.auto_choose and self.issues:
             table = self.query_one("#issues-table", DataTable)
             table.focus()
@@ -165,6 +542,11 @@ class IssueBrowserApp(App, JayrahAppMixin, IssueBrowserActions):
                 detail_panel = self.query_one(IssueDetailPanel)
                 detail_panel.update_issue(issue_key, self.config)
 
+    def on_unmount(self) -> None:
+        self.worker_pool.shutdown()
+        if not self.auto_choose:
+            self.jayrah_obj.state_store.save_session(self.session_state())
+
     # ─────────────────────────  Events  ──────────────────────────
     @on(DataTable.RowHighlighted)
     def _handle_row_highlighted(self, event: DataTable.RowHighlighted) -> None:  # type: ignore[name-defined]
@@ -176,12 +558,18 @@ class IssueBrowserApp(App, JayrahAppMixin, IssueBrowserActions):
         if event.row_key is None:
             return
 
+        if self.visual_anchor is not None:
+            self.mark_visual_range(event.cursor_row)
+        self.load_more_issues(event.cursor_row)
+
         try:
             row = table.get_row(event.row_key)
             issue_key = str(row[1]) if row and len(row) > 1 else None
 
             if issue_key and issue_key != self.selected_issue:
                 self.log(f"Row highlighted → {issue_key}")
+                if issue_key in self.updated_issues:
+                    self._mark_issue_seen(issue_key)
                 self.selected_issue = issue_key
                 self.query_one(IssueDetailPanel).update_issue(issue_key, self.config)
         except Exception as e:
@@ -190,28 +578,22 @@ class IssueBrowserApp(App, JayrahAppMixin, IssueBrowserActions):
     @on(DataTable.RowSelected)
     def _handle_row_selected(self, event: DataTable.RowSelected) -> None:  # type: ignore[name-defined]
         """Handle Enter key press on a row - exit if in auto_choose mode."""
+        row_key = getattr(getattr(event, "row_key", None), "value", None) or ""
+        if row_key.startswith(GROUP_ROW_PREFIX):
+            self.toggle_group_fold(row_key.removeprefix(GROUP_ROW_PREFIX))
+            return
         if self.auto_choose:
             self.action_confirm_selection()
 
     def apply_fuzzy_filter(
-        self, text: str = "", msg: str = "Showing all issues"
+        self, text: str = "", msg: str = "Showing all issues", notify: bool = True
     ) -> None:
         """Apply a fuzzy filter to all visible fields in the issues table."""
         table = self.query_one("#issues-table", DataTable)
 
         # Add the headers if they don't exist
         if not table.columns:
-            table.add_columns(
-                "",
-                "Ticket",
-                "Summary",
-                "Status",
-                "Priority",
-                "Assignee",
-                "Reporter",
-                "Created",
-                "Updated",
-            )
+            self._add_columns(table)
 
         # Get current visible rows
         current_rows = {}
@@ -221,39 +603,52 @@ class IssueBrowserApp(App, JayrahAppMixin, IssueBrowserActions):
                 current_rows[row_key] = row  # Use DataTable's row_key
 
         # Filter issues using helper function
-        filtered_issues = filter_issues_by_text(self.issues, text)
+        self.filter_text = text
+        filtered_issues = sort_issues(
+            filter_issues_by_text(self.issues, text, self.config.get("filter_mode")),
+            self.sort_column,
+            self.sort_reverse,
+        )
 
-        # Update UI with filter information
-        if filtered_issues:
+        # Update UI with filter information, unless pages are still loading
+        if notify and filtered_issues:
             txt_message = f"Found {len(filtered_issues)} issues"
             if text.strip():
                 txt_message += f" matching '{text}'"
             self.notify(txt_message)
-        else:
+        elif notify:
             self.notify(f"No issues match '{text}'", severity="warning")
 
         # Create a set of filtered issue keys for quick lookup
         filtered_keys = {issue["key"] for issue in filtered_issues}
 
-        # Remove rows that are no longer in the filtered set
-        for row_key in list(current_rows.keys()):
-            if row_key not in filtered_keys:
-                table.remove_row(row_key)
-
-        # Add or update rows for filtered issues
-        for issue in filtered_issues:
-            key = issue["key"]
-            row_data = get_row_data_for_issue(issue)
-
-            if key in current_rows:
-                # Update existing row if data has changed
-                current_row = current_rows[key]
-                if current_row != row_data:
-                    table.remove_row(key)
+        if self.group_by:
+            self._add_group_rows(table, filtered_issues)
+        else:
+            if self.sort_column or text.strip():
+                # Rows can only be put in order by adding them again, fuzzy
+                # matches come best first and show what matched
+                table.clear()
+                current_rows = {}
+            # Remove rows that are no longer in the filtered set
+            for row_key in list(current_rows.keys()):
+                if row_key not in filtered_keys:
+                    table.remove_row(row_key)
+
+            # Add or update rows for filtered issues
+            for issue in filtered_issues:
+                key = issue["key"]
+                row_data = self._row_data(issue)
+
+                if key in current_rows:
+                    # Update existing row if data has changed
+                    current_row = current_rows[key]
+                    if current_row != row_data:
+                        table.remove_row(key)
+                        table.add_row(*row_data, key=key)
+                else:
+                    # Add new row
                     table.add_row(*row_data, key=key)
-            else:
-                # Add new row
-                table.add_row(*row_data, key=key)
 
         # Clear the selected issue if it doesn't exist in the filtered results
         if self.selected_issue and self.selected_issue not in filtered_keys:
@@ -261,21 +656,116 @@ class IssueBrowserApp(App, JayrahAppMixin, IssueBrowserActions):
             detail_panel = self.query_one(IssueDetailPanel)
             detail_panel.update_issue(None, self.config)
 
+    def _add_group_rows(self, table: DataTable, issues: list) -> None:
+        """Rebuild the table with the issues under one header row per group."""
+        groups = group_issues(
+            issues, self.group_by, self.config.get("epic_link_field")
+        )
+        table.clear()
+        for group_key, label, members in groups:
+            folded = group_key in self.folded_groups
+            table.add_row(
+                "▶" if folded else "▼",
+                "",
+                f"{label} ({len(members)})",
+                *[""] * (len(self._shown_columns()) - 1),
+                key=f"{GROUP_ROW_PREFIX}{group_key}",
+            )
+            if folded:
+                continue
+            for issue in members:
+                table.add_row(*self._row_data(issue), key=issue["key"])
+
+    def toggle_group_fold(self, group_key: str) -> None:
+        """Fold or unfold the issues of a group."""
+        self.folded_groups ^= {group_key}
+        table = self.query_one("#issues-table", DataTable)
+        issues = sort_issues(
+            filter_issues_by_text(
+                self.issues, self.filter_text, self.config.get("filter_mode")
+            ),
+            self.sort_column,
+            self.sort_reverse,
+        )
+        self._add_group_rows(table, issues)
+        table.move_cursor(row=table.get_row_index(f"{GROUP_ROW_PREFIX}{group_key}"))
+
+    def _mark_issue_seen(self, issue_key: str) -> None:
+        """Stop highlighting an updated issue once it has been selected."""
+        self.updated_issues.discard(issue_key)
+        table = self.query_one("#issues-table", DataTable)
+        ticket_column = table.ordered_columns[1].key
+        issue = next(x for x in self.issues if x["key"] == issue_key)
+        table.update_cell(issue_key, ticket_column, self._row_data(issue)[1])
+
+    def set_marked_issues(self, issue_keys: set[str]) -> None:
+        """Replace the issues marked for bulk actions and refresh their rows."""
+        changed = self.marked_issues ^ issue_keys
+        self.marked_issues = set(issue_keys)
+        table = self.query_one("#issues-table", DataTable)
+        type_column = table.ordered_columns[0].key
+        for issue in self.issues:
+            if issue["key"] in changed and issue["key"] in table.rows:
+                table.update_cell(issue["key"], type_column, self._row_data(issue)[0])
+
+    def mark_visual_range(self, cursor_row: int) -> None:
+        """Mark every issue between the visual mode anchor and the cursor."""
+        if self.visual_anchor is None:
+            return
+        table = self.query_one("#issues-table", DataTable)
+        start, end = sorted((self.visual_anchor, cursor_row))
+        in_range = set()
+        for index in range(start, end + 1):
+            row = table.get_row_at(index)
+            # Epic header rows have no ticket
+            if row[1]:
+                in_range.add(str(row[1]))
+        self.set_marked_issues(self.visual_base | in_range)
+
 
 # ─────────────────────────  Public helper  ──────────────────────────
 def run_textual_browser(
-    issues: list,
+    issues: list | None,
     config: dict,
     command: str,
     jql: str,
     order_by: str,
     auto_choose: bool = False,
+    on_no_issues=None,
+    choose_multi: bool = False,
+    board_overrides: dict | None = None,
+    session: dict | None = None,
+    refresh: int | None = None,
 ):
-    """Launch the **IssueBrowserApp** and return the ticket selected by the user."""
+    """
+    Launch the **IssueBrowserApp** and return the ticket selected by the user.
+
+    When issues is None the app fetches them itself after it starts, if the
+    query returns nothing the app exits and on_no_issues is called. With
+    choose_multi the app returns the list of the issues chosen with Enter.
+    The board_overrides of the board being browsed change the table columns,
+    the issues fetched and the auto reload interval, the session the filter,
+    sort, grouping and issue it starts with. refresh, in seconds, replaces
+    the auto reload interval of every board, 0 turning it off.
+    """
     app = IssueBrowserApp(
-        issues, config, command, jql, order_by, auto_choose=auto_choose
+        issues,
+        config,
+        command,
+        jql,
+        order_by,
+        auto_choose=auto_choose,
+        choose_multi=choose_multi,
+        board_overrides=board_overrides,
+        session=session,
+        refresh=refresh,
     )
-    result = app.run()
+    # Without mouse capture the terminal keeps its native text selection
+    result = app.run(mouse=not config.get("no_mouse"))
+    if app.no_issues_found:
+        if on_no_issues is not None:
+            on_no_issues()
+        return None
     # In auto_choose mode, only return explicit exit value (Enter key)
     # Don't fallback to selected_issue for q/Ctrl+C
     if auto_choose:
@@ -2,10 +2,22 @@
 
 import re
 
+from textual import events
 from textual.binding import Binding
 from textual.widgets import Input, TextArea
 
 
+def normalize_pasted_text(text: str) -> str:
+    """Normalize line endings of pasted text to plain newlines."""
+    return text.replace("\r\n", "\n").replace("\r", "\n")
+
+
+def flatten_pasted_text(text: str) -> str:
+    """Fold multi-line pasted text into a single line for one-line inputs."""
+    lines = normalize_pasted_text(text).split("\n")
+    return " ".join(line.strip() for line in lines if line.strip())
+
+
 class EmacsInput(Input):
     """Input widget with emacs/readline keybindings support."""
 
@@ -30,6 +42,13 @@ class EmacsInput(Input):
         Binding("ctrl+t", "transpose_characters", "Transpose chars", show=False),
     ]
 
+    def _on_paste(self, event: events.Paste) -> None:
+        """Insert the whole pasted text instead of only its first line."""
+        text = flatten_pasted_text(event.text)
+        if text:
+            self.insert_text_at_cursor(text)
+        event.stop()
+
     def action_cursor_line_start(self) -> None:
         """Move cursor to start of line (Ctrl+A)."""
         self.cursor_position = 0
@@ -184,6 +203,13 @@ class EmacsTextArea(TextArea):
         Binding("ctrl+t", "transpose_characters", "Transpose chars", show=False),
     ]
 
+    def _on_paste(self, event: events.Paste) -> None:
+        """Insert the pasted text in one go, keeping its newlines."""
+        text = normalize_pasted_text(event.text)
+        if text and not self.read_only:
+            self.replace(text, *self.selection)
+        event.stop()
+
     def action_cursor_line_start(self) -> None:
         """Move cursor to start of current line (Ctrl+A)."""
         cursor_row, _cursor_col = self.cursor_location
@@ -1,5 +1,29 @@
 """Helper functions and utilities for the issue browser."""
 
-from jayrah.ui.shared_helpers import filter_issues_by_text, get_row_data_for_issue
+from jayrah.ui.shared_helpers import (
+    board_stats,
+    filter_issues_by_text,
+    get_issue_epic,
+    get_row_data_for_issue,
+    group_issues,
+    kanban_column,
+    kanban_columns,
+    label_completion,
+    match_positions,
+    sort_issues,
+    visible_columns,
+)
 
-__all__ = ["filter_issues_by_text", "get_row_data_for_issue"]
+__all__ = [
+    "board_stats",
+    "filter_issues_by_text",
+    "get_issue_epic",
+    "get_row_data_for_issue",
+    "group_issues",
+    "kanban_column",
+    "kanban_columns",
+    "label_completion",
+    "match_positions",
+    "sort_issues",
+    "visible_columns",
+]
@@ -0,0 +1,39 @@
+"""
+Previews of the image attachments with the graphics of the terminal.
+
+Drawing images needs the optional textual-image package, installed with the
+images extra (`uv tool install 'jayrah[images]'`). It finds out what the
+terminal supports, Kitty graphics or Sixel, and falls back to half blocks.
+It has to be imported before the app starts to query the terminal, so this
+module is imported with the views.
+"""
+
+import io
+
+try:
+    from textual_image import widget as image_widgets
+except ImportError:  # pragma: no cover - depends on the installed extras
+    image_widgets = None
+
+
+def available() -> bool:
+    """Whether images can be drawn, the images extra being installed."""
+    return image_widgets is not None
+
+
+def enabled(config: dict) -> bool:
+    """Whether the config asks for previews and they can be drawn."""
+    return bool(config.get("image_preview")) and available()
+
+
+def preview(data: bytes, protocol: str | None = None, **kwargs):
+    """A widget drawing an image with the protocol of the config, auto by default."""
+    if image_widgets is None:
+        raise RuntimeError("Image previews need the images extra of jayrah")
+    widgets = {
+        "kitty": image_widgets.TGPImage,
+        "sixel": image_widgets.SixelImage,
+        "halfcell": image_widgets.HalfcellImage,
+    }
+    widget = widgets.get(protocol or "auto", image_widgets.Image)
+    return widget(io.BytesIO(data), **kwargs)
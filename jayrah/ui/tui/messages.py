@@ -0,0 +1,43 @@
+"""The notifications shown by the browser, kept to be read again."""
+
+import datetime
+from collections import deque
+from dataclasses import dataclass, field
+
+from jayrah.config import defaults
+
+
+@dataclass(frozen=True)
+class Message:
+    """A notification with its severity and when it was shown."""
+
+    text: str
+    severity: str = "information"
+    when: datetime.datetime = field(
+        default_factory=lambda: datetime.datetime.now().astimezone()
+    )
+
+
+class MessageLog:
+    """The latest notifications, the oldest are dropped once it is full."""
+
+    def __init__(self, size: int = defaults.MESSAGES_SIZE):
+        self.messages: deque[Message] = deque(maxlen=size)
+
+    def __len__(self) -> int:
+        return len(self.messages)
+
+    def add(self, text: str, severity: str = "information") -> Message:
+        message = Message(text, severity)
+        self.messages.append(message)
+        return message
+
+    def recent(self) -> list[Message]:
+        """The notifications, newest first."""
+        return list(reversed(self.messages))
+
+
+def notify_timeout(severity: str) -> float:
+    """Seconds a notification stays, errors stay until they can be read."""
+    timeouts = defaults.NOTIFY_TIMEOUTS
+    return timeouts.get(severity, timeouts["information"])
@@ -0,0 +1,101 @@
+"""Tabs of the browser, each with its own query and issues."""
+
+import copy
+from dataclasses import dataclass, field
+from typing import Any
+
+from jayrah.config import defaults
+
+# What a tab keeps of the app: the source of its issues, the issues loaded
+# and how they are shown. The details are cached by issue key, tabs share them.
+TAB_STATE = (
+    "command",
+    "jql",
+    "order_by",
+    "board_overrides",
+    "issues",
+    "issues_total",
+    "issue_pages",
+    "selected_issue",
+    "filter_text",
+    "group_by",
+    "folded_groups",
+    "sort_column",
+    "sort_reverse",
+    "marked_issues",
+    "updated_issues",
+)
+
+
+def tab_title(command: str | None, jql: str | None) -> str:
+    """The board name, or the query of a tab without board."""
+    if command and command != "query":
+        return command
+    jql = jql or "query"
+    limit = defaults.TAB_TITLE_LENGTH
+    return jql if len(jql) <= limit else f"{jql[: limit - 1]}…"
+
+
+@dataclass
+class Tab:
+    """A tab left in the background, with the state the app had in it."""
+
+    state: dict[str, Any] = field(default_factory=dict)
+
+    @classmethod
+    def capture(cls, app) -> "Tab":
+        """The tab of what the app shows, copied so the app can change it."""
+        return cls(
+            {name: copy.copy(getattr(app, name, None)) for name in TAB_STATE}
+        )
+
+    def restore(self, app) -> None:
+        for name, value in self.state.items():
+            setattr(app, name, value)
+
+    @property
+    def title(self) -> str:
+        return tab_title(self.state.get("command"), self.state.get("jql"))
+
+
+class TabSet:
+    """The tabs of the browser, the active one being what the app shows."""
+
+    def __init__(self, app):
+        self.tabs = [Tab.capture(app)]
+        self.active = 0
+
+    def __len__(self) -> int:
+        return len(self.tabs)
+
+    def open(self, app) -> int:
+        """Add a tab showing what the app shows, after the active one."""
+        self.tabs[self.active] = Tab.capture(app)
+        self.active += 1
+        self.tabs.insert(self.active, Tab.capture(app))
+        return self.active
+
+    def switch(self, app, index: int) -> bool:
+        """Keep the state of the active tab and give the app the one of index."""
+        if not 0 <= index < len(self.tabs) or index == self.active:
+            return False
+        self.tabs[self.active] = Tab.capture(app)
+        self.active = index
+        self.tabs[index].restore(app)
+        return True
+
+    def close(self, app) -> bool:
+        """Drop the active tab and restore its neighbour, the last one stays."""
+        if len(self.tabs) == 1:
+            return False
+        del self.tabs[self.active]
+        self.active = min(self.active, len(self.tabs) - 1)
+        self.tabs[self.active].restore(app)
+        return True
+
+    def titles(self, app) -> list[str]:
+        """The titles of the tabs, the active one from the app itself."""
+        return [
+            tab_title(app.command, app.jql) if index == self.active else tab.title
+            for index, tab in enumerate(self.tabs)
+        ]
@@ -0,0 +1,66 @@
+"""The changes made from the browser, to take the latest ones back."""
+
+from dataclasses import dataclass, field
+from typing import Any
+
+from jayrah.config import defaults
+
+
+@dataclass
+class Change:
+    """
+    A change of an issue with what it takes to revert it.
+
+    Field edits keep the values the fields had, sent again to revert them.
+    Transitions keep the status the issue was in, reverting them needs a
+    transition back to it.
+    """
+
+    issue_key: str
+    description: str
+    fields: dict[str, Any] = field(default_factory=dict)
+    status: str | None = None
+
+
+class UndoStack:
+    """The latest changes, the most recent is reverted first."""
+
+    def __init__(self, size: int = defaults.UNDO_SIZE):
+        self.size = size
+        self.changes: list[Change] = []
+
+    def __len__(self) -> int:
+        return len(self.changes)
+
+    def push(self, change: Change) -> None:
+        self.changes = [*self.changes, change][-self.size :]
+
+    def last(self) -> Change | None:
+        return self.changes[-1] if self.changes else None
+
+    def pop(self) -> Change | None:
+        return self.changes.pop() if self.changes else None
+
+
+def revert(jira, change: Change) -> None:
+    """
+    Send the inverse of a change to Jira.
+
+    Raises ValueError when the workflow has no transition back to the status
+    the issue was in.
+    """
+    if change.status is None:
+        jira.update_issue(change.issue_key, change.fields)
+        return
+    # The issue moved since its transitions were cached
+    transitions = jira.get_transitions(change.issue_key, use_cache=False).get(
+        "transitions", []
+    )
+    transition = next(
+        (x for x in transitions if x["to"]["name"] == change.status), None
+    )
+    if transition is None:
+        raise ValueError(
+            f"No transition of {change.issue_key} goes back to '{change.status}'"
+        )
+    jira.transition_issue(change.issue_key, transition["id"])
@@ -1,21 +1,42 @@
 """UI views and screens for the issue browser."""
 
+import asyncio
 import re
+from concurrent.futures import Future
+from functools import partial
 from typing import ClassVar
 
+import jira2markdown
+from rich.text import Text
 from click import ClickException
 from textual.app import ComposeResult
 from textual.binding import Binding
-from textual.containers import Container, Vertical
-from textual.suggester import SuggestFromList
-from textual.widgets import DataTable, Label, Markdown
+from textual.containers import Container, Horizontal, Vertical
+from textual.suggester import Suggester, SuggestFromList
+from textual.widgets import DataTable, Label, Log, Markdown, OptionList, Tree
 
 from jayrah import utils
 from jayrah.api import exceptions as jira_exceptions
-
-from ...utils import adf, issue_view
+from jayrah.api import models, request_handler
+from jayrah.config import defaults
+
+from ...utils import (
+    adf,
+    cache,
+    clipboard,
+    custom_fields,
+    issue_view,
+    jql,
+    logs,
+    markdown_to_jira,
+    mentions,
+    report,
+)
+from . import images, undo
 from .base import BaseModalScreen
 from .enhanced_widgets import EmacsInput, EmacsTextArea
+from .helpers import board_stats, kanban_column, kanban_columns, label_completion
+from .messages import Message
 
 
 class CommentsViewScreen(BaseModalScreen):
@@ -30,6 +51,8 @@ class CommentsViewScreen(BaseModalScreen):
         Binding("n", "next_comment", "Next Comment"),
         Binding("p", "prev_comment", "Previous Comment"),
         Binding("a", "add_comment", "Add Comment"),
+        Binding("L", "load_more", "Load More"),
+        Binding("o", "toggle_order", "Reverse Order"),
     )
 
     CSS = """
@@ -71,7 +94,9 @@ class CommentsViewScreen(BaseModalScreen):
         self.issue_key = issue_key
         self.config = config or {}
         self.comments: list = []  # Store comments data
+        self.total = 0  # Comments on the issue, more than loaded for long threads
         self.current_comment_index = 0  # Track which comment we're viewing
+        self.newest_first = self.config.get("comments_order") == "newest"
 
     def compose(self) -> ComposeResult:
         with Vertical(id="comments-container"):
@@ -79,72 +104,86 @@ class CommentsViewScreen(BaseModalScreen):
             yield Markdown("Loading comments...", id="comments-content")
             yield Label("Press Escape or Q to close", id="comments-help")
 
-    async def on_mount(self) -> None:
+    def on_mount(self) -> None:
         """Load comments when the screen is mounted."""
-        await self._load_comments()
-
-    async def _load_comments(self) -> None:
-        """Load and format comments for the issue."""
-        try:
-            # Get issue with comment field expanded
-            issue_data = self._parent.jayrah_obj.jira.get_issue(
-                self.issue_key, fields=["comment", "summary", "key"], use_cache=False
+        self._load_comments(use_cache=True)
+
+    def _load_comments(self, use_cache: bool = False) -> None:
+        """Load and format the first page of comments for the issue."""
+        # Reuse the issue prefetched by the detail panel when we can, it
+        # embeds the oldest comments first
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        issue_data = None
+        if use_cache and not self.newest_first:
+            issue_data = detail_panel.issue_data_cache.get(self.issue_key)
+        elif not use_cache:
+            # Comments changed, don't let the next popup reuse stale data
+            detail_panel.issue_data_cache.pop(self.issue_key, None)
+
+        if issue_data is not None:
+            self._show_comments(
+                self.newest_first, issue_data.get("fields", {}).get("comment") or {}
             )
+            return
+        newest_first = self.newest_first
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_issue_comments,
+            self.issue_key,
+            newest_first=newest_first,
+            use_cache=False,
+            on_result=lambda page: self._show_comments(newest_first, page),
+            on_error=self._show_comments_error,
+            is_stale=lambda: self._popped or self.newest_first != newest_first,
+        )
 
-            # Store comments data for navigation
-            fields = issue_data.get("fields", {})
-            if "comment" in fields and fields["comment"]["comments"]:
-                self.comments = fields["comment"]["comments"]
-            else:
-                self.comments = []
-
-            comments_content = self._format_comments(issue_data)
-
-            # Update the markdown widget
-            markdown_widget = self.query_one("#comments-content", Markdown)
-            markdown_widget.update(comments_content)
-
-            # Update help text with navigation info
-            help_widget = self.query_one("#comments-help", Label)
-            if self.comments:
-                help_widget.update(
-                    f"Press n/p to navigate comments (1/{len(self.comments)}), a to add comment, Escape or Q to close"
-                )
-            else:
-                help_widget.update("Press a to add comment, Escape or Q to close")
+    def _show_comments(self, newest_first: bool, page: dict) -> None:
+        # The order may have been switched again while this page loaded
+        if self._popped or self.newest_first != newest_first:
+            return
+        # Store comments data for navigation
+        self.comments = page.get("comments") or []
+        self.total = page.get("total", len(self.comments))
+        self.current_comment_index = 0
+        self._refresh_comments_display(scroll=False)
+
+    def _show_comments_error(self, exc: Exception) -> None:
+        if self._popped:
+            return
+        error_message = f"Error loading comments: {exc!s}"
+        markdown_widget = self.query_one("#comments-content", Markdown)
+        markdown_widget.update(error_message)
 
-        except jira_exceptions.JiraAPIError as exc:
-            error_message = f"Error loading comments: {exc!s}"
-            markdown_widget = self.query_one("#comments-content", Markdown)
-            markdown_widget.update(error_message)
+        # Log more detailed error info for debugging
+        if self._parent.config.get("verbose"):
+            from jayrah.utils import log
 
-            # Log more detailed error info for debugging
-            if self._parent.config.get("verbose"):
-                from jayrah.utils import log
+            log(f"Comment loading error details: {type(exc).__name__} - {exc}")
+            import traceback
 
-                log(f"Comment loading error details: {type(exc).__name__} - {exc}")
-                import traceback
+            log("".join(traceback.format_exception(exc)))
 
-                log(traceback.format_exc())
+        self._parent.notify(f"Failed to load comments: {exc}", severity="error")
 
-            self._parent.notify(f"Failed to load comments: {exc}", severity="error")
+    @property
+    def has_more(self) -> bool:
+        return len(self.comments) < self.total
 
-    def _format_comments(self, issue_data: dict) -> str:
+    def _format_comments(self) -> str:
         """Format comments into markdown."""
-        fields = issue_data.get("fields", {})
-
-        if "comment" not in fields or not fields["comment"]["comments"]:
+        if not self.comments:
             return "No comments found for this issue."
 
-        comments = fields["comment"]["comments"]
-        total = fields["comment"]["total"]
-
         # Build markdown content
         content = [f"# Comments for {self.issue_key}"]
-        content.append(f"*Showing {len(comments)} of {total} comments*")
+        order = "newest" if self.newest_first else "oldest"
+        showing = f"*Showing {len(self.comments)} of {self.total} comments"
+        showing += f", {order} first"
+        if self.has_more:
+            showing += ", press L to load more"
+        content.append(showing + "*")
         content.append("")
 
-        for i, comment in enumerate(comments):
+        for i, comment in enumerate(self.comments):
             author = comment.get("author", {}).get("displayName", "Unknown")
             created = comment.get("created", "Unknown date")
 
@@ -176,28 +215,23 @@ class CommentsViewScreen(BaseModalScreen):
                 and "type" in comment_body
                 and comment_body.get("type") == "doc"
             ):
+                comment_content = adf.adf_to_markdown(comment_body)
+            else:
                 try:
-                    # Import here to avoid circular imports
-                    from jayrah.utils import adf
+                    import jira2markdown
 
-                    comment_body = adf.extract_text_from_adf(comment_body)
+                    comment_content = (
+                        jira2markdown.convert(comment_body)
+                        if isinstance(comment_body, str)
+                        else str(comment_body)
+                    )
                 except ImportError:
-                    # Fallback if adf module is not available
-                    comment_body = str(comment_body)
-
-            try:
-                import jira2markdown
-
-                comment_content = (
-                    jira2markdown.convert(comment_body)
-                    if isinstance(comment_body, str)
-                    else str(comment_body)
-                )
-            except ImportError:
-                # Fallback if jira2markdown is not available
-                comment_content = (
-                    comment_body if isinstance(comment_body, str) else str(comment_body)
-                )
+                    # Fallback if jira2markdown is not available
+                    comment_content = (
+                        comment_body
+                        if isinstance(comment_body, str)
+                        else str(comment_body)
+                    )
 
             content.append(comment_content)
             content.append("")
@@ -238,32 +272,29 @@ class CommentsViewScreen(BaseModalScreen):
         )
         self._refresh_comments_display()
 
-    def _refresh_comments_display(self) -> None:
+    def _refresh_comments_display(self, scroll: bool = True) -> None:
         """Refresh the comments display and scroll to current comment."""
-        if not self.comments:
-            return
-
-        # Create a fake issue_data structure for _format_comments
-        issue_data = {
-            "fields": {
-                "comment": {"comments": self.comments, "total": len(self.comments)}
-            }
-        }
-
         # Update the markdown content
-        comments_content = self._format_comments(issue_data)
         markdown_widget = self.query_one("#comments-content", Markdown)
-        markdown_widget.update(comments_content)
+        markdown_widget.update(self._format_comments())
 
-        # Update help text
+        # Update help text with navigation info
         help_widget = self.query_one("#comments-help", Label)
-        help_widget.update(
-            f"Press n/p to navigate comments ({self.current_comment_index + 1}/{len(self.comments)}), a to add comment, Escape or Q to close"
-        )
+        if self.comments:
+            position = f"{self.current_comment_index + 1}/{len(self.comments)}"
+            help_text = (
+                f"Press n/p to navigate comments ({position}), o to reverse the "
+                "order, a to add comment, Escape or Q to close"
+            )
+        else:
+            help_text = "Press a to add comment, Escape or Q to close"
+        if self.has_more:
+            help_text = f"{help_text}, L to load more"
+        help_widget.update(help_text)
 
         # Scroll to the current comment section
         # We'll scroll to roughly where the current comment should be
-        if len(self.comments) > 1:
+        if scroll and len(self.comments) > 1:
             # Calculate scroll position based on comment index
             scroll_percentage = self.current_comment_index / (len(self.comments) - 1)
             # Get the total content height and scroll to the appropriate position
@@ -271,6 +302,41 @@ class CommentsViewScreen(BaseModalScreen):
                 y=scroll_percentage * markdown_widget.max_scroll_y, animate=True
             )
 
+    def action_load_more(self) -> None:
+        """Fetch the next page of comments of a long thread."""
+        if not self.has_more:
+            self._parent.notify("All comments are loaded")
+            return
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_issue_comments,
+            self.issue_key,
+            start_at=len(self.comments),
+            newest_first=self.newest_first,
+            use_cache=False,
+            on_result=partial(self._show_more_comments, len(self.comments)),
+            on_error=lambda exc: self._parent.notify(
+                f"Failed to load comments: {exc}", severity="error"
+            ),
+            is_stale=lambda: self._popped,
+        )
+
+    def _show_more_comments(self, start_at: int, page: dict) -> None:
+        # Dropped when the comments were reloaded in the meantime
+        if self._popped or len(self.comments) != start_at:
+            return
+        self.current_comment_index = len(self.comments)
+        self.comments.extend(page.get("comments") or [])
+        self.total = page.get("total", self.total)
+        self.current_comment_index = min(
+            self.current_comment_index, max(len(self.comments) - 1, 0)
+        )
+        self._refresh_comments_display()
+
+    def action_toggle_order(self) -> None:
+        """Switch between the oldest and the newest comments first."""
+        self.newest_first = not self.newest_first
+        self._load_comments(use_cache=True)
+
     def action_add_comment(self) -> None:
         """Open modal to add a new comment to the issue."""
         self.app.push_screen(
@@ -282,9 +348,12 @@ class CommentsViewScreen(BaseModalScreen):
             )
         )
 
-    async def _on_comment_added(self) -> None:
+    def _on_comment_added(self) -> None:
         """Callback when a new comment is added - refresh the comments display."""
-        await self._load_comments()
+        self._load_comments()
+
+
+COMMENT_PLACEHOLDER = "Enter your comment here..."
 
 
 class AddCommentScreen(BaseModalScreen):
@@ -293,7 +362,13 @@ class AddCommentScreen(BaseModalScreen):
     BINDINGS: ClassVar[tuple[Binding, ...]] = (
         Binding("escape", "cancel", "Cancel"),
         Binding("ctrl+enter", "apply", "Add Comment"),
+        Binding("ctrl+s", "apply", "Add Comment", show=False),
+        Binding("f2", "toggle_preview", "Preview"),
         Binding("f1", "help", "Help"),
+        # Only while the users matching an @mention are shown
+        Binding("tab", "complete_mention", "Mention", show=False, priority=True),
+        Binding("down", "mention_cursor(1)", show=False, priority=True),
+        Binding("up", "mention_cursor(-1)", show=False, priority=True),
     )
 
     CSS = """
@@ -320,6 +395,30 @@ class AddCommentScreen(BaseModalScreen):
         height: 1fr;
         margin: 1 0;
     }
+
+    #comment-preview {
+        display: none;
+        height: 1fr;
+        margin: 1 0;
+        overflow-y: auto;
+    }
+
+    #comment-container.preview #comment-preview {
+        display: block;
+    }
+
+    #comment-container.preview #comment-textarea {
+        display: none;
+    }
+
+    #mention-options {
+        display: none;
+        max-height: 8;
+    }
+
+    #mention-options.visible {
+        display: block;
+    }
     
     #comment-help {
         text-align: center;
@@ -334,43 +433,166 @@ class AddCommentScreen(BaseModalScreen):
         self.issue_key = issue_key
         self.config = config or {}
         self.on_comment_added = on_comment_added
+        # The @query being completed and the users matching it
+        self.mention_query: str | None = None
+        self.mention_users: list[dict] = []
+        self.mentions: list[mentions.Mention] = []
 
     def compose(self) -> ComposeResult:
         with Vertical(id="comment-container"):
             yield Label(f"✍️ Add Comment to {self.issue_key}", id="comment-title")
+            # A comment that could not be added or was cancelled is kept
+            draft = self._parent.jayrah_obj.state_store.comment_draft(self.issue_key)
             yield EmacsTextArea(
-                text="Enter your comment here...", id="comment-textarea"
+                text=draft or COMMENT_PLACEHOLDER, id="comment-textarea"
             )
+            yield Markdown(id="comment-preview")
+            yield OptionList(id="mention-options")
             yield Label(
-                "Press Ctrl+Enter to add comment, Escape to cancel", id="comment-help"
+                "Press Ctrl+S to add comment, F2 to preview, Escape to cancel",
+                id="comment-help",
             )
 
-    def action_apply(self) -> None:
-        """Add the comment to the issue."""
+    def _comment_text(self) -> str:
+        text = self.query_one("#comment-textarea", EmacsTextArea).text.strip()
+        return "" if text == COMMENT_PLACEHOLDER else text
+
+    def _save_draft(self) -> None:
+        self._parent.jayrah_obj.state_store.save_comment_draft(
+            self.issue_key, self._comment_text()
+        )
+
+    def action_cancel(self) -> None:
+        """Close the popup, keeping what was written for the next time."""
+        self._save_draft()
+        super().action_cancel()
+
+    def action_toggle_preview(self) -> None:
+        """Switch between the editor and the rendered Markdown of the comment."""
+        container = self.query_one("#comment-container", Vertical)
+        if container.has_class("preview"):
+            container.remove_class("preview")
+            self.query_one("#comment-textarea", EmacsTextArea).focus()
+            return
+        self.query_one("#comment-preview", Markdown).update(
+            self._comment_text() or "*Nothing to preview*"
+        )
+        container.add_class("preview")
+
+    def check_action(self, action: str, parameters: tuple) -> bool | None:
+        if action in ("complete_mention", "mention_cursor"):
+            return bool(self.mention_users)
+        return True
+
+    def _text_before_cursor(self) -> str:
+        textarea = self.query_one("#comment-textarea", EmacsTextArea)
+        row, column = textarea.cursor_location
+        return textarea.document.get_line(row)[:column]
+
+    def on_text_area_changed(self, event) -> None:
+        """Look up the users matching the @mention being typed."""
+        query = mentions.mention_query(self._text_before_cursor())
+        if query == self.mention_query:
+            return
+        self.mention_query = query
+        if query is None:
+            self.show_mention_users(None, [])
+            return
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.search_users,
+            query,
+            defaults.MENTION_SUGGESTIONS,
+            on_result=lambda users: self.show_mention_users(query, users),
+            on_error=lambda exc: self.show_mention_users(query, []),
+            is_stale=lambda: self._popped or self.mention_query != query,
+        )
+
+    def show_mention_users(self, query: str | None, users: list[dict]) -> None:
+        if query != self.mention_query:
+            return
+        self.mention_users = users
+        options = self.query_one("#mention-options", OptionList)
+        options.clear_options()
+        for user in users:
+            email = user.get("emailAddress")
+            name = user.get("displayName") or user.get("name") or ""
+            options.add_option(f"{name} <{email}>" if email else name)
+        options.set_class(bool(users), "visible")
+        if users:
+            options.highlighted = 0
+        self.refresh_bindings()
+
+    def action_mention_cursor(self, step: int) -> None:
+        options = self.query_one("#mention-options", OptionList)
+        current = options.highlighted or 0
+        options.highlighted = (current + step) % len(self.mention_users)
+
+    def action_complete_mention(self) -> None:
+        """Replace the @query with the highlighted user."""
+        options = self.query_one("#mention-options", OptionList)
+        self.complete_mention(options.highlighted or 0)
+
+    def on_option_list_option_selected(self, event) -> None:
+        self.complete_mention(event.option_index)
+
+    def complete_mention(self, index: int) -> None:
+        if self.mention_query is None or not self.mention_users:
+            return
+        mention = mentions.Mention.from_user(self.mention_users[index])
         textarea = self.query_one("#comment-textarea", EmacsTextArea)
-        comment_text = textarea.text.strip()
+        row, column = textarea.cursor_location
+        start = (row, column - len(self.mention_query) - 1)
+        self.mention_query = None
+        self.show_mention_users(None, [])
+        textarea.replace(f"{mention.text} ", start, (row, column))
+        self.mentions.append(mention)
+        textarea.focus()
 
-        # Check if the text is still the placeholder text or empty
-        if not comment_text or comment_text == "Enter your comment here...":
+    def action_apply(self) -> None:
+        """Add the comment to the issue."""
+        comment_text = self._comment_text()
+        if not comment_text:
             self._parent.notify("Comment cannot be empty", severity="warning")
             return
 
-        try:
-            # Add comment using the Jira API
-            self._parent.jayrah_obj.jira.add_comment(self.issue_key, comment_text)
+        jira = self._parent.jayrah_obj.jira
+        comment = comment_body(jira, comment_text, self.mentions)
+
+        self._parent.worker_pool.submit(
+            jira.add_comment,
+            self.issue_key,
+            comment,
+            on_result=lambda _result: self._comment_added(),
+            on_error=self._comment_failed,
+        )
 
-            self._parent.notify(f"✅ Comment added to {self.issue_key}")
+    def _comment_added(self) -> None:
+        self._parent.notify(f"✅ Comment added to {self.issue_key}")
 
-            # Call the callback to refresh comments if provided
-            if self.on_comment_added:
-                self.app.call_later(self.on_comment_added)
+        # Call the callback to refresh comments if provided
+        if self.on_comment_added:
+            self.on_comment_added()
 
-            self.action_cancel()
+        self._parent.jayrah_obj.state_store.save_comment_draft(self.issue_key, "")
+        self.safe_pop_screen()
+
+    def _comment_failed(self, exc: Exception) -> None:
+        if self.config.get("verbose") and self.config["verbose"]:
+            utils.log(f"Error adding comment: {exc}")
+        # The popup stays open, the draft also survives closing jayrah
+        if not self._popped:
+            self._save_draft()
+        self._parent.notify(f"Failed to add comment: {exc}", severity="error")
 
-        except (jira_exceptions.JiraAPIError, ClickException) as exc:
-            if self.config.get("verbose") and self.config["verbose"]:
-                utils.log(f"Error adding comment: {exc}")
-            self._parent.notify(f"Failed to add comment: {exc}", severity="error")
+
+def comment_body(jira, text: str, picked: list[mentions.Mention]):
+    """The body of a Markdown comment with its mentions as the API wants them."""
+    if jira.api_version != "3":
+        # API v3 converts the Markdown to ADF, v2 needs it as wiki markup
+        return mentions.to_wiki(markdown_to_jira.convert(text), picked)
+    if not picked:
+        return text
+    return mentions.to_adf(jira.formatter.format_comment(text)["body"], picked)
 
 
 class SuggestFromListComma(SuggestFromList):
@@ -425,6 +647,38 @@ class SuggestFromListComma(SuggestFromList):
         return None
 
 
+class LabelSuggester(Suggester):
+    """
+    Complete the label typed after the last comma.
+
+    The recently used labels are tried first, then the ones Jira suggests for
+    what was typed, fetched in a thread so typing doesn't wait on the server.
+    """
+
+    def __init__(self, recent: list[str], suggest, excludes: str | None = None):
+        super().__init__(use_cache=True, case_sensitive=True)
+        self.excludes = re.compile(excludes.strip()) if excludes else None
+        self.recent = self._allowed(recent)
+        self.suggest = suggest
+
+    def _allowed(self, labels: list[str]) -> list[str]:
+        if self.excludes is None:
+            return labels
+        return [x for x in labels if not self.excludes.match(x)]
+
+    async def get_suggestion(self, value: str) -> str | None:
+        typed = value.split(",")[-1].strip()
+        if not typed:
+            return None
+        if completion := label_completion(value, self.recent):
+            return completion
+        try:
+            labels = await asyncio.to_thread(self.suggest, typed)
+        except (jira_exceptions.JiraAPIError, ClickException):
+            return None
+        return label_completion(value, self._allowed(labels))
+
+
 class IssueDetailPanel(Vertical):
     """Panel showing detailed information about the selected issue using Markdown."""
 
@@ -451,7 +705,13 @@ class IssueDetailPanel(Vertical):
         super().__init__()
         self.ticket = ticket
         self.config = config or {}
-        self.ticket_cache: dict = {}
+        size = self.config.get("detail_cache_size") or defaults.DETAIL_CACHE_SIZE
+        ttl = self.config.get("detail_cache_ttl", defaults.DETAIL_CACHE_TTL)
+        self.ticket_cache = cache.LRUCache(size, ttl)
+        self.issue_data_cache = cache.LRUCache(size, ttl)
+        self.transitions_cache = cache.LRUCache(size, ttl)
+        # Change histories of the issues, for the time spent in each status
+        self.changelog_cache = cache.LRUCache(size, ttl)
         # Import here to avoid circular imports
         from .. import boards
 
@@ -480,51 +740,152 @@ class IssueDetailPanel(Vertical):
         if not ticket:
             markdown_widget.update("Select an issue to view details")
             return
+        if self.ticket_cache.expired(ticket):
+            # The HTTP cache would give back the same old copy of the issue
+            use_cache = False
+        if use_cache and ticket in self.ticket_cache:
+            self._update_markdown(markdown_widget, self.ticket_cache[ticket])
+            self._record_view(ticket, self.issue_data_cache.get(ticket))
+            return
         # Show loading message immediately
         markdown_widget.update(f"🔄 Loading details for {ticket}...")
         self.app.refresh()
-        # Run the blocking fetch in a worker (threaded)
-        self.run_worker(
-            lambda: self._fetch_and_update_issue(ticket, self.config, use_cache),
-            exclusive=True,
-            thread=True,
-        )
+        self._prefetch_issue(ticket, self.config, use_cache)
+
+    @property
+    def loading(self) -> bool:
+        """Whether the issue shown is still being fetched."""
+        return bool(self.ticket) and self.ticket not in self.ticket_cache
 
-    def _fetch_and_update_issue(
+    def _is_stale(self, ticket: str) -> bool:
+        """Check whether the selection moved away from the ticket being loaded."""
+        return self.ticket != ticket
+
+    def _prefetch_issue(
         self, ticket: str, config: dict, use_cache: bool = True
-    ) -> None:
-        markdown_widget = self.query_one("#detail-markdown", Markdown)
-        self.log(f"Ticket cache: {self.ticket_cache}")
-        self.log(f"Use cache: {use_cache}")
-        self.log(f"Ticket: {ticket}")
-        try:
-            all_content = ""
-            if ticket not in self.ticket_cache or not use_cache:
-                issue_data = self.jayrah_obj.jira.get_issue(
-                    ticket, fields=None, use_cache=use_cache
-                )
-                header_content, markdown_content = issue_view.build_issue(
-                    issue_data, config, 0
-                )
-                if isinstance(markdown_content, list):
-                    markdown_content = "\n".join(markdown_content)
-                all_content = str(header_content) + "\n" + str(markdown_content)
-                self.ticket_cache[ticket] = all_content
-            else:
-                all_content = self.ticket_cache.get(ticket, "")
-            # Update the UI in the main thread
-            self.app.call_from_thread(
-                lambda: self._update_markdown(markdown_widget, all_content)
-            )
-        except (jira_exceptions.JiraAPIError, ClickException) as exc:
-            error_message = (
-                f"⚠️ Error loading issue {ticket}:\n\n```\n{exc!s}\n```\n\n"
-                "Please check the ticket ID and your connection."
+    ) -> list[Future]:
+        """
+        Fetch the issue (with its comments) and its transitions concurrently.
+
+        The raw results are kept so the comments and transition popups can be
+        shown without another round trip to Jira.
+        """
+        jira = self.jayrah_obj.jira
+        pool = self.app.worker_pool
+        issue_future = pool.submit(
+            self._fetch_issue,
+            ticket,
+            use_cache,
+            on_result=lambda issue_data: self._on_issue_loaded(
+                ticket, issue_data, config
+            ),
+            on_error=lambda exc: self._on_issue_error(ticket, exc),
+            is_stale=lambda: self._is_stale(ticket),
+            key=("issue", ticket, use_cache),
+        )
+        if self.config.get("offline"):
+            return [issue_future]
+        transitions_future = pool.submit(
+            jira.get_transitions,
+            ticket,
+            use_cache=use_cache,
+            on_result=lambda data: self.transitions_cache.update({ticket: data}),
+            # Transitions are only a convenience here, the popup refetches
+            on_error=lambda exc: self.transitions_cache.pop(ticket, None),
+            is_stale=lambda: self._is_stale(ticket),
+            key=("transitions", ticket, use_cache),
+        )
+        changelog_future = pool.submit(
+            jira.get_issue_changelog,
+            ticket,
+            use_cache,
+            on_result=lambda histories: self._on_changelog_loaded(
+                ticket, histories, config
+            ),
+            # Without the changelog the details only miss the time in status
+            on_error=lambda exc: self.changelog_cache.pop(ticket, None),
+            is_stale=lambda: self._is_stale(ticket),
+            key=("changelog", ticket, use_cache),
+        )
+        return [issue_future, transitions_future, changelog_future]
+
+    def _fetch_issue(self, ticket: str, use_cache: bool) -> dict:
+        """Worker method fetching an issue, from the offline store in offline mode."""
+        store = self.jayrah_obj.offline_store
+        if self.config.get("offline"):
+            saved = store.load_issue(ticket)
+            if saved is None:
+                raise ValueError(f"{ticket} has not been viewed online yet")
+            return saved[0]
+
+        issue_data = self.jayrah_obj.jira.get_issue(
+            ticket, fields=None, use_cache=use_cache
+        )
+        store.save_issue(issue_data)
+        return issue_data
+
+    def _build_content(self, ticket: str, issue_data: dict, config: dict) -> str:
+        """Markdown of an issue, with its time in status once the changelog is in."""
+        header_content, markdown_content = issue_view.build_issue(
+            issue_data, config, 0
+        )
+        if isinstance(markdown_content, list):
+            markdown_content = "\n".join(markdown_content)
+        all_content = str(header_content) + "\n"
+        if histories := self.changelog_cache.get(ticket):
+            if section := issue_view.time_in_status(issue_data, histories):
+                all_content += "\n" + section + "\n"
+        all_content += str(markdown_content)
+        if config.get("offline"):
+            all_content = (
+                "> ⚠️ Offline, this is the last copy fetched from Jira\n\n"
+                + all_content
             )
-            self.app.call_from_thread(
-                lambda: self._update_markdown(markdown_widget, error_message)
+        return all_content
+
+    def _on_changelog_loaded(self, ticket: str, histories: list, config: dict) -> None:
+        """Add the time in status to the details of an issue already shown."""
+        self.changelog_cache[ticket] = histories
+        issue_data = self.issue_data_cache.get(ticket)
+        if issue_data is None or not histories:
+            # The issue is still loading, it is built with the changelog
+            return
+        self.ticket_cache[ticket] = self._build_content(ticket, issue_data, config)
+        if not self._is_stale(ticket):
+            # Not scrolled back to the top, the user may be reading already
+            self.query_one("#detail-markdown", Markdown).update(
+                self.ticket_cache[ticket]
             )
-            self.app.log.error(f"Failed to load or build issue {ticket}: {exc}")
+
+    def _on_issue_loaded(self, ticket: str, issue_data: dict, config: dict) -> None:
+        """Cache a fetched issue and show it if it is still the selected one."""
+        all_content = self._build_content(ticket, issue_data, config)
+        self.issue_data_cache[ticket] = issue_data
+        self.ticket_cache[ticket] = all_content
+        if self._is_stale(ticket):
+            # Keep the cached result but don't paint an old issue
+            self.log(f"Dropping stale result for {ticket}")
+            return
+        markdown_widget = self.query_one("#detail-markdown", Markdown)
+        self._update_markdown(markdown_widget, all_content)
+        self._record_view(ticket, issue_data)
+
+    def _record_view(self, ticket: str, issue_data: dict | None) -> None:
+        """Add a shown issue to the history of the recently viewed ones."""
+        summary = ((issue_data or {}).get("fields") or {}).get("summary") or ""
+        self.jayrah_obj.state_store.record_view(ticket, summary)
+
+    def _on_issue_error(self, ticket: str, exc: Exception) -> None:
+        """Show the error for a failed fetch if the issue is still selected."""
+        self.app.log.error(f"Failed to load or build issue {ticket}: {exc}")
+        if self._is_stale(ticket):
+            return
+        error_message = (
+            f"⚠️ Error loading issue {ticket}:\n\n```\n{exc!s}\n```\n\n"
+            "Please check the ticket ID and your connection."
+        )
+        markdown_widget = self.query_one("#detail-markdown", Markdown)
+        self._update_markdown(markdown_widget, error_message)
 
     def _update_markdown(self, markdown_widget, content):
         markdown_widget.update(content)
@@ -536,6 +897,65 @@ class IssueDetailPanel(Vertical):
             self.update_issue(self.ticket, self.config)
 
 
+class DebugPanel(Vertical):
+    """Pane with the latest requests sent to Jira, hidden until toggled."""
+
+    DEFAULT_CSS = """
+    DebugPanel {
+        display: none;
+        dock: bottom;
+        height: 12;
+        width: 100%;
+        background: $surface;
+        border-top: solid $warning;
+    }
+    DebugPanel.visible {
+        display: block;
+    }
+
+    #debug-table {
+        width: 100%;
+        height: 1fr;
+    }
+    """
+
+    def compose(self) -> ComposeResult:  # type: ignore[override]
+        table = DataTable(id="debug-table")
+        table.cursor_type = "row"
+        table.add_columns("Time", "Request", "Status", "Duration", "Error")
+        yield table
+
+    def on_mount(self) -> None:
+        self.set_interval(defaults.DEBUG_PANE_REFRESH, self.refresh_requests)
+
+    def toggle(self) -> bool:
+        """Show or hide the pane, gives whether it is now shown."""
+        self.toggle_class("visible")
+        shown = self.has_class("visible")
+        if shown:
+            self.refresh_requests()
+        return shown
+
+    def refresh_requests(self) -> None:
+        """List the latest requests, newest first, while the pane is shown."""
+        if not self.has_class("visible"):
+            return
+        table = self.query_one("#debug-table", DataTable)
+        table.clear()
+        for record in request_handler.recent_requests():
+            failed = record.status is None or record.status >= 400
+            table.add_row(
+                record.at.strftime("%H:%M:%S"),
+                f"{record.method} {record.endpoint}",
+                Text(
+                    str(record.status) if record.status else "unreachable",
+                    style="bold red" if failed else "green",
+                ),
+                f"{record.duration:.0f}ms",
+                record.error,
+            )
+
+
 class LabelsEditScreen(BaseModalScreen):
     """Modal screen for editing issue labels."""
 
@@ -593,15 +1013,7 @@ class LabelsEditScreen(BaseModalScreen):
         current_labels_text = (
             ", ".join(self.current_labels) if self.current_labels else "No labels"
         )
-        all_labels = self._parent.jayrah_obj.jira.get_labels()
-        if label_excludes := self.config.get("label_excludes"):
-            labels_excldues_re = re.compile(label_excludes.strip())
-            all_labels = [
-                label
-                for label in all_labels
-                if label and not labels_excldues_re.match(label)
-            ]
-        print(f"All labels: {all_labels}")
+        jayrah_obj = self._parent.jayrah_obj
 
         with Vertical(id="labels-container"):
             yield Label(f"Edit Labels for {self.issue_key}", id="labels-title")
@@ -610,12 +1022,16 @@ class LabelsEditScreen(BaseModalScreen):
                 placeholder="Enter labels separated by commas (e.g., bug, frontend, urgent)",
                 id="labels-input",
                 value=", ".join(self.current_labels),
-                suggester=SuggestFromListComma(
-                    all_labels,
-                    case_sensitive=False,
+                suggester=LabelSuggester(
+                    jayrah_obj.state_store.recent_labels(),
+                    jayrah_obj.jira.suggest_labels,
+                    self.config.get("label_excludes"),
                 ),
             )
-            yield Label("Press Enter to update, Escape to cancel", id="labels-help")
+            yield Label(
+                "Right arrow to complete, Enter to update, Escape to cancel",
+                id="labels-help",
+            )
 
     def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
         """Handle when user presses Enter in the input field."""
@@ -634,39 +1050,53 @@ class LabelsEditScreen(BaseModalScreen):
             new_labels = []
 
         # Update the issue with new labels
-        try:
-            self._parent.jayrah_obj.jira.update_issue(
-                self.issue_key, {"labels": new_labels}
-            )
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.update_issue,
+            self.issue_key,
+            {"labels": new_labels},
+            on_result=lambda _result: self._labels_updated(new_labels),
+            on_error=lambda exc: self._parent.notify(
+                f"Error updating labels: {exc}", severity="error"
+            ),
+        )
+        self.safe_pop_screen()
 
-            # Update the issue cache to reflect changes
-            detail_panel = self._parent.query_one(IssueDetailPanel)
-            if detail_panel.ticket == self.issue_key:
-                detail_panel.update_issue(
-                    self.issue_key, self._parent.config, use_cache=False
-                )
+    def _labels_updated(self, new_labels: list[str]) -> None:
+        self._parent.undo_stack.push(
+            undo.Change(
+                self.issue_key,
+                f"labels of {self.issue_key}",
+                {"labels": self.current_labels},
+            )
+        )
 
-            # Show success notification
-            labels_text = ", ".join(new_labels) if new_labels else "No labels"
-            self._parent.notify(f"✅ Labels updated: {labels_text}")
+        # Update the issue cache to reflect changes
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        if detail_panel.ticket == self.issue_key:
+            detail_panel.update_issue(
+                self.issue_key, self._parent.config, use_cache=False
+            )
 
-        except jira_exceptions.JiraAPIError as exc:
-            self._parent.notify(f"Error updating labels: {exc}", severity="error")
+        if added := [x for x in new_labels if x not in self.current_labels]:
+            self._parent.jayrah_obj.state_store.record_labels(added)
 
-        self.safe_pop_screen()
+        # Show success notification
+        labels_text = ", ".join(new_labels) if new_labels else "No labels"
+        self._parent.notify(f"✅ Labels updated: {labels_text}")
 
 
-class ComponentsEditScreen(BaseModalScreen):
-    """Modal screen for editing issue components."""
+class MultiSelectScreen(BaseModalScreen):
+    """Modal screen for picking any number of values from a list."""
 
     BINDINGS: ClassVar[tuple[Binding, ...]] = (
         Binding("escape", "cancel", "Cancel"),
-        Binding("enter", "apply", "Apply"),
+        Binding("space", "toggle", "Toggle"),
+        Binding("ctrl+s", "apply", "Apply"),
         Binding("f1", "help", "Help"),
     )
 
     CSS = """
-    #components-container {
+    #multi-select-container {
         dock: bottom;
         padding: 1;
         width: 100%;
@@ -675,106 +1105,332 @@ class ComponentsEditScreen(BaseModalScreen):
         border-top: thick $primary;
         margin: 0;
     }
-    
-    #components-title {
+
+    #multi-select-title {
         text-align: center;
         text-style: bold;
         width: 100%;
         height: 1;
         content-align: center middle;
     }
-    
-    #components-current {
-        width: 100%;
-        margin: 0 0 1 0;
-        text-align: center;
-        color: $text-muted;
-    }
-    
-    #components-input {
+
+    #multi-select-table {
         width: 100%;
         margin: 0;
+        height: auto;
+        max-height: 15;
     }
-    
-    #components-help {
+
+    #multi-select-help {
         text-align: center;
         color: $text-muted;
         margin-top: 0;
     }
     """
 
-    def __init__(self, parent, issue_key: str, current_components: list, config: dict):
+    HELP_TEXT = "Space to toggle, Enter or Ctrl+S to apply, Escape to cancel"
+    OPTIONS_ERROR = "Could not fetch the values"
+
+    def __init__(self, parent, heading: str, selected: list[str]):
         super().__init__(parent)
-        self.issue_key = issue_key
-        self.current_components = current_components or []
-        self.config = config or {}
+        self.heading = heading
+        self.selected = list(selected or [])
+        self.options: list[str] = []
+
+    def load_options(self) -> list[str]:
+        """
+        The values to pick from, subclasses fetch them from Jira.
+
+        Called on the worker pool, errors are reported with `OPTIONS_ERROR`.
+        """
+        return []
 
     def compose(self) -> ComposeResult:
-        current_components_text = (
-            ", ".join(self.current_components)
-            if self.current_components
-            else "No components"
+        with Vertical(id="multi-select-container"):
+            yield Label(self.heading, id="multi-select-title")
+            table = DataTable(id="multi-select-table")
+            table.cursor_type = "row"
+            table.add_column("", key="mark")
+            table.add_column("Value", key="value")
+            table.loading = True
+            yield table
+            yield Label(self.HELP_TEXT, id="multi-select-help")
+
+    def on_mount(self) -> None:
+        """Focus the list to pick values right away, while they load."""
+        self.query_one("#multi-select-table", DataTable).focus()
+        self._parent.worker_pool.submit(
+            self.load_options,
+            on_result=self.show_options,
+            on_error=self._options_failed,
+            is_stale=lambda: self._popped,
         )
-        all_components = self._parent.jayrah_obj.jira.get_components()
 
-        with Vertical(id="components-container"):
-            yield Label(f"Edit Components for {self.issue_key}", id="components-title")
-            yield Label(f"Current: {current_components_text}", id="components-current")
-            yield EmacsInput(
-                placeholder="Enter components separated by commas (e.g., backend, frontend, api)",
-                id="components-input",
-                value=", ".join(self.current_components),
-                suggester=SuggestFromListComma(
-                    all_components,
-                    case_sensitive=False,
-                ),
-            )
-            yield Label("Press Enter to update, Escape to cancel", id="components-help")
+    def show_options(self, options: list[str]) -> None:
+        """Fill the list with the values fetched."""
+        if self._popped:
+            return
+        # Keep the current values even when the server does not list them
+        self.options = options + [x for x in self.selected if x not in options]
+        table = self.query_one("#multi-select-table", DataTable)
+        for option in self.options:
+            table.add_row(self._mark(option), option, key=option)
+        table.loading = False
+
+    def _options_failed(self, exc: Exception) -> None:
+        self._parent.notify(f"{self.OPTIONS_ERROR}: {exc}", severity="error")
+        self.show_options([])
+
+    def _mark(self, option: str) -> str:
+        return "[x]" if option in self.selected else "[ ]"
+
+    def action_toggle(self) -> None:
+        """Select or unselect the value under the cursor."""
+        table = self.query_one("#multi-select-table", DataTable)
+        if not self.options:
+            return
+        option = self.options[table.cursor_row]
+        if option in self.selected:
+            self.selected.remove(option)
+        else:
+            self.selected.append(option)
+        table.update_cell(option, "mark", self._mark(option))
 
-    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
-        """Handle when user presses Enter in the input field."""
+    def on_data_table_row_selected(self, event) -> None:
+        """Enter applies the selection."""
         self.action_apply()
 
     def action_apply(self) -> None:
-        """Apply the component changes."""
-        components_input = self.query_one("#components-input", EmacsInput).value.strip()
-
-        # Parse the components from input (split by comma and strip whitespace)
-        if components_input:
-            new_components = [
-                component.strip()
-                for component in components_input.split(",")
-                if component.strip()
-            ]
-        else:
-            new_components = []
+        """Apply the selected values, in the order of the list."""
+        self.apply_selection([x for x in self.options if x in self.selected])
 
-        # Update the issue with new components
-        try:
-            # Format components for Jira API
-            components_data = [{"name": component} for component in new_components]
-            self._parent.jayrah_obj.jira.update_issue(
-                self.issue_key, {"components": components_data}
+    def apply_selection(self, values: list[str]) -> None:
+        """Save the picked values, subclasses send them to Jira."""
+        self.safe_pop_screen()
+
+
+class SingleSelectScreen(MultiSelectScreen):
+    """Modal screen for picking one value from a list."""
+
+    HELP_TEXT = "Enter to apply, Escape to cancel"
+
+    def show_options(self, options: list[str]) -> None:
+        """Fill the list and put the cursor on the current value."""
+        super().show_options(options)
+        if self._popped:
+            return
+        table = self.query_one("#multi-select-table", DataTable)
+        if self.selected and self.selected[0] in self.options:
+            table.move_cursor(row=self.options.index(self.selected[0]))
+
+    def action_toggle(self) -> None:
+        """Mark the value under the cursor as the only selected one."""
+        table = self.query_one("#multi-select-table", DataTable)
+        if not self.options:
+            return
+        previous = self.selected
+        self.selected = [self.options[table.cursor_row]]
+        for option in previous + self.selected:
+            table.update_cell(option, "mark", self._mark(option))
+
+    def action_apply(self) -> None:
+        """Apply the value under the cursor."""
+        table = self.query_one("#multi-select-table", DataTable)
+        if not self.options:
+            self.safe_pop_screen()
+            return
+        self.apply_selection([self.options[table.cursor_row]])
+
+
+class ComponentsEditScreen(MultiSelectScreen):
+    """Modal screen for picking the components of an issue."""
+
+    OPTIONS_ERROR = "Could not fetch components"
+
+    def __init__(self, parent, issue_key: str, current_components: list, config: dict):
+        super().__init__(
+            parent, f"Edit Components for {issue_key}", current_components
+        )
+        self.issue_key = issue_key
+        self.current_components = list(current_components or [])
+        self.config = config or {}
+
+    def load_options(self) -> list[str]:
+        """The components the issue accepts, from its editmeta when possible."""
+        jira = self._parent.jayrah_obj.jira
+        meta = jira.get_edit_meta(self.issue_key).get("components", {})
+        if allowed := models.allowed_values(meta):
+            return allowed
+        return jira.list_project_components(self.issue_key.split("-")[0])
+
+    def apply_selection(self, values: list[str]) -> None:
+        """Update the issue with the picked components."""
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.update_issue,
+            self.issue_key,
+            {"components": [{"name": x} for x in values]},
+            on_result=lambda _result: self._components_updated(values),
+            on_error=lambda exc: self._parent.notify(
+                f"Error updating components: {exc}", severity="error"
+            ),
+        )
+        self.safe_pop_screen()
+
+    def _components_updated(self, values: list[str]) -> None:
+        self._parent.undo_stack.push(
+            undo.Change(
+                self.issue_key,
+                f"components of {self.issue_key}",
+                {"components": [{"name": x} for x in self.current_components]},
             )
+        )
 
-            # Update the issue cache to reflect changes
-            detail_panel = self._parent.query_one(IssueDetailPanel)
-            if detail_panel.ticket == self.issue_key:
-                detail_panel.update_issue(
-                    self.issue_key, self._parent.config, use_cache=False
-                )
+        # Update the issue cache to reflect changes
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        if detail_panel.ticket == self.issue_key:
+            detail_panel.update_issue(
+                self.issue_key, self._parent.config, use_cache=False
+            )
+
+        components_text = ", ".join(values) if values else "No components"
+        self._parent.notify(f"✅ Components updated: {components_text}")
+
+
+class FixVersionsEditScreen(MultiSelectScreen):
+    """Modal screen for picking the fix versions of an issue."""
+
+    OPTIONS_ERROR = "Could not fetch versions"
+
+    def __init__(self, parent, issue_key: str, current_versions: list, config: dict):
+        super().__init__(parent, f"Edit Fix Versions for {issue_key}", current_versions)
+        self.issue_key = issue_key
+        self.current_versions = list(current_versions or [])
+        self.config = config or {}
+
+    def load_options(self) -> list[str]:
+        """The versions the issue accepts, from its editmeta when possible."""
+        jira = self._parent.jayrah_obj.jira
+        meta = jira.get_edit_meta(self.issue_key).get("fixVersions", {})
+        if allowed := models.allowed_values(meta):
+            return allowed
+        return jira.list_project_versions(self.issue_key.split("-")[0])
+
+    def apply_selection(self, values: list[str]) -> None:
+        """Add the newly picked versions and remove the unpicked ones."""
+        self._parent.worker_pool.submit(
+            self._update_fix_versions,
+            values,
+            on_result=lambda _result: self._fix_versions_updated(values),
+            on_error=lambda exc: self._parent.notify(
+                f"Error updating fix versions: {exc}", severity="error"
+            ),
+        )
+        self.safe_pop_screen()
+
+    def _update_fix_versions(self, values: list[str]) -> None:
+        """Worker method sending the added and the removed versions."""
+        jira = self._parent.jayrah_obj.jira
+        added = [x for x in values if x not in self.current_versions]
+        removed = [x for x in self.current_versions if x not in values]
+        if added:
+            jira.update_issue_fix_versions(self.issue_key, added, "add")
+        if removed:
+            jira.update_issue_fix_versions(self.issue_key, removed, "remove")
+
+    def _fix_versions_updated(self, values: list[str]) -> None:
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        if detail_panel.ticket == self.issue_key:
+            detail_panel.update_issue(
+                self.issue_key, self._parent.config, use_cache=False
+            )
+
+        versions_text = ", ".join(values) if values else "No fix versions"
+        self._parent.notify(f"✅ Fix versions updated: {versions_text}")
+
+
+class PriorityEditScreen(SingleSelectScreen):
+    """Modal screen for picking the priority of an issue."""
+
+    OPTIONS_ERROR = "Could not fetch priorities"
+
+    def __init__(self, parent, issue_key: str, current_priority: str, config: dict):
+        super().__init__(
+            parent,
+            f"Edit Priority for {issue_key}",
+            [current_priority] if current_priority else [],
+        )
+        self.issue_key = issue_key
+        self.config = config or {}
+
+    def load_options(self) -> list[str]:
+        """The priorities the issue accepts, from its editmeta when possible."""
+        jira = self._parent.jayrah_obj.jira
+        meta = jira.get_edit_meta(self.issue_key).get("priority", {})
+        return models.allowed_values(meta) or jira.list_priorities()
+
+    def apply_selection(self, values: list[str]) -> None:
+        """Update the issue with the picked priority."""
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.update_issue_priority,
+            self.issue_key,
+            values[0],
+            on_result=lambda _result: self._priority_updated(values[0]),
+            on_error=lambda exc: self._parent.notify(
+                f"Error updating priority: {exc}", severity="error"
+            ),
+        )
+        self.safe_pop_screen()
 
-            # Show success notification
-            components_text = (
-                ", ".join(new_components) if new_components else "No components"
+    def _priority_updated(self, priority: str) -> None:
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        if detail_panel.ticket == self.issue_key:
+            detail_panel.update_issue(
+                self.issue_key, self._parent.config, use_cache=False
             )
-            self._parent.notify(f"✅ Components updated: {components_text}")
+        self._parent.notify(f"✅ Priority updated: {priority}")
+
+
+class IssueTypeEditScreen(SingleSelectScreen):
+    """Modal screen for picking the type of an issue."""
 
-        except jira_exceptions.JiraAPIError as exc:
-            self._parent.notify(f"Error updating components: {exc}", severity="error")
+    OPTIONS_ERROR = "Could not fetch issue types"
 
+    def __init__(self, parent, issue_key: str, current_type: str, config: dict):
+        super().__init__(
+            parent,
+            f"Edit Issue Type for {issue_key}",
+            [current_type] if current_type else [],
+        )
+        self.issue_key = issue_key
+        self.config = config or {}
+
+    def load_options(self) -> list[str]:
+        """The types the issue can move to, from its editmeta when possible."""
+        jira = self._parent.jayrah_obj.jira
+        meta = jira.get_edit_meta(self.issue_key).get("issuetype", {})
+        return models.allowed_values(meta) or list(jira.get_issue_types())
+
+    def apply_selection(self, values: list[str]) -> None:
+        """Update the issue with the picked type."""
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.update_issue_type,
+            self.issue_key,
+            values[0],
+            on_result=lambda _result: self._issue_type_updated(values[0]),
+            on_error=lambda exc: self._parent.notify(
+                f"Error updating issue type: {exc}", severity="error"
+            ),
+        )
         self.safe_pop_screen()
 
+    def _issue_type_updated(self, issue_type: str) -> None:
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        if detail_panel.ticket == self.issue_key:
+            detail_panel.update_issue(
+                self.issue_key, self._parent.config, use_cache=False
+            )
+        self._parent.notify(f"✅ Issue type updated: {issue_type}")
+
 
 class FuzzyFilterScreen(BaseModalScreen):
     """Modal screen for fuzzy filtering issues."""
@@ -837,17 +1493,19 @@ class FuzzyFilterScreen(BaseModalScreen):
         self.safe_pop_screen()
 
 
-class BoardSelectionScreen(BaseModalScreen):
-    """Modal screen for selecting a different board."""
+class JqlQueryScreen(BaseModalScreen):
+    """Modal screen for browsing the issues of a JQL query."""
 
     BINDINGS: ClassVar[tuple[Binding, ...]] = (
         Binding("escape", "cancel", "Cancel"),
         Binding("enter", "apply", "Apply"),
+        Binding("up", "history_previous", "Previous query"),
+        Binding("down", "history_next", "Next query"),
         Binding("f1", "help", "Help"),
     )
 
     CSS = """
-    #board-container {
+    #query-container {
         dock: bottom;
         padding: 1;
         width: 100%;
@@ -856,68 +1514,88 @@ class BoardSelectionScreen(BaseModalScreen):
         border-top: thick $primary;
         margin: 0;
     }
-    
-    #board-title {
+
+    #query-title {
         text-align: center;
         text-style: bold;
         width: 100%;
         height: 1;
         content-align: center middle;
     }
-    
-    #board-table {
+
+    #query-text {
         width: 100%;
         margin: 0;
-        height: 10;
     }
-    
-    #board-help {
+
+    #query-help {
         text-align: center;
         color: $text-muted;
         margin-top: 0;
     }
     """
 
-    def __init__(self, parent, config):
+    HELP_TEXT = "Press Enter to run the query, Up/Down for history, Escape to cancel"
+
+    def __init__(self, parent, query: str = "", history: list[str] | None = None):
         super().__init__(parent)
-        self.config = config
-        self.selected_board = None
+        self.initial_query = query
+        self.history = history or []
+        # Past the last entry is the query being typed
+        self.history_index = len(self.history)
+        self.draft = query
 
     def compose(self) -> ComposeResult:
-        with Vertical(id="board-container"):
-            yield Label("Select Board", id="board-title")
-            table = DataTable(id="board-table")
-            table.cursor_type = "row"
-            table.add_columns("Name", "Description")
+        with Vertical(id="query-container"):
+            yield Label("JQL query", id="query-title")
+            yield EmacsInput(
+                placeholder="project = PROJ AND status != Done",
+                id="query-text",
+                value=self.initial_query,
+            )
+            yield Label(self.HELP_TEXT, id="query-help")
 
-            # Populate the table with available boards
-            boards = self.config.get("boards", [])
-            for board in boards:
-                name = board.get("name", "")
-                description = board.get("description", "No description")
-                table.add_row(name, description, key=name)
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Handle when user presses Enter in the input field."""
+        self.action_apply()
 
-            yield table
-            yield Label("Press Enter to select, Escape to cancel", id="board-help")
+    def _show_history_entry(self, index: int) -> None:
+        """Replace the input with a history entry, or the draft past the last one."""
+        query_input = self.query_one("#query-text", EmacsInput)
+        if self.history_index == len(self.history):
+            self.draft = query_input.value
+        self.history_index = index
+        if index == len(self.history):
+            query_input.value = self.draft
+        else:
+            query_input.value = self.history[index]
+        query_input.cursor_position = len(query_input.value)
+        self.query_one("#query-help", Label).update(self.HELP_TEXT)
 
-    def on_data_table_row_selected(self, event):
-        """Handle board selection."""
-        self.selected_board = (
-            event.row_key.value
-            if hasattr(event.row_key, "value")
-            else str(event.row_key)
-        )
-        self.action_apply()
+    def action_history_previous(self) -> None:
+        """Go back to the previous query of the history."""
+        if self.history_index > 0:
+            self._show_history_entry(self.history_index - 1)
+
+    def action_history_next(self) -> None:
+        """Go forward in the history, back to the draft after the last query."""
+        if self.history_index < len(self.history):
+            self._show_history_entry(self.history_index + 1)
 
     def action_apply(self) -> None:
-        """Apply the board selection."""
-        if self.selected_board:
-            self._parent.change_board(self.selected_board)
+        """Run the query once it parses, syntax errors are shown in place."""
+        query = self.query_one("#query-text", EmacsInput).value.strip()
+        try:
+            jql.validate(query)
+        except jql.JqlSyntaxError as e:
+            self.query_one("#query-help", Label).update(f"❌ {e}")
+            return
         self.safe_pop_screen()
+        self._parent.run_query(query)
 
 
-class TransitionSelectionScreen(BaseModalScreen):
-    """Modal screen for selecting a transition to apply to an issue."""
+class SaveBoardScreen(BaseModalScreen):
+    """Modal screen for saving the current query as a board of the config."""
 
     BINDINGS: ClassVar[tuple[Binding, ...]] = (
         Binding("escape", "cancel", "Cancel"),
@@ -926,7 +1604,7 @@ class TransitionSelectionScreen(BaseModalScreen):
     )
 
     CSS = """
-    #transition-container {
+    #save-board-container {
         dock: bottom;
         padding: 1;
         width: 100%;
@@ -935,150 +1613,135 @@ class TransitionSelectionScreen(BaseModalScreen):
         border-top: thick $primary;
         margin: 0;
     }
-    
-    #transition-title {
+
+    #save-board-title {
         text-align: center;
         text-style: bold;
         width: 100%;
         height: 1;
         content-align: center middle;
     }
-    
-    #transition-table {
+
+    #save-board-name {
         width: 100%;
         margin: 0;
-        height: 10;
     }
-    
-    #transition-help {
+
+    #save-board-help {
         text-align: center;
         color: $text-muted;
         margin-top: 0;
     }
     """
 
-    def __init__(self, parent, issue_key: str, config: dict):
+    def __init__(self, parent, query: str, name: str = ""):
         super().__init__(parent)
-        self.config = config
-        self.issue_key = issue_key
-        self.selected_transition_id = None
+        self.jql_query = query
+        self.board_name = name
 
     def compose(self) -> ComposeResult:
-        with Vertical(id="transition-container"):
-            yield Label(
-                f"Select Transition for {self.issue_key}", id="transition-title"
+        with Vertical(id="save-board-container"):
+            yield Label(f"Save as board: {self.jql_query}", id="save-board-title")
+            yield EmacsInput(
+                placeholder="Board name", id="save-board-name", value=self.board_name
             )
-            table = DataTable(id="transition-table")
-            table.cursor_type = "row"
-            table.add_columns("ID", "Name", "To Status", "Description")
-
-            # Get available transitions for the issue
-            try:
-                transitions_data = self._parent.jayrah_obj.jira.get_transitions(
-                    self.issue_key
-                )
-                transitions = transitions_data.get("transitions", [])
-
-                if not transitions:
-                    table.add_row("", "No transitions available", "", "", key="none")
-                else:
-                    for transition in transitions:
-                        transition_id = transition["id"]
-                        name = transition["name"]
-                        to_status = transition["to"]["name"]
-                        description = transition["to"].get(
-                            "description", "No description"
-                        )
-
-                        table.add_row(
-                            transition_id,
-                            name,
-                            to_status,
-                            description,
-                            key=transition_id,
-                        )
-
-            except jira_exceptions.JiraAPIError as exc:
-                table.add_row(
-                    "", f"Error loading transitions: {exc}", "", "", key="error"
-                )
-
-            yield table
             yield Label(
-                "Press Enter to apply transition, Escape to cancel",
-                id="transition-help",
+                "Press Enter to save the board, an existing board with the same "
+                "name is replaced. Escape to cancel",
+                id="save-board-help",
             )
 
-    def on_data_table_row_selected(self, event):
-        """Handle transition selection."""
-        self.selected_transition_id = (
-            event.row_key.value
-            if hasattr(event.row_key, "value")
-            else str(event.row_key)
-        )
-
-        # Don't apply if no valid transition selected
-        if self.selected_transition_id in ["none", "error"]:
-            return
-
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Handle when user presses Enter in the input field."""
         self.action_apply()
 
     def action_apply(self) -> None:
-        """Apply the selected transition."""
-        if not self.selected_transition_id or self.selected_transition_id in [
-            "none",
-            "error",
-        ]:
-            self._parent.notify("No valid transition selected", severity="warning")
-            self.safe_pop_screen()
+        """Save the board under the name typed."""
+        name = self.query_one("#save-board-name", EmacsInput).value.strip()
+        if not name:
+            self.query_one("#save-board-help", Label).update("❌ A name is required")
             return
+        self.safe_pop_screen()
+        self._parent.save_board(name, self.jql_query)
 
-        try:
-            # Get the transition name for display
-            transitions_data = self._parent.jayrah_obj.jira.get_transitions(
-                self.issue_key
-            )
-            transitions = transitions_data.get("transitions", [])
-            selected_transition = next(
-                (t for t in transitions if t["id"] == self.selected_transition_id), None
-            )
 
-            if not selected_transition:
-                self._parent.notify("Invalid transition selected", severity="error")
-                self.safe_pop_screen()
-                return
+class ExportScreen(BaseModalScreen):
+    """Modal screen for exporting the visible issues to a CSV or Markdown file."""
 
-            transition_name = selected_transition["name"]
-            to_status = selected_transition["to"]["name"]
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Apply"),
+        Binding("f1", "help", "Help"),
+    )
 
-            # Apply the transition
-            self._parent.jayrah_obj.jira.transition_issue(
-                self.issue_key, self.selected_transition_id
-            )
+    CSS = """
+    #export-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
 
-            # Update the issue cache to reflect changes
-            detail_panel = self._parent.query_one(IssueDetailPanel)
-            if detail_panel.ticket == self.issue_key:
-                detail_panel.update_issue(
-                    self.issue_key, self._parent.config, use_cache=False
-                )
+    #export-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
 
-            # Reload the issues table to show updated status
-            self._parent.action_reload()
+    #export-path {
+        width: 100%;
+        margin: 0;
+    }
 
-            # Show success notification
-            self._parent.notify(
-                f"✅ Issue {self.issue_key} transitioned to '{to_status}' via '{transition_name}'"
+    #export-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, path: str, count: int):
+        super().__init__(parent)
+        self.path = path
+        self.count = count
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="export-container"):
+            yield Label(f"Export {self.count} issues", id="export-title")
+            yield EmacsInput(placeholder="File path", id="export-path", value=self.path)
+            yield Label(
+                "Press Enter to export, a .csv file gets CSV and a .md file a "
+                "Markdown table. Escape to cancel",
+                id="export-help",
             )
 
-        except jira_exceptions.JiraAPIError as exc:
-            self._parent.notify(f"Error applying transition: {exc}", severity="error")
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Handle when user presses Enter in the input field."""
+        self.action_apply()
 
+    def action_apply(self) -> None:
+        """Export to the path typed."""
+        path = self.query_one("#export-path", EmacsInput).value.strip()
+        if not path.lower().endswith((".csv", ".md", ".markdown")):
+            self.query_one("#export-help", Label).update(
+                "❌ The file should end with .csv or .md"
+            )
+            return
         self.safe_pop_screen()
+        self._parent.export_issues(path)
 
 
-class EditSelectionScreen(BaseModalScreen):
-    """Modal screen for selecting what to edit (title or description)."""
+# Row key of the pinned issues in the boards popup, not a board of the config
+PINNED_BOARD_ROW = "pinned:"
+
+
+class BoardSelectionScreen(BaseModalScreen):
+    """Modal screen for selecting a different board."""
 
     BINDINGS: ClassVar[tuple[Binding, ...]] = (
         Binding("escape", "cancel", "Cancel"),
@@ -1087,7 +1750,7 @@ class EditSelectionScreen(BaseModalScreen):
     )
 
     CSS = """
-    #edit-container {
+    #board-container {
         dock: bottom;
         padding: 1;
         width: 100%;
@@ -1097,7 +1760,7 @@ class EditSelectionScreen(BaseModalScreen):
         margin: 0;
     }
     
-    #edit-title {
+    #board-title {
         text-align: center;
         text-style: bold;
         width: 100%;
@@ -1105,54 +1768,51 @@ class EditSelectionScreen(BaseModalScreen):
         content-align: center middle;
     }
     
-    #edit-table {
+    #board-table {
         width: 100%;
         margin: 0;
-        height: 5;
+        height: 10;
     }
     
-    #edit-help {
+    #board-help {
         text-align: center;
         color: $text-muted;
         margin-top: 0;
     }
     """
 
-    def __init__(self, parent, issue_key: str, config: dict):
+    def __init__(self, parent, config):
         super().__init__(parent)
         self.config = config
-        self.issue_key = issue_key
-        self.selected_edit_type = None
-        self.verbose = self.config.get("verbose", False)
+        self.selected_board = None
 
     def compose(self) -> ComposeResult:
-        with Vertical(id="edit-container"):
-            yield Label(f"Edit Issue {self.issue_key}", id="edit-title")
-            table = DataTable(id="edit-table")
+        with Vertical(id="board-container"):
+            yield Label("Select Board", id="board-title")
+            table = DataTable(id="board-table")
             table.cursor_type = "row"
-            table.add_columns("Action", "Description")
-
-            # Add edit options
-            table.add_row("title", "Edit issue title/summary", key="title")
-            table.add_row("description", "Edit issue description", key="description")
+            table.add_columns("Name", "Description")
 
-            # Add custom field edit options from config
-            custom_fields = self.config.get("custom_fields", [])
-            for cf in custom_fields:
-                name = cf.get("name")
-                field = cf.get("field")
-                description = cf.get("description", f"Update custom field {field}")
-                if name and field:
-                    table.add_row(name, description, key=f"customfield:{field}")
+            # Populate the table with available boards
+            boards = self.config.get("boards", [])
+            for board in boards:
+                name = board.get("name", "")
+                description = board.get("description", "No description")
+                table.add_row(name, description, key=name)
+            pinned = self._parent.jayrah_obj.state_store.pinned_issues()
+            if pinned:
+                table.add_row(
+                    "📌 Pinned",
+                    f"{len(pinned)} pinned issues from any board",
+                    key=PINNED_BOARD_ROW,
+                )
 
             yield table
-            yield Label(
-                "Press Enter to select edit type, Escape to cancel", id="edit-help"
-            )
+            yield Label("Press Enter to select, Escape to cancel", id="board-help")
 
     def on_data_table_row_selected(self, event):
-        """Handle edit type selection."""
-        self.selected_edit_type = (
+        """Handle board selection."""
+        self.selected_board = (
             event.row_key.value
             if hasattr(event.row_key, "value")
             else str(event.row_key)
@@ -1160,83 +1820,29 @@ class EditSelectionScreen(BaseModalScreen):
         self.action_apply()
 
     def action_apply(self) -> None:
-        """Apply the edit selection."""
-        if not self.selected_edit_type:
-            self._parent.notify("No edit type selected", severity="warning")
-            self.safe_pop_screen()
-            return
-
-        try:
-            if self.selected_edit_type == "title":
-                # Get current issue data for title editing
-                issue_data = self._parent.jayrah_obj.jira.get_issue(self.issue_key)
-                current_title = issue_data.get("fields", {}).get("summary", "")
-
-                # Close this selection screen first
-                self.safe_pop_screen()
-
-                # Show the title edit screen
-                self._parent.push_screen(
-                    TitleEditScreen(
-                        self._parent,
-                        self.issue_key,
-                        current_title,
-                        self.config,
-                    )
-                )
-            elif self.selected_edit_type == "description":
-                # Get current issue data for description editing
-                issue_data = self._parent.jayrah_obj.jira.get_issue(self.issue_key)
-                current_description = issue_data.get("fields", {}).get(
-                    "description", ""
-                )
-                # Close this selection screen first
-                self.safe_pop_screen()
-
-                # Show the description edit screen
-                self._parent.push_screen(
-                    DescriptionEditScreen(
-                        self._parent,
-                        self.issue_key,
-                        current_description,
-                        self.config,
-                    )
-                )
-            elif self.selected_edit_type.startswith("customfield:"):
-                field_id = self.selected_edit_type.split(":", 1)[1]
-                # Get current value for the custom field
-                issue_data = self._parent.jayrah_obj.jira.get_issue(self.issue_key)
-                current_value = issue_data.get("fields", {}).get(field_id, "")
-                self.safe_pop_screen()
-                self._parent.push_screen(
-                    CustomFieldEditScreen(
-                        self._parent,
-                        self.issue_key,
-                        field_id,
-                        current_value,
-                        self.config,
-                    )
-                )
-        except jira_exceptions.JiraAPIError as exc:
-            if self.verbose:
-                raise
-            self._parent.notify(
-                f"Error starting edit please try again. {exc} ", severity="error"
-            )
-            self.safe_pop_screen()
+        """Apply the board selection."""
+        if self.selected_board == PINNED_BOARD_ROW:
+            self._parent.show_pinned_issues()
+        elif self.selected_board:
+            self._parent.change_board(self.selected_board)
+        self.safe_pop_screen()
 
 
-class TitleEditScreen(BaseModalScreen):
-    """Modal screen for editing issue title/summary."""
+class YankScreen(BaseModalScreen):
+    """Modal menu of what to copy from the selected issue, yy copies the key."""
 
     BINDINGS: ClassVar[tuple[Binding, ...]] = (
         Binding("escape", "cancel", "Cancel"),
         Binding("enter", "apply", "Apply"),
+        Binding("y", "select('copy_key')", "Key"),
+        Binding("Y", "select('copy_url')", "URL"),
+        Binding("s", "select('copy_summary')", "Summary"),
+        Binding("b", "select('copy_branch')", "Branch"),
         Binding("f1", "help", "Help"),
     )
 
     CSS = """
-    #title-container {
+    #yank-container {
         dock: bottom;
         padding: 1;
         width: 100%;
@@ -1245,96 +1851,3328 @@ class TitleEditScreen(BaseModalScreen):
         border-top: thick $primary;
         margin: 0;
     }
-    
-    #title-title {
+
+    #yank-title {
         text-align: center;
         text-style: bold;
         width: 100%;
         height: 1;
         content-align: center middle;
     }
-    
-    #title-current {
+
+    #yank-table {
         width: 100%;
-        margin: 0 0 1 0;
+        margin: 0;
+        height: 5;
+    }
+
+    #yank-help {
         text-align: center;
         color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="yank-container"):
+            yield Label(f"Copy from {self._parent.selected_issue}", id="yank-title")
+            table = DataTable(id="yank-table")
+            table.cursor_type = "row"
+            table.add_columns("Key", "Copy")
+            table.add_row("y", "Issue key", key="copy_key")
+            table.add_row("Y", "Issue URL", key="copy_url")
+            table.add_row("s", "Summary", key="copy_summary")
+            table.add_row("b", "Git branch name", key="copy_branch")
+            yield table
+            yield Label(
+                "Press a key or Enter to copy, Escape to cancel", id="yank-help"
+            )
+
+    def on_mount(self) -> None:
+        self.query_one("#yank-table", DataTable).focus()
+
+    def on_data_table_row_selected(self, event) -> None:
+        self.action_select(event.row_key.value)
+
+    def action_apply(self) -> None:
+        table = self.query_one("#yank-table", DataTable)
+        self.action_select(table.ordered_rows[table.cursor_row].key.value)
+
+    def action_select(self, action: str) -> None:
+        """Close the menu and copy what was picked."""
+        self.safe_pop_screen()
+        getattr(self._parent, f"action_{action}")()
+
+
+class ProjectsScreen(BaseModalScreen):
+    """Modal screen listing the projects to browse their unresolved issues."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Apply"),
+        Binding("s", "save", "Save as board"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #projects-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #projects-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #projects-table {
+        width: 100%;
+        margin: 0;
+        height: 12;
+    }
+
+    #projects-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, config):
+        super().__init__(parent)
+        self.config = config
+        self.projects: list[dict] = []
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="projects-container"):
+            yield Label("Projects", id="projects-title")
+            table = DataTable(id="projects-table")
+            table.cursor_type = "row"
+            table.add_columns("Key", "Name")
+            yield table
+            yield Label(
+                "Press Enter to browse the unresolved issues, s to save them as a "
+                "board, Escape to cancel",
+                id="projects-help",
+            )
+
+    def on_mount(self) -> None:
+        table = self.query_one("#projects-table", DataTable)
+        table.loading = True
+        table.focus()
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.list_projects,
+            on_result=self.show_projects,
+            on_error=self._projects_failed,
+            is_stale=lambda: self._popped,
+        )
+
+    def show_projects(self, projects: list[dict]) -> None:
+        if self._popped:
+            return
+        self.projects = projects
+        table = self.query_one("#projects-table", DataTable)
+        for project in self.projects:
+            table.add_row(project.get("key", ""), project.get("name", ""))
+        table.loading = False
+
+    def _projects_failed(self, exc: Exception) -> None:
+        self._parent.notify(f"Could not fetch projects: {exc}", severity="error")
+        if not self._popped:
+            self.query_one("#projects-table", DataTable).loading = False
+
+    def selected_jql(self) -> tuple[str, str] | None:
+        """The key of the project under the cursor and the JQL of its board."""
+        if not self.projects:
+            return None
+        table = self.query_one("#projects-table", DataTable)
+        key = self.projects[table.cursor_row].get("key", "")
+        return key, defaults.PROJECT_BOARD_JQL.format(key=key)
+
+    def on_data_table_row_selected(self, event) -> None:
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Show the unresolved issues of the project."""
+        selected = self.selected_jql()
+        self.safe_pop_screen()
+        if selected:
+            self._parent.run_query(selected[1])
+
+    def action_save(self) -> None:
+        """Show the unresolved issues of the project and offer to save them."""
+        selected = self.selected_jql()
+        self.safe_pop_screen()
+        if selected:
+            key, query = selected
+            self._parent.run_query(query)
+            self._parent.push_screen(
+                SaveBoardScreen(self._parent, query, name=key.lower())
+            )
+
+
+class ProfileSelectionScreen(BaseModalScreen):
+    """Modal screen for switching to another profile of the config."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Apply"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #profile-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #profile-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #profile-table {
+        width: 100%;
+        margin: 0;
+        height: 10;
+    }
+
+    #profile-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    # Row key of the config without any profile applied
+    DEFAULT_PROFILE = "default"
+
+    def __init__(self, parent, config):
+        super().__init__(parent)
+        self.config = config
+        self.selected_profile = None
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="profile-container"):
+            yield Label("Select Profile", id="profile-title")
+            table = DataTable(id="profile-table")
+            table.cursor_type = "row"
+            table.add_columns("", "Name", "Server")
+
+            base = self.config.get("base_config", self.config)
+            current = self.config.get("profile") or self.DEFAULT_PROFILE
+            profiles = {
+                self.DEFAULT_PROFILE: base,
+                **(base.get("profiles") or {}),
+            }
+            for name, options in profiles.items():
+                table.add_row(
+                    "●" if name == current else "",
+                    name,
+                    (options or {}).get("jira_server", base.get("jira_server", "")),
+                    key=name,
+                )
+
+            yield table
+            yield Label("Press Enter to select, Escape to cancel", id="profile-help")
+
+    def on_data_table_row_selected(self, event):
+        """Handle profile selection."""
+        self.selected_profile = (
+            event.row_key.value
+            if hasattr(event.row_key, "value")
+            else str(event.row_key)
+        )
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Apply the profile selection."""
+        if self.selected_profile:
+            name = self.selected_profile
+            self._parent.change_profile(None if name == self.DEFAULT_PROFILE else name)
+        self.safe_pop_screen()
+
+
+class SprintSelectionScreen(BaseModalScreen):
+    """Modal screen for switching to a sprint of the project agile boards."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Apply"),
+        Binding("m", "move_issue", "Move issue"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #sprint-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #sprint-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #sprint-table {
+        width: 100%;
+        margin: 0;
+        height: 10;
+    }
+
+    #sprint-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, config):
+        super().__init__(parent)
+        self.config = config
+        self.sprints: dict[str, str] = {}
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="sprint-container"):
+            yield Label("Select Sprint", id="sprint-title")
+            table = DataTable(id="sprint-table")
+            table.cursor_type = "row"
+            table.add_columns("Name", "State", "Board")
+            yield table
+            yield Label(
+                "Press Enter to show the sprint issues, m to move the selected "
+                "issue to the sprint, Escape to cancel",
+                id="sprint-help",
+            )
+
+    def on_mount(self) -> None:
+        """Load the active and future sprints of the project scrum boards."""
+        table = self.query_one("#sprint-table", DataTable)
+        table.loading = True
+        table.focus()
+        self._parent.worker_pool.submit(
+            self._load_sprints,
+            on_result=self.show_sprints,
+            on_error=self._sprints_failed,
+            is_stale=lambda: self._popped,
+        )
+
+    def _load_sprints(self) -> list[tuple[str, dict, str]]:
+        """Worker method listing the sprints with the name of their board."""
+        agile = self._parent.jayrah_obj.jira.agile
+        boards = agile.list_boards(
+            project_key=self.config.get("jira_project"), board_type="scrum"
+        )
+        return [
+            (str(sprint["id"]), sprint, board.get("name", ""))
+            for board in boards
+            for sprint in agile.get_board_sprints(board["id"])
+        ]
+
+    def show_sprints(self, sprints: list[tuple[str, dict, str]]) -> None:
+        if self._popped:
+            return
+        table = self.query_one("#sprint-table", DataTable)
+        for sprint_id, sprint, board_name in sprints:
+            # Sprints can be shared between boards
+            if sprint_id in self.sprints:
+                continue
+            self.sprints[sprint_id] = sprint.get("name", sprint_id)
+            table.add_row(
+                self.sprints[sprint_id],
+                sprint.get("state", ""),
+                board_name,
+                key=sprint_id,
+            )
+        table.loading = False
+        if not self.sprints:
+            self._parent.notify("No active or future sprints found", severity="warning")
+
+    def _sprints_failed(self, exc: Exception) -> None:
+        self._parent.notify(f"Error loading sprints: {exc}", severity="error")
+        self.show_sprints([])
+
+    def _selected_sprint(self) -> str | None:
+        """Return the id of the sprint under the cursor."""
+        table = self.query_one("#sprint-table", DataTable)
+        if not self.sprints or table.cursor_row is None:
+            return None
+        row_key = table.coordinate_to_cell_key((table.cursor_row, 0)).row_key
+        return row_key.value
+
+    def on_data_table_row_selected(self, event):
+        """Handle sprint selection."""
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Show the issues of the selected sprint."""
+        sprint_id = self._selected_sprint()
+        if sprint_id:
+            self._parent.change_sprint(sprint_id, self.sprints[sprint_id])
+        self.safe_pop_screen()
+
+    def action_move_issue(self) -> None:
+        """Move the issue selected in the browser to the highlighted sprint."""
+        sprint_id = self._selected_sprint()
+        issue_key = self._parent.selected_issue
+        if not sprint_id or not issue_key:
+            self._parent.notify("No issue or sprint selected", severity="warning")
+            return
+
+        sprint_name = self.sprints[sprint_id]
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.agile.move_issues_to_sprint,
+            int(sprint_id),
+            [issue_key],
+            on_result=lambda _result: self._parent.notify(
+                f"✅ Moved {issue_key} to {sprint_name}"
+            ),
+            on_error=lambda exc: self._parent.notify(
+                f"Error moving {issue_key}: {exc}", severity="error"
+            ),
+        )
+        self.safe_pop_screen()
+
+
+class TransitionSelectionScreen(BaseModalScreen):
+    """Modal screen for selecting a transition to apply to an issue."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Apply"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #transition-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+    
+    #transition-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+    
+    #transition-table {
+        width: 100%;
+        margin: 0;
+        height: 10;
+    }
+    
+    #transition-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str, config: dict):
+        super().__init__(parent)
+        self.config = config
+        self.issue_key = issue_key
+        self.selected_transition_id = None
+        self.transitions: list = []
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="transition-container"):
+            yield Label(
+                f"Select Transition for {self.issue_key}", id="transition-title"
+            )
+            table = DataTable(id="transition-table")
+            table.cursor_type = "row"
+            table.add_columns("ID", "Name", "To Status", "Description")
+            yield table
+            yield Label(
+                "Press Enter to apply transition, Escape to cancel",
+                id="transition-help",
+            )
+
+    def on_mount(self) -> None:
+        """Get transitions, preferring the ones prefetched by the detail panel."""
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        transitions_data = detail_panel.transitions_cache.get(self.issue_key)
+        if transitions_data is not None:
+            self.show_transitions(transitions_data)
+            return
+        self.query_one("#transition-table", DataTable).loading = True
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_transitions,
+            self.issue_key,
+            expand_fields=True,
+            on_result=self.show_transitions,
+            on_error=self._transitions_failed,
+            is_stale=lambda: self._popped,
+        )
+
+    def show_transitions(self, transitions_data: dict) -> None:
+        if self._popped:
+            return
+        table = self.query_one("#transition-table", DataTable)
+        table.loading = False
+        self.transitions = transitions = transitions_data.get("transitions", [])
+        if not transitions:
+            table.add_row("", "No transitions available", "", "", key="none")
+            return
+        for transition in transitions:
+            transition_id = transition["id"]
+            name = transition["name"]
+            to_status = transition["to"]["name"]
+            description = transition["to"].get("description", "No description")
+
+            table.add_row(
+                transition_id,
+                name,
+                to_status,
+                description,
+                key=transition_id,
+            )
+
+    def _transitions_failed(self, exc: Exception) -> None:
+        if self._popped:
+            return
+        table = self.query_one("#transition-table", DataTable)
+        table.loading = False
+        table.add_row("", f"Error loading transitions: {exc}", "", "", key="error")
+
+    def on_data_table_row_selected(self, event):
+        """Handle transition selection."""
+        self.selected_transition_id = (
+            event.row_key.value
+            if hasattr(event.row_key, "value")
+            else str(event.row_key)
+        )
+
+        # Don't apply if no valid transition selected
+        if self.selected_transition_id in ["none", "error"]:
+            return
+
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Apply the selected transition."""
+        if not self.selected_transition_id or self.selected_transition_id in [
+            "none",
+            "error",
+        ]:
+            self._parent.notify("No valid transition selected", severity="warning")
+            self.safe_pop_screen()
+            return
+
+        # Get the transition name for display
+        selected_transition = next(
+            (t for t in self.transitions if t["id"] == self.selected_transition_id),
+            None,
+        )
+
+        if not selected_transition:
+            self._parent.notify("Invalid transition selected", severity="error")
+            self.safe_pop_screen()
+            return
+
+        self.safe_pop_screen()
+        # Some workflows want a resolution or other fields on the way
+        if "fields" in selected_transition:
+            self._apply_transition(selected_transition, None)
+            return
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_transitions,
+            self.issue_key,
+            expand_fields=True,
+            on_result=lambda data: self._apply_transition(selected_transition, data),
+            on_error=lambda exc: self._parent.notify(
+                f"Error applying transition: {exc}", severity="error"
+            ),
+        )
+
+    def _apply_transition(self, transition: dict, transitions_data) -> None:
+        """Apply a transition, asking for its fields first when it has some."""
+        if transitions_data is not None:
+            transition = next(
+                (
+                    t
+                    for t in transitions_data.get("transitions", [])
+                    if t["id"] == transition["id"]
+                ),
+                transition,
+            )
+        if models.required_transition_fields(transition):
+            self._parent.push_screen(
+                TransitionFieldsScreen(self._parent, self.issue_key, transition)
+            )
+            return
+
+        apply_transition(self._parent, self.issue_key, transition)
+
+
+def apply_transition(
+    parent, issue_key: str, transition: dict, transition_fields=None, on_done=None
+) -> None:
+    """
+    Move an issue through a transition and refresh the browser.
+
+    The transition is sent on the worker pool, `on_done` is called once it
+    went through and errors are notified.
+    """
+    # The status left, before the reloads bring the new one
+    from_status = current_status(parent, parent.query_one(IssueDetailPanel), issue_key)
+    parent.worker_pool.submit(
+        parent.jayrah_obj.jira.transition_issue,
+        issue_key,
+        transition["id"],
+        transition_fields,
+        on_result=lambda _result: _transition_applied(
+            parent, issue_key, transition, from_status, on_done
+        ),
+        on_error=lambda exc: parent.notify(
+            f"Error applying transition: {exc}", severity="error"
+        ),
+    )
+
+
+def _transition_applied(
+    parent, issue_key: str, transition: dict, from_status: str | None, on_done
+) -> None:
+    transition_name = transition["name"]
+    to_status = transition["to"]["name"]
+    detail_panel = parent.query_one(IssueDetailPanel)
+
+    if from_status and from_status != to_status:
+        parent.undo_stack.push(
+            undo.Change(issue_key, f"transition of {issue_key}", status=from_status)
+        )
+
+    # Update the issue cache to reflect changes
+    if detail_panel.ticket == issue_key:
+        detail_panel.update_issue(issue_key, parent.config, use_cache=False)
+
+    # Reload the issues table to show updated status
+    parent.action_reload()
+
+    # Show success notification
+    parent.notify(
+        f"✅ Issue {issue_key} transitioned to '{to_status}' via '{transition_name}'"
+    )
+    if on_done is not None:
+        on_done()
+
+
+def current_status(parent, detail_panel, issue_key: str) -> str | None:
+    """The status of an issue as last fetched, None when it is not known."""
+    issue = detail_panel.issue_data_cache.get(issue_key) or next(
+        (x for x in parent.issues if x.get("key") == issue_key), None
+    )
+    if not issue:
+        return None
+    return ((issue.get("fields") or {}).get("status") or {}).get("name")
+
+
+class TransitionFieldsScreen(BaseModalScreen):
+    """Modal screen asking for the fields a transition requires."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("ctrl+s", "apply", "Transition"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #transition-fields-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #transition-fields-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    .transition-field-input {
+        width: 100%;
+        margin: 0;
+    }
+
+    #transition-fields-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str, transition: dict):
+        super().__init__(parent)
+        self.issue_key = issue_key
+        self.transition = transition
+        # The required fields, plus the comment when the screen has one
+        self.fields = models.required_transition_fields(transition)
+        screen_fields = transition.get("fields") or {}
+        if "comment" in screen_fields:
+            self.fields.setdefault("comment", screen_fields["comment"])
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="transition-fields-container"):
+            yield Label(
+                f"Move {self.issue_key} to '{self.transition['to']['name']}'",
+                id="transition-fields-title",
+            )
+            for index, (field_id, meta) in enumerate(self.fields.items()):
+                name = meta.get("name", field_id)
+                allowed = models.allowed_values(meta)
+                placeholder = f"{name} (required)" if meta.get("required") else name
+                suggester = None
+                if allowed:
+                    placeholder += f": {', '.join(allowed[:5])}"
+                    suggester = SuggestFromList(allowed, case_sensitive=False)
+                yield EmacsInput(
+                    placeholder=placeholder,
+                    id=f"transition-field-{index}",
+                    classes="transition-field-input",
+                    suggester=suggester,
+                )
+            yield Label(
+                "Press Enter to transition, Escape to cancel",
+                id="transition-fields-help",
+            )
+
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Transition when the user presses Enter in one of the inputs."""
+        self.action_apply()
+
+    def _field_value(self, meta: dict, text: str):
+        """The value Jira expects for a field, allowed values are objects."""
+        allowed = meta.get("allowedValues") or []
+        if not allowed:
+            return text
+        chosen = next(
+            value
+            for value, name in zip(allowed, models.allowed_values(meta))
+            if name == text
+        )
+        key = next(key for key in ("name", "value", "id") if key in chosen)
+        value = {key: chosen[key]}
+        return [value] if meta.get("schema", {}).get("type") == "array" else value
+
+    def action_apply(self) -> None:
+        """Validate the fields and apply the transition."""
+        transition_fields = models.TransitionFields()
+        for index, (field_id, meta) in enumerate(self.fields.items()):
+            field_input = self.query_one(f"#transition-field-{index}", EmacsInput)
+            text = field_input.value.strip()
+            name = meta.get("name", field_id)
+            allowed = models.allowed_values(meta)
+            if not text:
+                if meta.get("required"):
+                    self._parent.notify(f"{name} is required", severity="error")
+                    field_input.focus()
+                    return
+                continue
+            if allowed:
+                # Jira wants the exact name, the input is case insensitive
+                text = models.match_allowed_value(meta, text) or ""
+                if not text:
+                    self._parent.notify(
+                        f"{name} should be one of {', '.join(allowed)}",
+                        severity="error",
+                    )
+                    field_input.focus()
+                    return
+
+            if field_id == "resolution":
+                transition_fields.resolution = text
+            elif field_id == "comment":
+                transition_fields.comment = text
+            else:
+                transition_fields.fields[field_id] = self._field_value(meta, text)
+
+        # The popup stays open to try again when the transition fails
+        apply_transition(
+            self._parent,
+            self.issue_key,
+            self.transition,
+            transition_fields,
+            on_done=self.safe_pop_screen,
+        )
+
+
+class LinksViewScreen(BaseModalScreen):
+    """Modal screen for browsing the issues linked to an issue."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Jump"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #links-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #links-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #links-table {
+        width: 100%;
+        margin: 0;
+        height: 10;
+    }
+
+    #links-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str, config: dict):
+        super().__init__(parent)
+        self.issue_key = issue_key
+        self.config = config or {}
+        self.selected_key: str | None = None
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="links-container"):
+            yield Label(f"🔗 Links for {self.issue_key}", id="links-title")
+            table = DataTable(id="links-table")
+            table.cursor_type = "row"
+            table.add_columns("Relation", "Ticket", "Summary", "Status")
+            table.loading = True
+            yield table
+            yield Label(
+                "Press Enter to jump to the linked issue, Escape to cancel",
+                id="links-help",
+            )
+
+    def on_mount(self) -> None:
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_issue_links,
+            self.issue_key,
+            on_result=self.show_links,
+            on_error=lambda exc: self._show_row(f"Error loading links: {exc}", "error"),
+            is_stale=lambda: self._popped,
+        )
+
+    def show_links(self, issuelinks: list) -> None:
+        linked_issues = issue_view.get_linked_issues({"issuelinks": issuelinks})
+        if self._popped or not linked_issues:
+            self._show_row("No linked issues", "none")
+            return
+        table = self.query_one("#links-table", DataTable)
+        table.loading = False
+        for linked in linked_issues:
+            table.add_row(
+                linked["relation"],
+                linked["key"],
+                linked["summary"],
+                linked["status"],
+                key=linked["key"],
+            )
+
+    def _show_row(self, text: str, key: str) -> None:
+        if self._popped:
+            return
+        table = self.query_one("#links-table", DataTable)
+        table.loading = False
+        table.add_row("", text, "", "", key=key)
+
+    def on_data_table_row_selected(self, event):
+        """Handle linked issue selection."""
+        self.selected_key = (
+            event.row_key.value
+            if hasattr(event.row_key, "value")
+            else str(event.row_key)
+        )
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Jump to the selected linked issue."""
+        if not self.selected_key or self.selected_key in ["none", "error"]:
+            self.safe_pop_screen()
+            return
+
+        self.safe_pop_screen()
+        self._parent.jump_to_issue(self.selected_key)
+
+
+class FollowLinkScreen(BaseModalScreen):
+    """Modal screen with the issue keys and URLs of the details, to follow one."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #follow-link-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #follow-link-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #follow-link-table {
+        width: 100%;
+        margin: 0 0 1 0;
+        height: auto;
+        max-height: 15;
+    }
+
+    #follow-link-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str, links: list[tuple[str, str]]):
+        super().__init__(parent)
+        self.issue_key = issue_key
+        self.links = links
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="follow-link-container"):
+            yield Label(f"🔗 Links in {self.issue_key}", id="follow-link-title")
+            table = DataTable(id="follow-link-table")
+            table.cursor_type = "row"
+            table.add_columns("Link", "URL")
+            for index, (label, url) in enumerate(self.links):
+                table.add_row(label, url, key=str(index))
+            yield table
+            yield Label(
+                "Press Enter to open the link in your browser, Escape to close",
+                id="follow-link-help",
+            )
+
+    def on_mount(self) -> None:
+        self.query_one("#follow-link-table", DataTable).focus()
+
+    def on_data_table_row_selected(self, event) -> None:
+        """Open the selected link in the browser."""
+        _, url = self.links[event.cursor_row]
+        self._parent.open_url(url)
+        self._parent.notify(f"Opening {url} in browser")
+        self.safe_pop_screen()
+
+
+class ReferencesScreen(FollowLinkScreen):
+    """Modal screen with the issue keys the details refer to, to jump to one."""
+
+    def __init__(self, parent, issue_key: str, keys: list[str]):
+        super().__init__(parent, issue_key, [(key, key) for key in keys])
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="follow-link-container"):
+            yield Label(
+                f"🔎 Issues referenced by {self.issue_key}", id="follow-link-title"
+            )
+            table = DataTable(id="follow-link-table")
+            table.cursor_type = "row"
+            table.add_columns("Issue")
+            for key, _ in self.links:
+                table.add_row(key, key=key)
+            yield table
+            yield Label(
+                "Press Enter to jump to the issue, Ctrl+T to come back, "
+                "Escape to close",
+                id="follow-link-help",
+            )
+
+    def on_data_table_row_selected(self, event) -> None:
+        """Jump to the selected issue."""
+        key, _ = self.links[event.cursor_row]
+        self.safe_pop_screen()
+        self._parent.jump_to_reference(key)
+
+
+class RemoteLinksScreen(BaseModalScreen):
+    """Modal screen listing the web links of an issue and attaching new ones."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #remote-links-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #remote-links-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #remote-links-table {
+        width: 100%;
+        margin: 0 0 1 0;
+        height: 10;
+    }
+
+    #remote-link-url-input, #remote-link-title-input {
+        width: 100%;
+        margin: 0;
+    }
+
+    #remote-links-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str, config: dict):
+        super().__init__(parent)
+        self.issue_key = issue_key
+        self.config = config or {}
+        self.urls: dict[str, str] = {}
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="remote-links-container"):
+            yield Label(f"🌐 Web links of {self.issue_key}", id="remote-links-title")
+            table = DataTable(id="remote-links-table")
+            table.cursor_type = "row"
+            table.add_columns("Title", "URL", "Application")
+            yield table
+            yield EmacsInput(
+                placeholder="URL to attach (e.g. a pull request)",
+                id="remote-link-url-input",
+            )
+            yield EmacsInput(placeholder="Optional title", id="remote-link-title-input")
+            yield Label(
+                "Press Enter on a link to open it, in the inputs to attach the URL, "
+                "Escape to close",
+                id="remote-links-help",
+            )
+
+    def on_mount(self) -> None:
+        """Load the web links when the screen is mounted."""
+        self._load_links()
+        self.query_one("#remote-links-table", DataTable).focus()
+
+    def _load_links(self) -> None:
+        """Fetch the web links of the issue into the table."""
+        self.query_one("#remote-links-table", DataTable).loading = True
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_remote_links,
+            self.issue_key,
+            on_result=self.show_links,
+            on_error=self._links_failed,
+            is_stale=lambda: self._popped,
+        )
+
+    def _links_failed(self, exc: Exception) -> None:
+        self._parent.notify(f"Error loading web links: {exc}", severity="error")
+        if not self._popped:
+            self.query_one("#remote-links-table", DataTable).loading = False
+
+    def show_links(self, links: list) -> None:
+        if self._popped:
+            return
+        table = self.query_one("#remote-links-table", DataTable)
+        table.loading = False
+        table.clear()
+        self.urls = {}
+        if not links:
+            table.add_row("No web links yet", "", "", key="none")
+            return
+
+        for link in links:
+            link_object = link.get("object") or {}
+            url = link_object.get("url", "")
+            row_key = str(link.get("id"))
+            self.urls[row_key] = url
+            table.add_row(
+                link_object.get("title") or url,
+                url,
+                (link.get("application") or {}).get("name", ""),
+                key=row_key,
+            )
+
+    def on_data_table_row_selected(self, event):
+        """Open the selected web link in the browser."""
+        row_key = (
+            event.row_key.value
+            if hasattr(event.row_key, "value")
+            else str(event.row_key)
+        )
+        url = self.urls.get(row_key)
+        if not url:
+            return
+        try:
+            utils.browser_open(url, self._parent.config)
+        except (OSError, ValueError) as exc:
+            self._parent.notify(f"Error opening {url}: {exc}", severity="error")
+            return
+        self._parent.notify(f"Opening {url} in browser")
+
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Attach the URL when the user presses Enter in one of the inputs."""
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Attach the URL of the input to the issue."""
+        url_input = self.query_one("#remote-link-url-input", EmacsInput)
+        title_input = self.query_one("#remote-link-title-input", EmacsInput)
+        url = url_input.value.strip()
+
+        if not re.match(r"^https?://\S+$", url):
+            self._parent.notify(
+                "The URL should start with http:// or https://", severity="error"
+            )
+            url_input.focus()
+            return
+
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.add_remote_link,
+            self.issue_key,
+            url,
+            title_input.value.strip() or None,
+            on_result=lambda _result: self._link_attached(url),
+            on_error=lambda exc: self._parent.notify(
+                f"Error attaching the link: {exc}", severity="error"
+            ),
+        )
+
+    def _link_attached(self, url: str) -> None:
+        self._parent.notify(f"✅ Attached {url} to {self.issue_key}")
+        if self._popped:
+            return
+        self.query_one("#remote-link-url-input", EmacsInput).value = ""
+        self.query_one("#remote-link-title-input", EmacsInput).value = ""
+        self._load_links()
+
+
+class AttachmentsScreen(BaseModalScreen):
+    """Modal screen with the attachments of an issue, previewing the images."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "preview", "Preview"),
+        Binding("o", "open", "Open"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #attachments-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: 80%;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #attachments-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #attachments-table {
+        width: 100%;
+        margin: 0;
+        height: auto;
+        max-height: 8;
+    }
+
+    #attachment-preview {
+        width: 100%;
+        height: 1fr;
+        margin: 1 0 0 0;
+    }
+
+    #attachments-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str, config: dict):
+        super().__init__(parent)
+        self.issue_key = issue_key
+        self.config = config or {}
+        self.attachments: list[models.Attachment] = []
+        # The attachment shown, a preview landing after a move is dropped
+        self.previewed: str | None = None
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="attachments-container"):
+            yield Label(f"📎 Attachments of {self.issue_key}", id="attachments-title")
+            table = DataTable(id="attachments-table")
+            table.cursor_type = "row"
+            table.add_columns("Name", "Type", "Size", "Author", "Created")
+            table.loading = True
+            yield table
+            yield Vertical(id="attachment-preview")
+            yield Label(
+                "Press Enter to preview an image, o to open it, Escape to cancel",
+                id="attachments-help",
+            )
+
+    def on_mount(self) -> None:
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_issue_attachments,
+            self.issue_key,
+            on_result=self.show_attachments,
+            on_error=self._attachments_failed,
+            is_stale=lambda: self._popped,
+        )
+
+    def show_attachments(self, attachments: list[models.Attachment]) -> None:
+        if self._popped:
+            return
+        self.attachments = attachments
+        table = self.query_one("#attachments-table", DataTable)
+        table.loading = False
+        if not self.attachments:
+            table.add_row("No attachments", "", "", "", "", key="none")
+        for attachment in self.attachments:
+            table.add_row(
+                attachment.filename,
+                attachment.mime_type,
+                attachment.human_size,
+                attachment.author or "",
+                (attachment.created or "")[:10],
+                key=attachment.id,
+            )
+
+    def _attachments_failed(self, exc: Exception) -> None:
+        if self._popped:
+            return
+        table = self.query_one("#attachments-table", DataTable)
+        table.loading = False
+        table.add_row(f"Error loading attachments: {exc}", "", "", "", "")
+
+    def _selected(self) -> models.Attachment | None:
+        if not self.attachments:
+            return None
+        table = self.query_one("#attachments-table", DataTable)
+        return self.attachments[table.cursor_row]
+
+    def on_data_table_row_selected(self, event) -> None:
+        self.action_preview()
+
+    def action_open(self) -> None:
+        """Open the attachment in the browser."""
+        if not (attachment := self._selected()):
+            return
+        try:
+            utils.browser_open(attachment.content, self.config)
+        except (OSError, ValueError) as exc:
+            self.notify(f"Error opening {attachment.filename}: {exc}", severity="error")
+
+    def action_preview(self) -> None:
+        """Draw the image under the cursor, or describe the file."""
+        attachment = self._selected()
+        if attachment is None:
+            return
+        self.previewed = attachment.id
+        if not attachment.is_image or not images.enabled(self.config):
+            self.show_preview(attachment, None)
+            return
+        self.show_message(f"🔄 Downloading {attachment.filename}…")
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.download_attachment,
+            attachment,
+            on_result=lambda data: self.show_preview(attachment, data),
+            on_error=lambda exc: self.show_message(
+                f"Could not download {attachment.filename}: {exc}"
+            ),
+            is_stale=lambda: self._popped or self.previewed != attachment.id,
+        )
+
+    def show_message(self, text: str) -> None:
+        container = self.query_one("#attachment-preview", Vertical)
+        container.remove_children()
+        container.mount(Label(text))
+
+    def show_preview(self, attachment: models.Attachment, data: bytes | None) -> None:
+        """The image when downloaded, what is known of the file otherwise."""
+        if self._popped or self.previewed != attachment.id:
+            return
+        if data is not None:
+            container = self.query_one("#attachment-preview", Vertical)
+            container.remove_children()
+            container.mount(images.preview(data, self.config.get("image_protocol")))
+            return
+        text = (
+            f"{attachment.filename}, {attachment.mime_type or 'file'} of "
+            f"{attachment.human_size} by {attachment.author or 'Unknown'}"
+        )
+        if attachment.is_image and not images.available():
+            text += "\nInstall jayrah[images] to preview the images"
+        elif attachment.is_image:
+            text += "\nSet image_preview: true in the config to preview the images"
+        self.show_message(text)
+
+
+class PullRequestScreen(BaseModalScreen):
+    """Modal screen for attaching a pull or merge request to an issue."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Apply"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #pull-request-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #pull-request-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #pull-request-url, #pull-request-name {
+        width: 100%;
+        margin: 0;
+    }
+
+    #pull-request-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str):
+        super().__init__(parent)
+        self.issue_key = issue_key
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="pull-request-container"):
+            yield Label(
+                f"🔀 Attach a pull request to {self.issue_key}",
+                id="pull-request-title",
+            )
+            yield EmacsInput(
+                placeholder="GitHub, GitLab or Bitbucket pull request URL",
+                id="pull-request-url",
+            )
+            yield EmacsInput(
+                placeholder="Optional title (default: repository#number)",
+                id="pull-request-name",
+            )
+            yield Label(
+                "Press Enter to attach the pull request, Escape to cancel",
+                id="pull-request-help",
+            )
+
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Handle when user presses Enter in one of the inputs."""
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Attach the pull request of the input to the issue."""
+        url = self.query_one("#pull-request-url", EmacsInput).value.strip()
+        title = self.query_one("#pull-request-name", EmacsInput).value.strip()
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.add_pull_request_link,
+            self.issue_key,
+            url,
+            title or None,
+            on_result=lambda _result: self._pull_request_attached(url),
+            on_error=self._pull_request_failed,
+        )
+
+    def _pull_request_attached(self, url: str) -> None:
+        self.safe_pop_screen()
+        self._parent.notify(f"✅ Attached {url} to {self.issue_key}")
+
+    def _pull_request_failed(self, exc: Exception) -> None:
+        if isinstance(exc, ValueError):
+            message = "❌ Not a GitHub, GitLab or Bitbucket pull request URL"
+        else:
+            message = f"❌ Error attaching the pull request: {exc}"
+        if self._popped:
+            self._parent.notify(message, severity="error")
+            return
+        self.query_one("#pull-request-help", Label).update(message)
+
+
+class SubtasksTreeScreen(BaseModalScreen):
+    """Modal screen showing the parent and subtasks hierarchy of an issue."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #subtasks-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #subtasks-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #subtasks-tree {
+        width: 100%;
+        margin: 0;
+        height: 12;
+    }
+
+    #subtasks-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    HIERARCHY_FIELDS = ["summary", "status", "parent", "subtasks"]
+
+    def __init__(self, parent, issue_key: str, config: dict):
+        super().__init__(parent)
+        self.issue_key = issue_key
+        self.config = config or {}
+        self._current_node = None
+
+    @staticmethod
+    def _node_label(issue: dict) -> str:
+        fields = issue.get("fields", {})
+        status = fields.get("status", {}).get("name", "")
+        return f"{issue.get('key', '')}: {fields.get('summary', '')} [{status}]"
+
+    def _fetch_root_issue(self) -> dict:
+        """Worker method fetching the parent issue, or the issue itself."""
+        jira = self._parent.jayrah_obj.jira
+        issue = jira.get_issue(self.issue_key, fields=self.HIERARCHY_FIELDS)
+        parent = issue.get("fields", {}).get("parent")
+        if parent:
+            # Fetch the parent to show the siblings of the issue as well
+            return jira.get_issue(parent["key"], fields=self.HIERARCHY_FIELDS)
+        return issue
+
+    def _build_tree(self, root_issue: dict) -> Tree:
+        """Build a tree rooted at the parent issue, or at the issue itself."""
+        tree: Tree = Tree(self._node_label(root_issue), id="subtasks-tree")
+        tree.root.data = root_issue.get("key")
+        tree.root.expand()
+        for subtask in root_issue.get("fields", {}).get("subtasks", []):
+            node = tree.root.add_leaf(self._node_label(subtask), data=subtask["key"])
+            if subtask["key"] == self.issue_key:
+                self._current_node = node
+        if not root_issue.get("fields", {}).get("subtasks"):
+            tree.root.add_leaf("No subtasks", data=None)
+        return tree
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="subtasks-container"):
+            yield Label(f"🌳 Hierarchy for {self.issue_key}", id="subtasks-title")
+            yield Label("🔄 Loading the hierarchy...", id="subtasks-loading")
+            yield Label(
+                "Space to expand/collapse, Enter to jump to an issue, Escape to cancel",
+                id="subtasks-help",
+            )
+
+    def on_mount(self) -> None:
+        self._parent.worker_pool.submit(
+            self._fetch_root_issue,
+            on_result=self.show_tree,
+            on_error=self._tree_failed,
+            is_stale=lambda: self._popped,
+        )
+
+    def show_tree(self, root_issue: dict) -> None:
+        """Show the tree with the cursor on the issue we opened it for."""
+        if self._popped:
+            return
+        tree = self._build_tree(root_issue)
+        loading = self.query_one("#subtasks-loading", Label)
+        self.query_one("#subtasks-container", Vertical).mount(tree, before=loading)
+        loading.remove()
+        tree.focus()
+        if self._current_node is not None:
+            self.call_after_refresh(tree.move_cursor, self._current_node)
+
+    def _tree_failed(self, exc: Exception) -> None:
+        if not self._popped:
+            self.query_one("#subtasks-loading", Label).update(
+                f"Error loading subtasks: {exc}"
+            )
+
+    def on_tree_node_selected(self, event) -> None:
+        """Jump to the issue of the selected node."""
+        issue_key = event.node.data
+        if not issue_key:
+            return
+        self.safe_pop_screen()
+        self._parent.jump_to_issue(issue_key)
+
+
+class KanbanScreen(BaseModalScreen):
+    """Full screen view of the issues in side by side columns of status."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Open"),
+        Binding("h", "previous_column", "Previous column"),
+        Binding("l", "next_column", "Next column"),
+        Binding("left", "previous_column", "Previous column", show=False),
+        Binding("right", "next_column", "Next column", show=False),
+        Binding("j", "cursor_down", "Down"),
+        Binding("k", "cursor_up", "Up"),
+        Binding("H", "move_left", "Move left"),
+        Binding("L", "move_right", "Move right"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #kanban-container {
+        padding: 1;
+        width: 100%;
+        height: 100%;
+        background: $surface;
+    }
+
+    #kanban-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #kanban-columns {
+        height: 1fr;
+    }
+
+    .kanban-column {
+        width: 1fr;
+        height: 100%;
+        border: round $primary-darken-2;
+    }
+
+    .kanban-column:focus {
+        border: round $accent;
+    }
+
+    #kanban-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issues: list, config: dict):
+        super().__init__(parent)
+        # Copies, the status of an issue moved is updated in place
+        self.issues = [dict(x, fields=dict(x.get("fields", {}))) for x in issues]
+        self.status_columns = (config or {}).get("kanban_columns") or {}
+        self.columns = kanban_columns(self.status_columns)
+        self.current = 0
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="kanban-container"):
+            yield Label("Kanban", id="kanban-title")
+            with Horizontal(id="kanban-columns"):
+                for index in range(len(self.columns)):
+                    table = DataTable(
+                        id=f"kanban-column-{index}", classes="kanban-column"
+                    )
+                    table.cursor_type = "row"
+                    table.add_columns("Ticket", "Summary", "Assignee")
+                    yield table
+            yield Label(
+                "h/l to change column, H/L to move the issue to the column on its "
+                "left or right, Enter to open it, Escape to cancel",
+                id="kanban-help",
+            )
+
+    def on_mount(self) -> None:
+        self.refresh_columns()
+        self.focus_column(0)
+
+    def _table(self, index: int | None = None) -> DataTable:
+        index = self.current if index is None else index
+        return self.query_one(f"#kanban-column-{index}", DataTable)
+
+    def column_issues(self, index: int) -> list:
+        """The issues shown in a column."""
+        return [
+            issue
+            for issue in self.issues
+            if kanban_column(issue["fields"].get("status") or {}, self.status_columns)
+            == self.columns[index]
+        ]
+
+    def refresh_columns(self) -> None:
+        """Fill every column with its issues."""
+        for index, name in enumerate(self.columns):
+            issues = self.column_issues(index)
+            table = self._table(index)
+            table.clear()
+            table.border_title = f"{name} ({len(issues)})"
+            for issue in issues:
+                assignee = issue["fields"].get("assignee")
+                table.add_row(
+                    issue["key"],
+                    issue["fields"].get("summary", ""),
+                    utils.parse_email(assignee) if assignee else "None",
+                    key=issue["key"],
+                )
+
+    def selected_issue(self) -> dict | None:
+        """The issue under the cursor of the current column."""
+        issues = self.column_issues(self.current)
+        if not issues:
+            return None
+        return issues[min(self._table().cursor_row, len(issues) - 1)]
+
+    def focus_column(self, index: int) -> None:
+        if 0 <= index < len(self.columns):
+            self.current = index
+            self._table().focus()
+
+    def action_previous_column(self) -> None:
+        self.focus_column(self.current - 1)
+
+    def action_next_column(self) -> None:
+        self.focus_column(self.current + 1)
+
+    def action_cursor_down(self) -> None:
+        self._table().action_cursor_down()
+
+    def action_cursor_up(self) -> None:
+        self._table().action_cursor_up()
+
+    def action_move_left(self) -> None:
+        self.move_issue(self.current - 1)
+
+    def action_move_right(self) -> None:
+        self.move_issue(self.current + 1)
+
+    def move_issue(self, target: int) -> None:
+        """Transition the selected issue to a status of another column."""
+        issue = self.selected_issue()
+        if issue is None or not 0 <= target < len(self.columns):
+            return
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_transitions,
+            issue["key"],
+            expand_fields=True,
+            on_result=lambda data: self._move_issue(issue, target, data),
+            on_error=lambda exc: self._parent.notify(
+                f"Error applying transition: {exc}", severity="error"
+            ),
+            is_stale=lambda: self._popped,
+        )
+
+    def _move_issue(self, issue: dict, target: int, transitions_data: dict) -> None:
+        if self._popped:
+            return
+        column = self.columns[target]
+        transition = next(
+            (
+                x
+                for x in transitions_data.get("transitions", [])
+                if kanban_column(x.get("to") or {}, self.status_columns) == column
+            ),
+            None,
+        )
+        if transition is None:
+            self._parent.notify(
+                f"No transition of {issue['key']} leads to {column}",
+                severity="warning",
+            )
+            return
+        if models.required_transition_fields(transition):
+            # The fields screen does the transition and reloads the list
+            self.safe_pop_screen()
+            self._parent.push_screen(
+                TransitionFieldsScreen(self._parent, issue["key"], transition)
+            )
+            return
+        apply_transition(
+            self._parent,
+            issue["key"],
+            transition,
+            on_done=lambda: self._issue_moved(issue, target, transition),
+        )
+
+    def _issue_moved(self, issue: dict, target: int, transition: dict) -> None:
+        issue["fields"]["status"] = transition["to"]
+        if self._popped:
+            return
+        self.refresh_columns()
+        self.focus_column(target)
+        table = self._table()
+        table.move_cursor(row=table.get_row_index(issue["key"]))
+
+    def action_apply(self) -> None:
+        """Close the view on the issue under the cursor."""
+        issue = self.selected_issue()
+        self.safe_pop_screen()
+        if issue is not None:
+            self._parent.jump_to_issue(issue["key"])
+
+
+class StatsScreen(BaseModalScreen):
+    """Modal screen with bar charts of the listed issues by status and people."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("q", "cancel", "Close"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #stats-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #stats-title, #stats-ages {
+        text-align: center;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #stats-title {
+        text-style: bold;
+    }
+
+    #stats-table {
+        width: 100%;
+        margin: 0;
+        height: 20;
+    }
+
+    #stats-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    SECTIONS: ClassVar[tuple[tuple[str, str], ...]] = (
+        ("status", "Status"),
+        ("assignee", "Assignee"),
+        ("priority", "Priority"),
+    )
+
+    def __init__(self, parent, issues: list):
+        super().__init__(parent)
+        self.stats = board_stats(issues)
+
+    def _ages(self) -> str:
+        oldest = self.stats["oldest"]
+        if oldest is None:
+            return "No unresolved issues"
+        return (
+            f"Oldest unresolved: {oldest['key']} "
+            f"({issue_view.format_duration(self.stats['oldest_age'])}), "
+            f"average age {issue_view.format_duration(self.stats['average_age'])}"
+        )
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="stats-container"):
+            yield Label(f"📊 Stats of {self.stats['total']} issues", id="stats-title")
+            yield Label(self._ages(), id="stats-ages")
+            table = DataTable(id="stats-table")
+            table.cursor_type = "row"
+            table.add_columns("By", "Name", "Issues", "")
+            for section, title in self.SECTIONS:
+                counts = self.stats[section]
+                most = counts[0][1] if counts else 0
+                for name, count in counts:
+                    # Bars are scaled to the largest count of their section
+                    width = max(1, round(count * defaults.STATS_BAR_WIDTH / most))
+                    table.add_row(title, name, str(count), "█" * width)
+                    title = ""
+            yield table
+            yield Label("Press Escape or Q to close", id="stats-help")
+
+    def on_mount(self) -> None:
+        self.query_one("#stats-table", DataTable).focus()
+
+
+class ActivityScreen(BaseModalScreen):
+    """Modal screen with the latest comments and changes of the listed issues."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Open"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #activity-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #activity-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #activity-table {
+        width: 100%;
+        margin: 0;
+        height: 20;
+    }
+
+    #activity-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    KIND_ICONS: ClassVar[dict[str, str]] = {
+        "comment": "💬",
+        "transition": "🔀",
+        "assignment": "👤",
+    }
+
+    def __init__(self, parent, issues: list):
+        super().__init__(parent)
+        # Recent activity is on the issues updated last
+        self.issue_keys = [
+            x["key"]
+            for x in sorted(
+                issues, key=lambda x: x["fields"].get("updated") or "", reverse=True
+            )[: defaults.ACTIVITY_ISSUES]
+        ]
+        self.events: list[models.ActivityEvent] = []
+        self.pending = len(self.issue_keys)
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="activity-container"):
+            yield Label("Activity", id="activity-title")
+            table = DataTable(id="activity-table")
+            table.cursor_type = "row"
+            table.add_columns("When", "Issue", "Who", "What")
+            yield table
+            yield Label(
+                "Press Enter to go to the issue, Escape to cancel", id="activity-help"
+            )
+
+    def on_mount(self) -> None:
+        self._update_title()
+        for issue_key in self.issue_keys:
+            self._parent.worker_pool.submit(
+                self._fetch_activity,
+                issue_key,
+                on_result=self.add_events,
+                on_error=lambda exc: self.add_events([]),
+                is_stale=lambda: self._popped,
+            )
+        self.query_one("#activity-table", DataTable).focus()
+
+    def _fetch_activity(self, issue_key: str) -> list[models.ActivityEvent]:
+        """Worker method fetching the changelog and latest comments of an issue."""
+        jira = self._parent.jayrah_obj.jira
+        comments = jira.get_issue_comments(
+            issue_key, max_results=defaults.ACTIVITY_COMMENTS, newest_first=True
+        ).get("comments", [])
+        return models.issue_activity(
+            issue_key, jira.get_issue_changelog(issue_key), comments
+        )
+
+    def _update_title(self) -> None:
+        title = f"Activity of the {len(self.issue_keys)} latest updated issues"
+        if self.pending:
+            title += f", loading {self.pending}…"
+        self.query_one("#activity-title", Label).update(title)
+
+    def add_events(self, events: list[models.ActivityEvent]) -> None:
+        """Show the events of one more issue among the others by time."""
+        if self._popped:
+            return
+        self.pending -= 1
+        self.events = models.sort_activity(self.events + events)
+        table = self.query_one("#activity-table", DataTable)
+        table.clear()
+        for event in self.events:
+            table.add_row(
+                event.created[:16].replace("T", " "),
+                event.issue_key,
+                event.author or "",
+                f"{self.KIND_ICONS.get(event.kind, '')} {event.text}",
+            )
+        self._update_title()
+
+    def action_apply(self) -> None:
+        """Go to the issue of the event under the cursor."""
+        table = self.query_one("#activity-table", DataTable)
+        event = self.events[table.cursor_row] if self.events else None
+        self.safe_pop_screen()
+        if event is not None:
+            self._parent.jump_to_issue(event.issue_key)
+
+    def on_data_table_row_selected(self, event) -> None:
+        self.action_apply()
+
+
+class StandupScreen(BaseModalScreen):
+    """Modal screen with the Markdown standup of the last days, y copies it."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("q", "cancel", "Close"),
+        Binding("y", "copy", "Copy"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #standup-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: 80%;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #standup-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #standup-content {
+        width: 100%;
+        height: 1fr;
+        margin: 1 0;
+        overflow: auto;
+    }
+
+    #standup-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+        height: 1;
+    }
+    """
+
+    def __init__(self, parent, days: int):
+        super().__init__(parent)
+        self.days = days
+        self.text = ""
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="standup-container"):
+            yield Label(f"Standup of the last {self.days} days", id="standup-title")
+            yield Markdown("Loading the standup...", id="standup-content")
+            yield Label(
+                "Press y to copy the Markdown, Escape or Q to close",
+                id="standup-help",
+            )
+
+    def on_mount(self) -> None:
+        app = self._parent
+        app.worker_pool.submit(
+            report.standup_report,
+            app.jayrah_obj.jira,
+            app.jayrah_obj.issues_client,
+            self.days,
+            app.config.get("jira_server", ""),
+            on_result=self.show_report,
+            on_error=lambda exc: self.query_one("#standup-content", Markdown).update(
+                f"Error building the standup: {exc}"
+            ),
+            is_stale=lambda: self._popped,
+        )
+
+    def show_report(self, text: str) -> None:
+        """Show the standup once its issues and events have been fetched."""
+        self.text = text
+        self.query_one("#standup-content", Markdown).update(text)
+
+    def action_copy(self) -> None:
+        """Copy the Markdown of the standup to paste it in a chat."""
+        if not self.text:
+            return
+        if not clipboard.copy_to_clipboard(self.text):
+            self._parent.copy_to_clipboard(self.text)
+        self._parent.notify("📋 Copied the standup")
+
+
+class LogsScreen(BaseModalScreen):
+    """Modal screen with the end of the log file, r reloads it."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("q", "cancel", "Close"),
+        Binding("r", "reload", "Reload"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #logs-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: 80%;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #logs-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #logs-content {
+        width: 100%;
+        height: 1fr;
+        margin: 1 0;
+    }
+
+    #logs-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+        height: 1;
+    }
+    """
+
+    def __init__(self, parent, path):
+        super().__init__(parent)
+        self.path = path
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="logs-container"):
+            yield Label(str(self.path), id="logs-title")
+            yield Log(id="logs-content")
+            yield Label("Press r to reload, Escape or Q to close", id="logs-help")
+
+    def on_mount(self) -> None:
+        self.action_reload()
+
+    def action_reload(self) -> None:
+        """Show the last lines of the log file, newest at the bottom."""
+        log = self.query_one("#logs-content", Log)
+        log.clear()
+        lines = logs.tail(self.path, defaults.LOG_VIEW_LINES)
+        log.write_lines(lines or ["Nothing logged yet"])
+        log.focus()
+
+
+SEVERITY_STYLES = {"information": "", "warning": "yellow", "error": "bold red"}
+
+
+class MessagesScreen(BaseModalScreen):
+    """Modal screen with the latest notifications, newest first."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("q", "cancel", "Close"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #messages-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #messages-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #messages-table {
+        width: 100%;
+        margin: 0;
+        height: 20;
+    }
+
+    #messages-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, messages: list[Message]):
+        super().__init__(parent)
+        self.messages = messages
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="messages-container"):
+            yield Label(f"{len(self.messages)} latest messages", id="messages-title")
+            table = DataTable(id="messages-table")
+            table.cursor_type = "row"
+            table.add_columns("When", "Severity", "Message")
+            for message in self.messages:
+                style = SEVERITY_STYLES.get(message.severity, "")
+                table.add_row(
+                    message.when.strftime("%H:%M:%S"),
+                    Text(message.severity, style=style),
+                    Text(message.text, style=style),
+                )
+            yield table
+            yield Label("Press Escape or Q to close", id="messages-help")
+
+    def on_mount(self) -> None:
+        self.query_one("#messages-table", DataTable).focus()
+
+
+class HistoryScreen(BaseModalScreen):
+    """Modal screen with the recently viewed issues, across sessions."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Open"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #history-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #history-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #history-table {
+        width: 100%;
+        margin: 0;
+        height: 20;
+    }
+
+    #history-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, history: list[dict[str, str]]):
+        super().__init__(parent)
+        self.history = history
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="history-container"):
+            yield Label("Recently viewed issues", id="history-title")
+            table = DataTable(id="history-table")
+            table.cursor_type = "row"
+            table.add_columns("Viewed", "Issue", "Summary")
+            for entry in self.history:
+                table.add_row(
+                    entry.get("viewed", "")[:16].replace("T", " "),
+                    entry.get("key", ""),
+                    entry.get("summary", ""),
+                )
+            yield table
+            yield Label(
+                "Press Enter to go to the issue, Escape to cancel", id="history-help"
+            )
+
+    def on_mount(self) -> None:
+        self.query_one("#history-table", DataTable).focus()
+
+    def action_apply(self) -> None:
+        """Go to the issue under the cursor."""
+        table = self.query_one("#history-table", DataTable)
+        entry = self.history[table.cursor_row] if self.history else None
+        self.safe_pop_screen()
+        if entry is not None:
+            self._parent.jump_to_issue(entry["key"])
+
+    def on_data_table_row_selected(self, event) -> None:
+        self.action_apply()
+
+
+class EditSelectionScreen(BaseModalScreen):
+    """Modal screen for selecting what to edit (title, description, assignee...)."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Apply"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #edit-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+    
+    #edit-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+    
+    #edit-table {
+        width: 100%;
+        margin: 0;
+        height: 9;
+    }
+    
+    #edit-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str, config: dict):
+        super().__init__(parent)
+        self.config = config
+        self.issue_key = issue_key
+        self.selected_edit_type = None
+        self.verbose = self.config.get("verbose", False)
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="edit-container"):
+            yield Label(f"Edit Issue {self.issue_key}", id="edit-title")
+            table = DataTable(id="edit-table")
+            table.cursor_type = "row"
+            table.add_columns("Action", "Description")
+
+            # Add edit options
+            table.add_row("title", "Edit issue title/summary", key="title")
+            table.add_row("description", "Edit issue description", key="description")
+            table.add_row("assignee", "Change issue assignee", key="assignee")
+            table.add_row("priority", "Change issue priority", key="priority")
+            table.add_row("type", "Change issue type", key="issuetype")
+            table.add_row("due date", "Set or clear the due date", key="duedate")
+            table.add_row(
+                "fix versions", "Add or remove fix versions", key="fix_versions"
+            )
+
+            # Add custom field edit options from config
+            custom_fields = self.config.get("custom_fields", [])
+            for cf in custom_fields:
+                name = cf.get("name")
+                field = cf.get("field")
+                description = cf.get("description", f"Update custom field {field}")
+                if name and field:
+                    table.add_row(name, description, key=f"customfield:{field}")
+
+            yield table
+            yield Label(
+                "Press Enter to select edit type, Escape to cancel", id="edit-help"
+            )
+
+    def on_data_table_row_selected(self, event):
+        """Handle edit type selection."""
+        self.selected_edit_type = (
+            event.row_key.value
+            if hasattr(event.row_key, "value")
+            else str(event.row_key)
+        )
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Apply the edit selection."""
+        if not self.selected_edit_type:
+            self._parent.notify("No edit type selected", severity="warning")
+            self.safe_pop_screen()
+            return
+
+        edit_type = self.selected_edit_type
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_issue,
+            self.issue_key,
+            on_result=lambda issue_data: self._open_editor(edit_type, issue_data),
+            on_error=self._edit_failed,
+            is_stale=lambda: self._popped,
+        )
+
+    def _open_editor(self, edit_type: str, issue_data: dict) -> None:
+        """Replace this selection screen with the editor of the current value."""
+        # Close this selection screen first, once only when Enter was repeated
+        if not self.safe_pop_screen():
+            return
+        fields = issue_data.get("fields", {})
+        if edit_type == "title":
+            # Show the title edit screen
+            self._parent.push_screen(
+                TitleEditScreen(
+                    self._parent,
+                    self.issue_key,
+                    fields.get("summary", ""),
+                    self.config,
+                )
+            )
+        elif edit_type == "description":
+            # Show the description edit screen
+            self._parent.push_screen(
+                DescriptionEditScreen(
+                    self._parent,
+                    self.issue_key,
+                    fields.get("description", ""),
+                    self.config,
+                )
+            )
+        elif edit_type == "assignee":
+            assignee = fields.get("assignee") or {}
+            self._parent.push_screen(
+                AssigneeEditScreen(
+                    self._parent,
+                    self.issue_key,
+                    assignee.get("displayName", ""),
+                    self.config,
+                )
+            )
+        elif edit_type in ("priority", "issuetype"):
+            field = fields.get(edit_type) or {}
+            screen_class = (
+                PriorityEditScreen if edit_type == "priority" else IssueTypeEditScreen
+            )
+            self._parent.push_screen(
+                screen_class(
+                    self._parent,
+                    self.issue_key,
+                    field.get("name", ""),
+                    self.config,
+                )
+            )
+        elif edit_type == "duedate":
+            self._parent.push_screen(
+                CustomFieldEditScreen(
+                    self._parent,
+                    self.issue_key,
+                    "duedate",
+                    fields.get("duedate") or "",
+                    self.config,
+                    field_type="date",
+                )
+            )
+        elif edit_type == "fix_versions":
+            versions = fields.get("fixVersions") or []
+            self._parent.push_screen(
+                FixVersionsEditScreen(
+                    self._parent,
+                    self.issue_key,
+                    [x.get("name", "") for x in versions],
+                    self.config,
+                )
+            )
+        elif edit_type.startswith("customfield:"):
+            field_id = edit_type.split(":", 1)[1]
+            self._parent.push_screen(
+                CustomFieldEditScreen(
+                    self._parent,
+                    self.issue_key,
+                    field_id,
+                    fields.get(field_id, ""),
+                    self.config,
+                )
+            )
+
+    def _edit_failed(self, exc: Exception) -> None:
+        if self.verbose:
+            raise exc
+        self._parent.notify(
+            f"Error starting edit please try again. {exc} ", severity="error"
+        )
+        self.safe_pop_screen()
+
+
+class TitleEditScreen(BaseModalScreen):
+    """Modal screen for editing issue title/summary."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Apply"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #title-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+    
+    #title-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+    
+    #title-current {
+        width: 100%;
+        margin: 0 0 1 0;
+        text-align: center;
+        color: $text-muted;
+    }
+    
+    #title-input {
+        width: 100%;
+        margin: 0;
+    }
+    
+    #title-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str, current_title: str, config: dict):
+        super().__init__(parent)
+        self.config = config
+        self.issue_key = issue_key
+        self.current_title = current_title
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="title-container"):
+            yield Label(f"Edit Title for {self.issue_key}", id="title-title")
+            yield Label(
+                f"Current: {self.current_title[:60] + '...' if len(self.current_title) > 60 else self.current_title}",
+                id="title-current",
+            )
+            yield EmacsInput(
+                placeholder="Enter new title/summary",
+                id="title-input",
+                value=self.current_title,
+            )
+            yield Label("Press Enter to update, Escape to cancel", id="title-help")
+
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Handle when user presses Enter in the input field."""
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Apply the title changes."""
+        title_input = self.query_one("#title-input", EmacsInput).value.strip()
+
+        # Validate that title is not empty
+        if not title_input:
+            self._parent.notify("Title cannot be empty", severity="error")
+            return
+
+        # Check if there were changes
+        if title_input == self.current_title:
+            self._parent.notify("No changes made to title", severity="warning")
+            self.safe_pop_screen()
+            return
+
+        # Update the issue with new title
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.update_issue,
+            self.issue_key,
+            {"summary": title_input},
+            on_result=lambda _result: self._title_updated(),
+            on_error=lambda exc: self._parent.notify(
+                f"Error updating title: {exc}", severity="error"
+            ),
+        )
+        self.safe_pop_screen()
+
+    def _title_updated(self) -> None:
+        self._parent.undo_stack.push(
+            undo.Change(
+                self.issue_key,
+                f"title of {self.issue_key}",
+                {"summary": self.current_title},
+            )
+        )
+
+        # Update the issue cache to reflect changes
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        if detail_panel.ticket == self.issue_key:
+            detail_panel.update_issue(
+                self.issue_key, self._parent.config, use_cache=False
+            )
+
+        # Reload the issues table to show updated title
+        self._parent.action_reload()
+
+        # Show success notification
+        self._parent.notify(f"✅ Title updated for {self.issue_key}")
+
+
+class AssigneeEditScreen(BaseModalScreen):
+    """Modal screen for searching a user and assigning the issue to them."""
+
+    UNASSIGN_KEY = "__unassign__"
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #assignee-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #assignee-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #assignee-current {
+        width: 100%;
+        margin: 0 0 1 0;
+        text-align: center;
+        color: $text-muted;
+    }
+
+    #assignee-input {
+        width: 100%;
+        margin: 0;
+    }
+
+    #assignee-table {
+        width: 100%;
+        margin: 0;
+        height: 8;
+    }
+
+    #assignee-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(
+        self,
+        parent,
+        issue_key: str,
+        current_assignee: str,
+        config: dict,
+        issue_keys: list[str] | None = None,
+    ):
+        super().__init__(parent)
+        self.config = config
+        self.issue_key = issue_key
+        self.current_assignee = current_assignee
+        # When set every one of these issues gets assigned, see BulkActionsScreen
+        self.issue_keys = issue_keys
+        self.users: dict[str, str] = {}
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="assignee-container"):
+            yield Label(f"Assign {self.issue_key}", id="assignee-title")
+            yield Label(
+                f"Current: {self.current_assignee or 'Unassigned'}",
+                id="assignee-current",
+            )
+            yield EmacsInput(
+                placeholder="Search users by name or email", id="assignee-input"
+            )
+            table = DataTable(id="assignee-table")
+            table.cursor_type = "row"
+            table.add_columns("Name", "Email")
+            if self.current_assignee:
+                table.add_row("Unassigned", "", key=self.UNASSIGN_KEY)
+            yield table
+            yield Label(
+                "Press Enter to search, select a user to assign, Escape to cancel",
+                id="assignee-help",
+            )
+
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Search users matching the input."""
+        query = event.value.strip()
+        if not query:
+            self._parent.notify("Type a name to search users", severity="warning")
+            return
+
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.search_users,
+            query,
+            on_result=lambda users: self.show_users(query, users),
+            on_error=lambda exc: self._parent.notify(
+                f"Error searching users: {exc}", severity="error"
+            ),
+            is_stale=lambda: self._popped,
+        )
+
+    def show_users(self, query: str, users: list[dict]) -> None:
+        # An earlier search answering late is dropped
+        if self._popped:
+            return
+        if query != self.query_one("#assignee-input", EmacsInput).value.strip():
+            return
+        table = self.query_one("#assignee-table", DataTable)
+        table.clear()
+        self.users = {}
+        if self.current_assignee:
+            table.add_row("Unassigned", "", key=self.UNASSIGN_KEY)
+        for user in users:
+            # Cloud identifies users by accountId, Server by username
+            identifier = user.get("accountId") or user.get("name")
+            if not identifier:
+                continue
+            display_name = user.get("displayName", identifier)
+            self.users[identifier] = display_name
+            table.add_row(display_name, user.get("emailAddress", ""), key=identifier)
+
+        if not self.users:
+            self._parent.notify(f"No users matching '{query}'", severity="warning")
+            return
+        table.focus()
+
+    def on_data_table_row_selected(self, event):
+        """Assign the issue to the selected user."""
+        identifier = (
+            event.row_key.value
+            if hasattr(event.row_key, "value")
+            else str(event.row_key)
+        )
+        self.assign(None if identifier == self.UNASSIGN_KEY else identifier)
+
+    def assign(self, identifier: str | None) -> None:
+        """Assign the issue and refresh the browser."""
+        if self.issue_keys:
+            self.safe_pop_screen()
+            name = self.users.get(identifier or "", "nobody")
+            self._parent.run_bulk_action(
+                f"Assign to {name}",
+                self.issue_keys,
+                self._parent.jayrah_obj.jira.assign_issue,
+                identifier,
+            )
+            return
+
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.assign_issue,
+            self.issue_key,
+            identifier,
+            on_result=lambda _result: self._assigned(identifier),
+            on_error=lambda exc: self._parent.notify(
+                f"Error assigning issue: {exc}", severity="error"
+            ),
+        )
+        self.safe_pop_screen()
+
+    def _assigned(self, identifier: str | None) -> None:
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        if detail_panel.ticket == self.issue_key:
+            detail_panel.update_issue(
+                self.issue_key, self._parent.config, use_cache=False
+            )
+
+        self._parent.action_reload()
+
+        if identifier is None:
+            self._parent.notify(f"✅ {self.issue_key} is now unassigned")
+        else:
+            name = self.users.get(identifier, identifier)
+            self._parent.notify(f"✅ {self.issue_key} assigned to {name}")
+
+
+class WorklogsViewScreen(BaseModalScreen):
+    """Modal screen for viewing and logging work on an issue."""
+
+    TIME_SPENT_RE = re.compile(r"^(\d+(\.\d+)?[wdhm]\s*)+$")
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #worklogs-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #worklogs-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #worklogs-table {
+        width: 100%;
+        margin: 0 0 1 0;
+        height: 10;
+    }
+
+    #worklog-time-input, #worklog-comment-input {
+        width: 100%;
+        margin: 0;
+    }
+
+    #worklogs-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_key: str, config: dict):
+        super().__init__(parent)
+        self.issue_key = issue_key
+        self.config = config or {}
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="worklogs-container"):
+            yield Label(f"⏱️ Worklogs for {self.issue_key}", id="worklogs-title")
+            table = DataTable(id="worklogs-table")
+            table.cursor_type = "row"
+            table.add_columns("Author", "Time Spent", "Started", "Comment")
+            yield table
+            yield EmacsInput(
+                placeholder="Time spent (e.g. 2h, 30m, 1d 4h)",
+                id="worklog-time-input",
+            )
+            yield EmacsInput(placeholder="Optional comment", id="worklog-comment-input")
+            yield Label("Press Enter to log work, Escape to close", id="worklogs-help")
+
+    def on_mount(self) -> None:
+        """Load worklogs when the screen is mounted."""
+        self._load_worklogs(use_cache=True)
+        self.query_one("#worklog-time-input", EmacsInput).focus()
+
+    def _load_worklogs(self, use_cache: bool = False) -> None:
+        """Fetch the issue worklogs into the table."""
+        self.query_one("#worklogs-table", DataTable).loading = True
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_issue_worklogs,
+            self.issue_key,
+            use_cache=use_cache,
+            on_result=self.show_worklogs,
+            on_error=self._worklogs_failed,
+            is_stale=lambda: self._popped,
+        )
+
+    def _worklogs_failed(self, exc: Exception) -> None:
+        self._parent.notify(f"Error loading worklogs: {exc}", severity="error")
+        if not self._popped:
+            self.query_one("#worklogs-table", DataTable).loading = False
+
+    def show_worklogs(self, worklogs: list) -> None:
+        if self._popped:
+            return
+        table = self.query_one("#worklogs-table", DataTable)
+        table.loading = False
+        table.clear()
+        if not worklogs:
+            table.add_row("", "No work logged yet", "", "", key="none")
+            return
+
+        for worklog in worklogs:
+            comment = worklog.get("comment", "")
+            if isinstance(comment, dict):
+                comment = adf.extract_text_from_adf(comment)
+            table.add_row(
+                worklog.get("author", {}).get("displayName", "Unknown"),
+                worklog.get("timeSpent", ""),
+                worklog.get("started", "")[:16].replace("T", " "),
+                " ".join(str(comment).split())[:60],
+                key=str(worklog.get("id")),
+            )
+
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Log work when the user presses Enter in one of the inputs."""
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Add a worklog to the issue."""
+        time_input = self.query_one("#worklog-time-input", EmacsInput)
+        comment_input = self.query_one("#worklog-comment-input", EmacsInput)
+        time_spent = time_input.value.strip()
+
+        if not self.TIME_SPENT_RE.match(time_spent):
+            self._parent.notify(
+                "Time spent should look like 2h, 30m or 1d 4h", severity="error"
+            )
+            time_input.focus()
+            return
+
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.add_worklog,
+            self.issue_key,
+            time_spent,
+            comment=comment_input.value.strip(),
+            on_result=lambda _result: self._work_logged(time_spent),
+            on_error=lambda exc: self._parent.notify(
+                f"Error logging work: {exc}", severity="error"
+            ),
+        )
+
+    def _work_logged(self, time_spent: str) -> None:
+        self._parent.notify(f"✅ Logged {time_spent} on {self.issue_key}")
+        if self._popped:
+            return
+        time_input = self.query_one("#worklog-time-input", EmacsInput)
+        time_input.value = ""
+        self.query_one("#worklog-comment-input", EmacsInput).value = ""
+        time_input.focus()
+        self._load_worklogs()
+
+
+class CloneIssueScreen(BaseModalScreen):
+    """Modal screen for cloning an issue into the same or another project."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("enter", "apply", "Apply"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #clone-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #clone-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #clone-project, #clone-summary {
+        width: 100%;
+        margin: 0;
+    }
+
+    #clone-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    HELP_TEXT = (
+        "Press Enter to clone, components and fix versions are only kept in the "
+        "same project. Escape to cancel"
+    )
+
+    def __init__(self, parent, issue_key: str, summary: str, config: dict):
+        super().__init__(parent)
+        self.issue_key = issue_key
+        self.summary = summary
+        self.config = config or {}
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="clone-container"):
+            yield Label(f"Clone {self.issue_key}", id="clone-title")
+            yield EmacsInput(
+                placeholder="Project key",
+                id="clone-project",
+                value=self.issue_key.split("-")[0],
+            )
+            yield EmacsInput(
+                placeholder="Summary", id="clone-summary", value=self.summary
+            )
+            yield Label(self.HELP_TEXT, id="clone-help")
+
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Clone when the user presses Enter in one of the inputs."""
+        self.action_apply()
+
+    def action_apply(self) -> None:
+        """Create the copy in the project typed."""
+        project = self.query_one("#clone-project", EmacsInput).value.strip().upper()
+        summary = self.query_one("#clone-summary", EmacsInput).value.strip()
+        if not CreateIssueScreen.PROJECT_KEY_RE.match(project):
+            self._parent.notify(
+                "Project should be a project key like PROJ", severity="error"
+            )
+            return
+        if not summary:
+            self._parent.notify("Summary is required", severity="error")
+            return
+
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.clone_issue,
+            self.issue_key,
+            overrides={"project": {"key": project}, "summary": summary},
+            on_result=self._cloned,
+            on_error=lambda exc: self._parent.notify(
+                f"Error cloning issue: {exc}", severity="error"
+            ),
+        )
+
+    def _cloned(self, created: dict) -> None:
+        self._parent.notify(f"✅ Cloned {self.issue_key} to {created.get('key')}")
+        self.safe_pop_screen()
+        self._parent.action_reload()
+
+
+class CreateIssueScreen(BaseModalScreen):
+    """Modal screen with a form for creating a new issue."""
+
+    PROJECT_KEY_RE = re.compile(r"^[A-Z][A-Z0-9_]*$")
+    SUMMARY_MAX_LENGTH = 255
+    # Fields filled by the form, or by Jira like the reporter
+    FORM_FIELDS = (
+        "project",
+        "issuetype",
+        "summary",
+        "description",
+        "labels",
+        "priority",
+        "components",
+        "reporter",
+    )
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("ctrl+s", "apply", "Create"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #create-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: 100%;
+        background: $surface;
+        border: thick $primary;
+        margin: 0;
+    }
+
+    #create-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
     }
-    
-    #title-input {
+
+    #create-project-input, #create-type-input, #create-summary-input,
+    #create-labels-input, #create-priority-input, #create-components-input {
         width: 100%;
         margin: 0;
     }
-    
-    #title-help {
+
+    #create-description-textarea {
+        width: 100%;
+        height: 1fr;
+        margin: 1 0;
+    }
+
+    #create-help {
         text-align: center;
         color: $text-muted;
         margin-top: 0;
     }
     """
 
-    def __init__(self, parent, issue_key: str, current_title: str, config: dict):
+    HELP_TEXT = "Press Ctrl+S to create the issue, Escape to cancel"
+
+    def __init__(self, parent, config: dict):
         super().__init__(parent)
-        self.config = config
-        self.issue_key = issue_key
-        self.current_title = current_title
+        self.config = config or {}
+        self.issue_types: list[str] = []
+        # Create screen fields of the chosen project and issue type
+        self.create_meta: dict = {}
+        self.create_meta_for: tuple[str, str] | None = None
+        # Project and issue type of the fields being fetched
+        self.create_meta_loading: tuple[str, str] | None = None
+        self.confirming = False
 
     def compose(self) -> ComposeResult:
-        with Vertical(id="title-container"):
-            yield Label(f"Edit Title for {self.issue_key}", id="title-title")
-            yield Label(
-                f"Current: {self.current_title[:60] + '...' if len(self.current_title) > 60 else self.current_title}",
-                id="title-current",
+        # Same defaults as `jayrah create` from the create section of the config
+        create_defaults = self.config.get("create") or {}
+        with Vertical(id="create-container"):
+            yield Label("✨ Create Issue", id="create-title")
+            yield EmacsInput(
+                value=self.config.get("jira_project") or "",
+                placeholder="Project key",
+                id="create-project-input",
             )
             yield EmacsInput(
-                placeholder="Enter new title/summary",
-                id="title-input",
-                value=self.current_title,
+                value=create_defaults.get("type", ""),
+                placeholder="Issue type (e.g. Story, Bug, Task)",
+                id="create-type-input",
             )
-            yield Label("Press Enter to update, Escape to cancel", id="title-help")
+            yield EmacsInput(placeholder="Summary", id="create-summary-input")
+            yield EmacsTextArea(
+                id="create-description-textarea",
+                language="markdown",
+            )
+            yield EmacsInput(
+                value=", ".join(create_defaults.get("labels") or []),
+                placeholder="Labels (comma separated)",
+                id="create-labels-input",
+            )
+            yield EmacsInput(
+                value=create_defaults.get("priority", ""),
+                placeholder="Priority",
+                id="create-priority-input",
+            )
+            yield EmacsInput(
+                value=", ".join(create_defaults.get("components") or []),
+                placeholder="Components (comma separated)",
+                id="create-components-input",
+            )
+            yield Label(self.HELP_TEXT, id="create-help")
+
+    def on_mount(self) -> None:
+        """Offer the project issue types as suggestions."""
+        self._parent.worker_pool.submit(
+            lambda: list(self._parent.jayrah_obj.jira.get_issue_types()),
+            on_result=self._show_issue_types,
+            on_error=lambda exc: self._parent.log(
+                f"Could not fetch issue types: {exc}"
+            ),
+            is_stale=lambda: self._popped,
+        )
+        self.query_one("#create-summary-input", EmacsInput).focus()
 
-    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
-        """Handle when user presses Enter in the input field."""
-        self.action_apply()
+    def _show_issue_types(self, issue_types: list[str]) -> None:
+        if self._popped:
+            return
+        self.issue_types = issue_types
+        if self.issue_types:
+            type_input = self.query_one("#create-type-input", EmacsInput)
+            type_input.suggester = SuggestFromList(
+                self.issue_types, case_sensitive=False
+            )
 
-    def action_apply(self) -> None:
-        """Apply the title changes."""
-        title_input = self.query_one("#title-input", EmacsInput).value.strip()
+    def _get_values(self) -> dict:
+        """Read the form fields."""
+
+        def value(widget_id: str) -> str:
+            return self.query_one(widget_id, EmacsInput).value.strip()
+
+        textarea = self.query_one("#create-description-textarea", EmacsTextArea)
+        labels = value("#create-labels-input").split(",")
+        components = value("#create-components-input").split(",")
+        return {
+            "project": value("#create-project-input").upper(),
+            "issuetype": value("#create-type-input"),
+            "summary": value("#create-summary-input"),
+            "description": textarea.text.strip(),
+            "labels": [label.strip() for label in labels if label.strip()],
+            "priority": value("#create-priority-input"),
+            "components": [x.strip() for x in components if x.strip()],
+        }
 
-        # Validate that title is not empty
-        if not title_input:
-            self._parent.notify("Title cannot be empty", severity="error")
-            return
+    def _load_create_meta(self, project: str, issuetype: str) -> None:
+        """
+        Fetch the create screen fields, once per project and issue type.
 
-        # Check if there were changes
-        if title_input == self.current_title:
-            self._parent.notify("No changes made to title", severity="warning")
-            self.safe_pop_screen()
-            return
+        The form is validated again once they are in.
+        """
+        self.create_meta_loading = (project, issuetype)
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_create_meta,
+            project,
+            issuetype,
+            on_result=lambda meta: self._create_meta_loaded(project, issuetype, meta),
+            on_error=lambda exc: self._create_meta_failed(project, issuetype, exc),
+            is_stale=lambda: self._popped,
+        )
 
-        # Update the issue with new title
-        try:
-            self._parent.jayrah_obj.jira.update_issue(
-                self.issue_key, {"summary": title_input}
-            )
+    def _create_meta_failed(self, project: str, issuetype: str, exc) -> None:
+        self._parent.log(f"Could not fetch create metadata: {exc}")
+        self._create_meta_loaded(project, issuetype, {})
 
-            # Update the issue cache to reflect changes
-            detail_panel = self._parent.query_one(IssueDetailPanel)
-            if detail_panel.ticket == self.issue_key:
-                detail_panel.update_issue(
-                    self.issue_key, self._parent.config, use_cache=False
+    def _create_meta_loaded(self, project: str, issuetype: str, meta: dict) -> None:
+        if self._popped or self.create_meta_loading != (project, issuetype):
+            return
+        self.create_meta_loading = None
+        self.create_meta_for = (project, issuetype)
+        self.create_meta = meta
+        for field_id, widget_id, suggester_class in (
+            ("priority", "#create-priority-input", SuggestFromList),
+            ("components", "#create-components-input", SuggestFromListComma),
+        ):
+            allowed = models.allowed_values(self.create_meta.get(field_id, {}))
+            if allowed:
+                self.query_one(widget_id, EmacsInput).suggester = suggester_class(
+                    allowed, case_sensitive=False
                 )
+        self.action_apply()
+
+    def _allowed_name(self, field_id: str, text: str) -> str:
+        """The exact name Jira wants for a value typed in any case."""
+        meta = self.create_meta.get(field_id, {})
+        return models.match_allowed_value(meta, text) or text
+
+    def validate(self, values: dict) -> list[str]:
+        """Return the problems preventing the issue from being created."""
+        errors = []
+        if not self.PROJECT_KEY_RE.match(values["project"]):
+            errors.append("Project should be a project key like PROJ")
+        if not values["issuetype"]:
+            errors.append("Issue type is required")
+        elif self.issue_types and values["issuetype"] not in self.issue_types:
+            errors.append(f"Unknown issue type: {values['issuetype']}")
+        if not values["summary"]:
+            errors.append("Summary is required")
+        elif len(values["summary"]) > self.SUMMARY_MAX_LENGTH:
+            errors.append(
+                f"Summary should be at most {self.SUMMARY_MAX_LENGTH} characters"
+            )
+        if any(" " in label for label in values["labels"]):
+            errors.append("Labels cannot contain spaces")
+
+        # Fields known from the create screen of the issue type
+        priority_meta = self.create_meta.get("priority", {})
+        if (
+            values.get("priority")
+            and models.allowed_values(priority_meta)
+            and not models.match_allowed_value(priority_meta, values["priority"])
+        ):
+            errors.append(f"Unknown priority: {values['priority']}")
+        components_meta = self.create_meta.get("components", {})
+        if models.allowed_values(components_meta):
+            unknown = [
+                x
+                for x in values.get("components") or []
+                if not models.match_allowed_value(components_meta, x)
+            ]
+            if unknown:
+                errors.append(f"Unknown components: {', '.join(unknown)}")
+        for field_id, meta in self.create_meta.items():
+            if (
+                meta.get("required")
+                and not meta.get("hasDefaultValue")
+                and field_id not in self.FORM_FIELDS
+            ):
+                errors.append(f"{meta.get('name', field_id)} is required")
+        return errors
+
+    def _reset_confirmation(self) -> None:
+        if self.confirming:
+            self.confirming = False
+            self.query_one("#create-help", Label).update(self.HELP_TEXT)
+
+    def on_input_changed(self, event: EmacsInput.Changed) -> None:
+        """Ask for confirmation again once the form has been modified."""
+        self._reset_confirmation()
+
+    def on_text_area_changed(self, event: EmacsTextArea.Changed) -> None:
+        """Ask for confirmation again once the description has been modified."""
+        self._reset_confirmation()
+
+    def action_cancel(self) -> None:
+        """Go back to the form when confirming, close the form otherwise."""
+        if self.confirming:
+            self._reset_confirmation()
+            return
+        self.safe_pop_screen()
 
-            # Reload the issues table to show updated title
-            self._parent.action_reload()
+    def action_apply(self) -> None:
+        """Validate the form, then create the issue once confirmed."""
+        if self.create_meta_loading:
+            # Applied once the fields are in
+            return
+        values = self._get_values()
+        meta_for = (values["project"], values["issuetype"])
+        if all(meta_for) and self.create_meta_for != meta_for:
+            self._load_create_meta(*meta_for)
+            return
+        errors = self.validate(values)
+        if errors:
+            self._parent.notify("\n".join(errors), severity="error")
+            return
 
-            # Show success notification
-            self._parent.notify(f"✅ Title updated for {self.issue_key}")
+        if not self.confirming:
+            self.confirming = True
+            self.query_one("#create-help", Label).update(
+                f"Create a {values['issuetype']} in {values['project']}: "
+                f"{values['summary']}? Press Ctrl+S again to confirm, "
+                "Escape to go back"
+            )
+            return
 
-        except jira_exceptions.JiraAPIError as exc:
-            self._parent.notify(f"Error updating title: {exc}", severity="error")
+        jira = self._parent.jayrah_obj.jira
+        description = values["description"]
+        if description:
+            # The description editor is markdown, Jira wants wiki markup or ADF
+            if jira.api_version == "3":
+                description = markdown_to_jira.convert_v3(description)
+            else:
+                description = markdown_to_jira.convert(description)
+
+        # Confirmed again to create another one while this one is sent
+        self._reset_confirmation()
+        self._parent.worker_pool.submit(
+            jira.create_issue,
+            issuetype=values["issuetype"],
+            summary=values["summary"],
+            description=description or None,
+            priority=self._allowed_name("priority", values["priority"]) or None,
+            labels=values["labels"],
+            components=[
+                self._allowed_name("components", x) for x in values["components"]
+            ],
+            extra_fields={"project": {"key": values["project"]}},
+            on_result=self._issue_created,
+            on_error=lambda exc: self._parent.notify(
+                f"Error creating issue: {exc}", severity="error"
+            ),
+        )
 
+    def _issue_created(self, result: dict) -> None:
+        self._parent.notify(f"✅ Created {result.get('key', 'issue')}")
         self.safe_pop_screen()
+        self._parent.action_reload()
 
 
 class DescriptionEditScreen(BaseModalScreen):
@@ -1394,15 +5232,16 @@ class DescriptionEditScreen(BaseModalScreen):
         # Store original description (could be str or dict for ADF)
         self.original_description = current_description
 
-        # Convert to string if it's a JSON object (ADF format)
+        # ADF descriptions are edited as Markdown and converted back on save
         self.is_adf_format = False
         if current_description and isinstance(current_description, dict):
-            from ...utils.adf import extract_text_from_adf
-
             self.is_adf_format = True
-            self.current_description = extract_text_from_adf(current_description)
+            self.current_description = adf.adf_to_markdown(current_description)
+        elif current_description:
+            # API v2 stores wiki markup, edited as Markdown as well
+            self.current_description = jira2markdown.convert(current_description)
         else:
-            self.current_description = current_description or ""
+            self.current_description = ""
 
     def compose(self) -> ComposeResult:
         with Vertical(id="description-container"):
@@ -1441,7 +5280,7 @@ class DescriptionEditScreen(BaseModalScreen):
 
         # If original was ADF format, convert edited text back to ADF
         if self.is_adf_format:
-            new_description = adf.create_adf_from_text(new_description_text)
+            new_description = markdown_to_jira.convert_v3(new_description_text)
             if self.verbose:
                 with open("/tmp/debug_save.log", "w") as f:
                     f.write(f"New text: {new_description_text}\n")
@@ -1449,27 +5288,192 @@ class DescriptionEditScreen(BaseModalScreen):
                         f"New ADF: {__import__('json').dumps(new_description, indent=2)}"
                     )
         else:
-            new_description = new_description_text
+            new_description = markdown_to_jira.convert(new_description_text)
 
         # Update the issue with new description
-        try:
-            self._parent.jayrah_obj.jira.update_issue(
-                self.issue_key, {"description": new_description}
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.update_issue,
+            self.issue_key,
+            {"description": new_description},
+            on_result=lambda _result: self._description_updated(),
+            # The screen stays open on errors to read the message, then cancel
+            on_error=lambda exc: self._parent.notify(
+                f"Error updating description: {exc}", severity="error"
+            ),
+        )
+
+    def _description_updated(self) -> None:
+        # Update the issue cache to reflect changes
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        if detail_panel.ticket == self.issue_key:
+            detail_panel.update_issue(
+                self.issue_key, self._parent.config, use_cache=False
             )
 
-            # Update the issue cache to reflect changes
-            detail_panel = self._parent.query_one(IssueDetailPanel)
-            if detail_panel.ticket == self.issue_key:
-                detail_panel.update_issue(
-                    self.issue_key, self._parent.config, use_cache=False
-                )
+        self._parent.notify(f"✅ Description updated for {self.issue_key}")
+        self.safe_pop_screen()
+
+
+class BulkActionsScreen(BaseModalScreen):
+    """Modal screen applying an action to every marked issue."""
+
+    BINDINGS: ClassVar[tuple[Binding, ...]] = (
+        Binding("escape", "cancel", "Cancel"),
+        Binding("f1", "help", "Help"),
+    )
+
+    CSS = """
+    #bulk-container {
+        dock: bottom;
+        padding: 1;
+        width: 100%;
+        height: auto;
+        background: $surface;
+        border-top: thick $primary;
+        margin: 0;
+    }
+
+    #bulk-title {
+        text-align: center;
+        text-style: bold;
+        width: 100%;
+        height: 1;
+        content-align: center middle;
+    }
+
+    #bulk-table {
+        width: 100%;
+        margin: 0 0 1 0;
+        height: 5;
+    }
+
+    #bulk-input {
+        width: 100%;
+        margin: 0;
+    }
+
+    #bulk-help {
+        text-align: center;
+        color: $text-muted;
+        margin-top: 0;
+    }
+    """
+
+    def __init__(self, parent, issue_keys: list[str], config: dict):
+        super().__init__(parent)
+        self.issue_keys = issue_keys
+        self.config = config or {}
+        self.selected_action: str | None = None
+
+    def compose(self) -> ComposeResult:
+        with Vertical(id="bulk-container"):
+            yield Label(
+                f"Bulk actions on {len(self.issue_keys)} issues", id="bulk-title"
+            )
+            table = DataTable(id="bulk-table")
+            table.cursor_type = "row"
+            table.add_columns("Action", "Description")
+            table.add_row(
+                "Transition all", "Move every issue to a new status", key="transition"
+            )
+            table.add_row("Add label to all", "Add a label to every issue", key="label")
+            table.add_row("Assign all", "Assign every issue to a user", key="assign")
+            yield table
+            yield EmacsInput(placeholder="Select an action first", id="bulk-input")
+            yield Label(
+                "Select an action, fill the value and press Enter, Escape to cancel",
+                id="bulk-help",
+            )
+
+    def on_mount(self) -> None:
+        self.query_one("#bulk-table", DataTable).focus()
+
+    def _suggestions(self, action: str) -> list[str]:
+        """
+        Worker method listing the values to suggest for an action.
 
-            self._parent.notify(f"✅ Description updated for {self.issue_key}")
+        Transitions come from the first issue.
+        """
+        jira = self._parent.jayrah_obj.jira
+        if action == "transition":
+            transitions = jira.get_transitions(self.issue_keys[0])
+            return [t["name"] for t in transitions.get("transitions", [])]
+        return jira.get_labels()
+
+    def _show_suggestions(self, action: str, suggestions: list[str]) -> None:
+        # Another action may have been picked while these loaded
+        if self._popped or self.selected_action != action:
+            return
+        self.query_one("#bulk-input", EmacsInput).suggester = SuggestFromList(
+            suggestions, case_sensitive=False
+        )
+
+    def on_data_table_row_selected(self, event):
+        """Ask for the value of the selected action."""
+        action = (
+            event.row_key.value
+            if hasattr(event.row_key, "value")
+            else str(event.row_key)
+        )
+        if action == "assign":
             self.safe_pop_screen()
+            self._parent.push_screen(
+                AssigneeEditScreen(
+                    self._parent,
+                    f"{len(self.issue_keys)} issues",
+                    "",
+                    self.config,
+                    issue_keys=self.issue_keys,
+                )
+            )
+            return
+
+        self.selected_action = action
+        bulk_input = self.query_one("#bulk-input", EmacsInput)
+        bulk_input.placeholder = (
+            "Transition name (e.g. Start Progress)"
+            if action == "transition"
+            else "Label to add"
+        )
+        bulk_input.suggester = None
+        bulk_input.focus()
+        self._parent.worker_pool.submit(
+            self._suggestions,
+            action,
+            on_result=lambda suggestions: self._show_suggestions(action, suggestions),
+            on_error=lambda exc: self._parent.log(
+                f"Could not fetch suggestions: {exc}"
+            ),
+            is_stale=lambda: self._popped or self.selected_action != action,
+        )
+
+    def on_input_submitted(self, event: EmacsInput.Submitted) -> None:
+        """Run the selected action on every marked issue."""
+        value = event.value.strip()
+        if not self.selected_action:
+            self._parent.notify("Select an action first", severity="warning")
+            return
+        if not value or (self.selected_action == "label" and " " in value):
+            self._parent.notify(
+                "Type a transition name or a label without spaces", severity="warning"
+            )
+            return
 
-        except jira_exceptions.JiraAPIError as exc:
-            self._parent.notify(f"Error updating description: {exc}", severity="error")
-            # Do not pop the screen on error, allow user to see the message and manually cancel.
+        self.safe_pop_screen()
+        if self.selected_action == "transition":
+            self._parent.run_bulk_action(
+                f"Transition '{value}'",
+                self.issue_keys,
+                self._parent.bulk_transition,
+                value,
+            )
+        else:
+            self._parent.run_bulk_action(
+                f"Add label '{value}'",
+                self.issue_keys,
+                self._parent.bulk_add_label,
+                value,
+            )
 
 
 class ActionsPanel(BaseModalScreen):
@@ -1482,9 +5486,26 @@ class ActionsPanel(BaseModalScreen):
         Binding("ctrl+c", "select_components", "Components"),
         Binding("c", "select_comments", "Comments"),
         Binding("t", "select_transition", "Transition"),
+        Binding("w", "select_worklogs", "Worklogs"),
+        Binding("W", "select_watch", "Watch"),
+        Binding("V", "select_vote", "Vote"),
+        Binding("L", "select_links", "Links"),
+        Binding("U", "select_remote_links", "Web links"),
+        Binding("R", "select_pull_request", "Pull request"),
+        Binding("S", "select_subtasks", "Subtasks"),
+        Binding("d", "select_clone", "Clone"),
         Binding("e", "select_edit", "Edit"),
         Binding("f", "select_filter", "Filter"),
         Binding("b", "select_board", "Board"),
+        Binding("s", "select_sprint", "Sprint"),
+        Binding("P", "select_profile", "Profile"),
+        Binding("p", "select_projects", "Projects"),
+        Binding("/", "select_query", "JQL query"),
+        Binding("ctrl+s", "select_save_board", "Save as board"),
+        Binding("D", "select_doctor", "Doctor"),
+        Binding("E", "select_export", "Export"),
+        Binding("n", "select_create", "New issue"),
+        Binding("B", "select_bulk", "Bulk actions"),
         Binding("f1", "help", "Help"),
     )
 
@@ -1510,7 +5531,7 @@ class ActionsPanel(BaseModalScreen):
     #actions-table {
         width: 100%;
         margin: 0;
-        height: 10;
+        height: 26;
     }
     
     #actions-help {
@@ -1556,6 +5577,48 @@ class ActionsPanel(BaseModalScreen):
                 "Change status of the selected issue",
                 key="transition_issue",
             )
+            table.add_row(
+                "w",
+                "Worklogs",
+                "View or log work on the selected issue",
+                key="view_worklogs",
+            )
+            table.add_row(
+                "W", "Watch", "Start or stop watching the issue", key="toggle_watch"
+            )
+            table.add_row(
+                "V", "Vote", "Vote for the issue or remove the vote", key="toggle_vote"
+            )
+            table.add_row(
+                "L",
+                "Links",
+                "Browse the issues linked to the selected issue",
+                key="view_links",
+            )
+            table.add_row(
+                "U",
+                "Web links",
+                "Open or attach web links like pull requests",
+                key="view_remote_links",
+            )
+            table.add_row(
+                "R",
+                "Pull request",
+                "Attach a pull or merge request to the selected issue",
+                key="attach_pull_request",
+            )
+            table.add_row(
+                "S",
+                "Subtasks",
+                "Show the parent and subtasks of the selected issue",
+                key="view_subtasks",
+            )
+            table.add_row(
+                "d",
+                "Clone",
+                "Copy the selected issue into this or another project",
+                key="clone_issue",
+            )
             table.add_row(
                 "e",
                 "Edit",
@@ -1568,6 +5631,33 @@ class ActionsPanel(BaseModalScreen):
             table.add_row(
                 "b", "Board", "Switch to a different board", key="change_board"
             )
+            table.add_row(
+                "s", "Sprint", "Switch to a sprint of the project", key="change_sprint"
+            )
+            table.add_row(
+                "P", "Profile", "Switch to another Jira profile", key="change_profile"
+            )
+            table.add_row(
+                "p", "Projects", "Browse the issues of a project", key="projects"
+            )
+            table.add_row("/", "JQL query", "Browse the issues of a query", key="query")
+            table.add_row(
+                "Ctrl+S",
+                "Save as board",
+                "Save the current query as a board of the config",
+                key="save_board",
+            )
+            table.add_row("D", "Doctor", "Check the connection to Jira", key="doctor")
+            table.add_row(
+                "E", "Export", "Export the issues to CSV or Markdown", key="export"
+            )
+            table.add_row("n", "New issue", "Create a new issue", key="create_issue")
+            table.add_row(
+                "B",
+                "Bulk actions",
+                "Transition, label or assign all the marked issues",
+                key="bulk_actions",
+            )
 
             yield table
             yield Label(
@@ -1603,12 +5693,46 @@ class ActionsPanel(BaseModalScreen):
             self._parent.action_view_comments()
         elif self.selected_action == "transition_issue":
             self._parent.action_transition_issue()
+        elif self.selected_action == "view_worklogs":
+            self._parent.action_view_worklogs()
+        elif self.selected_action == "view_links":
+            self._parent.action_view_links()
+        elif self.selected_action == "view_remote_links":
+            self._parent.action_view_remote_links()
+        elif self.selected_action == "attach_pull_request":
+            self._parent.action_attach_pull_request()
+        elif self.selected_action == "view_subtasks":
+            self._parent.action_view_subtasks()
+        elif self.selected_action == "clone_issue":
+            self._parent.action_clone_issue()
         elif self.selected_action == "edit_issue":
             self._parent.action_edit_issue()
         elif self.selected_action == "filter":
             self._parent.action_filter()
         elif self.selected_action == "change_board":
             self._parent.action_change_board()
+        elif self.selected_action == "change_sprint":
+            self._parent.action_change_sprint()
+        elif self.selected_action == "change_profile":
+            self._parent.action_change_profile()
+        elif self.selected_action == "projects":
+            self._parent.action_browse_projects()
+        elif self.selected_action == "query":
+            self._parent.action_jql_query()
+        elif self.selected_action == "save_board":
+            self._parent.action_save_board()
+        elif self.selected_action == "doctor":
+            self._parent.action_doctor()
+        elif self.selected_action == "export":
+            self._parent.action_export_issues()
+        elif self.selected_action == "toggle_watch":
+            self._parent.action_toggle_watch()
+        elif self.selected_action == "toggle_vote":
+            self._parent.action_toggle_vote()
+        elif self.selected_action == "create_issue":
+            self._parent.action_create_issue()
+        elif self.selected_action == "bulk_actions":
+            self._parent.action_bulk_actions()
 
     def action_select_labels(self) -> None:
         """Shortcut to select labels action."""
@@ -1630,6 +5754,36 @@ class ActionsPanel(BaseModalScreen):
         self.selected_action = "transition_issue"
         self.action_apply()
 
+    def action_select_worklogs(self) -> None:
+        """Shortcut to select worklogs action."""
+        self.selected_action = "view_worklogs"
+        self.action_apply()
+
+    def action_select_links(self) -> None:
+        """Shortcut to select links action."""
+        self.selected_action = "view_links"
+        self.action_apply()
+
+    def action_select_remote_links(self) -> None:
+        """Shortcut to select web links action."""
+        self.selected_action = "view_remote_links"
+        self.action_apply()
+
+    def action_select_pull_request(self) -> None:
+        """Shortcut to select pull request action."""
+        self.selected_action = "attach_pull_request"
+        self.action_apply()
+
+    def action_select_subtasks(self) -> None:
+        """Shortcut to select subtasks action."""
+        self.selected_action = "view_subtasks"
+        self.action_apply()
+
+    def action_select_clone(self) -> None:
+        """Shortcut to select clone action."""
+        self.selected_action = "clone_issue"
+        self.action_apply()
+
     def action_select_edit(self) -> None:
         """Shortcut to select edit action."""
         self.selected_action = "edit_issue"
@@ -1645,10 +5799,73 @@ class ActionsPanel(BaseModalScreen):
         self.selected_action = "change_board"
         self.action_apply()
 
+    def action_select_sprint(self) -> None:
+        """Shortcut to select sprint action."""
+        self.selected_action = "change_sprint"
+        self.action_apply()
+
+    def action_select_profile(self) -> None:
+        """Shortcut to select profile action."""
+        self.selected_action = "change_profile"
+        self.action_apply()
+
+    def action_select_projects(self) -> None:
+        """Shortcut to select projects action."""
+        self.selected_action = "projects"
+        self.action_apply()
+
+    def action_select_query(self) -> None:
+        """Shortcut to select JQL query action."""
+        self.selected_action = "query"
+        self.action_apply()
+
+    def action_select_save_board(self) -> None:
+        """Shortcut to select save as board action."""
+        self.selected_action = "save_board"
+        self.action_apply()
+
+    def action_select_doctor(self) -> None:
+        """Shortcut to select doctor action."""
+        self.selected_action = "doctor"
+        self.action_apply()
+
+    def action_select_export(self) -> None:
+        """Shortcut to select export action."""
+        self.selected_action = "export"
+        self.action_apply()
+
+    def action_select_watch(self) -> None:
+        """Shortcut to select watch action."""
+        self.selected_action = "toggle_watch"
+        self.action_apply()
+
+    def action_select_vote(self) -> None:
+        """Shortcut to select vote action."""
+        self.selected_action = "toggle_vote"
+        self.action_apply()
+
+    def action_select_create(self) -> None:
+        """Shortcut to select create issue action."""
+        self.selected_action = "create_issue"
+        self.action_apply()
+
+    def action_select_bulk(self) -> None:
+        """Shortcut to select bulk actions."""
+        self.selected_action = "bulk_actions"
+        self.action_apply()
+
 
 class CustomFieldEditScreen(BaseModalScreen):
     """Modal screen for editing a custom field."""
 
+    PLACEHOLDERS: ClassVar[dict[str, str]] = {
+        "date": "YYYY-MM-DD, today, tomorrow or +3d (empty to clear)",
+        "datetime": "YYYY-MM-DDTHH:MM or a date (empty to clear)",
+        "multi-option": "Values separated by commas",
+        "cascading": "Parent > child",
+        "user": "Username, or account id on Jira Cloud",
+    }
+
     BINDINGS: ClassVar[tuple[Binding, ...]] = (
         Binding("escape", "cancel", "Cancel"),
         Binding("enter", "apply", "Apply"),
@@ -1690,7 +5907,13 @@ class CustomFieldEditScreen(BaseModalScreen):
     """
 
     def __init__(
-        self, parent, issue_key: str, field_id: str, current_value: str, config: dict
+        self,
+        parent,
+        issue_key: str,
+        field_id: str,
+        current_value: str,
+        config: dict,
+        field_type: str | None = None,
     ):
         super().__init__(parent)
         self.config = config
@@ -1702,18 +5925,62 @@ class CustomFieldEditScreen(BaseModalScreen):
             if cf.get("field") == field_id:
                 self.custom_field_cfg = cf
                 break
-        if isinstance(current_value, list):
-            self.current_value = current_value[0] if current_value else ""
-        else:
-            self.current_value = current_value if current_value is not None else ""
-        self.type = (
+        self.type = field_type or (
             self.custom_field_cfg.get("type", "string")
             if self.custom_field_cfg
             else "string"
         )
+        if self.type in (*custom_fields.CHOICE_TYPES, "user"):
+            self.current_value = custom_fields.format_custom_field_value(current_value)
+        elif isinstance(current_value, list):
+            self.current_value = current_value[0] if current_value else ""
+        else:
+            self.current_value = current_value if current_value is not None else ""
         self.description = (
             self.custom_field_cfg.get("description") if self.custom_field_cfg else None
         )
+        # The field of the editmeta, for the allowed values of the choices
+        self.field_meta: dict = {}
+
+    def on_mount(self) -> None:
+        """Fetch the allowed values of a choice field, as suggestions."""
+        if self.type not in custom_fields.CHOICE_TYPES:
+            return
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.get_edit_meta,
+            self.issue_key,
+            on_result=self._show_field_meta,
+            on_error=lambda exc: self._parent.log(
+                f"Could not fetch edit metadata: {exc}"
+            ),
+            is_stale=lambda: self._popped,
+        )
+
+    def _show_field_meta(self, edit_meta: dict) -> None:
+        if self._popped:
+            return
+        self.field_meta = edit_meta.get(self.field_id, {})
+        if suggestions := self._field_suggestions():
+            is_array = self.field_meta.get("schema", {}).get("type") == "array"
+            suggester_class = SuggestFromListComma if is_array else SuggestFromList
+            self.query_one("#customfield-input", EmacsInput).suggester = (
+                suggester_class(suggestions, case_sensitive=False)
+            )
+
+    def _field_suggestions(self) -> list[str]:
+        """The allowed values of a choice field."""
+        if self.type != "cascading":
+            return models.allowed_values(self.field_meta)
+        # Cascading selects are typed as "parent > child"
+        suggestions = []
+        for parent in self.field_meta.get("allowedValues") or []:
+            suggestions.append(parent.get("value", ""))
+            suggestions += [
+                f"{parent.get('value', '')} {custom_fields.CASCADE_SEPARATOR} "
+                f"{child.get('value', '')}"
+                for child in parent.get("children") or []
+            ]
+        return suggestions
 
     def compose(self) -> ComposeResult:
         with Vertical(id="customfield-container"):
@@ -1738,7 +6005,7 @@ class CustomFieldEditScreen(BaseModalScreen):
                 from .enhanced_widgets import EmacsInput
 
                 yield EmacsInput(
-                    placeholder="Enter new value",
+                    placeholder=self.PLACEHOLDERS.get(self.type, "Enter new value"),
                     id="customfield-input",
                     value=self.current_value,
                 )
@@ -1759,28 +6026,32 @@ class CustomFieldEditScreen(BaseModalScreen):
             self._parent.notify("No changes made", severity="warning")
             self.safe_pop_screen()
             return
-        if self.type == "url":
-            url_regex = re.compile(r"^(https?|ftp)://[^\s/$.?#].[^\s]*$")
-            if not url_regex.match(value):
-                self._parent.notify("Invalid URL format", severity="error")
-                return
-        if self.type == "number":
-            try:
-                value = float(value) if "." in value else int(value)
-            except (ValueError, TypeError):
-                self._parent.notify("Invalid number format", severity="error")
-                return
         try:
-            self._parent.jayrah_obj.jira.update_issue(
-                self.issue_key, {self.field_id: value}
+            value = custom_fields.parse_custom_field_value(
+                self.type,
+                value,
+                meta=self.field_meta,
+                format_user=self._parent.jayrah_obj.jira.formatter.format_assignee,
             )
-            detail_panel = self._parent.query_one(IssueDetailPanel)
-            if detail_panel.ticket == self.issue_key:
-                detail_panel.update_issue(
-                    self.issue_key, self._parent.config, use_cache=False
-                )
-            self._parent.action_reload()
-            self._parent.notify(f"✅ Updated {self.field_id} for {self.issue_key}")
-        except jira_exceptions.JiraAPIError as exc:
-            self._parent.notify(f"Error updating field: {exc}", severity="error")
+        except ValueError as exc:
+            self._parent.notify(str(exc), severity="error")
+            return
+        self._parent.worker_pool.submit(
+            self._parent.jayrah_obj.jira.update_issue,
+            self.issue_key,
+            {self.field_id: value},
+            on_result=lambda _result: self._field_updated(),
+            on_error=lambda exc: self._parent.notify(
+                f"Error updating field: {exc}", severity="error"
+            ),
+        )
         self.safe_pop_screen()
+
+    def _field_updated(self) -> None:
+        detail_panel = self._parent.query_one(IssueDetailPanel)
+        if detail_panel.ticket == self.issue_key:
+            detail_panel.update_issue(
+                self.issue_key, self._parent.config, use_cache=False
+            )
+        self._parent.action_reload()
+        self._parent.notify(f"✅ Updated {self.field_id} for {self.issue_key}")
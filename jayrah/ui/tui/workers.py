@@ -0,0 +1,133 @@
+"""Shared worker pool for running blocking Jira calls off the UI thread."""
+
+import threading
+from collections.abc import Callable, Hashable
+from concurrent.futures import Future, ThreadPoolExecutor
+from dataclasses import dataclass
+from typing import Any
+
+DEFAULT_MAX_WORKERS = 4
+
+
+@dataclass
+class _Waiter:
+    """The callbacks of one submission of a request."""
+
+    on_result: Callable[[Any], Any] | None
+    on_error: Callable[[Exception], Any] | None
+    is_stale: Callable[[], bool] | None
+
+    def stale(self) -> bool:
+        return self.is_stale is not None and self.is_stale()
+
+
+class JiraWorkerPool:
+    """
+    A bounded pool running Jira requests and dispatching results back to the UI.
+
+    Every blocking request goes through `submit`, results and errors are handed
+    to the callbacks through `dispatch` (usually `App.call_from_thread`) so they
+    always run on the UI thread.
+    """
+
+    def __init__(
+        self,
+        dispatch: Callable[..., Any],
+        max_workers: int = DEFAULT_MAX_WORKERS,
+    ):
+        self._dispatch = dispatch
+        self._executor = ThreadPoolExecutor(
+            max_workers=max_workers, thread_name_prefix="jayrah-jira"
+        )
+        self._lock = threading.Lock()
+        # Future and submissions of the keyed requests queued or running
+        self._in_flight: dict[Hashable, tuple[Future, list[_Waiter]]] = {}
+        self._pending = 0
+
+    @property
+    def pending(self) -> int:
+        """Number of requests queued or running, for the loading spinners."""
+        with self._lock:
+            return self._pending
+
+    def _track(self, future: Future) -> Future:
+        with self._lock:
+            self._pending += 1
+        future.add_done_callback(self._untrack)
+        return future
+
+    def _untrack(self, future: Future) -> None:
+        with self._lock:
+            self._pending -= 1
+
+    def submit(
+        self,
+        func: Callable[..., Any],
+        *args,
+        on_result: Callable[[Any], Any] | None = None,
+        on_error: Callable[[Exception], Any] | None = None,
+        is_stale: Callable[[], bool] | None = None,
+        key: Hashable | None = None,
+        **kwargs,
+    ) -> Future:
+        """
+        Queue `func(*args, **kwargs)` on the pool.
+
+        When `is_stale` returns True by the time the job is picked up, the
+        request is skipped entirely and no callback is called.
+
+        Requests submitted with the same `key` while one is queued or running
+        share it, the callbacks of each submission get its result instead of
+        the request being sent twice, and it is only skipped once all of them
+        are stale.
+        """
+        waiter = _Waiter(on_result, on_error, is_stale)
+        submissions = [waiter]
+
+        def answered() -> list[_Waiter]:
+            """The submissions to answer, no more can join once taken."""
+            if key is not None:
+                with self._lock:
+                    self._in_flight.pop(key, None)
+            return submissions
+
+        def job():
+            if key is None:
+                all_stale = waiter.stale()
+            else:
+                with self._lock:
+                    all_stale = all(x.stale() for x in submissions)
+                    if all_stale:
+                        self._in_flight.pop(key, None)
+            if all_stale:
+                return None
+            try:
+                result = func(*args, **kwargs)
+            except Exception as exc:  # pylint: disable=broad-exception-caught
+                errored = answered()
+                for x in errored:
+                    if x.on_error is not None:
+                        self._dispatch(x.on_error, exc)
+                if any(x.on_error is None for x in errored):
+                    raise
+                return None
+            for x in answered():
+                if x.on_result is not None:
+                    self._dispatch(x.on_result, result)
+            return result
+
+        if key is None:
+            return self._track(self._executor.submit(job))
+        with self._lock:
+            if key in self._in_flight:
+                future, waiting = self._in_flight[key]
+                waiting.append(waiter)
+                return future
+            # The job waits for the lock, so it can't finish before this
+            future = self._executor.submit(job)
+            self._in_flight[key] = (future, submissions)
+        return self._track(future)
+
+    def shutdown(self) -> None:
+        """Stop accepting requests and drop the ones not started yet."""
+        self._executor.shutdown(wait=False, cancel_futures=True)
@@ -1,14 +1,31 @@
 """Jayrah utility functions and helpers."""
 
 import datetime
+import importlib.metadata
 import os
+import re
+import shlex
 import subprocess
 import sys
 import tempfile
+import urllib.parse
 import webbrowser
 
 import click
 
+# An issue key alone, like JAY-123, or at the end of a browse URL
+ISSUE_ARGUMENT_RE = re.compile(
+    r"^(?:https?://[^\s]+/browse/)?([A-Za-z][A-Za-z0-9_]*-\d+)/?(?:[?#].*)?$"
+)
+
+
+def package_version() -> str:
+    """The installed version of jayrah, dev when run from a checkout."""
+    try:
+        return importlib.metadata.version("jayrah")
+    except importlib.metadata.PackageNotFoundError:
+        return "dev"
+
 
 def make_osc8_link(text, url):
     return f"\033]8;;{url}\033\\{text}\033]8;;\033\\"
@@ -20,19 +37,58 @@ def make_full_url(ticket, server):
     return f"{server}/browse/{ticket}"
 
 
+def issue_key_from_text(text):
+    """The issue key of a key or browse URL pasted by the user, None otherwise."""
+    match = ISSUE_ARGUMENT_RE.match(text.strip())
+    return match.group(1).upper() if match else None
+
+
+def make_search_url(jql, server):
+    """URL of the issue search of Jira showing the issues of a JQL query."""
+    if server is None:
+        raise ValueError("No Jira server URL provided")
+    return f"{server}/issues/?jql={urllib.parse.quote(jql)}"
+
+
+def browser_open(url, config=None):
+    """
+    Open a URL in the browser.
+
+    The browser option of the config is a command run instead of the default
+    browser, like `firefox --new-tab`, the URL replaces a `%s` in it or comes
+    last. A command that cannot be run raises an OSError, one with unbalanced
+    quotes a ValueError.
+    """
+    command = (config or {}).get("browser")
+    if not command:
+        webbrowser.open(url)
+        return
+    args = shlex.split(command)
+    if "%s" in args:
+        args = [url if x == "%s" else x for x in args]
+    else:
+        args.append(url)
+    # Detached so the browser outlives jayrah and doesn't write over the TUI
+    subprocess.Popen(  # pylint: disable=consider-using-with
+        args,
+        stdin=subprocess.DEVNULL,
+        stdout=subprocess.DEVNULL,
+        stderr=subprocess.DEVNULL,
+        start_new_session=True,
+    )
+
+
 def browser_open_ticket(ticket, config):
+    """Open an issue in the browser, the project without ticket."""
     server = config.get("jira_server")
     if not ticket:
         project = config.get("jira_component")
         if not project:
             raise ValueError("No ticket or project specified")
-        webbrowser.open(f"{server}/projects/{project}")
+        browser_open(f"{server}/projects/{project}", config)
         return
 
-    try:
-        webbrowser.open(make_full_url(ticket, server))
-    except Exception as e:
-        click.secho(f"Failed to open URL {ticket}: {e}", fg="red")
+    browser_open(make_full_url(ticket, server), config)
 
 
 def log(message, level="INFO", verbose_only=False, verbose=False, file=sys.stdout):
@@ -75,15 +131,6 @@ def parse_email(dico):
     return s.split("@")[0].split("+")[0]
 
 
-def get_pass_key(pass_cmd, s):
-    cmd = [pass_cmd, "show", s]
-    try:
-        return subprocess.check_output(cmd, text=True).strip()
-    except subprocess.CalledProcessError:
-        click.secho(f"Failed to retrieve password for {s}", fg="red")
-        return None
-
-
 def edit_text_with_editor(initial_text, extension=".md"):
     """Edit text using the system's default editor"""
     # Use the EDITOR environment variable, or default to vi
@@ -1,6 +1,9 @@
 """Utilities for handling Atlassian Document Format (ADF)."""
 
 import json
+from datetime import datetime, timezone
+
+from . import adf_document
 
 
 def extract_text_from_adf(adf_data):
@@ -48,6 +51,152 @@ def extract_text_from_adf(adf_data):
     return "\n".join(text_parts)
 
 
+def _apply_marks(node: adf_document.Text) -> str:
+    """Wrap a text node in the Markdown of its bold, italic, code or link marks."""
+    text = node.text
+    for mark in node.marks:
+        if mark.type == "code":
+            text = f"`{text}`"
+        elif mark.type == "strong":
+            text = f"**{text}**"
+        elif mark.type == "em":
+            text = f"*{text}*"
+        elif mark.type == "strike":
+            text = f"~~{text}~~"
+        elif mark.type == "link":
+            text = f"[{text}]({mark.attrs.get('href', '')})"
+    return text
+
+
+def _inline_to_markdown(nodes: list) -> str:
+    parts = []
+    for node in nodes:
+        if isinstance(node, adf_document.Text):
+            parts.append(_apply_marks(node))
+        elif isinstance(node, adf_document.HardBreak):
+            parts.append("  \n")
+        elif isinstance(node, adf_document.Mention):
+            parts.append(f"**{node.text or '@' + node.id}**")
+        elif isinstance(node, adf_document.Emoji):
+            parts.append(node.text or node.short_name)
+        elif isinstance(node, adf_document.InlineCard):
+            parts.append(f"<{node.url}>")
+        elif isinstance(node, adf_document.Status):
+            parts.append(f"`{node.text}`")
+        elif isinstance(node, adf_document.Date):
+            parts.append(_format_timestamp(node.timestamp))
+        elif isinstance(node, adf_document.Unknown):
+            parts.append(extract_text_from_adf(node.raw).replace("\n", " "))
+    return "".join(parts)
+
+
+def _format_timestamp(timestamp: str) -> str:
+    """ADF dates are milliseconds since the epoch."""
+    try:
+        moment = datetime.fromtimestamp(int(timestamp) / 1000, tz=timezone.utc)
+    except ValueError:
+        return timestamp
+    return moment.strftime("%Y-%m-%d")
+
+
+def _quote(lines: list[str]) -> list[str]:
+    return ["> " + line if line else ">" for line in lines]
+
+
+def _table_to_markdown(table: adf_document.Table) -> list[str]:
+    """A Markdown table, the first row is the header like in Jira."""
+    rows = []
+    for row in table.rows:
+        cells = []
+        for cell in row.cells:
+            text = " ".join(" ".join(_blocks_to_markdown(cell.content)).split())
+            cells.append(text.replace("|", "\\|"))
+        rows.append(cells)
+    if not rows:
+        return []
+    width = max(len(row) for row in rows)
+    rows = [row + [""] * (width - len(row)) for row in rows]
+    lines = [f"| {' | '.join(rows[0])} |", f"| {' | '.join(['---'] * width)} |"]
+    lines += [f"| {' | '.join(row)} |" for row in rows[1:]]
+    return lines
+
+
+def _block_to_markdown(block, indent: str = "") -> list[str]:
+    """Markdown lines of a block, list items nest with indent."""
+    if isinstance(block, adf_document.Paragraph):
+        return [indent + _inline_to_markdown(block.content)]
+    if isinstance(block, adf_document.Heading):
+        return [f"{indent}{'#' * block.level} {_inline_to_markdown(block.content)}"]
+    if isinstance(block, adf_document.CodeBlock):
+        fence = f"{indent}```"
+        code_lines = [indent + line for line in block.code.split("\n")]
+        return [fence + block.language, *code_lines, fence]
+    # Panels (info, warning...) have no Markdown equivalent, shown as quotes
+    if isinstance(block, (adf_document.Blockquote, adf_document.Panel)):
+        return [indent + line for line in _quote(_blocks_to_markdown(block.content))]
+    if isinstance(block, adf_document.Rule):
+        return [indent + "---"]
+    if isinstance(block, (adf_document.BulletList, adf_document.OrderedList)):
+        ordered = isinstance(block, adf_document.OrderedList)
+        start = block.start if isinstance(block, adf_document.OrderedList) else 1
+        lines = []
+        for number, item in enumerate(block.items, start=start):
+            bullet = f"{number}. " if ordered else "- "
+            item_lines = []
+            for child in item.content:
+                item_lines += _block_to_markdown(child, indent + "  ")
+            if item_lines:
+                item_lines[0] = indent + bullet + item_lines[0].lstrip()
+            lines += item_lines
+        return lines
+    if isinstance(block, adf_document.Table):
+        return [indent + line for line in _table_to_markdown(block)]
+    if isinstance(block, adf_document.Media):
+        if block.url:
+            return [f"{indent}![{block.alt}]({block.url})"]
+        return [f"{indent}📎 *{block.alt or 'attachment'}*"]
+    if isinstance(block, adf_document.Expand):
+        lines = [f"{indent}**▸ {block.title}**"] if block.title else []
+        return lines + [indent + x for x in _blocks_to_markdown(block.content)]
+    if isinstance(block, adf_document.Unknown):
+        text = extract_text_from_adf(block.raw) if "content" in block.raw else ""
+        return [indent + text] if text else []
+    return []
+
+
+def _blocks_to_markdown(blocks: list) -> list[str]:
+    """Lines of consecutive blocks, separated by an empty line."""
+    lines: list[str] = []
+    for block in blocks:
+        block_lines = _block_to_markdown(block)
+        if block_lines:
+            if lines:
+                lines.append("")
+            lines += block_lines
+    return lines
+
+
+def document_to_markdown(document: adf_document.Document) -> str:
+    """Render a parsed ADF document as Markdown."""
+    return "\n".join(_blocks_to_markdown(document.content))
+
+
+def adf_to_markdown(adf_data) -> str:
+    """Render an ADF document as Markdown, keeping its formatting.
+
+    Unlike extract_text_from_adf this keeps headings, lists, code blocks,
+    tables, quotes, links and inline marks so the TUI can show them styled.
+    """
+    if isinstance(adf_data, str):
+        try:
+            adf_data = json.loads(adf_data)
+        except json.JSONDecodeError:
+            return adf_data
+    if not isinstance(adf_data, dict) or "content" not in adf_data:
+        return extract_text_from_adf(adf_data)
+    return document_to_markdown(adf_document.parse(adf_data))
+
+
 def create_adf_from_text(text):
     """Create a simple ADF document from plain text.
 
@@ -0,0 +1,290 @@
+"""
+Structured tree of an Atlassian Document Format (ADF) document.
+
+`parse` turns the ADF JSON sent by API v3 into typed nodes so renderers like
+the Markdown one of the detail pane or the exports don't have to walk raw
+dictionaries. Nodes this module does not know are kept as `Unknown` with
+their raw JSON so nothing gets silently dropped.
+"""
+
+import json
+from dataclasses import dataclass, field
+from typing import Any, Union
+
+
+@dataclass
+class Mark:
+    """Inline formatting: strong, em, code, strike, underline, link..."""
+
+    type: str
+    attrs: dict[str, Any] = field(default_factory=dict)
+
+
+@dataclass
+class Text:
+    text: str
+    marks: list[Mark] = field(default_factory=list)
+
+    def has_mark(self, mark_type: str) -> bool:
+        return any(mark.type == mark_type for mark in self.marks)
+
+    @property
+    def href(self) -> str | None:
+        """Target of the link mark, None when the text is not a link."""
+        for mark in self.marks:
+            if mark.type == "link":
+                return mark.attrs.get("href")
+        return None
+
+
+@dataclass
+class Mention:
+    id: str
+    text: str = ""
+
+
+@dataclass
+class Emoji:
+    short_name: str
+    text: str = ""
+
+
+@dataclass
+class HardBreak:
+    pass
+
+
+@dataclass
+class InlineCard:
+    url: str
+
+
+@dataclass
+class Status:
+    text: str
+    color: str = ""
+
+
+@dataclass
+class Date:
+    timestamp: str
+
+
+Inline = Union[Text, Mention, Emoji, HardBreak, InlineCard, Status, Date, "Unknown"]
+
+
+@dataclass
+class Paragraph:
+    content: list[Inline] = field(default_factory=list)
+
+
+@dataclass
+class Heading:
+    level: int
+    content: list[Inline] = field(default_factory=list)
+
+
+@dataclass
+class CodeBlock:
+    code: str
+    language: str = ""
+
+
+@dataclass
+class Blockquote:
+    content: list["Block"] = field(default_factory=list)
+
+
+@dataclass
+class Panel:
+    """Info, note, warning, success or error box."""
+
+    panel_type: str
+    content: list["Block"] = field(default_factory=list)
+
+
+@dataclass
+class Rule:
+    pass
+
+
+@dataclass
+class ListItem:
+    content: list["Block"] = field(default_factory=list)
+
+
+@dataclass
+class BulletList:
+    items: list[ListItem] = field(default_factory=list)
+
+
+@dataclass
+class OrderedList:
+    items: list[ListItem] = field(default_factory=list)
+    start: int = 1
+
+
+@dataclass
+class TableCell:
+    header: bool = False
+    content: list["Block"] = field(default_factory=list)
+
+
+@dataclass
+class TableRow:
+    cells: list[TableCell] = field(default_factory=list)
+
+
+@dataclass
+class Table:
+    rows: list[TableRow] = field(default_factory=list)
+
+
+@dataclass
+class Media:
+    """An attachment or an external image, only referenced by ADF."""
+
+    id: str = ""
+    media_type: str = "file"
+    collection: str = ""
+    url: str = ""
+    alt: str = ""
+
+
+@dataclass
+class Expand:
+    """A collapsible section with a title."""
+
+    title: str = ""
+    content: list["Block"] = field(default_factory=list)
+
+
+@dataclass
+class Unknown:
+    """A node type this module does not handle, raw is the original JSON."""
+
+    type: str
+    raw: dict[str, Any] = field(default_factory=dict)
+
+
+Block = Union[
+    Paragraph,
+    Heading,
+    CodeBlock,
+    Blockquote,
+    Panel,
+    Rule,
+    BulletList,
+    OrderedList,
+    Table,
+    Media,
+    Expand,
+    Unknown,
+]
+
+
+@dataclass
+class Document:
+    content: list[Block] = field(default_factory=list)
+    version: int = 1
+
+
+def _parse_mark(mark: dict) -> Mark:
+    return Mark(mark.get("type", ""), mark.get("attrs") or {})
+
+
+def _parse_inline(node: dict) -> Inline:
+    node_type = node.get("type", "")
+    attrs = node.get("attrs") or {}
+    if node_type == "text":
+        marks = node.get("marks") or []
+        return Text(node.get("text", ""), [_parse_mark(x) for x in marks])
+    if node_type == "mention":
+        return Mention(str(attrs.get("id", "")), attrs.get("text", ""))
+    if node_type == "emoji":
+        return Emoji(attrs.get("shortName", ""), attrs.get("text", ""))
+    if node_type == "hardBreak":
+        return HardBreak()
+    if node_type == "inlineCard":
+        return InlineCard(attrs.get("url", ""))
+    if node_type == "status":
+        return Status(attrs.get("text", ""), attrs.get("color", ""))
+    if node_type == "date":
+        return Date(str(attrs.get("timestamp", "")))
+    return Unknown(node_type, node)
+
+
+def _parse_inlines(nodes: list) -> list[Inline]:
+    return [_parse_inline(node) for node in nodes or []]
+
+
+def _parse_blocks(nodes: list) -> list[Block]:
+    blocks: list[Block] = []
+    for node in nodes or []:
+        blocks.extend(_parse_block(node))
+    return blocks
+
+
+def _parse_media(node: dict) -> Media:
+    attrs = node.get("attrs") or {}
+    return Media(
+        id=str(attrs.get("id", "")),
+        media_type=attrs.get("type", "file"),
+        collection=attrs.get("collection", ""),
+        url=attrs.get("url", ""),
+        alt=attrs.get("alt", ""),
+    )
+
+
+def _parse_block(node: dict) -> list[Block]:
+    """The blocks of a node, media groups give one block per media."""
+    node_type = node.get("type", "")
+    attrs = node.get("attrs") or {}
+    content = node.get("content") or []
+
+    if node_type == "paragraph":
+        return [Paragraph(_parse_inlines(content))]
+    if node_type == "heading":
+        return [Heading(int(attrs.get("level", 1)), _parse_inlines(content))]
+    if node_type == "codeBlock":
+        code = "".join(x.get("text", "") for x in content)
+        return [CodeBlock(code, attrs.get("language") or "")]
+    if node_type == "blockquote":
+        return [Blockquote(_parse_blocks(content))]
+    if node_type == "panel":
+        return [Panel(attrs.get("panelType", "info"), _parse_blocks(content))]
+    if node_type == "rule":
+        return [Rule()]
+    if node_type in ("bulletList", "orderedList"):
+        items = [ListItem(_parse_blocks(x.get("content"))) for x in content]
+        if node_type == "orderedList":
+            return [OrderedList(items, int(attrs.get("order", 1)))]
+        return [BulletList(items)]
+    if node_type == "table":
+        rows = []
+        for row in content:
+            cells = [
+                TableCell(
+                    header=x.get("type") == "tableHeader",
+                    content=_parse_blocks(x.get("content")),
+                )
+                for x in row.get("content") or []
+            ]
+            rows.append(TableRow(cells))
+        return [Table(rows)]
+    if node_type in ("mediaSingle", "mediaGroup"):
+        return [_parse_media(x) for x in content if x.get("type") == "media"]
+    if node_type == "media":
+        return [_parse_media(node)]
+    if node_type in ("expand", "nestedExpand"):
+        return [Expand(attrs.get("title", ""), _parse_blocks(content))]
+    return [Unknown(node_type, node)]
+
+
+def parse(adf_data: dict | str) -> Document:
+    """Build the Document tree of ADF JSON, given as a dict or a string."""
+    if isinstance(adf_data, str):
+        adf_data = json.loads(adf_data)
+    return Document(
+        _parse_blocks(adf_data.get("content")), int(adf_data.get("version", 1))
+    )
@@ -6,12 +6,59 @@ import pickle
 import sqlite3
 import threading
 import time
+from collections import OrderedDict
+from collections.abc import Iterator, MutableMapping
 from pathlib import Path
 from typing import Any
 
 from jayrah import utils
 
 
+class LRUCache(MutableMapping):
+    """
+    An in-memory mapping keeping the max_size most recently used entries.
+
+    Entries older than ttl seconds are dropped as if they were never set, a
+    ttl of 0 keeps them until they are evicted.
+    """
+
+    def __init__(self, max_size: int, ttl: float = 0):
+        self.max_size = max(int(max_size), 1)
+        self.ttl = float(ttl)
+        self._entries: OrderedDict[Any, tuple[Any, float]] = OrderedDict()
+
+    def expired(self, key: Any) -> bool:
+        """Whether the key was set but is now too old to be used."""
+        entry = self._entries.get(key)
+        return (
+            entry is not None
+            and self.ttl > 0
+            and time.monotonic() - entry[1] > self.ttl
+        )
+
+    def __getitem__(self, key: Any) -> Any:
+        if self.expired(key):
+            del self._entries[key]
+        value, _ = self._entries[key]
+        self._entries.move_to_end(key)
+        return value
+
+    def __setitem__(self, key: Any, value: Any) -> None:
+        self._entries[key] = (value, time.monotonic())
+        self._entries.move_to_end(key)
+        while len(self._entries) > self.max_size:
+            self._entries.popitem(last=False)
+
+    def __delitem__(self, key: Any) -> None:
+        del self._entries[key]
+
+    def __iter__(self) -> Iterator[Any]:
+        return iter(list(self._entries))
+
+    def __len__(self) -> int:
+        return len(self._entries)
+
+
 class JiraCache:
     """Cache for Jira API requests."""
 
@@ -95,7 +142,7 @@ class JiraCache:
             self._preloaded_cache = {k: (d, t) for k, d, t in rows}
 
     def get(
-        self, url: str, params: dict | None = None, data: dict | None = None
+        self, url: str, params: dict | None = None, data: dict | str | None = None
     ) -> dict | None:
         """Get a cached response."""
         if self.config.get("no_cache"):
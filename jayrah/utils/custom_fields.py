@@ -0,0 +1,171 @@
+"""Conversion of the values typed for custom fields to what Jira expects."""
+
+import datetime
+import re
+from collections.abc import Callable
+from typing import Any
+
+from ..api import models
+
+URL_RE = re.compile(r"^(https?|ftp)://[^\s/$.?#].[^\s]*$")
+RELATIVE_DATE_RE = re.compile(r"^([+-]\d+)([dw])$")
+FIELD_TYPES = (
+    "string",
+    "text",
+    "number",
+    "url",
+    "date",
+    "datetime",
+    "option",
+    "multi-option",
+    "user",
+    "version",
+    "cascading",
+)
+# Types whose values come from a list, checked against the editmeta
+CHOICE_TYPES = ("option", "multi-option", "version", "cascading")
+# Separator of the parent and child values of a cascading select
+CASCADE_SEPARATOR = ">"
+
+# What Jira wants for datetime fields, milliseconds and a numeric offset
+JIRA_DATETIME_FORMAT = "%Y-%m-%dT%H:%M:%S.000%z"
+
+
+def parse_date(text: str, today: datetime.date | None = None) -> datetime.date:
+    """
+    A date given as YYYY-MM-DD, today, tomorrow, yesterday or relative.
+
+    Relative dates count days or weeks from today, like +3d or -1w.
+    """
+    today = today or datetime.date.today()
+    text = text.strip().lower()
+    named = {"today": 0, "tomorrow": 1, "yesterday": -1}
+    if text in named:
+        return today + datetime.timedelta(days=named[text])
+    if match := RELATIVE_DATE_RE.match(text):
+        amount, unit = int(match.group(1)), match.group(2)
+        return today + datetime.timedelta(days=amount * (7 if unit == "w" else 1))
+    try:
+        return datetime.date.fromisoformat(text)
+    except ValueError as exc:
+        raise ValueError(
+            f"Invalid date '{text}', use YYYY-MM-DD, today, tomorrow or +3d"
+        ) from exc
+
+
+def parse_datetime(text: str, now: datetime.datetime | None = None) -> str:
+    """An ISO-8601 date and time, or a date form of `parse_date` at midnight."""
+    now = now or datetime.datetime.now().astimezone()
+    try:
+        value = datetime.datetime.fromisoformat(text.strip())
+    except ValueError:
+        day = parse_date(text, now.date())
+        value = datetime.datetime.combine(day, datetime.time(), now.tzinfo)
+    if value.tzinfo is None:
+        value = value.replace(tzinfo=now.tzinfo)
+    return value.strftime(JIRA_DATETIME_FORMAT)
+
+
+def _allowed(meta: dict[str, Any] | None, text: str) -> str:
+    """The exact allowed name of a value, when the field meta is known."""
+    if not meta or not models.allowed_values(meta):
+        return text
+    name = models.match_allowed_value(meta, text)
+    if name is None:
+        choices = ", ".join(models.allowed_values(meta))
+        raise ValueError(f"'{text}' is not one of {choices}")
+    return name
+
+
+def _split(text: str) -> list[str]:
+    return [x.strip() for x in text.split(",") if x.strip()]
+
+
+def _parse_cascading(text: str, meta: dict[str, Any] | None) -> dict[str, Any]:
+    parent, _, child = (x.strip() for x in text.partition(CASCADE_SEPARATOR))
+    parent = _allowed(meta, parent)
+    value: dict[str, Any] = {"value": parent}
+    if child:
+        # The children allowed under the parent hang from its allowed value
+        parent_meta = next(
+            (
+                x
+                for x in (meta or {}).get("allowedValues") or []
+                if x.get("value") == parent
+            ),
+            None,
+        )
+        children = (parent_meta or {}).get("children") or []
+        if parent_meta is not None and not children:
+            raise ValueError(f"'{parent}' has no child values")
+        value["child"] = {"value": _allowed({"allowedValues": children}, child)}
+    return value
+
+
+def parse_custom_field_value(
+    field_type: str,
+    value: Any,
+    meta: dict[str, Any] | None = None,
+    format_user: Callable[[str], dict[str, str]] | None = None,
+) -> Any:
+    """
+    The value to send for a custom field of the given type.
+
+    meta is the field of the editmeta, the choices are checked against its
+    allowed values when given. format_user builds the user reference of the
+    API version, the v2 username by default.
+
+    Raises ValueError with a message for the user when it does not fit the
+    type. Empty dates and choices clear the field.
+    """
+    text = "" if value is None else str(value).strip()
+    is_array = (meta or {}).get("schema", {}).get("type") == "array"
+    if field_type == "number":
+        try:
+            return float(text) if "." in text else int(text)
+        except ValueError as exc:
+            raise ValueError("Invalid number format") from exc
+    if field_type == "url":
+        if text and not URL_RE.match(text):
+            raise ValueError("Invalid URL format")
+        return text
+    if field_type == "date":
+        return parse_date(text).isoformat() if text else None
+    if field_type == "datetime":
+        return parse_datetime(text) if text else None
+    if field_type == "option":
+        return {"value": _allowed(meta, text)} if text else None
+    if field_type == "multi-option":
+        return [{"value": _allowed(meta, x)} for x in _split(text)]
+    if field_type == "version":
+        if is_array:
+            return [{"name": _allowed(meta, x)} for x in _split(text)]
+        return {"name": _allowed(meta, text)} if text else None
+    if field_type == "cascading":
+        return _parse_cascading(text, meta) if text else None
+    if field_type == "user":
+        if not text:
+            return None
+        return (format_user or (lambda name: {"name": name}))(text)
+    return text
+
+
+def format_custom_field_value(value: Any) -> str:
+    """The text to edit for the API value of a custom field."""
+    if value is None:
+        return ""
+    if isinstance(value, list):
+        return ", ".join(format_custom_field_value(x) for x in value)
+    if isinstance(value, dict):
+        # Users are edited by the id the API takes back, not their display name
+        text = (
+            value.get("value")
+            or value.get("name")
+            or value.get("accountId")
+            or value.get("displayName")
+            or ""
+        )
+        if child := value.get("child"):
+            text += f" {CASCADE_SEPARATOR} {format_custom_field_value(child)}"
+        return str(text)
+    return str(value)
@@ -0,0 +1,75 @@
+"""Export issue lists to CSV or Markdown tables, e.g. for status reports."""
+
+import csv
+import io
+import pathlib
+
+from ..api import models
+
+FORMATS = {".csv": "csv", ".md": "markdown", ".markdown": "markdown"}
+
+
+def issue_rows(issues: list, columns: list[str], server: str = "") -> list[dict]:
+    """The values of the wanted columns for each issue."""
+    rows = []
+    for issue in issues:
+        row = models.ListIssue.from_api(issue).to_dict()
+        row["url"] = f"{server.rstrip('/')}/browse/{row['key']}" if server else ""
+        unknown = [x for x in columns if x not in row]
+        if unknown:
+            raise ValueError(f"Unknown export columns: {', '.join(unknown)}")
+        rows.append({x: row[x] for x in columns})
+    return rows
+
+
+def _cell(value) -> str:
+    if value is None:
+        return ""
+    if isinstance(value, list):
+        return ", ".join(str(x) for x in value)
+    return str(value)
+
+
+def to_csv(rows: list[dict], columns: list[str]) -> str:
+    output = io.StringIO()
+    writer = csv.writer(output)
+    writer.writerow(columns)
+    for row in rows:
+        writer.writerow([_cell(row[x]) for x in columns])
+    return output.getvalue()
+
+
+def to_markdown(rows: list[dict], columns: list[str], server: str = "") -> str:
+    """A Markdown table, the keys link to the issues when the server is known."""
+
+    def markdown_cell(column: str, row: dict) -> str:
+        value = " ".join(_cell(row[column]).split()).replace("|", "\\|")
+        if column == "key" and server:
+            return f"[{value}]({server.rstrip('/')}/browse/{value})"
+        return value
+
+    lines = [
+        f"| {' | '.join(columns)} |",
+        f"| {' | '.join('---' for _ in columns)} |",
+    ]
+    for row in rows:
+        lines.append(f"| {' | '.join(markdown_cell(x, row) for x in columns)} |")
+    return "\n".join(lines) + "\n"
+
+
+def export_issues(
+    issues: list, path: pathlib.Path, columns: list[str], server: str = ""
+) -> int:
+    """Write the issues to a .csv or .md file, returns the number written."""
+    path = pathlib.Path(path).expanduser()
+    export_format = FORMATS.get(path.suffix.lower())
+    if export_format is None:
+        raise ValueError(f"Cannot export to {path.name}, use a .csv or .md file")
+
+    rows = issue_rows(issues, columns, server)
+    if export_format == "csv":
+        content = to_csv(rows, columns)
+    else:
+        content = to_markdown(rows, columns, server)
+    path.write_text(content, encoding="utf-8")
+    return len(rows)
@@ -0,0 +1,95 @@
+"""Git branch names from Jira issues and issues from git branches."""
+
+import pathlib
+import re
+import subprocess
+
+from ..config import defaults
+
+ISSUE_KEY_RE = re.compile(r"(?<![A-Za-z0-9])([A-Za-z][A-Za-z0-9_]*-\d+)", re.ASCII)
+PULL_REQUEST_RE = re.compile(
+    r"^https?://(?P<host>[^/]+)/(?P<repo>[^?#]+?)/(?:-/)?"
+    r"(?P<kind>pull|merge_requests|pull-requests)/(?P<number>\d+)"
+)
+# Name of the forge by the path of its pull requests, /pull/ is GitHub's
+FORGES = {"pull": "GitHub", "merge_requests": "GitLab", "pull-requests": "Bitbucket"}
+
+
+def branch_name(issue_key: str, summary: str) -> str:
+    """
+    A branch name for an issue, like JAY-123-fix-login-redirect.
+
+    The summary is turned into a lowercase slug cut on a word boundary so
+    the name stays short enough to type.
+    """
+    slug = re.sub(r"[^a-z0-9]+", "-", summary.lower()).strip("-")
+    if len(slug) > defaults.BRANCH_SLUG_LENGTH:
+        slug = slug[: defaults.BRANCH_SLUG_LENGTH + 1].rsplit("-", 1)[0]
+    return f"{issue_key}-{slug}" if slug else issue_key
+
+
+def current_branch(cwd: str | None = None) -> str | None:
+    """The branch checked out in a repository, None when HEAD is detached."""
+    try:
+        result = subprocess.run(
+            ["git", "rev-parse", "--abbrev-ref", "HEAD"],
+            cwd=cwd,
+            capture_output=True,
+            text=True,
+            check=True,
+        )
+    except (subprocess.CalledProcessError, FileNotFoundError):
+        return None
+    branch = result.stdout.strip()
+    return branch if branch and branch != "HEAD" else None
+
+
+def issue_key_from_branch(branch: str, project: str | None = None) -> str | None:
+    """
+    The issue key of a branch name, like JAY-123 in feature/jay-123-fix-login.
+
+    Keys are matched case insensitively as branch names are often lowercase,
+    a key of project is preferred when the branch has several candidates.
+    """
+    keys = [x.upper() for x in ISSUE_KEY_RE.findall(branch)]
+    if project:
+        keys.sort(key=lambda x: not x.startswith(f"{project.upper()}-"))
+    return keys[0] if keys else None
+
+
+def commit_message(issue_key: str, summary: str) -> str:
+    """The first line of a commit for an issue."""
+    return f"{issue_key}: {summary}"
+
+
+def prepare_commit_message(path: pathlib.Path, line: str, issue_key: str) -> bool:
+    """
+    Put line first in a commit message file, like a prepare-commit-msg hook.
+
+    The file is left alone when it mentions the issue already, so amending
+    or rewording a commit doesn't add it twice. Returns if it was written.
+    """
+    message = path.read_text() if path.exists() else ""
+    # JAY-1 is not mentioned by JAY-12 nor XJAY-1
+    if re.search(rf"\b{re.escape(issue_key)}\b", message):
+        return False
+    path.write_text(f"{line}\n{message}")
+    return True
+
+
+def pull_request_info(url: str) -> dict[str, str] | None:
+    """
+    Forge, repository and number of a pull or merge request URL.
+
+    GitHub, GitLab (self-hosted too) and Bitbucket URLs are recognized,
+    None is returned for anything else.
+    """
+    match = PULL_REQUEST_RE.match(url.strip())
+    if not match:
+        return None
+    return {
+        "forge": FORGES[match.group("kind")],
+        "host": match.group("host"),
+        "repo": match.group("repo"),
+        "number": match.group("number"),
+    }
@@ -1,15 +1,31 @@
 """Utilities for rendering and displaying Jira issues in the terminal."""
 
 import os
+import re
 import shutil
 import textwrap
 from datetime import datetime
 
 import jira2markdown
 
+from jayrah.api import models
 from jayrah.config import defaults
 
-from . import adf
+from . import adf, custom_fields
+
+# Links of the Markdown, [text](url) or <url>, to follow from the details
+MARKDOWN_LINK_RE = re.compile(
+    r"\[([^\]]*)\]\((https?://[^)\s]+)\)|<(https?://[^>\s]+)>"
+)
+# What the text links: inline code and links are kept, URLs and issue keys
+# written as they are become links
+TEXT_LINK_RE = re.compile(
+    r"(?P<code>`[^`\n]*`)|(?P<link>\[[^\]]*\]\([^)]*\))|(?P<auto><https?://[^>\s]+>)"
+    r"|(?P<url>\bhttps?://[^\s<>()\[\]`]+[^\s<>()\[\]`.,;:!?])"
+    r"|(?P<key>\b[A-Z][A-Z0-9_]+-\d+\b)"
+)
+# Issue keys referenced by the details, linked or not
+ISSUE_KEY_RE = re.compile(r"\b[A-Z][A-Z0-9_]+-\d+\b")
 
 
 def get_terminal_width() -> int:
@@ -51,10 +67,12 @@ def wrap_markdown(text):
         if line.startswith(("```", "#")):
             lines.append(line)
         else:
-            # Wrap the line to the terminal width
+            # Wrap the line to the terminal width, links are never cut
             wrapped_lines = textwrap.wrap(
                 line,
                 width=terminal_width,
+                break_long_words=False,
+                break_on_hyphens=False,
             )
             lines.append("\n".join(wrapped_lines))
 
@@ -74,6 +92,111 @@ def format_with_rich(text):
     console.print(md)
 
 
+def get_linked_issues(fields) -> list[dict]:
+    """Flatten the issuelinks field into relation, key, summary and status."""
+    linked = []
+    for link in fields.get("issuelinks") or []:
+        link_type = link.get("type", {})
+        if "outwardIssue" in link:
+            relation = link_type.get("outward", link_type.get("name", ""))
+            other = link["outwardIssue"]
+        elif "inwardIssue" in link:
+            relation = link_type.get("inward", link_type.get("name", ""))
+            other = link["inwardIssue"]
+        else:
+            continue
+        other_fields = other.get("fields", {})
+        linked.append(
+            {
+                "relation": relation,
+                "key": other.get("key", ""),
+                "summary": other_fields.get("summary", ""),
+                "status": other_fields.get("status", {}).get("name", ""),
+            }
+        )
+    return linked
+
+
+def issue_link(key: str, config) -> str:
+    """The key of an issue in bold, linked to the issue when the server is known."""
+    server = (config or {}).get("jira_server")
+    if not server:
+        return f"**{key}**"
+    return f"[**{key}**]({server.rstrip('/')}/browse/{key})"
+
+
+def summarize_issue(issue, config=None) -> str:
+    """One line summary of an issue reference: key, summary and status."""
+    fields = issue.get("fields", {})
+    status = fields.get("status", {}).get("name", "")
+    key = issue_link(issue.get("key", ""), config)
+    return f"{key}: {fields.get('summary', '')} ({status})"
+
+
+def linkify(text: str, config=None) -> str:
+    """Markdown with the bare URLs and issue keys outside of code as links."""
+
+    def link(match: re.Match) -> str:
+        if match.group("url"):
+            return f"<{match.group()}>"
+        if match.group("key"):
+            return issue_link(match.group(), config)
+        return match.group()
+
+    lines = []
+    in_code = False
+    for line in text.split("\n"):
+        if line.lstrip().startswith("```"):
+            in_code = not in_code
+        elif not in_code:
+            line = TEXT_LINK_RE.sub(link, line)
+        lines.append(line)
+    return "\n".join(lines)
+
+
+def issue_keys(text: str, exclude: str | None = None) -> list[str]:
+    """The issue keys a text refers to, each once in order, but exclude."""
+    keys = dict.fromkeys(ISSUE_KEY_RE.findall(text or ""))
+    keys.pop(exclude, None)
+    return list(keys)
+
+
+def markdown_links(text: str) -> list[tuple[str, str]]:
+    """The text and URL of the links of some Markdown, each URL once."""
+    links: dict[str, str] = {}
+    for match in MARKDOWN_LINK_RE.finditer(text or ""):
+        url = match.group(2) or match.group(3)
+        label = (match.group(1) or url).strip("*") or url
+        links.setdefault(url, label)
+    return [(label, url) for url, label in links.items()]
+
+
+def format_duration(duration) -> str:
+    """A duration in its two largest units, like 3d 4h or 25m."""
+    minutes = int(duration.total_seconds() // 60)
+    days, minutes = divmod(minutes, 24 * 60)
+    hours, minutes = divmod(minutes, 60)
+    if days:
+        return f"{days}d {hours}h"
+    if hours:
+        return f"{hours}h {minutes}m"
+    return f"{minutes}m"
+
+
+def time_in_status(issue, histories, now=None) -> str:
+    """Markdown section with the time the issue spent in each status."""
+    fields = issue.get("fields", {})
+    durations = models.time_in_status(fields, histories, now)
+    if not durations:
+        return ""
+    current = (fields.get("status") or {}).get("name")
+    lines = ["## ⏱️ Time in status", ""]
+    for status, duration in durations.items():
+        suffix = " (current)" if status == current else ""
+        lines.append(f"* {status}: {format_duration(duration)}{suffix}")
+    return "\n".join(lines)
+
+
 def build_issue(issue, config, comments_count):
     """Return issue in a pretty formatted view as a string"""
     fields = issue["fields"]
@@ -87,11 +210,11 @@ def build_issue(issue, config, comments_count):
     status_emoji = defaults.STATUS_EMOJI.get(issue_status, "❓")
     defaults.PRIORITY_EMOJI.get(issue_priority, "⚪")
 
-    plain_title = f"{type_emoji} {issue['key']}: {fields['summary']}"
+    title_key = issue_link(issue["key"], config).replace("**", "")
     output = []
 
     # Add issue title
-    output.append(f"# {plain_title}")
+    output.append(f"# {type_emoji} {title_key}: {fields['summary']}")
 
     # Start markdown table
 
@@ -103,6 +226,10 @@ def build_issue(issue, config, comments_count):
     output.append(f"* Priority: {color_code}{issue_priority}{reset_code}")
     output.append(f"* Type: {issue_type} {type_emoji}")
 
+    if fields.get("duedate"):
+        overdue = " ⚠️ overdue" if models.is_overdue(fields) else ""
+        output.append(f"* Due: 📅 {fields['duedate']}{overdue}")
+
     # Add fix versions if available
     if fields.get("fixVersions"):
         fix_versions = [v["name"] for v in fields["fixVersions"]]
@@ -117,6 +244,11 @@ def build_issue(issue, config, comments_count):
     if fields.get("labels"):
         output.append(f"* Labels: 🏷️ {', '.join(fields['labels'])} ")
 
+    # Jira Server keeps the epic in a custom field, Cloud shows it as the parent
+    epic_link_field = config.get("epic_link_field")
+    if epic_link_field and fields.get(epic_link_field):
+        output.append(f"* Epic: ⚡ {fields[epic_link_field]}")
+
     # Add people information
     # Helper function to get user identifier - works with both v2 and v3 API
     def get_user_info(user):
@@ -141,6 +273,15 @@ def build_issue(issue, config, comments_count):
         f"* Reporter: 📣 {reporter['displayName']} <{get_user_info(reporter)}>"
     )
 
+    watches = fields.get("watches")
+    if watches:
+        watching = " (watching)" if watches.get("isWatching") else ""
+        output.append(f"* Watchers: 👀 {watches.get('watchCount', 0)}{watching}")
+    votes = fields.get("votes")
+    if votes:
+        voted = " (voted)" if votes.get("hasVoted") else ""
+        output.append(f"* Votes: 👍 {votes.get('votes', 0)}{voted}")
+
     # Add dates
     date_format = "%Y-%m-%dT%H:%M:%S.%f%z"
     created_date = datetime.strptime(fields["created"], date_format)
@@ -150,13 +291,17 @@ def build_issue(issue, config, comments_count):
     output.append(f"* Updated: 🔄 {updated_date.strftime('%Y-%m-%d %H:%M:%S')}")
 
     # Show custom fields if present and not empty
-    custom_fields = config.get("custom_fields", [])
-    for cf in custom_fields:
+    custom_fields_cfg = config.get("custom_fields", [])
+    for cf in custom_fields_cfg:
         field_id = cf.get("field")
         field_name = cf.get("name", field_id)
         field_type = cf.get("type", "string")
         if field_id and fields.get(field_id):
             value = fields[field_id]
+            if field_type == "user" and isinstance(value, dict):
+                value = value.get("displayName") or value.get("name", "")
+            elif field_type in custom_fields.CHOICE_TYPES:
+                value = custom_fields.format_custom_field_value(value)
             # If value is a list, join, else str
             if isinstance(value, list):
                 value = ", ".join(str(v) for v in value if v)
@@ -179,16 +324,17 @@ def build_issue(issue, config, comments_count):
         if isinstance(description_text, dict) and "raw" in description_text:
             description_text = description_text["raw"]
         # Then, check if it's ADF format (has type, content, etc.)
-        elif (
+        if (
             isinstance(description_text, dict)
             and "type" in description_text
             and "content" in description_text
         ):
-            # Import here to avoid circular imports
-            description_text = adf.extract_text_from_adf(description_text)
+            description_text = adf.adf_to_markdown(description_text)
+        elif isinstance(description_text, str):
+            description_text = jira2markdown.convert(description_text)
 
         if description_text and isinstance(description_text, str):
-            markdown_description += jira2markdown.convert(description_text)
+            markdown_description += linkify(description_text, config)
             # Replace the first header with a second-level header but only on first line
             markdown_description_parts = markdown_description.split("\n", 1)
             if len(markdown_description_parts) > 1:
@@ -202,6 +348,35 @@ def build_issue(issue, config, comments_count):
     else:
         markdown_description += "No description provided"
 
+    # Parent and subtasks
+    if fields.get("parent"):
+        markdown_description += "\n\n## ⬆️ Parent\n"
+        markdown_description += f"\n* {summarize_issue(fields['parent'], config)}"
+    if fields.get("subtasks"):
+        markdown_description += f"\n\n## 🌳 Subtasks ({len(fields['subtasks'])})\n"
+        for subtask in fields["subtasks"]:
+            markdown_description += f"\n* {summarize_issue(subtask, config)}"
+
+    # Linked issues
+    linked_issues = get_linked_issues(fields)
+    if linked_issues:
+        markdown_description += "\n\n## 🔗 Links\n"
+        for linked in linked_issues:
+            markdown_description += (
+                f"\n* {linked['relation']} {issue_link(linked['key'], config)}: "
+                f"{linked['summary']} ({linked['status']})"
+            )
+
+    # Attachments, m in the browser previews the images
+    attachments = models.issue_attachments(fields)
+    if attachments:
+        markdown_description += f"\n\n## 📎 Attachments ({len(attachments)})\n"
+        for attachment in attachments:
+            markdown_description += (
+                f"\n* **{attachment.filename}** ({attachment.mime_type or 'file'}, "
+                f"{attachment.human_size}) by {attachment.author or 'Unknown'}"
+            )
+
     # Comments
     if comments_count > 0 and "comment" in fields and fields["comment"]["comments"]:
         comments = fields["comment"]["comments"]
@@ -225,18 +400,18 @@ def build_issue(issue, config, comments_count):
             # Handle v3 API format with "raw" key
             if isinstance(comment_body, dict) and "raw" in comment_body:
                 comment_body = comment_body["raw"]
-            # Handle ADF format
-            elif (
+            # Handle ADF format, rendered to Markdown with its formatting
+            if (
                 isinstance(comment_body, dict)
                 and "type" in comment_body
                 and "content" in comment_body
             ):
-                # Import here to avoid circular imports
-                comment_body = adf.extract_text_from_adf(comment_body)
+                comment_body = adf.adf_to_markdown(comment_body)
+            elif isinstance(comment_body, str):
+                comment_body = jira2markdown.convert(comment_body)
 
             if comment_body and isinstance(comment_body, str):
-                comment_content = jira2markdown.convert(comment_body)
-                markdown_description += "\n\n" + comment_content
+                markdown_description += "\n\n" + linkify(comment_body, config)
             else:
                 markdown_description += "\n\n[No comment content available]"
 
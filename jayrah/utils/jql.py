@@ -0,0 +1,309 @@
+"""Build JQL queries and check user supplied ones before sending them to Jira."""
+
+import re
+from dataclasses import dataclass, field
+
+KEYWORDS = {"AND", "OR", "NOT", "IN", "IS", "WAS", "CHANGED", "EMPTY", "NULL"}
+KEYWORDS |= {"ORDER", "BY", "ASC", "DESC"}
+HISTORY_PREDICATES = {"AFTER", "BEFORE", "ON", "DURING", "BY", "FROM", "TO"}
+
+TOKEN_RE = re.compile(
+    r"""
+    (?P<space>\s+)
+    | (?P<string>"(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*')
+    | (?P<operator>!=|!~|>=|<=|[=~<>])
+    | (?P<punct>[(),])
+    | (?P<word>[\w.\-@+*/:]+(?:\[\d+\])?)
+    """,
+    re.VERBOSE,
+)
+
+
+class JqlSyntaxError(ValueError):
+    """A JQL query that Jira would reject, position is the offset of the error."""
+
+    def __init__(self, message: str, position: int):
+        super().__init__(f"{message} at position {position}")
+        self.message = message
+        self.position = position
+
+
+@dataclass
+class Token:
+    kind: str
+    value: str
+    position: int
+
+    @property
+    def keyword(self) -> str:
+        """The upper cased word, empty for anything that is not a bare word."""
+        return self.value.upper() if self.kind == "word" else ""
+
+
+def tokenize(text: str) -> list[Token]:
+    """Split a JQL query in tokens, raising JqlSyntaxError on unknown characters."""
+    tokens = []
+    position = 0
+    while position < len(text):
+        match = TOKEN_RE.match(text, position)
+        if not match:
+            if text[position] in "\"'":
+                raise JqlSyntaxError("Unterminated string", position)
+            raise JqlSyntaxError(f"Unexpected character {text[position]!r}", position)
+        if match.lastgroup != "space":
+            tokens.append(Token(str(match.lastgroup), match.group(), position))
+        position = match.end()
+    return tokens
+
+
+class _Parser:
+    """Recursive descent parser following the JQL grammar closely enough."""
+
+    def __init__(self, text: str):
+        self.text = text
+        self.tokens = tokenize(text)
+        self.index = 0
+
+    def peek(self, offset: int = 0) -> Token | None:
+        if self.index + offset < len(self.tokens):
+            return self.tokens[self.index + offset]
+        return None
+
+    def error(self, expected: str) -> JqlSyntaxError:
+        token = self.peek()
+        if token is None:
+            return JqlSyntaxError(f"Expected {expected}", len(self.text))
+        return JqlSyntaxError(
+            f"Expected {expected} but got {token.value!r}", token.position
+        )
+
+    def accept(self, *keywords: str) -> bool:
+        token = self.peek()
+        if token is not None and token.keyword in keywords:
+            self.index += 1
+            return True
+        return False
+
+    def accept_punct(self, value: str) -> bool:
+        token = self.peek()
+        if token is not None and token.kind == "punct" and token.value == value:
+            self.index += 1
+            return True
+        return False
+
+    def parse(self) -> None:
+        token = self.peek()
+        if token is not None and token.keyword != "ORDER":
+            self.parse_or()
+        if self.accept("ORDER"):
+            if not self.accept("BY"):
+                raise self.error("BY after ORDER")
+            self.parse_order_by()
+        if self.peek() is not None:
+            raise self.error("AND, OR or ORDER BY")
+
+    def parse_or(self) -> None:
+        self.parse_and()
+        while self.accept("OR"):
+            self.parse_and()
+
+    def parse_and(self) -> None:
+        self.parse_not()
+        while self.accept("AND"):
+            self.parse_not()
+
+    def parse_not(self) -> None:
+        if self.accept("NOT"):
+            self.parse_not()
+        elif self.accept_punct("("):
+            self.parse_or()
+            if not self.accept_punct(")"):
+                raise self.error("')'")
+        else:
+            self.parse_clause()
+
+    def parse_field(self) -> None:
+        token = self.peek()
+        if token is None or token.kind not in ("word", "string") or (
+            token.keyword in KEYWORDS
+        ):
+            raise self.error("a field name")
+        self.index += 1
+
+    def parse_clause(self) -> None:
+        self.parse_field()
+        token = self.peek()
+        if token is not None and token.kind == "operator":
+            self.index += 1
+            self.parse_operand()
+        elif self.accept("IN"):
+            self.parse_list()
+        elif self.accept("NOT"):
+            if not self.accept("IN"):
+                raise self.error("IN after NOT")
+            self.parse_list()
+        elif self.accept("IS"):
+            self.accept("NOT")
+            if not self.accept("EMPTY", "NULL"):
+                raise self.error("EMPTY or NULL")
+        elif self.accept("WAS"):
+            self.accept("NOT")
+            if self.accept("IN"):
+                self.parse_list()
+            else:
+                self.parse_operand()
+            self.parse_history_predicates()
+        elif self.accept("CHANGED"):
+            self.parse_history_predicates()
+        else:
+            raise self.error("an operator")
+
+    def parse_history_predicates(self) -> None:
+        while self.accept(*HISTORY_PREDICATES):
+            self.parse_operand()
+
+    def parse_list(self) -> None:
+        token = self.peek()
+        if token is not None and token.kind == "word" and self.is_function():
+            self.parse_operand()
+            return
+        if not self.accept_punct("("):
+            raise self.error("'('")
+        self.parse_operand()
+        while self.accept_punct(","):
+            self.parse_operand()
+        if not self.accept_punct(")"):
+            raise self.error("')'")
+
+    def is_function(self) -> bool:
+        following = self.peek(1)
+        return (
+            following is not None
+            and following.kind == "punct"
+            and following.value == "("
+        )
+
+    def parse_operand(self) -> None:
+        token = self.peek()
+        if token is None or token.kind not in ("word", "string"):
+            raise self.error("a value")
+        if token.keyword in KEYWORDS - {"EMPTY", "NULL"}:
+            raise self.error("a value")
+        self.index += 1
+        if token.kind == "word" and self.accept_punct("("):
+            if not self.accept_punct(")"):
+                self.parse_operand()
+                while self.accept_punct(","):
+                    self.parse_operand()
+                if not self.accept_punct(")"):
+                    raise self.error("')'")
+
+    def parse_order_by(self) -> None:
+        self.parse_field()
+        self.accept("ASC", "DESC")
+        while self.accept_punct(","):
+            self.parse_field()
+            self.accept("ASC", "DESC")
+
+
+def validate(text: str) -> None:
+    """Raise a JqlSyntaxError when the query is not valid JQL."""
+    if not text.strip():
+        raise JqlSyntaxError("Empty query", 0)
+    _Parser(text).parse()
+
+
+def quote(value: str, force: bool = False) -> str:
+    """Quote a value unless it is a plain word JQL accepts as is."""
+    plain = re.fullmatch(r"[\w.\-@]+", value) and value.upper() not in KEYWORDS
+    if plain and not force:
+        return value
+    escaped = value.replace("\\", "\\\\").replace('"', '\\"')
+    return f'"{escaped}"'
+
+
+def replace_current_user(text: str, user: str) -> str:
+    """
+    The query with its currentUser() calls replaced by a user.
+
+    The user is always quoted, Cloud accountIds have colons and emails an @
+    that JQL reserves. Calls in strings are left alone.
+    """
+    tokens = tokenize(text)
+    parts = []
+    end = 0
+    for index, token in enumerate(tokens):
+        call = [x.value for x in tokens[index + 1 : index + 3]]
+        if token.keyword == "CURRENTUSER" and call == ["(", ")"]:
+            parts += [text[end : token.position], quote(user, force=True)]
+            end = tokens[index + 2].position + 1
+    return "".join([*parts, text[end:]])
+
+
+@dataclass
+class Jql:
+    """
+    A JQL query built from clauses.
+
+        Jql.assignee_current_user().and_(Jql.status_not("Done")).order_by("updated")
+    """
+
+    query: str
+    ordering: list[str] = field(default_factory=list)
+
+    @classmethod
+    def field_equals(cls, name: str, value: str) -> "Jql":
+        return cls(f"{quote(name)} = {quote(value)}")
+
+    @classmethod
+    def field_not_equals(cls, name: str, value: str) -> "Jql":
+        return cls(f"{quote(name)} != {quote(value)}")
+
+    @classmethod
+    def field_in(cls, name: str, values: list[str]) -> "Jql":
+        return cls(f"{quote(name)} IN ({', '.join(quote(v) for v in values)})")
+
+    @classmethod
+    def project(cls, key: str) -> "Jql":
+        return cls.field_equals("project", key)
+
+    @classmethod
+    def status(cls, status: str) -> "Jql":
+        return cls.field_equals("status", status)
+
+    @classmethod
+    def status_not(cls, status: str) -> "Jql":
+        return cls.field_not_equals("status", status)
+
+    @classmethod
+    def assignee_current_user(cls) -> "Jql":
+        return cls("assignee = currentUser()")
+
+    @classmethod
+    def reporter_current_user(cls) -> "Jql":
+        return cls("reporter = currentUser()")
+
+    @classmethod
+    def text(cls, value: str) -> "Jql":
+        return cls(f"text ~ {quote(value)}")
+
+    def _combine(self, operator: str, other: "Jql") -> "Jql":
+        return Jql(f"({self.query}) {operator} ({other.query})", list(self.ordering))
+
+    def and_(self, other: "Jql") -> "Jql":
+        return self._combine("AND", other)
+
+    def or_(self, other: "Jql") -> "Jql":
+        return self._combine("OR", other)
+
+    def not_(self) -> "Jql":
+        return Jql(f"NOT ({self.query})", list(self.ordering))
+
+    def order_by(self, name: str, descending: bool = False) -> "Jql":
+        direction = " DESC" if descending else ""
+        return Jql(self.query, [*self.ordering, f"{quote(name)}{direction}"])
+
+    def __str__(self) -> str:
+        if self.ordering:
+            return f"{self.query} ORDER BY {', '.join(self.ordering)}"
+        return self.query
@@ -0,0 +1,64 @@
+"""Log file of the Jira requests and errors, to debug what went wrong later."""
+
+import collections
+import logging
+import logging.handlers
+import pathlib
+
+from ..config import defaults
+
+FORMAT = "%(asctime)s %(levelname)s %(name)s: %(message)s"
+
+
+def setup_logging(
+    level: str = defaults.LOG_LEVEL, path: pathlib.Path | None = None
+) -> pathlib.Path | None:
+    """
+    Write the jayrah loggers to a rotating log file.
+
+    Gives the path of the file, None when it cannot be created so a read-only
+    home doesn't prevent jayrah from running. Calling it again replaces the
+    file and level of the previous call.
+    """
+    if level.lower() not in defaults.LOG_FILE_LEVELS:
+        levels = ", ".join(defaults.LOG_FILE_LEVELS)
+        raise ValueError(f"Invalid log level '{level}', one of {levels}")
+    path = path or defaults.LOG_FILE
+    logger = logging.getLogger("jayrah")
+    for handler in list(logger.handlers):
+        logger.removeHandler(handler)
+        handler.close()
+    logger.setLevel(level.upper())
+    # The terminal belongs to the TUI and the command output
+    logger.propagate = False
+    try:
+        path.parent.mkdir(parents=True, exist_ok=True)
+        handler = logging.handlers.RotatingFileHandler(
+            path,
+            maxBytes=defaults.LOG_MAX_BYTES,
+            backupCount=defaults.LOG_BACKUPS,
+            encoding="utf-8",
+        )
+    except OSError:
+        logger.addHandler(logging.NullHandler())
+        return None
+    handler.setFormatter(logging.Formatter(FORMAT))
+    logger.addHandler(handler)
+    return path
+
+
+def log_file() -> pathlib.Path | None:
+    """The file the jayrah loggers write to, None when not set up."""
+    for handler in logging.getLogger("jayrah").handlers:
+        if isinstance(handler, logging.FileHandler):
+            return pathlib.Path(handler.baseFilename)
+    return None
+
+
+def tail(path: pathlib.Path, count: int) -> list[str]:
+    """The last lines of a log file, empty when it doesn't exist yet."""
+    try:
+        with path.open(encoding="utf-8", errors="replace") as file:
+            return [x.rstrip("\n") for x in collections.deque(file, maxlen=count)]
+    except FileNotFoundError:
+        return []
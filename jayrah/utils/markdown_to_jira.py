@@ -10,6 +10,38 @@ CODE_BLOCK_LANGS_TO_JIRA = {
     "sh": "bash",
 }
 
+TABLE_SEPARATOR = r"^\s*\|(\s*:?-+:?\s*\|)+\s*$"
+LIST_ITEM = r"^(\s*)([\-\*]|\d+\.)\s+"
+
+
+def _inline_to_jira(line: str) -> str:
+    """Convert the inline Markdown of a line: bold, italic, code, links..."""
+    # Code spans are kept verbatim, the other rules must not touch them
+    code_spans = []
+
+    def keep_code(match):
+        code_spans.append(match.group(1))
+        return f"\x00{len(code_spans) - 1}\x00"
+
+    line = re.sub(r"`(.*?)`", keep_code, line)
+    line = re.sub(r"\\(.)", r"\1", line)
+    line = re.sub(r"!\[(.*?)\]\((.*?)\)", r"!\2!", line)
+    line = re.sub(r"\[(.*?)\]\((.*?)\)", r"[\1|\2]", line)
+    # Jira bold is a single star, hold it until the italic rules are applied
+    line = re.sub(r"\*\*\*(.*?)\*\*\*", "\x01_\\1_\x01", line)
+    line = re.sub(r"___(.*?)___", "\x01_\\1_\x01", line)
+    line = re.sub(r"\*\*(.*?)\*\*", "\x01\\1\x01", line)
+    line = re.sub(r"__(.*?)__", "\x01\\1\x01", line)
+    line = re.sub(r"(?<!\*)\*(?!\*)(.*?)(?<!\*)\*(?!\*)", r"_\1_", line)
+    line = line.replace("\x01", "*")
+    line = re.sub(r"~~(.*?)~~", r"-\1-", line)
+    line = re.sub(r"  $", r"\\\\", line)
+
+    def restore_code(match):
+        return "{{" + code_spans[int(match.group(1))] + "}}"
+
+    return re.sub(r"\x00(\d+)\x00", restore_code, line)
+
 
 def convert(markdown_text):
     """
@@ -23,6 +55,8 @@ def convert(markdown_text):
     in_quote_block = False
     code_block_lang = ""
     quote_lines = []
+    # (indent, marker) of the enclosing list items, Jira prefixes them all
+    list_stack: list[tuple[int, str]] = []
 
     for i, line in enumerate(lines):
         # --- Fenced Code Blocks (check first) ---
@@ -47,8 +81,13 @@ def convert(markdown_text):
             converted_lines.append(line)
             continue
 
-        # --- Indented Code Blocks (only if not in fenced) ---
-        if (re.match(r"^    ", line) or re.match(r"^\t", line)) and not in_fenced_code:
+        # --- Indented Code Blocks (only if not in fenced or nested in a list) ---
+        is_list_item = bool(re.match(LIST_ITEM, line))
+        if (
+            (re.match(r"^    ", line) or re.match(r"^\t", line))
+            and not in_fenced_code
+            and not (list_stack and is_list_item)
+        ):
             if not in_code_block:
                 converted_lines.append("{code}")
                 in_code_block = True
@@ -66,7 +105,7 @@ def convert(markdown_text):
                 in_quote_block = True
                 quote_lines = []
             quote_content = re.sub(r"^\s*>\s?", "", line)
-            quote_lines.append(quote_content)
+            quote_lines.append(_inline_to_jira(quote_content))
             continue
         if in_quote_block:
             converted_lines.append("{quote}")
@@ -77,10 +116,10 @@ def convert(markdown_text):
 
         # --- Tables ---
         if "|" in line and line.strip().startswith("|") and line.strip().endswith("|"):
-            if re.match(r"^\s*\|[\s\-:]+\|\s*$", line):
+            if re.match(TABLE_SEPARATOR, line):
                 continue
             cells = [c.strip() for c in line.split("|")[1:-1]]
-            if i + 1 < len(lines) and re.match(r"^\s*\|[\s\-:]+\|\s*$", lines[i + 1]):
+            if i + 1 < len(lines) and re.match(TABLE_SEPARATOR, lines[i + 1]):
                 converted_lines.append("||" + "||".join(cells) + "||")
             else:
                 converted_lines.append("|" + "|".join(cells) + "|")
@@ -95,38 +134,31 @@ def convert(markdown_text):
         line = re.sub(r"^\s*#\s+(.*)", r"h1. \1", line)
 
         # --- Nested Lists ---
-        if re.match(r"^(\s*)([\-\*]|\d+\.)\s+", line):
-            indent_match = re.match(r"^(\s*)", line)
-            indent_level = len(indent_match.group(1)) // 2 + 1
-
-            if re.match(r"^\s*[\-\*]\s+", line):
-                content = re.sub(r"^\s*[\-\*]\s+", "", line)
-                line = "*" * indent_level + " " + content
-            elif re.match(r"^\s*\d+\.\s+", line):
-                content = re.sub(r"^\s*\d+\.\s+", "", line)
-                line = "#" * indent_level + " " + content
+        list_match = re.match(LIST_ITEM, line)
+        if list_match:
+            indent = len(list_match.group(1))
+            marker = "#" if list_match.group(2)[0].isdigit() else "*"
+            while list_stack and list_stack[-1][0] > indent:
+                list_stack.pop()
+            if list_stack and list_stack[-1][0] == indent:
+                list_stack[-1] = (indent, marker)
+            else:
+                list_stack.append((indent, marker))
+            prefix = "".join(marker for _, marker in list_stack)
+            line = prefix + " " + line[list_match.end() :]
+        else:
+            list_stack = []
 
         # --- Task Lists ---
-        line = re.sub(r"^\s*[\-\*]\s+\[\s\]\s+", "* ", line)
-        line = re.sub(r"^\s*[\-\*]\s+\[x\]\s+", "* (/) ", line)
+        line = re.sub(r"^([\*#]+) \[\s\]\s+", r"\1 ", line)
+        line = re.sub(r"^([\*#]+) \[x\]\s+", r"\1 (/) ", line)
 
         # --- Horizontal Rules ---
         line = re.sub(r"^\s*---+\s*$", "----", line)
         line = re.sub(r"^\s*\*\*\*+\s*$", "----", line)
 
         # --- Inline Formatting ---
-        line = re.sub(r"\\(.)", r"\1", line)
-        line = re.sub(r"!\[(.*?)\]\((.*?)\)", r"!\2!", line)
-        line = re.sub(r"\[(.*?)\]\((.*?)\)", r"[\1|\2]", line)
-        line = re.sub(r"\*\*\*(.*?)\*\*\*", r"*_\1_*", line)
-        line = re.sub(r"___(.*?)___", r"*_\1_*", line)
-        line = re.sub(r"\*\*(.*?)\*\*", r"*\1*", line)
-        line = re.sub(r"__(.*?)__", r"*\1*", line)
-        line = re.sub(r"(?<!\*)\*(?!\*)(.*?)(?<!\*)\*(?!\*)", r"_\1_", line)
-        line = re.sub(r"(?<!_)_(?!_)(.*?)(?<!_)_(?!_)", r"_\1_", line)
-        line = re.sub(r"~~(.*?)~~", r"-\1-", line)
-        line = re.sub(r"`(.*?)`", r"{{\1}}", line)
-        line = re.sub(r"  $", r"\\\\", line)
+        line = _inline_to_jira(line)
 
         converted_lines.append(line)
 
@@ -232,7 +264,7 @@ def convert_v3(markdown_text: str) -> dict[str, Any]:
 
         # Complex regex to capture all inline formatting
         # Order matters: code first (to avoid processing markdown inside code)
-        pattern = r"(`[^`]+`|```[^`]*```|\*\*\*([^*]+)\*\*\*|___([^_]+)___|~~([^~]+)~~|\*\*([^*]+)\*\*|__([^_]+)__|[*_]([^*_]+)[*_]|\[([^\]]+)\]\(([^)]+)\)|!\[([^\]]*)\]\(([^)]+)\))"
+        pattern = r"(`[^`]+`|```[^`]*```|\*\*\*([^*]+)\*\*\*|___([^_]+)___|~~([^~]+)~~|\*\*([^*]+)\*\*|__([^_]+)__|[*_]([^*_]+)[*_]|\[([^\]]+)\]\(([^)]+)\)|!\[([^\]]*)\]\(([^)]+)\)|https?://[^\s)]*[^\s).,;:!?])"
 
         last_end = 0
         for match in re.finditer(pattern, text):
@@ -283,6 +315,14 @@ def convert_v3(markdown_text: str) -> dict[str, Any]:
                     )
                 )
 
+            # Bare URLs
+            elif full_match.startswith(("http://", "https://")):
+                content.append(
+                    text_node(
+                        full_match, [{"type": "link", "attrs": {"href": full_match}}]
+                    )
+                )
+
             # Images (skip for inline processing, handle separately)
             elif full_match.startswith("!["):
                 content.append(text_node(full_match))  # Keep as text for now
@@ -301,9 +341,13 @@ def convert_v3(markdown_text: str) -> dict[str, Any]:
 
         return content if content else [text_node(text)]
 
+    # Paragraph of the previous line, consecutive lines are joined into it
+    current_paragraph = None
+
     i = 0
     while i < len(lines):
         line = lines[i]
+        previous_paragraph, current_paragraph = current_paragraph, None
 
         # Handle empty lines
         if re.match(r"^\s*$", line):
@@ -503,7 +547,15 @@ def convert_v3(markdown_text: str) -> dict[str, Any]:
         # Default: paragraph with inline formatting
         if line.strip():
             formatted_content = process_inline_formatting(line)
-            doc["content"].append(paragraph_node(formatted_content))
+            if previous_paragraph:
+                # A single newline is a line break, a blank line a new paragraph
+                if previous_paragraph["content"][-1]["type"] != "hardBreak":
+                    previous_paragraph["content"].append(hardbreak_node())
+                previous_paragraph["content"].extend(formatted_content)
+                current_paragraph = previous_paragraph
+            else:
+                current_paragraph = paragraph_node(formatted_content)
+                doc["content"].append(current_paragraph)
 
         i += 1
 
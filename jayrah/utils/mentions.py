@@ -0,0 +1,100 @@
+"""@mentions of the comments, turned into the markup that notifies people."""
+
+import re
+from dataclasses import dataclass
+from typing import Any
+
+# The @name being typed before the cursor, two letters are enough to search
+QUERY_RE = re.compile(r"(?:^|(?<=\s))@([\w.\-]{2,})$")
+
+
+@dataclass(frozen=True)
+class Mention:
+    """A user picked from the completion, shown as @display name in the text."""
+
+    display_name: str
+    account_id: str = ""
+    name: str = ""
+
+    @classmethod
+    def from_user(cls, user: dict[str, Any]) -> "Mention":
+        """A mention of a user of the user search."""
+        identifier = user.get("accountId") or user.get("name") or ""
+        return cls(
+            user.get("displayName") or identifier,
+            user.get("accountId") or "",
+            user.get("name") or "",
+        )
+
+    @property
+    def text(self) -> str:
+        return f"@{self.display_name}"
+
+    @property
+    def wiki(self) -> str:
+        """The wiki markup of API v2, Cloud identifies users by accountId."""
+        if self.account_id:
+            return f"[~accountid:{self.account_id}]"
+        return f"[~{self.name}]"
+
+    def adf(self) -> dict[str, Any]:
+        return {
+            "type": "mention",
+            "attrs": {"id": self.account_id or self.name, "text": self.text},
+        }
+
+
+def mention_query(text: str) -> str | None:
+    """What follows the @ being typed at the end of a text, None outside one."""
+    match = QUERY_RE.search(text)
+    return match.group(1) if match else None
+
+
+def _pattern(mentions: list[Mention]) -> re.Pattern | None:
+    # The longest names first, "@Jane Doe" must not stop at "@Jane"
+    texts = sorted({x.text for x in mentions}, key=len, reverse=True)
+    if not texts:
+        return None
+    return re.compile("|".join(re.escape(x) for x in texts))
+
+
+def to_wiki(text: str, mentions: list[Mention]) -> str:
+    """Wiki markup with the mentions of the text turned into user links."""
+    pattern = _pattern(mentions)
+    if pattern is None:
+        return text
+    by_text = {x.text: x for x in mentions}
+    return pattern.sub(lambda match: by_text[match.group()].wiki, text)
+
+
+def to_adf(document: dict[str, Any], mentions: list[Mention]) -> dict[str, Any]:
+    """An ADF document with the mentions of its text nodes as mention nodes."""
+    pattern = _pattern(mentions)
+    if pattern is None:
+        return document
+    by_text = {x.text: x for x in mentions}
+
+    def split(node: dict[str, Any]) -> list[dict[str, Any]]:
+        marks = node.get("marks") or []
+        if node.get("type") != "text" or any(x.get("type") == "code" for x in marks):
+            return [node]
+        nodes: list[dict[str, Any]] = []
+        end = 0
+        for match in pattern.finditer(node.get("text", "")):
+            if match.start() > end:
+                nodes.append({**node, "text": node["text"][end : match.start()]})
+            nodes.append(by_text[match.group()].adf())
+            end = match.end()
+        if end < len(node.get("text", "")):
+            nodes.append({**node, "text": node["text"][end:]})
+        return nodes or [node]
+
+    def walk(node: dict[str, Any]) -> dict[str, Any]:
+        if "content" not in node:
+            return node
+        content = []
+        for child in node["content"]:
+            content.extend(split(walk(child)))
+        return {**node, "content": content}
+
+    return walk(document)
@@ -0,0 +1,80 @@
+"""Local store of the last issues seen, used to browse them offline."""
+
+import json
+import sqlite3
+import threading
+import time
+from pathlib import Path
+
+from jayrah import utils
+
+
+class OfflineStore:
+    """
+    Keep the last successful board results and issue details on disk.
+
+    Unlike the request cache nothing here expires or gets cleared on reload,
+    an entry is only replaced by a newer successful fetch.
+    """
+
+    def __init__(self, config):
+        self.config = config
+        cache_dir = Path(config.get("cache_dir", Path.home() / ".cache" / "jayrah"))
+        cache_dir.mkdir(parents=True, exist_ok=True)
+
+        self.db_path = cache_dir / "offline.db"
+        self._lock = threading.Lock()
+        self._conn = sqlite3.connect(self.db_path, check_same_thread=False)
+        with self._lock:
+            self._conn.execute("""
+                CREATE TABLE IF NOT EXISTS snapshots (
+                    key TEXT PRIMARY KEY,
+                    data TEXT,
+                    timestamp REAL
+                )
+            """)
+            self._conn.commit()
+
+    @staticmethod
+    def _issues_key(jql: str, order_by: str | None) -> str:
+        return f"issues|{jql}|{order_by or ''}"
+
+    def _save(self, key: str, data) -> None:
+        try:
+            with self._lock:
+                self._conn.execute(
+                    "INSERT OR REPLACE INTO snapshots (key, data, timestamp) VALUES (?, ?, ?)",
+                    (key, json.dumps(data), time.time()),
+                )
+                self._conn.commit()
+        except (sqlite3.Error, TypeError, ValueError) as e:
+            utils.log(f"Error saving offline data: {e}")
+
+    def _load(self, key: str) -> tuple | None:
+        try:
+            with self._lock:
+                row = self._conn.execute(
+                    "SELECT data, timestamp FROM snapshots WHERE key = ?", (key,)
+                ).fetchone()
+        except sqlite3.Error as e:
+            utils.log(f"Error reading offline data: {e}")
+            return None
+        if not row:
+            return None
+        return json.loads(row[0]), row[1]
+
+    def save_issues(self, jql: str, order_by: str | None, issues: list) -> None:
+        """Remember the issues returned by a query."""
+        self._save(self._issues_key(jql, order_by), issues)
+
+    def load_issues(self, jql: str, order_by: str | None) -> tuple | None:
+        """Return the (issues, saved timestamp) last returned by a query."""
+        return self._load(self._issues_key(jql, order_by))
+
+    def save_issue(self, issue: dict) -> None:
+        """Remember the full details of an issue."""
+        self._save(f"issue|{issue['key']}", issue)
+
+    def load_issue(self, issue_key: str) -> tuple | None:
+        """Return the (issue, saved timestamp) of the last fetched details."""
+        return self._load(f"issue|{issue_key}")
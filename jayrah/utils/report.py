@@ -0,0 +1,108 @@
+"""Standup summaries of what I did on the issues over the last days."""
+
+import datetime
+import re
+from typing import Any
+
+from ..api import models
+from ..config import defaults
+
+WINDOW_RE = re.compile(r"^(\d+)([dw])$")
+# Named windows of the standup, in days
+WINDOWS = {"yesterday": 1, "week": 7}
+
+
+def parse_window(text: str) -> int:
+    """Days covered by a window: yesterday, week, or a count like 3d or 2w."""
+    text = text.strip().lower()
+    if text in WINDOWS:
+        return WINDOWS[text]
+    if match := WINDOW_RE.match(text):
+        amount, unit = int(match.group(1)), match.group(2)
+        return amount * (7 if unit == "w" else 1)
+    raise ValueError(f"Invalid window '{text}', use yesterday, week, 3d or 2w")
+
+
+def standup_jql(days: int) -> str:
+    """
+    The issues I may have worked on in the last days.
+
+    JQL cannot search for the author of a comment, the events of these
+    issues are checked one by one afterwards.
+    """
+    return (
+        f"updated >= -{days}d AND (assignee = currentUser() OR "
+        "reporter = currentUser() OR watcher = currentUser() OR "
+        f"status CHANGED BY currentUser() AFTER -{days}d)"
+    )
+
+
+def my_events(
+    events: list[models.ActivityEvent], me: str, since: datetime.datetime
+) -> list[models.ActivityEvent]:
+    """The events of an issue done by me since a time, oldest first."""
+    return [x for x in reversed(events) if x.author == me and x.created_at >= since]
+
+
+def _event_line(event: models.ActivityEvent) -> str:
+    if event.kind == "comment":
+        return f"commented: {event.text}"
+    if event.kind == "transition":
+        return f"moved {event.text}"
+    return event.text
+
+
+def render_standup(
+    entries: list[tuple[dict[str, Any], list[models.ActivityEvent]]],
+    since: datetime.datetime,
+    server: str = "",
+) -> str:
+    """A Markdown summary of the issues and what I did on them, by status."""
+    lines = [f"# Standup since {since.strftime('%A %d %B')}", ""]
+    if not entries:
+        return "\n".join([*lines, "Nothing done on any issue."]) + "\n"
+    by_status: dict[str, list[str]] = {}
+    for issue, events in entries:
+        row = models.ListIssue.from_api(issue)
+        key = row.key
+        if server:
+            key = f"[{key}]({server.rstrip('/')}/browse/{key})"
+        items = by_status.setdefault(row.status or "No status", [])
+        items.append(f"- {key} {row.summary}")
+        items += [f"  - {_event_line(x)}" for x in events]
+    for status, items in by_status.items():
+        lines += [f"## {status}", "", *items, ""]
+    return "\n".join(lines)
+
+
+def standup_report(
+    jira,
+    issues_client,
+    days: int,
+    server: str = "",
+    now: datetime.datetime | None = None,
+) -> str:
+    """Fetch the issues and events of the last days and render the standup."""
+    now = now or datetime.datetime.now().astimezone()
+    since = now - datetime.timedelta(days=days)
+    me = jira.verify_auth().get("displayName")
+    issues = issues_client.list_issues(
+        standup_jql(days),
+        order_by="updated",
+        limit=defaults.STANDUP_ISSUES,
+        all_pages=False,
+        use_cache=False,
+    )
+    entries = []
+    for issue in issues[: defaults.STANDUP_ISSUES]:
+        comments = jira.get_issue_comments(
+            issue["key"],
+            max_results=defaults.COMMENTS_PAGE_SIZE,
+            newest_first=True,
+            use_cache=False,
+        ).get("comments", [])
+        histories = jira.get_issue_changelog(issue["key"], use_cache=False)
+        events = models.issue_activity(issue["key"], histories, comments)
+        if mine := my_events(events, me, since):
+            entries.append((issue, mine))
+    return render_standup(entries, since, server)
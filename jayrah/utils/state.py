@@ -0,0 +1,108 @@
+"""What the TUI remembers between sessions, like the pinned issues."""
+
+import datetime
+import json
+import threading
+from pathlib import Path
+from typing import Any
+
+from jayrah import utils
+from jayrah.config import defaults
+
+
+class StateStore:
+    """
+    A small JSON file next to the caches.
+
+    The state is kept by Jira server so profiles on other instances each have
+    their own issues, nothing in it expires.
+    """
+
+    def __init__(self, config):
+        cache_dir = Path(config.get("cache_dir", Path.home() / ".cache" / "jayrah"))
+        cache_dir.mkdir(parents=True, exist_ok=True)
+        self.path = cache_dir / "state.json"
+        self.scope = config.get("jira_server") or ""
+        self._lock = threading.Lock()
+
+    def _read(self) -> dict[str, Any]:
+        try:
+            data = json.loads(self.path.read_text(encoding="utf-8"))
+        except FileNotFoundError:
+            return {}
+        except (OSError, ValueError) as e:
+            utils.log(f"Error reading {self.path}: {e}")
+            return {}
+        return data if isinstance(data, dict) else {}
+
+    def get(self, name: str, default: Any = None) -> Any:
+        with self._lock:
+            return self._read().get(self.scope, {}).get(name, default)
+
+    def set(self, name: str, value: Any) -> None:
+        with self._lock:
+            data = self._read()
+            data.setdefault(self.scope, {})[name] = value
+            try:
+                self.path.write_text(
+                    json.dumps(data, indent=2, ensure_ascii=False), encoding="utf-8"
+                )
+            except OSError as e:
+                utils.log(f"Error saving {self.path}: {e}")
+
+    def pinned_issues(self) -> list[str]:
+        """Keys of the pinned issues, in the order they were pinned."""
+        return list(self.get("pinned", []))
+
+    def toggle_pin(self, issue_key: str) -> bool:
+        """Pin or unpin an issue, True when it is now pinned."""
+        pinned = self.pinned_issues()
+        if issue_key in pinned:
+            pinned.remove(issue_key)
+        else:
+            pinned.append(issue_key)
+        self.set("pinned", pinned)
+        return issue_key in pinned
+
+    def recent_labels(self) -> list[str]:
+        """The labels set from the labels popup, most recent first."""
+        return list(self.get("labels", []))
+
+    def record_labels(self, labels: list[str]) -> None:
+        """Put labels at the top of the recent ones, each is only listed once."""
+        recent = [x for x in self.recent_labels() if x not in labels]
+        self.set("labels", [*labels, *recent][: defaults.RECENT_LABELS])
+
+    def comment_draft(self, issue_key: str) -> str:
+        """The comment being written on an issue that was not added yet."""
+        return self.get("comment_drafts", {}).get(issue_key, "")
+
+    def save_comment_draft(self, issue_key: str, text: str) -> None:
+        """Keep the comment of an issue for later, an empty one drops it."""
+        drafts = dict(self.get("comment_drafts", {}))
+        if text:
+            drafts[issue_key] = text
+        else:
+            drafts.pop(issue_key, None)
+        self.set("comment_drafts", drafts)
+
+    def session(self) -> dict[str, Any]:
+        """What the browser showed when it was last closed, see save_session."""
+        return dict(self.get("session", {}))
+
+    def save_session(self, session: dict[str, Any]) -> None:
+        """Keep the query, filter, sort and issue of the browser for next time."""
+        self.set("session", session)
+
+    def history(self) -> list[dict[str, str]]:
+        """The recently viewed issues with their summary and time, newest first."""
+        return list(self.get("history", []))
+
+    def record_view(
+        self, issue_key: str, summary: str, when: datetime.datetime | None = None
+    ) -> None:
+        """Put an issue at the top of the history, it is only listed once."""
+        when = when or datetime.datetime.now().astimezone()
+        entry = {"key": issue_key, "summary": summary, "viewed": when.isoformat()}
+        history = [x for x in self.history() if x.get("key") != issue_key]
+        self.set("history", [entry, *history][: defaults.HISTORY_SIZE])
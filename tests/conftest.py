@@ -1,8 +1,12 @@
 import getpass
+import logging
 from unittest.mock import MagicMock, patch
 
 import pytest
 import yaml
+from fake_jira import FakeJira
+
+from jayrah.config import defaults
 
 
 @pytest.fixture
@@ -42,6 +46,28 @@ def sample_config(tmp_path):
     }
 
 
+@pytest.fixture
+def fake_jira():
+    """A Jira server answering from in-memory issues, see fake_jira.py."""
+    server = FakeJira().start()
+    yield server
+    server.stop()
+
+
+@pytest.fixture
+def fake_jira_config(sample_config, fake_jira):
+    """The sample configuration pointing to the fake Jira server."""
+    return {
+        **sample_config,
+        "jira_server": fake_jira.url,
+        "verbose": False,
+        "quiet": True,
+        "no_cache": True,
+        "retry_backoff": 0,
+        "retry_jitter": 0,
+    }
+
+
 @pytest.fixture
 def temp_config_file(tmp_path, sample_config):
     """Create a temporary config file with sample data."""
@@ -143,3 +169,16 @@ def mock_prompt_ask(monkeypatch):
     except ImportError:
         return
     monkeypatch.setattr(Prompt, "ask", lambda *args, **kwargs: "fakeinput")
+
+
+@pytest.fixture(autouse=True)
+def isolated_log_file(tmp_path, monkeypatch):
+    """Keep the log file of the commands out of the home directory."""
+    monkeypatch.setattr(defaults, "LOG_FILE", tmp_path / "jayrah.log")
+    yield
+    logger = logging.getLogger("jayrah")
+    for handler in list(logger.handlers):
+        logger.removeHandler(handler)
+        handler.close()
+    logger.setLevel(logging.NOTSET)
+    logger.propagate = True
@@ -0,0 +1,331 @@
+"""
+A minimal Jira server running in the tests process.
+
+Serves the search, issue, comment and transition endpoints of the REST API
+versions 2 and 3 and the attachment files from in-memory issues, so the client
+and the commands built on it can be tested end to end over real HTTP. Errors
+and latency can be injected to test how the retries and failures are handled.
+"""
+
+import json
+import re
+import threading
+import time
+import urllib.parse
+from dataclasses import dataclass
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from typing import Any
+
+MYSELF = {"name": "jdoe", "accountId": "1234", "displayName": "Jane Doe"}
+STATUSES = {
+    "To Do": "new",
+    "In Progress": "indeterminate",
+    "Done": "done",
+}
+# Conditions of the JQL queries understood by the search, the rest is ignored
+JQL_CONDITION_RE = re.compile(r'(\w+)\s*=\s*("[^"]*"|[\w().-]+)')
+
+
+@dataclass
+class Failure:
+    """An error answered to the requests matching a method and a path."""
+
+    method: str
+    path: re.Pattern
+    status: int
+    times: int
+    body: dict[str, Any]
+
+
+@dataclass
+class Redirect:
+    """An answer sending the client to another URL."""
+
+    location: str
+
+
+def status_field(name: str) -> dict[str, Any]:
+    return {"name": name, "statusCategory": {"key": STATUSES.get(name, "new")}}
+
+
+class FakeJira:
+    """The issues of the fake server, with the requests it received."""
+
+    def __init__(self):
+        self.issues: dict[str, dict[str, Any]] = {}
+        self.comments: dict[str, list[dict[str, Any]]] = {}
+        # Every issue can move to any status it isn't in
+        self.statuses = list(STATUSES)
+        self.requests: list[tuple[str, str]] = []
+        # Headers and query of the last request, to check what the client sends
+        self.last_headers: dict[str, str] = {}
+        self.last_query: dict[str, str] = {}
+        self.failures: list[Failure] = []
+        # Seconds every answer is delayed by
+        self.latency = 0.0
+        # When set, the API wants the cookie of a session opened with it
+        self.password: str | None = None
+        self.sessions: set[str] = set()
+        self.logins = 0
+        # Content of the attachments by id
+        self.files: dict[str, bytes] = {}
+        # When set, the attachments redirect to this host as Jira Cloud does to
+        # its media storage, which serves them without credentials
+        self.storage_url: str | None = None
+        self._lock = threading.Lock()
+        self._server = ThreadingHTTPServer(("127.0.0.1", 0), _Handler)
+        self._server.jira = self  # type: ignore[attr-defined]
+        self._thread = threading.Thread(target=self._server.serve_forever, daemon=True)
+
+    @property
+    def url(self) -> str:
+        host, port = self._server.server_address[:2]
+        return f"http://{host}:{port}"
+
+    def start(self) -> "FakeJira":
+        self._thread.start()
+        return self
+
+    def stop(self) -> None:
+        self._server.shutdown()
+        self._server.server_close()
+
+    def add_issue(
+        self, key: str, summary: str, status: str = "To Do", **fields: Any
+    ) -> dict[str, Any]:
+        """Add an issue, fields are given as the API names them."""
+        issue = {
+            "id": str(10000 + len(self.issues)),
+            "key": key,
+            "fields": {
+                "summary": summary,
+                "status": status_field(status),
+                "issuetype": {"name": "Task"},
+                "priority": {"name": "Major"},
+                "project": {"key": key.split("-")[0]},
+                "assignee": None,
+                "reporter": {"displayName": MYSELF["displayName"]},
+                "labels": [],
+                "created": "2026-10-01T09:00:00.000+0000",
+                "updated": "2026-10-01T09:00:00.000+0000",
+                **fields,
+            },
+        }
+        self.issues[key] = issue
+        self.comments[key] = []
+        return issue
+
+    def add_attachment(
+        self, key: str, filename: str, content: bytes, mime_type: str = "image/png"
+    ) -> dict[str, Any]:
+        """Attach a file to an issue, downloaded from its content URL."""
+        attachment_id = str(20000 + len(self.files))
+        attachment = {
+            "id": attachment_id,
+            "filename": filename,
+            "mimeType": mime_type,
+            "size": len(content),
+            "author": {"displayName": MYSELF["displayName"]},
+            "created": "2026-10-02T09:00:00.000+0000",
+            "content": f"{self.url}/secure/attachment/{attachment_id}/{filename}",
+        }
+        self.files[attachment_id] = content
+        self.issues[key]["fields"].setdefault("attachment", []).append(attachment)
+        return attachment
+
+    def fail(
+        self,
+        method: str,
+        path: str,
+        status: int = 500,
+        times: int = 1,
+        message: str = "Internal server error",
+    ) -> None:
+        """Answer the next requests to a path, a regular expression, with an error."""
+        self.failures.append(
+            Failure(
+                method.upper(),
+                re.compile(path),
+                status,
+                times,
+                {"errorMessages": [message], "errors": {}},
+            )
+        )
+
+    def expire_sessions(self) -> None:
+        """Forget the opened sessions, as Jira does after a while."""
+        self.sessions.clear()
+
+    def login(self, body: Any) -> tuple[int, Any]:
+        body = body or {}
+        if (body.get("username"), body.get("password")) != (
+            MYSELF["name"],
+            self.password,
+        ):
+            return 401, {"errorMessages": ["Login failed"], "errors": {}}
+        self.logins += 1
+        session = f"session-{self.logins}"
+        self.sessions.add(session)
+        return 200, {"session": {"name": "JSESSIONID", "value": session}}
+
+    def _failure(self, method: str, path: str) -> Failure | None:
+        with self._lock:
+            for failure in self.failures:
+                if failure.method == method and failure.path.search(path):
+                    failure.times -= 1
+                    if failure.times <= 0:
+                        self.failures.remove(failure)
+                    return failure
+        return None
+
+    def search(self, jql: str) -> list[dict[str, Any]]:
+        """The issues matching the key, project and status conditions of a JQL."""
+        jql = re.split(r"\border by\b", jql, flags=re.IGNORECASE)[0]
+        issues = list(self.issues.values())
+        for field, value in JQL_CONDITION_RE.findall(jql):
+            value = value.strip('"')
+            field = field.lower()
+            if field == "key":
+                issues = [x for x in issues if x["key"] == value]
+            elif field == "project":
+                issues = [x for x in issues if x["key"].startswith(f"{value}-")]
+            elif field == "status":
+                issues = [x for x in issues if x["fields"]["status"]["name"] == value]
+        return issues
+
+    def transitions(self, key: str) -> list[dict[str, Any]]:
+        current = self.issues[key]["fields"]["status"]["name"]
+        return [
+            {"id": str(index + 11), "name": name, "to": status_field(name)}
+            for index, name in enumerate(self.statuses)
+            if name != current
+        ]
+
+    def handle(
+        self, method: str, path: str, query: dict[str, str], body: Any
+    ) -> tuple[int, Any]:
+        """The status and body of the answer to a request."""
+        self.requests.append((method, path))
+        self.last_query = query
+        if self.latency:
+            time.sleep(self.latency)
+        if failure := self._failure(method, path):
+            return failure.status, failure.body
+        match = re.match(r"^/media/(\d+)$", path)
+        if match and method == "GET" and match.group(1) in self.files:
+            return 200, self.files[match.group(1)]
+        if path == "/rest/auth/1/session" and method == "POST":
+            return self.login(body)
+        if self.password is not None:
+            cookie = self.last_headers.get("Cookie", "")
+            if cookie.removeprefix("JSESSIONID=") not in self.sessions:
+                return 401, {"errorMessages": ["Session expired"], "errors": {}}
+        match = re.match(r"^/secure/attachment/(\d+)/", path)
+        if match and method == "GET" and match.group(1) in self.files:
+            if self.storage_url:
+                return 302, Redirect(f"{self.storage_url}/media/{match.group(1)}")
+            return 200, self.files[match.group(1)]
+        match = re.match(r"^/rest/api/[23]/(.+)$", path)
+        if not match:
+            return 404, {"errorMessages": [f"No API at {path}"], "errors": {}}
+        route = match.group(1)
+        if route == "myself" and method == "GET":
+            return 200, MYSELF
+        if route in ("search", "search/jql") and method == "GET":
+            found = self.search(query.get("jql", ""))
+            start = int(query.get("startAt", 0))
+            size = int(query.get("maxResults", 50))
+            return 200, {
+                "startAt": start,
+                "maxResults": size,
+                "total": len(found),
+                "issues": found[start : start + size],
+            }
+        match = re.match(r"^issue/([A-Z]+-\d+)(?:/(comment|transitions))?$", route)
+        if not match or match.group(1) not in self.issues:
+            return 404, {
+                "errorMessages": ["Issue does not exist or you do not have permission"],
+                "errors": {},
+            }
+        key, sub = match.groups()
+        issue = self.issues[key]
+        if sub is None and method == "GET":
+            comments = self.comments[key]
+            return 200, {
+                **issue,
+                "fields": {
+                    **issue["fields"],
+                    "comment": {"comments": comments, "total": len(comments)},
+                },
+            }
+        if sub is None and method == "PUT":
+            issue["fields"].update(body.get("fields") or {})
+            return 204, None
+        if sub == "comment" and method == "GET":
+            comments = self.comments[key]
+            start = int(query.get("startAt", 0))
+            size = int(query.get("maxResults", 50))
+            return 200, {
+                "startAt": start,
+                "maxResults": size,
+                "total": len(comments),
+                "comments": comments[start : start + size],
+            }
+        if sub == "comment" and method == "POST":
+            comment = {
+                "id": str(100 + len(self.comments[key])),
+                "author": MYSELF,
+                "created": "2026-10-14T09:00:00.000+0000",
+                "updated": "2026-10-14T09:00:00.000+0000",
+                "body": body.get("body"),
+            }
+            self.comments[key].append(comment)
+            return 201, comment
+        if sub == "transitions" and method == "GET":
+            return 200, {"transitions": self.transitions(key)}
+        if sub == "transitions" and method == "POST":
+            wanted = (body.get("transition") or {}).get("id")
+            transition = next(
+                (x for x in self.transitions(key) if x["id"] == wanted), None
+            )
+            if transition is None:
+                return 400, {
+                    "errorMessages": [f"Transition {wanted} is not valid"],
+                    "errors": {},
+                }
+            issue["fields"]["status"] = transition["to"]
+            return 204, None
+        return 405, {"errorMessages": [f"{method} not allowed"], "errors": {}}
+
+
+class _Handler(BaseHTTPRequestHandler):
+    def _answer(self) -> None:
+        url = urllib.parse.urlsplit(self.path)
+        query = dict(urllib.parse.parse_qsl(url.query))
+        length = int(self.headers.get("Content-Length") or 0)
+        body = json.loads(self.rfile.read(length)) if length else None
+        self.server.jira.last_headers = dict(self.headers)  # type: ignore[attr-defined]
+        status, data = self.server.jira.handle(  # type: ignore[attr-defined]
+            self.command, url.path, query, body
+        )
+        if isinstance(data, Redirect):
+            self.send_response(status)
+            self.send_header("Location", data.location)
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+            return
+        if isinstance(data, bytes):
+            payload, content_type = data, "application/octet-stream"
+        else:
+            payload = json.dumps(data).encode("utf-8") if data is not None else b""
+            content_type = "application/json"
+        self.send_response(status)
+        self.send_header("Content-Type", content_type)
+        self.send_header("Content-Length", str(len(payload)))
+        self.end_headers()
+        self.wfile.write(payload)
+
+    do_GET = do_POST = do_PUT = do_DELETE = _answer
+
+    def log_message(self, format, *args):  # pylint: disable=redefined-builtin
+        """Keep the requests out of the test output."""
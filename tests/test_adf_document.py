@@ -0,0 +1,82 @@
+"""Tests for the structured ADF document tree."""
+
+from jayrah.utils import adf, adf_document
+
+
+def doc(*content):
+    return {"type": "doc", "version": 1, "content": list(content)}
+
+
+def paragraph(*content):
+    return {"type": "paragraph", "content": list(content)}
+
+
+def text(value, *marks):
+    node = {"type": "text", "text": value}
+    if marks:
+        node["marks"] = list(marks)
+    return node
+
+
+def cell(value, cell_type="tableCell"):
+    return {"type": cell_type, "content": [paragraph(text(value))]}
+
+
+def test_links_and_mentions_are_kept():
+    """Inline links and mentions survive the parsing."""
+    link = {"type": "link", "attrs": {"href": "https://example.com"}}
+    mention = {"type": "mention", "attrs": {"id": "abc123", "text": "@Jane"}}
+    document = adf_document.parse(
+        doc(paragraph(text("see "), text("docs", link), mention))
+    )
+
+    inlines = document.content[0].content
+    assert inlines[0].href is None
+    assert inlines[1].href == "https://example.com"
+    assert inlines[2] == adf_document.Mention("abc123", "@Jane")
+
+
+def test_code_block_language():
+    """Code blocks keep their language and their text."""
+    code = {
+        "type": "codeBlock",
+        "attrs": {"language": "python"},
+        "content": [text("print(1)")],
+    }
+    block = adf_document.parse(doc(code)).content[0]
+
+    assert block == adf_document.CodeBlock("print(1)", "python")
+
+
+def test_table_and_media():
+    """Tables keep their header cells and media groups their attachments."""
+    table = {
+        "type": "table",
+        "content": [
+            {"type": "tableRow", "content": [cell("Name", "tableHeader")]},
+            {"type": "tableRow", "content": [cell("a|b")]},
+        ],
+    }
+    media = {
+        "type": "mediaSingle",
+        "content": [{"type": "media", "attrs": {"id": "42", "alt": "shot.png"}}],
+    }
+    document = adf_document.parse(doc(table, media))
+
+    parsed_table, parsed_media = document.content
+    assert parsed_table.rows[0].cells[0].header
+    assert not parsed_table.rows[1].cells[0].header
+    assert parsed_media == adf_document.Media(id="42", alt="shot.png")
+    assert adf.document_to_markdown(document) == (
+        "| Name |\n| --- |\n| a\\|b |\n\n📎 *shot.png*"
+    )
+
+
+def test_unknown_nodes_keep_their_json():
+    """Nodes the parser does not know are not dropped."""
+    node = {"type": "decisionList", "content": [paragraph(text("ship it"))]}
+
+    document = adf_document.parse(doc(node))
+
+    assert document.content == [adf_document.Unknown("decisionList", node)]
+    assert adf.adf_to_markdown(doc(node)) == "ship it"
@@ -0,0 +1,84 @@
+"""Tests for the Jira Agile boards and sprints client."""
+
+from unittest.mock import patch
+
+from jayrah.api.jira_client import JiraHTTP
+
+
+def test_agile_base_url(sample_config):
+    """The agile client talks to its own API root on the same server."""
+    client = JiraHTTP(sample_config, api_version="3")
+    assert (
+        client.agile.request_handler.base_url
+        == f"{sample_config['jira_server']}/rest/agile/1.0"
+    )
+    assert client.agile.request_handler.headers is client.headers
+
+
+def test_list_boards_follows_pagination(sample_config):
+    """Test listing boards collects every page until isLast."""
+    agile = JiraHTTP(sample_config).agile
+    with patch.object(agile, "_request") as mock_request:
+        mock_request.side_effect = [
+            {"values": [{"id": 1}], "isLast": False},
+            {"values": [{"id": 2}], "isLast": True},
+        ]
+
+        boards = agile.list_boards(project_key="TEST", board_type="scrum")
+
+    assert boards == [{"id": 1}, {"id": 2}]
+    assert mock_request.call_args_list[1].kwargs["params"] == {
+        "maxResults": 50,
+        "projectKeyOrId": "TEST",
+        "type": "scrum",
+        "startAt": 1,
+    }
+
+
+def test_get_board_sprints(sample_config):
+    """Test listing sprints of a board filters on state and skips the cache."""
+    agile = JiraHTTP(sample_config).agile
+    with patch.object(agile, "_request") as mock_request:
+        mock_request.return_value = {"values": [{"id": 10, "name": "Sprint 1"}]}
+
+        sprints = agile.get_board_sprints(42)
+
+    assert sprints == [{"id": 10, "name": "Sprint 1"}]
+    mock_request.assert_called_once_with(
+        "GET",
+        "board/42/sprint",
+        params={"maxResults": 50, "state": "active,future", "startAt": 0},
+        use_cache=False,
+    )
+
+
+def test_get_sprint_issues_follows_total(sample_config):
+    """Test sprint issues are fetched until the total is reached."""
+    agile = JiraHTTP(sample_config).agile
+    with patch.object(agile, "_request") as mock_request:
+        mock_request.side_effect = [
+            {"issues": [{"key": "TEST-1"}], "total": 2},
+            {"issues": [{"key": "TEST-2"}], "total": 2},
+        ]
+
+        issues = agile.get_sprint_issues(10, fields=["key", "summary"], max_results=1)
+
+    assert [issue["key"] for issue in issues] == ["TEST-1", "TEST-2"]
+    assert mock_request.call_args_list[1].kwargs["params"] == {
+        "maxResults": 1,
+        "fields": "key,summary",
+        "startAt": 1,
+    }
+
+
+def test_move_issues_to_sprint(sample_config):
+    """Test moving issues posts their keys to the sprint."""
+    agile = JiraHTTP(sample_config).agile
+    with patch.object(agile, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        agile.move_issues_to_sprint(10, ["TEST-1", "TEST-2"])
+
+    mock_request.assert_called_once_with(
+        "POST", "sprint/10/issue", jeez={"issues": ["TEST-1", "TEST-2"]}
+    )
@@ -79,7 +79,7 @@ class TestJiraHTTPAuth(unittest.TestCase):
         print(context.exception)
         self.assertTrue("requires both username and password" in str(context.exception))
 
-    @patch("urllib.request.urlopen")
+    @patch("urllib.request.OpenerDirector.open")
     def test_v3_basic_auth_request(self, mock_urlopen):
         """Test that a request with API v3 and Basic auth works correctly."""
         # Mock the response
@@ -29,6 +29,11 @@ def mock_boards(monkeypatch):
             self.list_issues_called = False
             self.list_issues_jql = None
             self.issues_client = MagicMock()
+            self.state_store = MagicMock()
+            self.state_store.session.return_value = dict(
+                mock_build_search_jql_wrapper.session
+            )
+            mock_build_search_jql_wrapper.instance = self
 
             def _list_issues(jql, order_by=None):
                 self.list_issues_called = True
@@ -38,7 +43,9 @@ def mock_boards(monkeypatch):
             self.issues_client.list_issues.side_effect = _list_issues
             self.issues_client.list_issues.return_value = []
 
-        def fuzzy_search(self, issues, auto_choose=False):
+        def fuzzy_search(
+            self, issues, auto_choose=False, on_no_issues=None, choose_multi=False
+        ):
             return None
 
     # Mock check function
@@ -63,6 +70,7 @@ def mock_boards(monkeypatch):
         )
 
     mock_build_search_jql_wrapper.base_jql = None
+    mock_build_search_jql_wrapper.session = {}
     mock_build_search_jql_wrapper.search_terms = None
 
     monkeypatch.setattr(boards, "Boards", MockBoards)
@@ -97,6 +105,17 @@ def test_browse_query_with_extra_terms(runner, mock_boards):
     assert "term" in mock_boards.search_terms
 
 
+@pytest.mark.parametrize(
+    "argument", ["TEST-123", "https://jira.example.com/browse/TEST-123"]
+)
+def test_browse_issue_key(runner, mock_boards, argument):
+    """An issue key or browse URL shows that issue alone"""
+    result = runner.invoke(commands.cli, ["browse", argument])
+
+    assert result.exit_code == 0
+    assert mock_boards.base_jql == "key = TEST-123"
+
+
 def test_browse_query_overrides_board_check(runner, mock_boards, monkeypatch):
     """Test that --query skips boards.check"""
     mock_check_called = False
@@ -113,3 +132,26 @@ def test_browse_query_overrides_board_check(runner, mock_boards, monkeypatch):
     assert result.exit_code == 0
     assert not mock_check_called
     assert mock_boards.base_jql == "assignee = currentUser()"
+
+
+def test_browse_restores_the_last_session(runner, mock_boards):
+    """Without arguments the query and view of the last session come back"""
+    mock_boards.session = {
+        "command": "Custom Query",
+        "jql": "project = TEAM",
+        "order_by": "created",
+        "filter_text": "login",
+    }
+
+    result = runner.invoke(commands.cli, ["browse"])
+
+    assert result.exit_code == 0
+    assert mock_boards.base_jql == "project = TEAM"
+    assert mock_boards.instance.order_by == "created"
+    assert mock_boards.instance.session["filter_text"] == "login"
+
+    result = runner.invoke(commands.cli, ["browse", "--fresh"])
+
+    assert result.exit_code == 0
+    assert mock_boards.base_jql == "project = TEST"
+    assert mock_boards.instance.session == {}
@@ -3,7 +3,7 @@ import time
 from pathlib import Path
 from unittest import TestCase, mock
 
-from jayrah.utils.cache import JiraCache
+from jayrah.utils.cache import JiraCache, LRUCache
 
 
 class TestJiraCache(TestCase):
@@ -167,3 +167,28 @@ class TestJiraCache(TestCase):
         cached_data = cache.get(url)
 
         self.assertIsNone(cached_data)
+
+
+class TestLRUCache(TestCase):
+    def test_evicts_least_recently_used(self):
+        """Test the oldest unused entry goes once the cache is full."""
+        lru = LRUCache(2)
+        lru["A-1"] = 1
+        lru["A-2"] = 2
+        self.assertEqual(lru["A-1"], 1)
+        lru["A-3"] = 3
+
+        self.assertEqual(list(lru), ["A-1", "A-3"])
+        self.assertNotIn("A-2", lru)
+
+    def test_expires_old_entries(self):
+        """Test entries older than the TTL are gone and reported as expired."""
+        lru = LRUCache(10, ttl=60)
+        with mock.patch("time.monotonic", return_value=1000.0):
+            lru["A-1"] = "detail"
+        with mock.patch("time.monotonic", return_value=1030.0):
+            self.assertEqual(lru.get("A-1"), "detail")
+        with mock.patch("time.monotonic", return_value=1100.0):
+            self.assertTrue(lru.expired("A-1"))
+            self.assertIsNone(lru.get("A-1"))
+        self.assertFalse(lru.expired("A-1"))
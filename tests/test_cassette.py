@@ -0,0 +1,158 @@
+"""Tests for recording the Jira requests and replaying them."""
+
+import json
+import pathlib
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from jayrah.api import exceptions, models
+from jayrah.api.cassette import Cassette, CassetteError
+from jayrah.api.jira_client import JiraHTTP
+
+CASSETTES = pathlib.Path(__file__).parent / "fixtures" / "cassettes"
+LIST_FIELDS = [
+    "summary",
+    "issuetype",
+    "status",
+    "priority",
+    "assignee",
+    "reporter",
+    "labels",
+    "created",
+    "updated",
+]
+
+
+@pytest.fixture
+def replay_client(sample_config):
+    """A client answered by the browse_issue cassette, nothing is sent."""
+    config = {
+        **sample_config,
+        "no_cache": True,
+        "cassette": str(CASSETTES / "browse_issue.json"),
+    }
+    with patch("urllib.request.OpenerDirector.open") as mock_urlopen:
+        yield JiraHTTP(config)
+    mock_urlopen.assert_not_called()
+
+
+def test_replay_parses_recorded_answers(replay_client):
+    """The recorded search, issue and transitions parse into the models."""
+    search = replay_client.search_issues(
+        "project = TEST", start_at=0, max_results=50, fields=LIST_FIELDS
+    )
+    rows = [models.ListIssue.from_api(x) for x in search["issues"]]
+    assert [(x.key, x.status_category, x.assignee) for x in rows] == [
+        ("TEST-1", "indeterminate", "Jane Doe"),
+        ("TEST-2", "new", None),
+    ]
+
+    issue = models.DetailIssue.from_api(replay_client.get_issue("TEST-1"))
+    assert issue.components == ["auth"]
+    assert issue.comments[0].body == "Found it, the next parameter is dropped."
+
+    transitions = replay_client.get_transitions("TEST-1", expand_fields=True)
+    assert models.Transition.from_api(transitions["transitions"][0]).to_dict() == {
+        "id": "31",
+        "name": "Done",
+        "to_status": "Done",
+        "to_category": "done",
+        "required_fields": ["resolution"],
+    }
+    replay_client.transition_issue("TEST-1", "31")
+
+
+def test_replay_raises_recorded_errors(replay_client):
+    """A recorded error goes through the same handling as a live one."""
+    with pytest.raises(exceptions.JiraNotFoundError) as excinfo:
+        replay_client.get_issue("TEST-9")
+
+    assert "Issue does not exist" in excinfo.value.response_body
+
+
+def test_replay_fails_on_unrecorded_requests(replay_client):
+    """A request missing from the cassette fails rather than reaching Jira."""
+    with pytest.raises(CassetteError, match="GET /rest/api/2/issue/TEST-3"):
+        replay_client.get_issue("TEST-3")
+
+
+def test_record_keeps_no_credentials(sample_config, tmp_path):
+    """Recording writes the requests and responses, without the headers."""
+    path = tmp_path / "recorded.json"
+    config = {
+        **sample_config,
+        "no_cache": True,
+        "cassette": str(path),
+        "cassette_mode": "record",
+    }
+    response = MagicMock()
+    response.status = 200
+    response.read.return_value = json.dumps({"key": "TEST-1"}).encode("utf-8")
+    response.__enter__.return_value = response
+    with patch("urllib.request.OpenerDirector.open", return_value=response):
+        JiraHTTP(config).update_issue("TEST-1", {"summary": "New title"})
+
+    text = path.read_text()
+    assert sample_config["jira_password"] not in text
+    assert json.loads(text) == [
+        {
+            "request": {
+                "method": "PUT",
+                "path": "/rest/api/2/issue/TEST-1",
+                "body": {"fields": {"summary": "New title"}},
+            },
+            "response": {"status": 200, "body": {"key": "TEST-1"}},
+        }
+    ]
+    assert Cassette(path).interactions == json.loads(text)
+
+
+def record_response(body):
+    """An answer of the patched opener."""
+    response = MagicMock()
+    response.status = 200
+    response.read.return_value = json.dumps(body).encode("utf-8")
+    response.__enter__.return_value = response
+    return response
+
+
+def test_record_masks_the_session_login(sample_config, tmp_path):
+    """The password and session of a cookie login are masked, when replayed too."""
+    path = tmp_path / "recorded.json"
+    config = {
+        **sample_config,
+        "no_cache": True,
+        "cassette": str(path),
+        "cassette_mode": "record",
+    }
+    session = {"session": {"name": "JSESSIONID", "value": "s3cret-session"}}
+    answers = [record_response(session), record_response({"key": "TEST-1"})]
+    with patch("urllib.request.OpenerDirector.open", side_effect=answers):
+        JiraHTTP(config, auth_method="cookie").get_issue("TEST-1")
+
+    text = path.read_text()
+    assert "testpassword" not in text
+    assert "s3cret-session" not in text
+    assert json.loads(text)[0] == {
+        "request": {
+            "method": "POST",
+            "path": "/rest/auth/1/session",
+            "body": {"username": "testuser", "password": "REDACTED"},
+        },
+        "response": {
+            "status": 200,
+            "body": {"session": {"name": "REDACTED", "value": "REDACTED"}},
+        },
+    }
+
+    replayed = JiraHTTP({**config, "cassette_mode": "replay"}, auth_method="cookie")
+    with patch("urllib.request.OpenerDirector.open") as mock_urlopen:
+        assert replayed.get_issue("TEST-1") == {"key": "TEST-1"}
+    mock_urlopen.assert_not_called()
+
+
+def test_invalid_cassette_mode(tmp_path):
+    """Only the record and replay modes exist."""
+    with pytest.raises(ValueError, match="record or replay"):
+        Cassette(tmp_path / "cassette.json", "append")
@@ -2,12 +2,15 @@
 Tests for the CLI commands.
 """
 
-from unittest.mock import MagicMock
+import json
+from unittest.mock import MagicMock, patch
 
 import pytest
+import yaml
 from click.testing import CliRunner
 
 from jayrah import commands
+from jayrah.api import jira_client, models
 from jayrah.ui import boards
 
 
@@ -31,6 +34,9 @@ def mock_boards(monkeypatch):
             self.fuzzy_search_called = False
             self.auto_choose = False
             self.issues_client = MagicMock()
+            self.jira = mock_build_search_jql.jira
+            self.state_store = MagicMock()
+            self.state_store.session.return_value = {}
 
             def _list_issues(jql, order_by=None):
                 self.list_issues_called = True
@@ -40,9 +46,12 @@ def mock_boards(monkeypatch):
             self.issues_client.list_issues.side_effect = _list_issues
             mock_build_search_jql.last_instance = self
 
-        def fuzzy_search(self, issues, auto_choose=False):
+        def fuzzy_search(
+            self, issues, auto_choose=False, on_no_issues=None, choose_multi=False
+        ):
             self.fuzzy_search_called = True
             self.auto_choose = auto_choose
+            self.choose_multi = choose_multi
             return mock_build_search_jql.fuzzy_search_result
 
         def suggest_git_branch(self, search_terms=None, use_or=False, filters=None):
@@ -76,6 +85,7 @@ def mock_boards(monkeypatch):
     mock_build_search_jql.last_instance = None
     mock_build_search_jql.issues_return_value = []
     mock_build_search_jql.fuzzy_search_result = None
+    mock_build_search_jql.jira = MagicMock()
 
     monkeypatch.setattr(boards, "Boards", MockBoards)
     monkeypatch.setattr(boards, "check", mock_check)
@@ -149,3 +159,518 @@ def test_browse_command_choose_option(runner, mock_boards, monkeypatch):
     assert f"TEST-123 {expected_server}/browse/TEST-123" in result.output
     assert mock_boards.last_instance.fuzzy_search_called
     assert mock_boards.last_instance.auto_choose
+
+
+def test_browse_command_choose_multi_format(runner, mock_boards, monkeypatch):
+    """--choose-multi prints every chosen issue with the --choose-format template"""
+    monkeypatch.setenv("JIRA_SERVER", "https://jira.example.com")
+    mock_boards.fuzzy_search_result = ["TEST-1", "TEST-2"]
+    mock_boards.jira.get_issue.side_effect = lambda key: {
+        "key": key,
+        "fields": {"summary": f"Summary of {key}"},
+    }
+
+    result = runner.invoke(
+        commands.cli,
+        ["browse", "myboard", "--choose-multi", "--choose-format", "{key}: {summary}"],
+    )
+
+    assert result.exit_code == 0
+    assert mock_boards.last_instance.choose_multi
+    assert mock_boards.last_instance.auto_choose
+    assert result.output == "TEST-1: Summary of TEST-1\nTEST-2: Summary of TEST-2\n"
+
+
+@pytest.fixture
+def cli_jira(monkeypatch):
+    """Boards stand-in sharing one mocked Jira client with the cli commands"""
+    jira = MagicMock()
+    issues_client = MagicMock()
+
+    class FakeBoards:
+        def __init__(self, config, *args, **kwargs):
+            self.config = config
+            self.jira = jira
+            self.issues_client = issues_client
+
+    monkeypatch.setattr(boards, "Boards", FakeBoards)
+    monkeypatch.setattr(boards, "check", lambda *args: ("project = TEST", "updated"))
+    jira.issues_client = issues_client
+    return jira
+
+
+def make_issue(key="TEST-1", **fields):
+    """Raw Jira issue with the fields of the issue list"""
+    return {
+        "key": key,
+        "fields": {
+            "summary": "A story",
+            "issuetype": {"name": "Story"},
+            "status": {"name": "New"},
+            "assignee": {"displayName": "Jane Doe"},
+            "labels": ["backend"],
+            **fields,
+        },
+    }
+
+
+def test_list_issue_keeps_the_status_category():
+    """The category of the status is kept next to its workflow name"""
+    status = {"name": "Review", "statusCategory": {"key": "indeterminate"}}
+
+    issue = models.ListIssue.from_api(make_issue(status=status))
+
+    assert (issue.status, issue.status_category) == ("Review", "indeterminate")
+
+
+def test_issue_activity_keeps_comments_transitions_and_assignments():
+    """Only status and assignee changes are kept from the changelog"""
+    histories = [
+        {
+            "created": "2024-01-02T10:00:00.000+0000",
+            "author": {"displayName": "Jane Doe"},
+            "items": [
+                {"field": "status", "fromString": "New", "toString": "Done"},
+                {"field": "labels", "fromString": "", "toString": "a"},
+            ],
+        },
+        {
+            "created": "2024-01-01T10:00:00.000+0000",
+            "items": [{"field": "assignee", "toString": None}],
+        },
+    ]
+    comments = [{"created": "2024-01-03T10:00:00.000+0000", "body": "Fixed\nmore"}]
+
+    events = models.issue_activity("TEST-1", histories, comments)
+
+    assert [(x.kind, x.text) for x in events] == [
+        ("comment", "Fixed"),
+        ("transition", "New → Done"),
+        ("assignment", "assigned to nobody"),
+    ]
+    assert events[1].author == "Jane Doe"
+
+
+def test_cli_standup_rejects_unknown_window(runner, cli_jira):
+    """--since only takes the windows the standup knows"""
+    result = runner.invoke(commands.cli, ["cli", "standup", "--since", "soon"])
+
+    assert result.exit_code == 2
+    assert "Invalid window 'soon'" in result.output
+    cli_jira.issues_client.list_issues.assert_not_called()
+
+
+def test_cli_standup_prints_the_report(runner, cli_jira):
+    """The standup of the week is printed as Markdown"""
+    cli_jira.verify_auth.return_value = {"displayName": "Jane Doe"}
+    cli_jira.issues_client.list_issues.return_value = []
+
+    result = runner.invoke(commands.cli, ["cli", "standup", "--since", "week"])
+
+    assert result.exit_code == 0, result.output
+    assert result.output.startswith("# Standup since ")
+    jql = cli_jira.issues_client.list_issues.call_args.args[0]
+    assert jql.startswith("updated >= -7d")
+
+
+def test_cli_list_outputs_json(runner, cli_jira):
+    """The issues of a board are printed as a JSON list"""
+    cli_jira.issues_client.list_issues.return_value = [make_issue()]
+
+    result = runner.invoke(commands.cli, ["cli", "list", "myboard"])
+
+    assert result.exit_code == 0, result.output
+    issues = json.loads(result.output)
+    assert issues[0]["key"] == "TEST-1"
+    assert issues[0]["status"] == "New"
+    assert issues[0]["assignee"] == "Jane Doe"
+    assert issues[0]["priority"] is None
+    cli_jira.issues_client.list_issues.assert_called_once_with(
+        "project = TEST", order_by="updated", board_overrides={}
+    )
+
+
+def test_cli_detail_includes_comments(runner, cli_jira):
+    """The detail of an issue carries its description and comments"""
+    cli_jira.get_issue.return_value = make_issue(
+        description="Some text",
+        comment={"comments": [{"id": "1", "body": "Looks good"}]},
+    )
+
+    result = runner.invoke(commands.cli, ["cli", "detail", "TEST-1"])
+
+    assert result.exit_code == 0, result.output
+    issue = json.loads(result.output)
+    assert issue["description"] == "Some text"
+    assert issue["comments"][0]["body"] == "Looks good"
+    assert issue["url"].endswith("/browse/TEST-1")
+
+
+def test_cli_comment_lists_whole_thread(runner, cli_jira):
+    """Listing the comments fetches every page of them"""
+    cli_jira.get_all_issue_comments.return_value = [
+        {"id": "2", "body": "Second"},
+        {"id": "1", "body": "First"},
+    ]
+
+    result = runner.invoke(commands.cli, ["cli", "comment", "TEST-1", "--newest-first"])
+
+    assert result.exit_code == 0, result.output
+    cli_jira.get_all_issue_comments.assert_called_once_with(
+        "TEST-1", newest_first=True, use_cache=False
+    )
+    assert [x["body"] for x in json.loads(result.output)] == ["Second", "First"]
+
+
+def test_cli_comment_adds_from_stdin(runner, cli_jira):
+    """A - as the comment text reads it from stdin"""
+    cli_jira.add_comment.return_value = {"id": "10", "body": "From stdin\n"}
+
+    result = runner.invoke(
+        commands.cli, ["cli", "comment", "TEST-1", "-"], input="From stdin\n"
+    )
+
+    assert result.exit_code == 0, result.output
+    cli_jira.add_comment.assert_called_once_with("TEST-1", "From stdin\n")
+    assert json.loads(result.output)["id"] == "10"
+
+
+def test_cli_edit_updates_fields_and_assignee(runner, cli_jira):
+    """Edited fields are sent in one update, the assignee separately"""
+    result = runner.invoke(
+        commands.cli,
+        ["cli", "edit", "TEST-1", "--summary", "New title", "--assignee", ""],
+    )
+
+    assert result.exit_code == 0, result.output
+    cli_jira.update_issue.assert_called_once_with("TEST-1", {"summary": "New title"})
+    cli_jira.assign_issue.assert_called_once_with("TEST-1", None)
+    assert json.loads(result.output)["updated"] == ["assignee", "summary"]
+
+
+def test_cli_edit_due_date(runner, cli_jira):
+    """The due date accepts ISO dates, an empty value clears it"""
+    result = runner.invoke(commands.cli, ["cli", "edit", "TEST-1", "--due", ""])
+
+    assert result.exit_code == 0, result.output
+    cli_jira.update_issue.assert_called_once_with("TEST-1", {"duedate": None})
+
+    result = runner.invoke(commands.cli, ["cli", "edit", "TEST-1", "--due", "soon"])
+
+    assert result.exit_code == 1
+    assert "Invalid date" in result.output
+
+
+def test_cli_edit_without_changes_fails(runner, cli_jira):
+    """Nothing to edit is an error"""
+    result = runner.invoke(commands.cli, ["cli", "edit", "TEST-1"])
+
+    assert result.exit_code == 1
+    cli_jira.update_issue.assert_not_called()
+
+
+def test_cli_list_tsv_and_yaml_output(runner, cli_jira):
+    """The issue list can also be printed as TSV or YAML"""
+    cli_jira.issues_client.list_issues.return_value = [
+        make_issue(summary="Tabs\tand\nnewlines", labels=["a", "b"])
+    ]
+
+    result = runner.invoke(commands.cli, ["cli", "list", "myboard", "-o", "tsv"])
+
+    assert result.exit_code == 0, result.output
+    header, row = result.output.strip().split("\n")
+    assert header.split("\t")[:2] == ["key", "summary"]
+    columns = dict(zip(header.split("\t"), row.split("\t")))
+    assert columns["summary"] == "Tabs and newlines"
+    assert columns["labels"] == "a,b"
+    assert columns["priority"] == ""
+
+    result = runner.invoke(commands.cli, ["cli", "list", "myboard", "-o", "yaml"])
+
+    assert result.exit_code == 0, result.output
+    assert yaml.safe_load(result.output)[0]["labels"] == ["a", "b"]
+
+
+def test_serialize_detail_as_tsv():
+    """Nested comments of a detail are counted in TSV"""
+    issue = models.DetailIssue.from_api(
+        make_issue(comment={"comments": [{"id": "1"}, {"id": "2"}]}),
+        "https://jira.example.com",
+    )
+
+    header, row = models.serialize(issue.to_dict(), "tsv").split("\n")
+
+    assert dict(zip(header.split("\t"), row.split("\t")))["comments"] == "2"
+
+
+@patch("jayrah.commands.mcli.git.current_branch", return_value="jay-7-login")
+def test_cli_commit_msg_prints_the_issue_line(_mock_branch, runner, cli_jira):
+    """The issue of the branch gives the commit message line"""
+    cli_jira.get_issue.return_value = make_issue("JAY-7", summary="Fix login")
+
+    result = runner.invoke(commands.cli, ["cli", "commit-msg"])
+
+    assert result.exit_code == 0, result.output
+    assert result.output == "JAY-7: Fix login\n"
+    cli_jira.get_issue.assert_called_once_with("JAY-7", fields=["summary"])
+
+
+def test_cli_commit_msg_without_issue_in_branch(runner, cli_jira):
+    """A branch without issue key is an error"""
+    result = runner.invoke(commands.cli, ["cli", "commit-msg", "--branch", "main"])
+
+    assert result.exit_code == 1
+    assert "No issue key in branch 'main'" in result.output
+
+
+def test_cli_commit_msg_hook_without_issue_in_branch(runner, cli_jira, tmp_path):
+    """As a hook, a branch without issue key warns and lets the commit go"""
+    path = tmp_path / "COMMIT_EDITMSG"
+    path.write_text("Fix login\n")
+
+    result = runner.invoke(
+        commands.cli, ["cli", "commit-msg", str(path), "--branch", "main"]
+    )
+
+    assert result.exit_code == 0, result.output
+    assert "No issue key in branch 'main', commit message left alone" in result.output
+    assert path.read_text() == "Fix login\n"
+    cli_jira.get_issue.assert_not_called()
+
+
+def test_cli_commit_msg_hook_when_the_fetch_fails(runner, cli_jira, tmp_path):
+    """As a hook, an issue that cannot be fetched warns and lets the commit go"""
+    cli_jira.get_issue.side_effect = Exception("Connection refused")
+    path = tmp_path / "COMMIT_EDITMSG"
+    path.write_text("Fix login\n")
+
+    result = runner.invoke(
+        commands.cli, ["cli", "commit-msg", str(path), "--branch", "jay-7-login"]
+    )
+
+    assert result.exit_code == 0, result.output
+    assert "Error fetching ticket JAY-7: Connection refused" in result.output
+    assert path.read_text() == "Fix login\n"
+
+
+def test_cli_transition_with_resolution(runner, cli_jira):
+    """The resolution and comment options fill the transition screen"""
+    cli_jira.get_transitions.return_value = {
+        "transitions": [{"id": "31", "name": "Close", "to": {"name": "Closed"}}]
+    }
+
+    result = runner.invoke(
+        commands.cli,
+        ["cli", "transition", "TEST-1", "closed", "--resolution", "Done"],
+    )
+
+    assert result.exit_code == 0, result.output
+    cli_jira.transition_issue.assert_called_once_with(
+        "TEST-1", "31", models.TransitionFields("Done", None)
+    )
+
+
+def rpc(method, request_id=1, **params):
+    """A JSON-RPC request line"""
+    request = {"jsonrpc": "2.0", "id": request_id, "method": method}
+    return json.dumps({**request, "params": params})
+
+
+def test_serve_answers_each_request_line(runner, cli_jira):
+    """Each request line gets a response line with the JSON of the cli"""
+    cli_jira.get_issue.return_value = make_issue()
+    cli_jira.add_comment.return_value = {"id": "1", "body": "LGTM"}
+    requests = "\n".join(
+        [
+            rpc("issue-show", 1, key="TEST-1"),
+            rpc("comment-add", 2, key="TEST-1", body="LGTM"),
+        ]
+    )
+
+    result = runner.invoke(commands.cli, ["serve", "--stdio"], input=requests)
+
+    assert result.exit_code == 0, result.output
+    responses = [json.loads(x) for x in result.output.splitlines()]
+    assert responses[0]["id"] == 1
+    assert responses[0]["result"]["key"] == "TEST-1"
+    assert responses[1]["result"]["body"] == "LGTM"
+    cli_jira.add_comment.assert_called_once_with("TEST-1", "LGTM")
+
+
+def test_serve_transition_by_status(runner, cli_jira):
+    """A transition is found by its target status"""
+    cli_jira.get_transitions.return_value = {
+        "transitions": [{"id": "31", "name": "Close", "to": {"name": "Closed"}}]
+    }
+
+    result = runner.invoke(
+        commands.cli,
+        ["serve", "--stdio"],
+        input=rpc("transition-apply", key="TEST-1", transition="closed"),
+    )
+
+    response = json.loads(result.output)
+    assert response["result"] == {
+        "key": "TEST-1",
+        "transition": "Close",
+        "status": "Closed",
+    }
+    cli_jira.transition_issue.assert_called_once_with("TEST-1", "31", None)
+
+
+def test_serve_errors(runner, cli_jira):
+    """Bad requests get JSON-RPC errors and the daemon keeps going"""
+    cli_jira.get_issue.side_effect = Exception("boom")
+    requests = "\n".join(
+        [
+            "not json",
+            rpc("unknown"),
+            rpc("issue-show"),
+            rpc("issue-show", key="TEST-1"),
+        ]
+    )
+
+    result = runner.invoke(commands.cli, ["serve", "--stdio"], input=requests)
+
+    codes = [json.loads(x)["error"]["code"] for x in result.output.splitlines()]
+    assert codes == [-32700, -32601, -32602, -32000]
+
+
+def test_serve_payloads_follow_the_contract(runner, cli_jira):
+    """The daemon payloads have exactly the keys of their payload kind"""
+    cli_jira.issues_client.list_issues.return_value = [make_issue()]
+    cli_jira.get_issue.return_value = make_issue()
+    cli_jira.get_transitions.return_value = {
+        "transitions": [
+            {
+                "id": "31",
+                "name": "Close",
+                "to": {"name": "Closed", "statusCategory": {"key": "done"}},
+                "fields": {"resolution": {"required": True}},
+            }
+        ]
+    }
+    requests = [
+        ("list", rpc("browse-list", jql="project = TEST")),
+        ("show", rpc("issue-show", key="TEST-1")),
+        ("transitions", rpc("transitions-list", key="TEST-1")),
+    ]
+
+    result = runner.invoke(
+        commands.cli,
+        ["serve", "--stdio"],
+        input="\n".join(request for _, request in requests),
+    )
+
+    responses = [json.loads(x)["result"] for x in result.output.splitlines()]
+    for (kind, _), response in zip(requests, responses):
+        models.validate_payload(kind, response)
+    assert responses[2][0]["required_fields"] == ["resolution"]
+    assert responses[2][0]["to_category"] == "done"
+
+
+def test_validate_payload_names_the_wrong_keys():
+    """A payload missing or adding keys is refused"""
+    board = models.Board.from_config({"name": "myboard", "jql": "project = TEST"})
+    models.validate_payload("boards", [board.to_dict()])
+
+    with pytest.raises(ValueError, match="Missing keys in boards: order_by"):
+        models.validate_payload("boards", {"name": "a", "description": "", "jql": ""})
+    with pytest.raises(ValueError, match="Unknown keys in boards: extra"):
+        models.validate_payload("boards", {**board.to_dict(), "extra": 1})
+
+
+def test_browse_command_startup_flags(runner, mock_boards, monkeypatch):
+    """The layout, zoom, theme and refresh flags go to the browser config"""
+    monkeypatch.setattr(
+        boards, "overrides", lambda *args: {"refresh_interval": 300, "limit": 10}
+    )
+
+    result = runner.invoke(
+        commands.cli,
+        ["browse", "myboard", "--layout", "horizontal", "--zoom", "detail"]
+        + ["--theme", "nord", "--refresh", "60"],
+    )
+
+    assert result.exit_code == 0
+    instance = mock_boards.last_instance
+    assert [instance.config[x] for x in ("layout", "zoom", "theme")] == [
+        "horizontal",
+        "detail",
+        "nord",
+    ]
+    assert instance.refresh_override == 60
+    assert instance.board_overrides == {"refresh_interval": 300, "limit": 10}
+
+    result = runner.invoke(commands.cli, ["browse", "myboard", "--layout", "diagonal"])
+    assert result.exit_code == 2
+
+
+def test_cli_without_command_browses_the_default_board(runner, mock_boards):
+    """No command runs browse, -h is a short --help"""
+    result = runner.invoke(commands.cli, [])
+
+    assert result.exit_code == 0
+    assert mock_boards.last_instance.fuzzy_search_called
+    assert mock_boards.base_jql == "project = TEST"
+
+    result = runner.invoke(commands.cli, ["browse", "-h"])
+    assert result.exit_code == 0
+    assert "--choose" in result.output
+
+
+def test_cli_version_and_completion_need_no_config(runner, mock_boards):
+    """--version and the completion scripts work before jayrah is configured"""
+    result = runner.invoke(commands.cli, ["--version"])
+    assert result.exit_code == 0
+    version = result.output.removeprefix("jayrah, version ").strip()
+    assert jira_client.user_agent().startswith(f"jayrah/{version} ")
+
+    result = runner.invoke(commands.cli, ["completion", "zsh"])
+    assert result.exit_code == 0
+    assert "_JAYRAH_COMPLETE=zsh_complete" in result.output
+    assert mock_boards.last_instance is None
+
+    result = runner.invoke(commands.cli, ["--bogus"])
+    assert result.exit_code == 2
+    assert "No such option" in result.output
+
+
+def test_board_completion_reads_config_and_profile(runner, tmp_path):
+    """The board names are completed from the config file and profile given"""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(
+        yaml.dump(
+            {
+                "boards": [
+                    {"name": "myissues", "jql": "assignee = currentUser()"},
+                    {"name": "mybugs", "jql": "type = Bug"},
+                    {"name": "team", "jql": "project = TEAM"},
+                ],
+                "profiles": {
+                    "work": {"boards": [{"name": "mywork", "jql": "project = W"}]}
+                },
+            }
+        )
+    )
+
+    def complete(words):
+        result = runner.invoke(
+            commands.cli,
+            prog_name="jayrah",
+            env={
+                "_JAYRAH_COMPLETE": "bash_complete",
+                "COMP_WORDS": f"jayrah -c {config_file} {words}",
+                "COMP_CWORD": str(len(words.split(" ")) + 2),
+            },
+        )
+        assert result.exit_code == 0
+        lines = result.output.split()
+        return [line.split(",", 1)[1] for line in lines]
+
+    assert complete("browse my") == ["myissues", "mybugs"]
+    assert complete("cli browse t") == ["team"]
+    assert complete("--profile work browse my") == ["mywork"]
+
+    config_file.write_text("boards: [")
+    assert not complete("browse my")
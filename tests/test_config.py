@@ -1,9 +1,10 @@
 from unittest.mock import patch
 
+import pytest
 import yaml
 
 from jayrah import config
-from jayrah.config import defaults
+from jayrah.config import defaults, secrets
 
 
 # Add a patch for the read_config function to handle the NoneType issue
@@ -100,6 +101,58 @@ def test_write_config(tmp_path):
     assert written_config["general"]["jira_user"] == "test_user"
 
 
+def test_write_config_writes_every_general_option(tmp_path):
+    """Test an option added to GENERAL_OPTIONS is written without more changes."""
+    config_file = tmp_path / "config.yaml"
+    config.write_config({x: "value" for x in config.GENERAL_OPTIONS}, config_file)
+
+    written = yaml.safe_load(config_file.read_text())
+    assert set(written["general"]) == set(config.GENERAL_OPTIONS)
+
+
+def test_write_config_keeps_the_boards_as_read(tmp_path):
+    """Test a loaded config is written with its extends and variables."""
+    boards = [
+        {"name": "team", "jql": "project = ${project}"},
+        {"name": "urgent", "extends": "team", "extra_jql": "AND priority = High"},
+    ]
+    source = tmp_path / "source.yaml"
+    source.write_text(
+        yaml.safe_dump({"variables": {"project": "TEST"}, "boards": boards})
+    )
+    loaded = config.read_config({}, source)
+    assert loaded["boards"][1]["jql"] == "(project = TEST) AND priority = High"
+
+    config_file = tmp_path / "config.yaml"
+    config.write_config(loaded, config_file)
+
+    written = yaml.safe_load(config_file.read_text())
+    assert written["boards"] == boards
+    assert written["variables"] == {"project": "TEST"}
+
+
+def test_read_config_no_mouse(tmp_path):
+    """Test the TUI mouse capture can be disabled from the config file."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(yaml.safe_dump({"general": {"no_mouse": True}}))
+
+    assert config.read_config({}, config_file)["no_mouse"] is True
+    assert config.read_config({}, tmp_path / "missing.yaml")["no_mouse"] is False
+
+
+def test_read_config_retry_options(tmp_path):
+    """Test retry options default sensibly and can be set to zero."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(
+        yaml.safe_dump({"general": {"max_retries": 0, "retry_backoff": 2.5}})
+    )
+
+    ret = config.read_config({}, config_file)
+    assert ret["max_retries"] == 0
+    assert ret["retry_backoff"] == 2.5
+    assert ret["retry_jitter"] == 0.5
+
+
 @patch("rich.prompt.Prompt.ask")
 @patch("jayrah.config.write_config")
 def test_make_config_prompts_for_missing_values(
@@ -219,3 +272,267 @@ def test_make_config_auth_prompt_default_is_bearer_for_v2(
 
     assert result["api_version"] == "2"
     assert result["auth_method"] == "bearer"
+
+
+@patch("rich.prompt.Prompt.ask", return_value="TEST")
+def test_make_config_saves_the_answers_not_the_secrets(mock_prompt_ask, tmp_path):
+    """Test the file keeps its secret references when an answer is saved."""
+    config_file = tmp_path / "config.yaml"
+    general = {
+        "jira_server": "https://jira.example.com",
+        "jira_user": "jdoe",
+        "jira_password": "pass::jira/token",
+        "headers": {"X-Token": "pass::gateway"},
+        "api_version": "2",
+        "auth_method": "bearer",
+    }
+    config_file.write_text(yaml.safe_dump({"general": general}))
+
+    with patch(
+        "jayrah.config.secrets.resolve_secret",
+        side_effect=lambda value, _: value.replace("pass::", "resolved-"),
+    ):
+        result = config.make_config({}, config_file)
+
+    assert result["jira_password"] == "resolved-jira/token"
+    assert result["jira_project"] == "TEST"
+    saved = yaml.safe_load(config_file.read_text())
+    assert saved["general"] == {**general, "jira_project": "TEST"}
+    assert saved["boards"] == defaults.BOARDS
+
+
+def test_save_board_keeps_the_rest_of_the_file(tmp_path):
+    """Test saving a board replaces it by name and leaves the other settings."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(
+        yaml.safe_dump(
+            {
+                "general": {"jira_password": "pass::jira/token"},
+                "boards": [
+                    {"name": "mine", "jql": "assignee = currentUser()"},
+                    {"name": "old", "jql": "project = OLD"},
+                ],
+            },
+            sort_keys=False,
+        )
+    )
+
+    config.save_board(config_file, {"name": "old", "jql": "project = NEW"})
+
+    saved = yaml.safe_load(config_file.read_text())
+    assert saved["general"] == {"jira_password": "pass::jira/token"}
+    assert saved["boards"] == [
+        {"name": "mine", "jql": "assignee = currentUser()"},
+        {"name": "old", "jql": "project = NEW"},
+    ]
+
+
+def test_save_board_without_boards_keeps_the_defaults(tmp_path):
+    """Test the default boards are kept when the file had none."""
+    config_file = tmp_path / "config.yaml"
+
+    config.save_board(config_file, {"name": "query", "jql": "project = TEST"})
+
+    saved = yaml.safe_load(config_file.read_text())
+    assert saved["boards"] == [
+        *defaults.BOARDS,
+        {"name": "query", "jql": "project = TEST"},
+    ]
+
+
+def test_profiles_override_general_options(tmp_path):
+    """Test a profile replaces the general options and boards it defines."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(
+        yaml.safe_dump(
+            {
+                "general": {
+                    "jira_server": "https://work.example.com",
+                    "jira_user": "me",
+                },
+                "boards": [{"name": "work", "jql": "project = WORK"}],
+                "profiles": {
+                    "personal": {
+                        "jira_server": "me.atlassian.net",
+                        "api_version": "3",
+                        "boards": [{"name": "mine", "jql": "project = ME"}],
+                        "unknown": "ignored",
+                    }
+                },
+            }
+        )
+    )
+    base = config.read_config({}, config_file)
+
+    personal = config.apply_profile(base, "personal")
+
+    assert personal["jira_server"] == "https://me.atlassian.net"
+    assert personal["jira_user"] == "me"
+    assert personal["boards"] == [{"name": "mine", "jql": "project = ME"}]
+    assert "unknown" not in personal
+    # Switching back starts from the config without profile
+    default = config.apply_profile(personal, None)
+    assert default["jira_server"] == "https://work.example.com"
+    assert default["profile"] is None
+    with pytest.raises(ValueError, match="Unknown profile work"):
+        config.apply_profile(personal, "work")
+
+
+def test_profile_variables_apply_to_the_general_boards(tmp_path):
+    """Test a profile only setting variables resolves the boards with them."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(
+        yaml.safe_dump(
+            {
+                "general": {"jira_server": "https://work.example.com"},
+                "variables": {"project": "WORK"},
+                "boards": [{"name": "mine", "jql": "project = ${project}"}],
+                "profiles": {"personal": {"variables": {"project": "ME"}}},
+            }
+        )
+    )
+    base = config.read_config({}, config_file)
+
+    personal = config.apply_profile(base, "personal")
+
+    assert personal["boards"] == [{"name": "mine", "jql": "project = ME"}]
+    assert personal["raw_boards"] == [{"name": "mine", "jql": "project = ${project}"}]
+    assert base["boards"] == [{"name": "mine", "jql": "project = WORK"}]
+
+
+@patch("jayrah.config.secrets.subprocess.check_output")
+def test_resolve_secret_providers(mock_output):
+    """Test secret references run the command of their provider."""
+    mock_output.return_value = "s3cret\n"
+
+    assert secrets.resolve_secret("bw::jira") == "s3cret"
+    mock_output.assert_called_with(["bw", "get", "password", "jira"], text=True)
+
+    custom = {"secret_providers": {"op": ["op", "read", "op://Work/{item}/token"]}}
+    assert secrets.resolve_secret("op::Jira", custom) == "s3cret"
+    mock_output.assert_called_with(["op", "read", "op://Work/Jira/token"], text=True)
+
+    # Unknown prefixes and plain values are not secret references
+    assert secrets.resolve_secret("abc::def") == "abc::def"
+    assert secrets.resolve_secret("plain-token") == "plain-token"
+    assert mock_output.call_count == 2
+
+
+def test_read_config_resolves_password_with_custom_provider(tmp_path):
+    """Test providers of the general section are used for the password."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(
+        yaml.safe_dump(
+            {
+                "general": {
+                    "jira_password": "vault::jira",
+                    "secret_providers": {"vault": ["echo", "from-{item}"]},
+                }
+            }
+        )
+    )
+
+    assert config.read_config({}, config_file)["jira_password"] == "from-jira"
+
+
+def test_read_config_resolves_header_references(tmp_path):
+    """Test the values of the extra headers can be secret references."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(
+        yaml.safe_dump(
+            {
+                "general": {
+                    "headers": {
+                        "CF-Access-Client-Secret": "vault::cloudflare",
+                        "X-Forwarded-User": "jdoe",
+                    },
+                    "secret_providers": {"vault": ["echo", "from-{item}"]},
+                }
+            }
+        )
+    )
+
+    assert config.read_config({}, config_file)["headers"] == {
+        "CF-Access-Client-Secret": "from-cloudflare",
+        "X-Forwarded-User": "jdoe",
+    }
+
+
+@pytest.mark.parametrize(
+    "override, error",
+    [
+        ({"limit": 0}, "limit must be above 0"),
+        ({"fields": "summary"}, "fields must be a list of names"),
+        ({"columns": ["summary", "votes"]}, "unknown columns votes"),
+        ({"refresh_interval": "often"}, "refresh_interval must be seconds"),
+    ],
+)
+def test_read_config_checks_board_overrides(tmp_path, override, error):
+    """Test invalid board overrides are refused when the config is read."""
+    config_file = tmp_path / "config.yaml"
+    board = {"name": "work", "jql": "project = WORK"}
+    config_file.write_text(yaml.safe_dump({"boards": [{**board, **override}]}))
+
+    with pytest.raises(ValueError, match=f"Board work: {error}"):
+        config.read_config({}, config_file)
+
+    valid = {"limit": 20, "columns": ["status", "summary"], "refresh_interval": 30}
+    config_file.write_text(yaml.safe_dump({"boards": [{**board, **valid}]}))
+    assert config.read_config({}, config_file)["boards"][0]["limit"] == 20
+
+
+def test_read_config_resolves_board_extends_and_variables(tmp_path):
+    """Test boards extending others and ${name} variables are resolved."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(
+        yaml.safe_dump(
+            {
+                "variables": {"project": "TEST"},
+                "boards": [
+                    {
+                        "name": "team",
+                        "jql": "project = ${project} OR reporter = currentUser()",
+                        "order_by": "priority",
+                        "description": "The ${project} issues",
+                        "limit": 50,
+                    },
+                    {
+                        "name": "urgent",
+                        "extends": "team",
+                        "extra_jql": "AND priority = High",
+                    },
+                    {"name": "urgent-bugs", "extends": "urgent", "limit": 10},
+                ],
+            }
+        )
+    )
+
+    team, urgent, bugs = config.read_config({}, config_file)["boards"]
+
+    assert team["jql"] == "project = TEST OR reporter = currentUser()"
+    assert team["description"] == "The TEST issues"
+    assert urgent == {
+        "name": "urgent",
+        "jql": "(project = TEST OR reporter = currentUser()) AND priority = High",
+        "order_by": "priority",
+        "limit": 50,
+    }
+    assert bugs["jql"] == urgent["jql"]
+    assert bugs["limit"] == 10
+
+
+@pytest.mark.parametrize(
+    "boards, error",
+    [
+        ([{"name": "a", "jql": "project = ${nope}"}], "Board a: unknown variable"),
+        ([{"name": "a", "extends": "b"}], "Board a: extends unknown board b"),
+        (
+            [{"name": "a", "extends": "b"}, {"name": "b", "extends": "a"}],
+            "Board b: extends loop through a",
+        ),
+    ],
+)
+def test_resolve_boards_errors(boards, error):
+    """Test unknown variables, unknown boards and loops are refused."""
+    with pytest.raises(ValueError, match=error):
+        config.resolve_boards(boards)
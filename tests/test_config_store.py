@@ -0,0 +1,78 @@
+"""Tests for writing the config file back."""
+
+import yaml
+
+from jayrah.config import ConfigStore, defaults
+
+CONFIG = """\
+# My jayrah config
+# with two lines of comments
+
+general:
+  jira_server: https://jira.example.com
+  jira_password: pass::jira/token
+  custom_option: kept
+boards:
+- name: mine
+  jql: assignee = currentUser()
+- name: old
+  jql: project = OLD
+"""
+
+
+def test_round_trip_keeps_order_header_and_unknown_keys(tmp_path):
+    """Test saving an unchanged file writes it back as it was."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(CONFIG)
+
+    ConfigStore(config_file).save()
+
+    assert config_file.read_text() == CONFIG
+
+
+def test_add_and_remove_boards(tmp_path):
+    """Test boards are replaced in place, appended or removed by name."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(CONFIG)
+
+    store = ConfigStore(config_file)
+    store.add_board({"name": "mine", "jql": "assignee = currentUser() AND x = y"})
+    store.add_board({"name": "new", "jql": "project = NEW"})
+    assert store.remove_board("old")
+    assert not store.remove_board("missing")
+    store.save()
+
+    saved = yaml.safe_load(config_file.read_text())
+    assert [board["name"] for board in saved["boards"]] == ["mine", "new"]
+    assert saved["boards"][0]["jql"].endswith("x = y")
+
+
+def test_set_general(tmp_path):
+    """Test general options are set, updated and removed with None."""
+    config_file = tmp_path / "config.yaml"
+    config_file.write_text(CONFIG)
+
+    store = ConfigStore(config_file)
+    store.set_general("no_mouse", True)
+    store.set_general("custom_option", None)
+    store.save()
+
+    general = yaml.safe_load(config_file.read_text())["general"]
+    assert general == {
+        "jira_server": "https://jira.example.com",
+        "jira_password": "pass::jira/token",
+        "no_mouse": True,
+    }
+
+
+def test_missing_file_starts_from_default_boards(tmp_path):
+    """Test a new file gets the default boards the user was seeing."""
+    config_file = tmp_path / "sub" / "config.yaml"
+
+    store = ConfigStore(config_file)
+    store.add_board({"name": "query", "jql": "project = TEST"})
+    store.save()
+
+    saved = yaml.safe_load(config_file.read_text())
+    assert saved["boards"][: len(defaults.BOARDS)] == defaults.BOARDS
+    assert list(tmp_path.glob("sub/.config.yaml.*")) == []
@@ -0,0 +1,113 @@
+"""Tests for the custom field value conversions."""
+
+import datetime
+
+import pytest
+
+from jayrah.utils import custom_fields
+
+TODAY = datetime.date(2026, 10, 14)
+
+
+@pytest.mark.parametrize(
+    ("text", "expected"),
+    [
+        ("2026-12-25", datetime.date(2026, 12, 25)),
+        ("today", TODAY),
+        ("Tomorrow", datetime.date(2026, 10, 15)),
+        ("yesterday", datetime.date(2026, 10, 13)),
+        ("+3d", datetime.date(2026, 10, 17)),
+        ("-1w", datetime.date(2026, 10, 7)),
+    ],
+)
+def test_parse_date(text, expected):
+    """ISO dates and the friendly forms are understood."""
+    assert custom_fields.parse_date(text, TODAY) == expected
+
+
+def test_parse_date_rejects_garbage():
+    """Unknown forms explain what is accepted."""
+    with pytest.raises(ValueError, match="YYYY-MM-DD"):
+        custom_fields.parse_date("next friday", TODAY)
+
+
+def test_parse_datetime():
+    """Datetimes are sent with milliseconds and the timezone offset."""
+    now = datetime.datetime(2026, 10, 14, 9, 30, tzinfo=datetime.timezone.utc)
+
+    assert (
+        custom_fields.parse_datetime("2026-10-20T14:00", now)
+        == "2026-10-20T14:00:00.000+0000"
+    )
+    assert custom_fields.parse_datetime("+1d", now) == "2026-10-15T00:00:00.000+0000"
+
+
+def test_parse_custom_field_value():
+    """Values are converted, or rejected, according to the field type."""
+    assert custom_fields.parse_custom_field_value("number", "3.5") == 3.5
+    assert custom_fields.parse_custom_field_value("number", "42") == 42
+    assert custom_fields.parse_custom_field_value("string", " text ") == "text"
+    assert custom_fields.parse_custom_field_value("date", "") is None
+    with pytest.raises(ValueError, match="URL"):
+        custom_fields.parse_custom_field_value("url", "not a url")
+    with pytest.raises(ValueError, match="number"):
+        custom_fields.parse_custom_field_value("number", "many")
+
+
+OPTION_META = {
+    "schema": {"type": "option"},
+    "allowedValues": [{"value": "Yes", "id": "1"}, {"value": "No", "id": "2"}],
+}
+CASCADING_META = {
+    "allowedValues": [
+        {"value": "Hardware", "children": [{"value": "Disk"}, {"value": "CPU"}]},
+        {"value": "Software"},
+    ]
+}
+
+
+def test_parse_option_values():
+    """Options are sent as values with the name the editmeta allows."""
+    parse = custom_fields.parse_custom_field_value
+
+    assert parse("option", "yes", meta=OPTION_META) == {"value": "Yes"}
+    assert parse("option", "") is None
+    assert parse("multi-option", "yes, No", meta=OPTION_META) == [
+        {"value": "Yes"},
+        {"value": "No"},
+    ]
+    with pytest.raises(ValueError, match="not one of Yes, No"):
+        parse("option", "Maybe", meta=OPTION_META)
+
+
+def test_parse_version_user_and_cascading_values():
+    """Versions, users and cascading selects get their own shapes."""
+    parse = custom_fields.parse_custom_field_value
+
+    assert parse("version", "2.0") == {"name": "2.0"}
+    assert parse("version", "1.0, 2.0", meta={"schema": {"type": "array"}}) == [
+        {"name": "1.0"},
+        {"name": "2.0"},
+    ]
+    assert parse("user", "jdoe") == {"name": "jdoe"}
+    assert parse("user", "abc", format_user=lambda x: {"accountId": x}) == {
+        "accountId": "abc"
+    }
+    assert parse("cascading", "hardware > cpu", meta=CASCADING_META) == {
+        "value": "Hardware",
+        "child": {"value": "CPU"},
+    }
+    with pytest.raises(ValueError, match="not one of Disk, CPU"):
+        parse("cascading", "Hardware > GPU", meta=CASCADING_META)
+    with pytest.raises(ValueError, match="no child values"):
+        parse("cascading", "Software > Disk", meta=CASCADING_META)
+
+
+def test_format_custom_field_value():
+    """API values are shown as the text parse_custom_field_value takes back."""
+    fmt = custom_fields.format_custom_field_value
+
+    assert fmt([{"value": "Yes"}, {"value": "No"}]) == "Yes, No"
+    assert fmt({"value": "Hardware", "child": {"value": "CPU"}}) == "Hardware > CPU"
+    assert fmt({"accountId": "abc", "displayName": "Jane"}) == "abc"
+    assert fmt(None) == ""
@@ -0,0 +1,105 @@
+"""Tests for the doctor diagnostics."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import MagicMock
+
+import click
+
+from jayrah.api import diagnostics, exceptions
+
+
+def server_time(offset: float = 0) -> str:
+    """A serverTime value offset by that many seconds from now."""
+    now = datetime.now(timezone.utc) + timedelta(seconds=offset)
+    return now.strftime("%Y-%m-%dT%H:%M:%S.000+0000")
+
+
+def make_jira(info=None, user=None, auth_method="basic", api_version="3"):
+    """Jira client stand-in answering serverInfo and myself."""
+    jira = MagicMock(auth_method=auth_method, api_version=api_version)
+    jira.config = {"jira_server": "https://example.atlassian.net"}
+    if isinstance(info, Exception):
+        jira.get_server_info.side_effect = info
+    else:
+        jira.get_server_info.return_value = info or {
+            "deploymentType": "Cloud",
+            "version": "1001.0.0",
+            "serverTime": server_time(),
+        }
+    if isinstance(user, Exception):
+        jira.verify_auth.side_effect = user
+    else:
+        jira.verify_auth.return_value = user or {"displayName": "Jane Doe"}
+    return jira
+
+
+def test_all_checks_pass():
+    """A working setup reports every check as fine."""
+    checks = diagnostics.run_checks(make_jira())
+
+    assert [check.name for check in checks] == [
+        "Server",
+        "API version",
+        "Authentication",
+        "Clock",
+    ]
+    assert all(check.ok for check in checks)
+    assert "Jane Doe" in checks[2].message
+
+
+def test_unreachable_server_stops_the_checks():
+    """Nothing else is checked when the server cannot be reached."""
+    jira = make_jira(info=click.ClickException("URL error: timed out"))
+
+    checks = diagnostics.run_checks(jira)
+
+    assert len(checks) == 1
+    assert not checks[0].ok
+    assert "jira_server" in checks[0].hint
+    jira.verify_auth.assert_not_called()
+
+
+def test_cloud_with_bearer_suggests_basic():
+    """A Cloud API token sent as bearer gets the basic authentication hint."""
+    error = exceptions.JiraAuthenticationError("myself", "")
+    checks = diagnostics.run_checks(make_jira(user=error, auth_method="bearer"))
+
+    auth = checks[2]
+    assert not auth.ok
+    assert "auth_method: basic" in auth.hint
+    assert diagnostics.CLOUD_TOKEN_URL in auth.hint
+
+
+def test_server_with_basic_suggests_bearer():
+    """A personal access token sent as basic gets the bearer hint."""
+    jira = make_jira(
+        info={"deploymentType": "Server", "serverTime": server_time()},
+        user=exceptions.JiraAuthenticationError("myself", ""),
+    )
+    jira.config["jira_server"] = "https://jira.corp"
+
+    auth = diagnostics.run_checks(jira)[2]
+
+    assert "auth_method: bearer" in auth.hint
+    assert "https://jira.corp/secure/ViewProfile.jspa" in auth.hint
+
+
+def test_missing_api_version():
+    """API v3 on Jira Server answers 404 and points to api_version 2."""
+    jira = make_jira(info=exceptions.JiraNotFoundError("serverInfo", ""))
+
+    checks = diagnostics.run_checks(jira)
+
+    assert checks[0].name == "API version"
+    assert not checks[0].ok
+    assert "api_version: 2" in checks[0].hint
+
+
+def test_clock_skew_is_reported():
+    """A clock more than a few minutes off is flagged."""
+    jira = make_jira(info={"deploymentType": "Cloud", "serverTime": server_time(900)})
+
+    clock = diagnostics.run_checks(jira)[-1]
+
+    assert clock.name == "Clock"
+    assert not clock.ok
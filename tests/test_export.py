@@ -0,0 +1,59 @@
+"""Tests for the CSV and Markdown export of issue lists."""
+
+import csv
+
+import pytest
+
+from jayrah.utils import export
+
+SERVER = "https://jira.example.com"
+
+
+def make_issue(key, summary, status="New"):
+    return {
+        "key": key,
+        "fields": {
+            "summary": summary,
+            "status": {"name": status},
+            "issuetype": {"name": "Bug"},
+            "assignee": None,
+            "labels": ["a", "b"],
+        },
+    }
+
+
+ISSUES = [make_issue("TEST-1", "First one"), make_issue("TEST-2", "Pipe | sep")]
+
+
+def test_export_csv(tmp_path):
+    """The CSV has a header and one row per issue with the wanted columns."""
+    path = tmp_path / "report.csv"
+    columns = ["key", "status", "labels", "url"]
+
+    count = export.export_issues(ISSUES, path, columns, SERVER)
+
+    assert count == 2
+    rows = list(csv.reader(path.open()))
+    assert rows[0] == ["key", "status", "labels", "url"]
+    assert rows[1] == ["TEST-1", "New", "a, b", f"{SERVER}/browse/TEST-1"]
+
+
+def test_export_markdown(tmp_path):
+    """The Markdown table links the keys and escapes pipes."""
+    path = tmp_path / "report.md"
+
+    export.export_issues(ISSUES, path, ["key", "summary", "assignee"], SERVER)
+
+    lines = path.read_text().splitlines()
+    assert lines[0] == "| key | summary | assignee |"
+    assert lines[1] == "| --- | --- | --- |"
+    assert lines[2] == f"| [TEST-1]({SERVER}/browse/TEST-1) | First one |  |"
+    assert "Pipe \\| sep" in lines[3]
+
+
+def test_export_rejects_unknown_format_and_columns(tmp_path):
+    """Only .csv and .md files and known columns are accepted."""
+    with pytest.raises(ValueError, match="use a .csv or .md file"):
+        export.export_issues(ISSUES, tmp_path / "report.txt", ["key"])
+    with pytest.raises(ValueError, match="Unknown export columns: colour"):
+        export.export_issues(ISSUES, tmp_path / "report.csv", ["key", "colour"])
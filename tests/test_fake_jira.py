@@ -0,0 +1,270 @@
+"""End to end tests of the client and commands against a fake Jira server."""
+
+import collections
+import concurrent.futures
+
+import click
+import pytest
+
+from jayrah.api import exceptions, request_handler
+from jayrah.commands import serve
+from jayrah.config import defaults
+from jayrah.ui import boards
+
+
+@pytest.fixture
+def jayrah_obj(fake_jira, fake_jira_config):
+    fake_jira.add_issue("TEST-1", "Fix login", "In Progress")
+    fake_jira.add_issue("TEST-2", "Write docs")
+    fake_jira.add_issue("OTHER-1", "Not on the board")
+    return boards.Boards(fake_jira_config)
+
+
+def rpc(jayrah_obj, method, **params):
+    response = serve.handle_request(
+        jayrah_obj, {"jsonrpc": "2.0", "id": 1, "method": method, "params": params}
+    )
+    assert response is not None
+    return response
+
+
+@pytest.mark.parametrize("api_version", ["2", "3"])
+def test_list_the_issues_of_a_board(
+    jayrah_obj, fake_jira, fake_jira_config, api_version
+):
+    """The issues of the board come from the search of the API version."""
+    jayrah_obj = boards.Boards({**fake_jira_config, "api_version": api_version})
+
+    issues = rpc(jayrah_obj, "browse-list", jql="project = TEST")["result"]
+
+    assert [(x["key"], x["status"]) for x in issues] == [
+        ("TEST-1", "In Progress"),
+        ("TEST-2", "To Do"),
+    ]
+    search = "search" if api_version == "2" else "search/jql"
+    assert ("GET", f"/rest/api/{api_version}/{search}") in fake_jira.requests
+
+
+def test_board_limit_and_fields(fake_jira, fake_jira_config):
+    """A board limit caps the issues listed, its fields come with the defaults."""
+    for index in range(1, 6):
+        fake_jira.add_issue(f"TEST-{index}", f"Issue {index}")
+    config = {
+        **fake_jira_config,
+        "boards": [
+            {
+                "name": "small",
+                "jql": "project = TEST",
+                "limit": 3,
+                "fields": ["customfield_10002"],
+            }
+        ],
+    }
+    jayrah_obj = boards.Boards(config)
+
+    issues = jayrah_obj.issues_client.list_issues(
+        "project = TEST", board_overrides=boards.overrides("small", config)
+    )
+
+    assert [x["key"] for x in issues] == ["TEST-1", "TEST-2", "TEST-3"]
+    assert fake_jira.last_query["maxResults"] == "3"
+    assert fake_jira.last_query["fields"].endswith(",customfield_10002")
+    assert "summary" in fake_jira.last_query["fields"]
+
+
+def test_comment_and_transition_an_issue(jayrah_obj, fake_jira):
+    """A comment and a transition show up when the issue is fetched again."""
+    added = rpc(jayrah_obj, "comment-add", key="TEST-2", body="Started on it")
+    moved = rpc(jayrah_obj, "transition-apply", key="TEST-2", transition="progress")
+    shown = rpc(jayrah_obj, "issue-show", key="TEST-2")["result"]
+
+    assert added["result"]["author"] == "Jane Doe"
+    assert moved["result"] == {
+        "key": "TEST-2",
+        "transition": "In Progress",
+        "status": "In Progress",
+    }
+    assert shown["status"] == "In Progress"
+    assert [x["body"] for x in shown["comments"]] == ["Started on it"]
+
+
+def test_extra_headers_are_sent(fake_jira, fake_jira_config):
+    """The headers of the config go with every request, after the User-Agent."""
+    fake_jira.add_issue("TEST-1", "Fix login")
+    config = {
+        **fake_jira_config,
+        "headers": {"X-Forwarded-User": "jdoe", "Authorization": "ignored"},
+    }
+
+    boards.Boards(config).jira.get_issue("TEST-1", use_cache=False)
+
+    assert fake_jira.last_headers["X-Forwarded-User"] == "jdoe"
+    assert fake_jira.last_headers["User-Agent"].startswith("jayrah/")
+    assert fake_jira.last_headers["Authorization"].startswith("Bearer ")
+
+
+def test_cookie_auth_logs_in_again_when_the_session_expires(
+    fake_jira, fake_jira_config
+):
+    """A 401 opens a new session and the request is sent again with it."""
+    fake_jira.password = "secret"
+    fake_jira.add_issue("TEST-1", "Fix login")
+    config = {
+        **fake_jira_config,
+        "auth_method": "cookie",
+        "jira_user": "jdoe",
+        "jira_password": "secret",
+    }
+    jira = boards.Boards(config).jira
+
+    jira.get_issue("TEST-1", use_cache=False)
+    fake_jira.expire_sessions()
+    issue = jira.get_issue("TEST-1", use_cache=False)
+
+    assert issue["key"] == "TEST-1"
+    assert fake_jira.last_headers["Cookie"] == "JSESSIONID=session-2"
+    assert fake_jira.requests.count(("POST", "/rest/auth/1/session")) == 2
+    assert "Authorization" not in fake_jira.last_headers
+
+
+def test_cookie_auth_logs_in_once_for_concurrent_401(fake_jira, fake_jira_config):
+    """Workers getting a 401 at once share the session the first one opens."""
+    fake_jira.password = "secret"
+    fake_jira.add_issue("TEST-1", "Fix login")
+    config = {
+        **fake_jira_config,
+        "auth_method": "cookie",
+        "jira_user": "jdoe",
+        "jira_password": "secret",
+    }
+    jira = boards.Boards(config).jira
+    jira.get_issue("TEST-1", use_cache=False)
+    fake_jira.expire_sessions()
+    fake_jira.latency = 0.1
+
+    with concurrent.futures.ThreadPoolExecutor(4) as pool:
+        issues = list(
+            pool.map(lambda _: jira.get_issue("TEST-1", use_cache=False), range(4))
+        )
+
+    assert [x["key"] for x in issues] == ["TEST-1"] * 4
+    assert fake_jira.logins == 2
+
+
+def test_cookie_auth_login_goes_through_the_request_handler(
+    fake_jira, fake_jira_config, monkeypatch
+):
+    """The login has the headers of the requests and shows in the debug pane."""
+    fake_jira.password = "secret"
+    config = {
+        **fake_jira_config,
+        "auth_method": "cookie",
+        "jira_user": "jdoe",
+        "jira_password": "secret",
+        "headers": {"X-Forwarded-User": "jdoe"},
+    }
+    monkeypatch.setattr(request_handler, "RECENT_REQUESTS", collections.deque())
+
+    boards.Boards(config).jira.authenticator.get_headers()
+
+    assert fake_jira.requests == [("POST", "/rest/auth/1/session")]
+    assert fake_jira.last_headers["X-Forwarded-User"] == "jdoe"
+    assert fake_jira.last_headers["User-Agent"].startswith("jayrah/")
+    (record,) = request_handler.RECENT_REQUESTS
+    assert (record.method, record.endpoint, record.status) == (
+        "POST",
+        "rest/auth/1/session",
+        200,
+    )
+
+
+def test_cookie_auth_with_a_wrong_password(fake_jira, fake_jira_config):
+    """A failed login is an error rather than a loop of 401."""
+    fake_jira.password = "secret"
+    config = {
+        **fake_jira_config,
+        "auth_method": "cookie",
+        "jira_user": "jdoe",
+        "jira_password": "wrong",
+    }
+
+    with pytest.raises(click.ClickException, match="session login failed: HTTP 401"):
+        boards.Boards(config).jira.get_issue("TEST-1", use_cache=False)
+
+
+def test_transient_errors_are_retried(jayrah_obj, fake_jira):
+    """A server error is retried and the request eventually succeeds."""
+    fake_jira.fail("GET", r"/issue/TEST-1$", status=503, times=2)
+
+    issue = jayrah_obj.jira.get_issue("TEST-1", use_cache=False)
+
+    assert issue["fields"]["summary"] == "Fix login"
+    assert fake_jira.requests.count(("GET", "/rest/api/2/issue/TEST-1")) == 3
+
+
+def test_errors_reach_the_caller(jayrah_obj, fake_jira):
+    """Errors the retries cannot fix are raised with the Jira messages."""
+    fake_jira.fail("POST", r"/comment$", status=400, message="Comment body is empty")
+
+    with pytest.raises(exceptions.JiraNotFoundError):
+        jayrah_obj.jira.get_issue("TEST-9", use_cache=False)
+    response = rpc(jayrah_obj, "comment-add", key="TEST-1", body="Hello")
+
+    assert response["error"]["code"] == serve.SERVER_ERROR
+    assert "Comment body is empty" in response["error"]["message"]
+
+
+def test_slow_answers(jayrah_obj, fake_jira):
+    """The latency of the server is seen in the duration of the requests."""
+    fake_jira.latency = 0.05
+
+    jayrah_obj.jira.get_issue("TEST-1", use_cache=False)
+
+    record = request_handler.recent_requests()[0]
+    assert record.endpoint == "issue/TEST-1"
+    assert record.duration >= 50
+
+
+def test_slow_answers_time_out(fake_jira, fake_jira_config):
+    """An answer slower than the read timeout fails instead of hanging."""
+    fake_jira.add_issue("TEST-1", "Fix login")
+    fake_jira.latency = 0.5
+    jira = boards.Boards({**fake_jira_config, "timeout": 0.1, "max_retries": 0}).jira
+
+    with pytest.raises(click.ClickException, match="Timed out waiting for GET"):
+        jira.get_issue("TEST-1", use_cache=False)
+
+    assert request_handler.recent_requests()[0].error == "timed out"
+
+
+def test_attachments_are_listed_and_downloaded(
+    jayrah_obj, fake_jira, monkeypatch
+):
+    """The files of an issue download with the credentials, big ones do not."""
+    fake_jira.add_attachment("TEST-1", "screenshot.png", b"\x89PNG" + b"0" * 2044)
+    jira = jayrah_obj.jira
+
+    [attachment] = jira.get_issue_attachments("TEST-1")
+
+    assert (attachment.filename, attachment.human_size) == ("screenshot.png", "2.0 KB")
+    assert attachment.is_image
+    assert jira.download_attachment(attachment).startswith(b"\x89PNG")
+    assert fake_jira.last_headers["Authorization"].startswith("Bearer ")
+
+    monkeypatch.setattr(defaults, "ATTACHMENT_PREVIEW_SIZE", 2047)
+    with pytest.raises(click.ClickException, match="bigger than"):
+        jira.download_attachment(attachment)
+
+
+def test_attachments_redirected_to_storage_get_no_credentials(jayrah_obj, fake_jira):
+    """The credentials stay on the Jira host, the storage host never gets them."""
+    fake_jira.add_attachment("TEST-1", "notes.txt", b"notes", "text/plain")
+    # The same server under another name, a host of its own for the client
+    fake_jira.storage_url = fake_jira.url.replace("127.0.0.1", "localhost")
+    jira = jayrah_obj.jira
+
+    [attachment] = jira.get_issue_attachments("TEST-1")
+
+    assert jira.download_attachment(attachment) == b"notes"
+    assert fake_jira.requests[-1] == ("GET", f"/media/{attachment.id}")
+    assert "Authorization" not in fake_jira.last_headers
@@ -0,0 +1,76 @@
+"""Tests for the git helpers."""
+
+from jayrah.utils import git
+
+
+def test_branch_name_slugs_the_summary():
+    """Punctuation and case of the summary don't end up in the branch."""
+    assert (
+        git.branch_name("JAY-123", "Fix: login *redirect* loop!")
+        == "JAY-123-fix-login-redirect-loop"
+    )
+
+
+def test_branch_name_cuts_long_summaries_on_a_word():
+    """Long summaries are cut at the end of a word."""
+    name = git.branch_name("JAY-1", "word " * 20)
+
+    assert name.startswith("JAY-1-word-word")
+    assert not name.endswith("-")
+    assert len(name) <= len("JAY-1-") + 50
+
+
+def test_branch_name_without_summary():
+    """An empty slug leaves the key alone."""
+    assert git.branch_name("JAY-1", "!!!") == "JAY-1"
+
+
+def test_issue_key_from_branch():
+    """Keys are found in lowercase branch names, the project first."""
+    assert git.issue_key_from_branch("feature/jay-123-fix-login") == "JAY-123"
+    assert git.issue_key_from_branch("release-2-jay-9", "jay") == "JAY-9"
+    assert git.issue_key_from_branch("main") is None
+
+
+def test_prepare_commit_message_adds_the_line_once(tmp_path):
+    """A message mentioning the issue already is left alone."""
+    path = tmp_path / "COMMIT_EDITMSG"
+    path.write_text("# Please enter the commit message\n")
+
+    assert git.prepare_commit_message(path, "JAY-1: Fix login", "JAY-1")
+    assert not git.prepare_commit_message(path, "JAY-1: Fix login", "JAY-1")
+    assert path.read_text() == "JAY-1: Fix login\n# Please enter the commit message\n"
+
+
+def test_prepare_commit_message_matches_whole_keys(tmp_path):
+    """A longer key containing the issue key is not a mention of it."""
+    path = tmp_path / "COMMIT_EDITMSG"
+    path.write_text("See JAY-12 and XJAY-1\n")
+
+    assert git.prepare_commit_message(path, "JAY-1: Fix login", "JAY-1")
+    assert path.read_text() == "JAY-1: Fix login\nSee JAY-12 and XJAY-1\n"
+
+
+def test_pull_request_info_of_the_forges():
+    """Test GitHub, GitLab and Bitbucket request URLs are recognized."""
+    github = git.pull_request_info("https://github.com/o/r/pull/12")
+    assert github == {
+        "forge": "GitHub",
+        "host": "github.com",
+        "repo": "o/r",
+        "number": "12",
+    }
+    gitlab = git.pull_request_info("https://gitlab.com/g/sub/r/-/merge_requests/3")
+    assert gitlab is not None
+    assert (gitlab["forge"], gitlab["repo"]) == ("GitLab", "g/sub/r")
+    bitbucket = git.pull_request_info(
+        "https://bitbucket.org/team/r/pull-requests/7/overview"
+    )
+    assert bitbucket is not None
+    assert (bitbucket["forge"], bitbucket["number"]) == ("Bitbucket", "7")
+
+
+def test_pull_request_info_of_other_urls():
+    """Test URLs that aren't requests give None."""
+    assert git.pull_request_info("https://github.com/o/r/issues/12") is None
+    assert git.pull_request_info("not a url") is None
@@ -0,0 +1,274 @@
+"""Tests for the issue detail rendering."""
+
+import datetime
+
+from jayrah.utils import adf, issue_view
+
+BROWSE = "https://test-jira.example.com/browse"
+
+ISSUE_LINKS = [
+    {
+        "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+        "outwardIssue": {
+            "key": "TEST-2",
+            "fields": {"summary": "Second", "status": {"name": "Open"}},
+        },
+    },
+    {
+        "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+        "inwardIssue": {
+            "key": "TEST-3",
+            "fields": {"summary": "Third", "status": {"name": "Done"}},
+        },
+    },
+]
+
+
+def test_get_linked_issues():
+    """Links are flattened with the relation seen from the current issue."""
+    linked = issue_view.get_linked_issues({"issuelinks": ISSUE_LINKS})
+
+    assert linked == [
+        {"relation": "blocks", "key": "TEST-2", "summary": "Second", "status": "Open"},
+        {
+            "relation": "is blocked by",
+            "key": "TEST-3",
+            "summary": "Third",
+            "status": "Done",
+        },
+    ]
+    assert issue_view.get_linked_issues({}) == []
+
+
+def test_build_issue_shows_links(sample_issues, sample_config, monkeypatch):
+    """The detail view lists the linked issues."""
+    monkeypatch.setenv("COLUMNS", "130")
+    issue = sample_issues["issues"][0]
+    issue["fields"]["issuelinks"] = ISSUE_LINKS
+
+    _, body = issue_view.build_issue(issue, sample_config, 0)
+
+    assert "## 🔗 Links" in body
+    assert f"blocks [**TEST-2**]({BROWSE}/TEST-2): Second (Open)" in body
+    assert f"is blocked by [**TEST-3**]({BROWSE}/TEST-3): Third (Done)" in body
+
+
+def test_build_issue_links_keys_and_urls(sample_issues, sample_config):
+    """Keys and bare URLs become links, the ones of the code blocks are kept."""
+    issue = sample_issues["issues"][0]
+    issue["fields"]["description"] = (
+        "See https://example.com/docs. and [the PR](https://example.com/pr/1)\n"
+        "```\ncurl https://example.com/api\n```"
+    )
+
+    header, body = issue_view.build_issue(issue, sample_config, 0)
+
+    assert f"[{issue['key']}]({BROWSE}/{issue['key']})" in header
+    assert "See <https://example.com/docs>." in body
+    assert "curl https://example.com/api\n" in body
+    assert issue_view.markdown_links(header + body)[1:] == [
+        ("https://example.com/docs", "https://example.com/docs"),
+        ("the PR", "https://example.com/pr/1"),
+    ]
+    assert issue_view.issue_link("TEST-1", {}) == "**TEST-1**"
+
+
+def test_build_issue_links_the_keys_of_the_text(sample_issues, sample_config):
+    """Keys written in a description link to their issue, unless in code."""
+    issue = sample_issues["issues"][0]
+    issue["fields"]["description"] = "Same as OTHER-12, see `FOO-1` and [BAR-2](x)"
+
+    _, body = issue_view.build_issue(issue, sample_config, 0)
+
+    assert f"Same as [**OTHER-12**]({BROWSE}/OTHER-12)," in body
+    assert "`FOO-1` and [BAR-2](x)" in body
+    assert issue_view.issue_keys(body, exclude="OTHER-12") == ["FOO-1", "BAR-2"]
+
+
+def test_build_issue_lists_attachments(sample_issues, sample_config):
+    """The detail view lists the attached files with their type and size."""
+    issue = sample_issues["issues"][0]
+    issue["fields"]["attachment"] = [
+        {
+            "id": "1",
+            "filename": "screenshot.png",
+            "mimeType": "image/png",
+            "size": 1536,
+            "author": {"displayName": "Jane Doe"},
+        }
+    ]
+
+    _, body = issue_view.build_issue(issue, sample_config, 0)
+
+    assert "## 📎 Attachments (1)" in body
+    assert "**screenshot.png** (image/png, 1.5 KB) by Jane Doe" in body
+
+
+def test_build_issue_shows_parent_and_subtasks(sample_issues, sample_config):
+    """The detail view shows the parent and the subtasks of an issue."""
+    issue = sample_issues["issues"][0]
+    issue["fields"]["parent"] = {
+        "key": "TEST-1",
+        "fields": {"summary": "Parent", "status": {"name": "Open"}},
+    }
+    issue["fields"]["subtasks"] = [
+        {"key": "TEST-5", "fields": {"summary": "Child", "status": {"name": "Done"}}}
+    ]
+
+    _, body = issue_view.build_issue(issue, sample_config, 0)
+
+    assert "## ⬆️ Parent" in body
+    assert f"[**TEST-1**]({BROWSE}/TEST-1): Parent (Open)" in body
+    assert "## 🌳 Subtasks (1)" in body
+    assert f"[**TEST-5**]({BROWSE}/TEST-5): Child (Done)" in body
+
+
+ADF_DESCRIPTION = {
+    "type": "doc",
+    "version": 1,
+    "content": [
+        {
+            "type": "heading",
+            "attrs": {"level": 3},
+            "content": [{"type": "text", "text": "Steps"}],
+        },
+        {
+            "type": "orderedList",
+            "content": [
+                {
+                    "type": "listItem",
+                    "content": [
+                        {
+                            "type": "paragraph",
+                            "content": [
+                                {"type": "text", "text": "Run "},
+                                {
+                                    "type": "text",
+                                    "text": "make",
+                                    "marks": [{"type": "code"}],
+                                },
+                            ],
+                        }
+                    ],
+                }
+            ],
+        },
+        {
+            "type": "codeBlock",
+            "attrs": {"language": "sh"},
+            "content": [{"type": "text", "text": "make test\nmake lint"}],
+        },
+        {
+            "type": "blockquote",
+            "content": [
+                {
+                    "type": "paragraph",
+                    "content": [
+                        {"type": "text", "text": "Fails", "marks": [{"type": "strong"}]}
+                    ],
+                }
+            ],
+        },
+    ],
+}
+
+
+def test_adf_to_markdown_keeps_formatting():
+    """Headings, lists, code and quotes of ADF documents survive as Markdown."""
+    assert adf.adf_to_markdown(ADF_DESCRIPTION) == (
+        "### Steps\n\n1. Run `make`\n\n```sh\nmake test\nmake lint\n```\n\n> **Fails**"
+    )
+
+
+def test_build_issue_renders_adf_description_and_comments(
+    sample_issues, sample_config
+):
+    """API v3 descriptions and comments are shown formatted, not flattened."""
+    issue = sample_issues["issues"][0]
+    issue["fields"]["description"] = ADF_DESCRIPTION
+    issue["fields"]["comment"] = {
+        "total": 1,
+        "comments": [
+            {
+                "author": {"displayName": "Jane"},
+                "created": "2024-01-02T10:00:00.000+0000",
+                "body": {
+                    "type": "doc",
+                    "content": [
+                        {
+                            "type": "bulletList",
+                            "content": [
+                                {
+                                    "type": "listItem",
+                                    "content": [
+                                        {
+                                            "type": "paragraph",
+                                            "content": [{"type": "text", "text": "ok"}],
+                                        }
+                                    ],
+                                }
+                            ],
+                        }
+                    ],
+                },
+            }
+        ],
+    }
+
+    _, body = issue_view.build_issue(issue, sample_config, 1)
+
+    assert "1. Run `make`" in body
+    assert "```sh" in body
+    assert "> **Fails**" in body
+    assert "\n- ok" in body
+
+
+def test_build_issue_flags_overdue_due_dates(sample_issues, sample_config):
+    """Unresolved issues past their due date are flagged as overdue."""
+    issue = sample_issues["issues"][0]
+    issue["fields"]["duedate"] = "2020-01-31"
+    issue["fields"]["resolution"] = None
+
+    header, body = issue_view.build_issue(issue, sample_config, 0)
+
+    assert "* Due: 📅 2020-01-31 ⚠️ overdue" in header + body
+
+    issue["fields"]["resolution"] = {"name": "Done"}
+    header, body = issue_view.build_issue(issue, sample_config, 0)
+
+    assert "overdue" not in header + body
+
+
+def test_time_in_status_follows_the_changelog():
+    """Each status gets the time until the next transition, the current until now."""
+    issue = {
+        "fields": {
+            "created": "2026-10-01T09:00:00.000+0000",
+            "status": {"name": "In Progress"},
+        }
+    }
+    histories = [
+        {
+            "created": "2026-10-05T09:00:00.000+0000",
+            "items": [
+                {"field": "status", "fromString": "Review", "toString": "In Progress"}
+            ],
+        },
+        {
+            "created": "2026-10-02T12:30:00.000+0000",
+            "items": [
+                {"field": "status", "fromString": "New", "toString": "Review"},
+                {"field": "labels", "fromString": "", "toString": "backend"},
+            ],
+        },
+    ]
+    now = datetime.datetime(2026, 10, 5, 9, 45, tzinfo=datetime.timezone.utc)
+
+    section = issue_view.time_in_status(issue, histories, now)
+
+    assert section.splitlines()[2:] == [
+        "* New: 1d 3h",
+        "* Review: 2d 20h",
+        "* In Progress: 45m (current)",
+    ]
+    assert issue_view.time_in_status({"fields": {}}, histories, now) == ""
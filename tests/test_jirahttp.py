@@ -1,15 +1,22 @@
+import collections
 import json
+import re
 import urllib.error
-from unittest.mock import MagicMock, patch
+import urllib.request
+from unittest.mock import MagicMock, call, patch
 
+import click
 import pytest
 
+from jayrah.api import exceptions, models
 from jayrah.api.jira_client import JiraHTTP
+from jayrah.api import request_handler
+from jayrah.api.request_handler import RetryPolicy, parse_retry_after
 
 
 @pytest.fixture
 def mock_urlopen():
-    with patch("urllib.request.urlopen") as mock:
+    with patch("urllib.request.OpenerDirector.open") as mock:
         mock_response = MagicMock()
         mock_response.status = 200
         mock_response.read.return_value = json.dumps({"key": "TEST-123"}).encode(
@@ -93,6 +100,43 @@ def test_search_issues_v3_uses_search_jql(sample_config):
         assert result["issues"][0]["key"] == "TEST-123"
 
 
+def test_from_profile_uses_the_profile_server(sample_config):
+    """Test creating a client for a profile of the config."""
+    sample_config["profiles"] = {
+        "personal": {
+            "jira_server": "https://me.atlassian.net",
+            "api_version": "3",
+            "auth_method": "basic",
+        }
+    }
+
+    client = JiraHTTP.from_profile(sample_config, "personal")
+
+    assert client.base_url == "https://me.atlassian.net/rest/api/3"
+    assert client.auth_method == "basic"
+
+
+def test_search_issues_iter_yields_pages_lazily(sample_config):
+    """Test the search iterator only fetches a page once it is consumed."""
+    client = JiraHTTP(sample_config)
+
+    with patch.object(client, "search_issues") as mock_search:
+        mock_search.side_effect = [
+            {"issues": [{"key": "TEST-1"}, {"key": "TEST-2"}], "total": 3},
+            {"issues": [{"key": "TEST-3"}], "total": 3},
+        ]
+
+        pages = client.search_issues_iter("project = TEST", page_size=2)
+        first = next(pages)
+        assert [issue["key"] for issue in first["issues"]] == ["TEST-1", "TEST-2"]
+        assert mock_search.call_count == 1
+
+        rest = list(pages)
+
+    assert [issue["key"] for issue in rest[0]["issues"]] == ["TEST-3"]
+    assert mock_search.call_args_list[1].kwargs["start_at"] == 2
+
+
 def test_get_issue(sample_config, mock_urlopen, mock_jira_client):
     """Test getting a specific issue."""
     client = JiraHTTP(sample_config)
@@ -182,6 +226,49 @@ def test_transition_issue(sample_config, mock_urlopen, mock_jira_client):
         )
 
 
+def test_transition_issue_with_screen_fields(sample_config):
+    """Test the resolution, comment and other fields go along the transition."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        client.transition_issue(
+            "TEST-123",
+            "31",
+            models.TransitionFields(
+                resolution="Won't Do",
+                comment="Duplicate",
+                fields={"customfield_1": {"value": "No"}},
+            ),
+        )
+
+        mock_request.assert_called_once_with(
+            "POST",
+            "issue/TEST-123/transitions",
+            jeez={
+                "transition": {"id": "31"},
+                "fields": {
+                    "customfield_1": {"value": "No"},
+                    "resolution": {"name": "Won't Do"},
+                },
+                "update": {"comment": [{"add": {"body": "Duplicate"}}]},
+            },
+        )
+
+
+def test_required_transition_fields():
+    """Test only the required fields without default have to be asked."""
+    transition = {
+        "fields": {
+            "resolution": {"required": True, "hasDefaultValue": False},
+            "assignee": {"required": True, "hasDefaultValue": True},
+            "comment": {"required": False},
+        }
+    }
+
+    assert list(models.required_transition_fields(transition)) == ["resolution"]
+
+
 def test_get_transitions(sample_config, mock_urlopen, mock_jira_client):
     """Test getting available transitions for an issue."""
     client = JiraHTTP(sample_config)
@@ -198,7 +285,7 @@ def test_get_transitions(sample_config, mock_urlopen, mock_jira_client):
 
         # Check _request was called with expected arguments
         mock_request.assert_called_once_with(
-            "GET", "issue/TEST-123/transitions", label="All transitions"
+            "GET", "issue/TEST-123/transitions", label="All transitions", use_cache=True
         )
         assert len(result["transitions"]) == 1
         assert result["transitions"][0]["name"] == "In Progress"
@@ -259,7 +346,7 @@ def test_get_components_v3_uses_search_jql(sample_config):
         )
 
 
-@patch("urllib.request.urlopen")
+@patch("urllib.request.OpenerDirector.open")
 def test_http_error_handling(mock_urlopen, sample_config):
     """Test handling of HTTP errors."""
     # Import JiraAuthenticationError for test
@@ -283,7 +370,191 @@ def test_http_error_handling(mock_urlopen, sample_config):
     assert "issue/TEST-123" in str(excinfo.value)
 
 
-@patch("urllib.request.urlopen")
+def http_error(code, headers=None):
+    """Build an HTTPError as raised by urlopen."""
+    error = urllib.error.HTTPError(
+        "https://test-jira.example.com", code, "Error", headers or {}, None
+    )
+    error.read = lambda: b""  # type: ignore[method-assign]
+    return error
+
+
+def ok_response():
+    """Build a successful urlopen response."""
+    response = MagicMock()
+    response.status = 200
+    response.read.return_value = json.dumps({"key": "TEST-123"}).encode("utf-8")
+    response.__enter__.return_value = response
+    return response
+
+
+@patch("time.sleep")
+@patch("urllib.request.OpenerDirector.open")
+def test_retries_transient_errors(mock_urlopen, mock_sleep, sample_config):
+    """Test a GET failing with a 503 is retried with exponential backoff."""
+    mock_urlopen.side_effect = [http_error(503), http_error(502), ok_response()]
+    client = JiraHTTP({**sample_config, "retry_jitter": 0})
+
+    result = client._request("GET", "issue/TEST-123", use_cache=False)
+
+    assert result == {"key": "TEST-123"}
+    assert [call.args[0] for call in mock_sleep.call_args_list] == [1.0, 2.0]
+
+
+@patch("time.sleep")
+@patch("urllib.request.OpenerDirector.open")
+def test_retries_honor_retry_after(mock_urlopen, mock_sleep, sample_config):
+    """Test a rate limited request waits for the Retry-After delay, even a POST."""
+    mock_urlopen.side_effect = [
+        http_error(429, {"Retry-After": "7"}),
+        ok_response(),
+    ]
+    client = JiraHTTP(sample_config)
+
+    client._request("POST", "issue", jeez={"fields": {}})
+
+    mock_sleep.assert_called_once_with(7.0)
+
+
+@patch("time.sleep")
+@patch("urllib.request.OpenerDirector.open")
+def test_retries_give_up(mock_urlopen, mock_sleep, sample_config):
+    """Test the error is raised once retries are exhausted or not allowed."""
+    from jayrah.api.exceptions import JiraAPIError, JiraRateLimitError
+
+    mock_urlopen.side_effect = [http_error(429) for _ in range(3)]
+    client = JiraHTTP({**sample_config, "max_retries": 2})
+    with pytest.raises(JiraRateLimitError):
+        client._request("GET", "issue/TEST-123", use_cache=False)
+    assert mock_sleep.call_count == 2
+
+    # A POST might have been applied despite the server error
+    mock_urlopen.side_effect = [http_error(500)]
+    mock_sleep.reset_mock()
+    with pytest.raises(JiraAPIError):
+        client._request("POST", "issue", jeez={"fields": {}})
+    mock_sleep.assert_not_called()
+
+
+@patch("time.sleep")
+@patch("urllib.request.OpenerDirector.open")
+def test_requests_are_logged(mock_urlopen, mock_sleep, sample_config, caplog):
+    """Test the requests are logged with their status, retries and errors."""
+    mock_urlopen.side_effect = [http_error(503), ok_response(), http_error(404)]
+    client = JiraHTTP({**sample_config, "retry_jitter": 0})
+
+    with caplog.at_level("INFO", logger="jayrah"):
+        client._request("GET", "issue/TEST-123", use_cache=False)
+        with pytest.raises(exceptions.JiraNotFoundError):
+            client._request("GET", "issue/TEST-404", use_cache=False)
+
+    messages = [(x.levelname, x.getMessage()) for x in caplog.records]
+    assert messages[0][0] == "WARNING"
+    assert "503, retrying in 1.0s (1/3)" in messages[0][1]
+    assert messages[1][0] == "INFO"
+    assert re.match(r"GET issue/TEST-123: 200 in \d+ms$", messages[1][1])
+    assert messages[2][0] == "WARNING"
+    assert messages[2][1].startswith("GET issue/TEST-404: 404 Error")
+
+
+@patch("urllib.request.OpenerDirector.open")
+def test_recent_requests(mock_urlopen, sample_config, monkeypatch):
+    """Test the latest requests are kept for the debug pane, newest first."""
+    monkeypatch.setattr(request_handler, "RECENT_REQUESTS", collections.deque(maxlen=2))
+    error = http_error(400)
+    error.read = lambda: b'{"errorMessages": ["Bad JQL"], "errors": {}}'
+    mock_urlopen.side_effect = [
+        ok_response(),
+        error,
+        urllib.error.URLError("Connection refused"),
+    ]
+    client = JiraHTTP(sample_config)
+
+    client._request("GET", "issue/TEST-1", use_cache=False)
+    with pytest.raises(exceptions.JiraAPIError):
+        client._request("GET", "search", use_cache=False)
+    with pytest.raises(click.ClickException):
+        client._request("POST", "issue", jeez={"fields": {}})
+
+    records = request_handler.recent_requests()
+    assert [(x.method, x.endpoint, x.status, x.error) for x in records] == [
+        ("POST", "issue", None, "Connection refused"),
+        ("GET", "search", 400, "Bad JQL"),
+    ]
+
+
+def test_error_message():
+    """Test the messages of a Jira error response are joined."""
+    body = '{"errorMessages": ["No project"], "errors": {"summary": "Required"}}'
+
+    assert request_handler.error_message(body, "Bad Request") == "No project; Required"
+    assert request_handler.error_message("<html>", "Bad Gateway") == "Bad Gateway"
+
+
+@patch("urllib.request.install_opener")
+def test_proxy_and_ca_bundle(mock_install_opener, sample_config, tmp_path):
+    """Test the requests go through the proxy and trust the CA bundle."""
+    ca_bundle = tmp_path / "ca.pem"
+    ca_bundle.write_text("")
+    config = {
+        **sample_config,
+        "proxy": "http://proxy.example.com:3128",
+        "ca_bundle": str(ca_bundle),
+    }
+
+    with patch("ssl.create_default_context") as mock_context:
+        client = JiraHTTP(config)
+
+    mock_context.assert_called_with(cafile=str(ca_bundle))
+    proxy_handler = next(
+        x
+        for x in client.request_handler._opener.handlers
+        if isinstance(x, urllib.request.ProxyHandler)
+    )
+    assert proxy_handler.proxies["https"] == "http://proxy.example.com:3128"
+    # Another client, like the one of another profile, keeps its own opener
+    other = JiraHTTP(sample_config)
+    assert other.request_handler._opener is not client.request_handler._opener
+    mock_install_opener.assert_not_called()
+    curl = client.request_handler._get_curl_command("GET", "https://jira", {})
+    assert f"--cacert '{ca_bundle}'" in curl
+    assert "--proxy 'http://proxy.example.com:3128'" in curl
+
+
+def test_missing_ca_bundle(sample_config, tmp_path):
+    """Test a CA bundle that cannot be read is reported."""
+    config = {**sample_config, "ca_bundle": str(tmp_path / "missing.pem")}
+
+    with pytest.raises(click.ClickException, match="Cannot load the ca_bundle"):
+        JiraHTTP(config)
+
+
+def test_timeouts_from_config():
+    """Test the timeouts options, searches getting the longer read timeout."""
+    timeouts = request_handler.Timeouts.from_config(
+        {"timeout": "20", "connect_timeout": 5}
+    )
+
+    assert timeouts.connect == 5.0
+    assert timeouts.read_timeout("issue/TEST-1") == 20.0
+    assert timeouts.read_timeout("search/jql") == 60.0
+    slow = request_handler.Timeouts.from_config({"timeout": 90})
+    assert slow.read_timeout("search") == 90.0
+
+
+def test_retry_policy_delay():
+    """Test backoff, jitter, Retry-After parsing and the delay cap."""
+    policy = RetryPolicy(backoff=1.0, jitter=0.5, max_delay=10)
+
+    assert 4.0 <= policy.get_delay(2) <= 4.5
+    assert policy.get_delay(10) == 10
+    assert policy.get_delay(0, "3") == 3.0
+    assert policy.get_delay(0, "120") == 10
+    assert parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT") == 0.0
+    assert parse_retry_after("soon") is None
+
+
+@patch("urllib.request.OpenerDirector.open")
 def test_url_error_handling(mock_urlopen, sample_config):
     """Test handling of URL errors."""
     mock_urlopen.side_effect = urllib.error.URLError("Connection refused")
@@ -351,3 +622,544 @@ def test_get_project_priorities_fallback(sample_config):
 
         priorities = client.get_project_priorities()
         assert priorities == ["Global High", "Global Low"]
+
+
+def test_search_users(sample_config):
+    """Test searching users uses username on v2 and query on v3."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = [{"name": "jdoe"}]
+
+        assert client.search_users("jdoe") == [{"name": "jdoe"}]
+        mock_request.assert_called_once_with(
+            "GET", "user/search", params={"username": "jdoe", "maxResults": 20}
+        )
+
+    client_v3 = JiraHTTP(sample_config, api_version="3")
+    with patch.object(client_v3, "_request") as mock_request:
+        mock_request.return_value = []
+
+        client_v3.search_users("john")
+        mock_request.assert_called_once_with(
+            "GET", "user/search", params={"query": "john", "maxResults": 20}
+        )
+
+
+def test_assign_issue(sample_config):
+    """Test assigning issues with Server and Cloud payloads."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        client.assign_issue("TEST-123", "jdoe")
+        mock_request.assert_called_once_with(
+            "PUT", "issue/TEST-123/assignee", jeez={"name": "jdoe"}
+        )
+
+    client_v3 = JiraHTTP(sample_config, api_version="3")
+    with patch.object(client_v3, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        client_v3.assign_issue("TEST-123", "557058:f58131cb-b67d-43c7-b30d")
+        mock_request.assert_called_once_with(
+            "PUT",
+            "issue/TEST-123/assignee",
+            jeez={"accountId": "557058:f58131cb-b67d-43c7-b30d"},
+        )
+
+
+def test_unassign_issue(sample_config):
+    """Test unassigning an issue sends a null assignee."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        client.assign_issue("TEST-123", None)
+        mock_request.assert_called_once_with(
+            "PUT", "issue/TEST-123/assignee", jeez={"name": None}
+        )
+
+    client_v3 = JiraHTTP(sample_config, api_version="3")
+    with patch.object(client_v3, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        client_v3.assign_issue("TEST-123", None)
+        mock_request.assert_called_once_with(
+            "PUT", "issue/TEST-123/assignee", jeez={"accountId": None}
+        )
+
+
+def test_get_issue_worklogs(sample_config):
+    """Test fetching worklogs returns the worklog list."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"worklogs": [{"id": "1", "timeSpent": "2h"}]}
+
+        worklogs = client.get_issue_worklogs("TEST-123", use_cache=False)
+
+        assert worklogs == [{"id": "1", "timeSpent": "2h"}]
+        mock_request.assert_called_once_with(
+            "GET", "issue/TEST-123/worklog", use_cache=False
+        )
+
+
+def test_get_issue_comments_page(sample_config):
+    """Test a page of comments is requested with its offset and order."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"comments": [], "total": 0}
+
+        client.get_issue_comments("TEST-123", start_at=50, newest_first=True)
+
+        mock_request.assert_called_once_with(
+            "GET",
+            "issue/TEST-123/comment",
+            params={"startAt": 50, "maxResults": 50, "orderBy": "-created"},
+            use_cache=True,
+        )
+
+
+def test_get_all_issue_comments(sample_config):
+    """Test the whole thread is fetched page after page."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "get_issue_comments") as mock_page:
+        mock_page.side_effect = [
+            {"comments": [{"id": "1"}, {"id": "2"}], "total": 3},
+            {"comments": [{"id": "3"}], "total": 3},
+        ]
+
+        comments = client.get_all_issue_comments("TEST-123")
+
+        assert [x["id"] for x in comments] == ["1", "2", "3"]
+        assert mock_page.call_args_list[1].kwargs["start_at"] == 2
+
+
+def test_watch_myself(sample_config):
+    """Test watching adds the logged in user, looked up once."""
+    client = JiraHTTP(sample_config, api_version="3")
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"accountId": "abc:123"}
+
+        client.add_watcher("TEST-123")
+        client.remove_watcher("TEST-123")
+
+        assert mock_request.call_args_list == [
+            call("GET", "myself", use_cache=False),
+            call("POST", "issue/TEST-123/watchers", jeez="abc:123"),
+            call(
+                "DELETE", "issue/TEST-123/watchers", params={"accountId": "abc:123"}
+            ),
+        ]
+
+
+def test_search_resolves_current_user(sample_config):
+    """Test currentUser() is replaced by the /myself user when asked to."""
+    client = JiraHTTP({**sample_config, "resolve_current_user": True}, "3")
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"accountId": "abc:123"}
+
+        client.search_issues("assignee = currentUser()")
+        client.search_issues("watcher = currentUser()")
+
+        searches = [x.kwargs["params"]["jql"] for x in mock_request.call_args_list[1:]]
+        assert searches == ['assignee = "abc:123"', 'watcher = "abc:123"']
+        # The user is only looked up once
+        assert mock_request.call_args_list[0] == call("GET", "myself", use_cache=False)
+        assert len(mock_request.call_args_list) == 3
+
+    client = JiraHTTP(sample_config, "3")
+    with patch.object(client, "_request") as mock_request:
+        client.search_issues("assignee = currentUser()")
+
+        params = mock_request.call_args.kwargs["params"]
+        assert params["jql"] == "assignee = currentUser()"
+
+
+def test_suggest_labels(sample_config):
+    """Test labels come from the JQL autocomplete, or the project issues."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {
+            "results": [{"value": "backend", "displayName": "<b>ba</b>ckend"}]
+        }
+
+        assert client.suggest_labels("ba") == ["backend"]
+        mock_request.assert_called_once_with(
+            "GET",
+            "jql/autocompletedata/suggestions",
+            params={"fieldName": "labels", "fieldValue": "ba"},
+        )
+
+    with (
+        patch.object(
+            client, "_request", side_effect=exceptions.JiraNotFoundError("jql", "")
+        ),
+        patch.object(client, "get_labels", return_value=["Backend", "bug", "docs"]),
+    ):
+        assert client.suggest_labels("b") == ["Backend", "bug"]
+
+
+def test_remove_watcher_v2_uses_username(sample_config):
+    """Test API v2 removes watchers by username."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        client.remove_watcher("TEST-123", "jdoe")
+
+        mock_request.assert_called_once_with(
+            "DELETE", "issue/TEST-123/watchers", params={"username": "jdoe"}
+        )
+
+
+def test_add_remote_link(sample_config):
+    """Test attaching a web link defaults its title to the URL."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"id": 10000}
+
+        client.add_remote_link("TEST-123", "https://github.com/o/r/pull/1")
+
+        mock_request.assert_called_once_with(
+            "POST",
+            "issue/TEST-123/remotelink",
+            jeez={
+                "object": {
+                    "url": "https://github.com/o/r/pull/1",
+                    "title": "https://github.com/o/r/pull/1",
+                }
+            },
+        )
+
+
+def test_add_pull_request_link(sample_config):
+    """Test a pull request is attached with its forge and a short title."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"id": 10000}
+
+        client.add_pull_request_link("TEST-123", "https://github.com/o/r/pull/1")
+
+        payload = mock_request.call_args.kwargs["jeez"]
+        assert payload["globalId"] == "https://github.com/o/r/pull/1"
+        assert payload["application"] == {"type": "github", "name": "GitHub"}
+        assert payload["object"]["title"] == "o/r#1"
+        assert payload["object"]["status"] == {"resolved": False}
+
+
+def test_add_pull_request_link_refuses_other_urls(sample_config):
+    """Test a URL that isn't a pull request is refused before any request."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        with pytest.raises(ValueError):
+            client.add_pull_request_link("TEST-123", "https://example.com/doc")
+
+        mock_request.assert_not_called()
+
+
+def test_list_project_versions(sample_config):
+    """Test archived versions are left out and released ones come last."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = [
+            {"name": "1.0", "released": True},
+            {"name": "0.9", "released": True, "archived": True},
+            {"name": "2.0", "released": False},
+        ]
+
+        assert client.list_project_versions("TEST") == ["2.0", "1.0"]
+        assert mock_request.call_args.args == ("GET", "project/TEST/versions")
+
+
+def test_list_projects_pages_through_project_search(sample_config):
+    """Test API v3 follows the pages of project/search and sorts by key."""
+    client = JiraHTTP(sample_config, api_version="3")
+    with patch.object(client, "_request") as mock_request:
+        mock_request.side_effect = [
+            {"values": [{"key": "TEST"}], "isLast": False},
+            {"values": [{"key": "ABC"}], "isLast": True},
+        ]
+
+        assert client.list_projects() == [{"key": "ABC"}, {"key": "TEST"}]
+        starts = [x.kwargs["params"]["startAt"] for x in mock_request.call_args_list]
+        assert starts == [0, 1]
+
+
+def test_list_projects_v2(sample_config):
+    """Test API v2 gets all the projects at once."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = [{"key": "TEST"}, {"key": "ABC"}]
+
+        assert [x["key"] for x in client.list_projects()] == ["ABC", "TEST"]
+        assert mock_request.call_args.args == ("GET", "project")
+
+
+def test_get_issue_changelog_v3_pages(sample_config):
+    """Test API v3 pages through the changelog endpoint."""
+    client = JiraHTTP(sample_config, api_version="3")
+    with patch.object(client, "_request") as mock_request:
+        mock_request.side_effect = [
+            {"values": [{"id": "1"}], "isLast": False},
+            {"values": [{"id": "2"}], "isLast": True},
+        ]
+
+        assert client.get_issue_changelog("TEST-1") == [{"id": "1"}, {"id": "2"}]
+        assert mock_request.call_args.kwargs["params"]["startAt"] == 1
+
+
+def test_get_issue_changelog_v2_expands_the_issue(sample_config):
+    """Test API v2 reads the histories expanded in the issue."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"changelog": {"histories": [{"id": "1"}]}}
+
+        assert client.get_issue_changelog("TEST-1") == [{"id": "1"}]
+        assert mock_request.call_args.kwargs["params"]["expand"] == "changelog"
+
+
+def test_update_issue_priority_and_type(sample_config):
+    """Test priority and issue type are changed by name."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        client.update_issue_priority("TEST-1", "Major")
+        client.update_issue_type("TEST-1", "Bug")
+
+        assert [x.kwargs["jeez"] for x in mock_request.call_args_list] == [
+            {"fields": {"priority": {"name": "Major"}}},
+            {"fields": {"issuetype": {"name": "Bug"}}},
+        ]
+
+
+def test_update_issue_fix_versions(sample_config):
+    """Test fix versions are set as a field and added or removed as updates."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        client.update_issue_fix_versions("TEST-1", ["2.0"])
+        client.update_issue_fix_versions("TEST-1", ["2.1"], "add")
+        client.update_issue_fix_versions("TEST-1", ["1.0"], "remove")
+
+        assert [x.kwargs["jeez"] for x in mock_request.call_args_list] == [
+            {"fields": {"fixVersions": [{"name": "2.0"}]}},
+            {"update": {"fixVersions": [{"add": {"name": "2.1"}}]}},
+            {"update": {"fixVersions": [{"remove": {"name": "1.0"}}]}},
+        ]
+
+        with pytest.raises(ValueError):
+            client.update_issue_fix_versions("TEST-1", ["2.0"], "replace")
+
+
+ISSUE_TO_CLONE = {
+    "key": "TEST-1",
+    "fields": {
+        "project": {"key": "TEST"},
+        "summary": "Original",
+        "description": "Some text",
+        "issuetype": {"id": "10001", "name": "Story"},
+        "priority": {"id": "3", "name": "Major"},
+        "labels": ["backend"],
+        "components": [{"id": "1", "name": "api"}],
+        "fixVersions": [],
+    },
+}
+
+
+def test_clone_issue_in_the_same_project(sample_config):
+    """Test the copy keeps the fields and is linked to the original."""
+    client = JiraHTTP(sample_config)
+    with (
+        patch.object(client, "get_issue", return_value=ISSUE_TO_CLONE),
+        patch.object(client, "link_issues") as link_issues,
+        patch.object(client, "_request", return_value={"key": "TEST-2"}) as request,
+    ):
+        assert client.clone_issue("TEST-1") == {"key": "TEST-2"}
+
+        request.assert_called_once_with(
+            "POST",
+            "issue",
+            jeez={
+                "fields": {
+                    "project": {"key": "TEST"},
+                    "summary": "Original",
+                    "description": "Some text",
+                    "issuetype": {"name": "Story"},
+                    "priority": {"name": "Major"},
+                    "labels": ["backend"],
+                    "components": [{"name": "api"}],
+                }
+            },
+        )
+        link_issues.assert_called_once_with("TEST-2", "TEST-1", "Cloners")
+
+
+def test_clone_issue_into_another_project(sample_config):
+    """Test components are dropped in another project and link errors ignored."""
+    client = JiraHTTP(sample_config)
+    with (
+        patch.object(client, "get_issue", return_value=ISSUE_TO_CLONE),
+        patch.object(
+            client,
+            "link_issues",
+            side_effect=exceptions.JiraNotFoundError("issueLink", ""),
+        ),
+        patch.object(client, "_request", return_value={"key": "NEW-1"}) as request,
+    ):
+        client.clone_issue("TEST-1", {"project": {"key": "NEW"}, "summary": "Copy"})
+
+        fields = request.call_args.kwargs["jeez"]["fields"]
+        assert fields["project"] == {"key": "NEW"}
+        assert fields["summary"] == "Copy"
+        assert "components" not in fields
+
+
+def test_move_issue_to_project(sample_config):
+    """Test moving uses the Cloud bulk move API and is refused on API v2."""
+    with pytest.raises(click.ClickException):
+        JiraHTTP(sample_config).move_issue_to_project("TEST-1", "NEW")
+
+    client = JiraHTTP(sample_config, api_version="3")
+    with (
+        patch.object(client, "get_issue", return_value=ISSUE_TO_CLONE),
+        patch.object(client, "_request", return_value={"taskId": "1"}) as request,
+    ):
+        assert client.move_issue_to_project("TEST-1", "NEW") == {"taskId": "1"}
+
+        mapping = request.call_args.kwargs["jeez"]["targetToSourcesMapping"]
+        assert mapping["NEW,10001"]["issueIdsOrKeys"] == ["TEST-1"]
+        assert request.call_args.args == ("POST", "bulk/issues/move")
+
+
+def test_get_edit_meta(sample_config):
+    """Test the editmeta fields are returned by field id."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"fields": {"priority": {"name": "Priority"}}}
+
+        assert client.get_edit_meta("TEST-123") == {"priority": {"name": "Priority"}}
+        assert mock_request.call_args.args == ("GET", "issue/TEST-123/editmeta")
+
+
+def test_get_create_meta_per_issue_type(sample_config):
+    """Test the per issue type createmeta is keyed by field id."""
+    client = JiraHTTP(sample_config)
+    with (
+        patch.object(client, "get_issue_types", return_value={"Story": "10001"}),
+        patch.object(client, "_request") as mock_request,
+    ):
+        mock_request.return_value = {
+            "values": [{"fieldId": "priority", "allowedValues": [{"name": "Major"}]}]
+        }
+
+        meta = client.get_create_meta("TEST", "Story")
+
+        assert meta == {
+            "priority": {"fieldId": "priority", "allowedValues": [{"name": "Major"}]}
+        }
+        assert mock_request.call_args.args == (
+            "GET",
+            "issue/createmeta/TEST/issuetypes/10001",
+        )
+
+
+def test_get_create_meta_legacy_fallback(sample_config):
+    """Test servers without the per issue type endpoint use the legacy one."""
+    client = JiraHTTP(sample_config)
+    legacy = {
+        "projects": [
+            {
+                "key": "TEST",
+                "issuetypes": [{"name": "Story", "fields": {"summary": {}}}],
+            }
+        ]
+    }
+    with (
+        patch.object(client, "get_issue_types", return_value={"Story": "10001"}),
+        patch.object(client, "_request") as mock_request,
+    ):
+        mock_request.side_effect = [
+            exceptions.JiraNotFoundError("issue/createmeta", ""),
+            legacy,
+        ]
+
+        assert client.get_create_meta("TEST", "Story") == {"summary": {}}
+        assert mock_request.call_args.args == ("GET", "issue/createmeta")
+
+
+def test_add_worklog(sample_config):
+    """Test logging work with an explicit start date and comment."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        client.add_worklog(
+            "TEST-123", "30m", started="2024-01-02T10:00:00.000+0000", comment="Fix"
+        )
+
+        mock_request.assert_called_once_with(
+            "POST",
+            "issue/TEST-123/worklog",
+            jeez={
+                "timeSpent": "30m",
+                "started": "2024-01-02T10:00:00.000+0000",
+                "comment": "Fix",
+            },
+        )
+
+
+def test_add_worklog_v3_defaults(sample_config):
+    """Test logging work on v3 defaults the start date and uses ADF comments."""
+    client = JiraHTTP(sample_config, api_version="3")
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        client.add_worklog("TEST-123", "2h", comment="Review")
+
+        payload = mock_request.call_args.kwargs["jeez"]
+        assert payload["timeSpent"] == "2h"
+        assert re.match(
+            r"^\d{4}-\d\d-\d\dT\d\d:\d\d:\d\d\.000[+-]\d{4}$", payload["started"]
+        )
+        assert payload["comment"]["type"] == "doc"
+
+
+def test_get_issue_links(sample_config):
+    """Test fetching issue links only requests the issuelinks field."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"fields": {"issuelinks": [{"id": "10"}]}}
+
+        assert client.get_issue_links("TEST-123") == [{"id": "10"}]
+        mock_request.assert_called_once_with(
+            "GET",
+            "issue/TEST-123",
+            params={"fields": "issuelinks"},
+            use_cache=False,
+        )
+
+
+def test_link_issues(sample_config):
+    """Test linking two issues."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {}
+
+        client.link_issues("TEST-1", "TEST-2", "Blocks")
+        mock_request.assert_called_once_with(
+            "POST",
+            "issueLink",
+            jeez={
+                "type": {"name": "Blocks"},
+                "inwardIssue": {"key": "TEST-1"},
+                "outwardIssue": {"key": "TEST-2"},
+            },
+        )
+
+
+def test_get_link_types(sample_config):
+    """Test fetching the available issue link types."""
+    client = JiraHTTP(sample_config)
+    with patch.object(client, "_request") as mock_request:
+        mock_request.return_value = {"issueLinkTypes": [{"name": "Blocks"}]}
+
+        assert client.get_link_types() == [{"name": "Blocks"}]
+        mock_request.assert_called_once_with("GET", "issueLinkType")
@@ -0,0 +1,90 @@
+"""Tests for the JQL builder and syntax checker."""
+
+import pytest
+
+from jayrah.ui import boards
+from jayrah.utils import jql
+from jayrah.utils.jql import Jql, JqlSyntaxError
+
+
+def test_build_query():
+    """Test clauses are combined with their parentheses and ordering."""
+    query = (
+        Jql.assignee_current_user()
+        .and_(Jql.status_not("Done").or_(Jql.field_in("labels", ["a b", "c"])))
+        .order_by("priority", descending=True)
+        .order_by("updated")
+    )
+
+    assert str(query) == (
+        '(assignee = currentUser()) AND ((status != Done) OR (labels IN ("a b", c)))'
+        " ORDER BY priority DESC, updated"
+    )
+    jql.validate(str(query))
+
+
+def test_quote_escapes_values():
+    """Test values with spaces, quotes or reserved words get quoted."""
+    assert jql.quote("TEST-1") == "TEST-1"
+    assert jql.quote("In Progress") == '"In Progress"'
+    assert jql.quote('say "hi"') == '"say \\"hi\\""'
+    assert jql.quote("empty") == '"empty"'
+
+
+def test_replace_current_user():
+    """Test currentUser() calls become the quoted user, strings are kept."""
+    query = (
+        'assignee = currentUser() OR text ~ "currentUser()" OR reporter=currentuser( )'
+    )
+
+    assert jql.replace_current_user(query, "5570:f0") == (
+        'assignee = "5570:f0" OR text ~ "currentUser()" OR reporter="5570:f0"'
+    )
+    assert jql.replace_current_user("project = TEST", "jdoe") == "project = TEST"
+
+
+@pytest.mark.parametrize(
+    "query",
+    [
+        "project = TEST AND status != Done ORDER BY updated DESC",
+        'assignee = currentUser() AND status in ("In Progress", New)',
+        "status was Open by jdoe after -7d",
+        "created >= startOfDay(-1) OR labels is not EMPTY",
+        'cf[10010] ~ "foo" AND NOT (priority = High)',
+        "issue in linkedIssues(TEST-1, blocks)",
+        "priority changed",
+        "ORDER BY created",
+    ],
+)
+def test_validate_accepts_valid_queries(query):
+    """Test common JQL queries are accepted."""
+    jql.validate(query)
+
+
+@pytest.mark.parametrize(
+    ("query", "message", "position"),
+    [
+        ("project = ", "Expected a value", 10),
+        ('status = "open', "Unterminated string", 9),
+        ("project = TEST status = Done", "Expected AND, OR or ORDER BY", 15),
+        ("(project = TEST", "Expected ')'", 15),
+        ("project == TEST", "Expected a value", 9),
+        ("status not Done", "Expected IN after NOT", 11),
+        ("project = TEST ORDER updated", "Expected BY after ORDER", 21),
+        ("", "Empty query", 0),
+    ],
+)
+def test_validate_reports_syntax_errors(query, message, position):
+    """Test invalid queries report what was expected and where."""
+    with pytest.raises(JqlSyntaxError) as excinfo:
+        jql.validate(query)
+
+    assert excinfo.value.message.startswith(message)
+    assert excinfo.value.position == position
+
+
+def test_board_with_invalid_jql_is_rejected(sample_config):
+    """Test boards with a broken JQL are refused before querying Jira."""
+    sample_config["boards"] = [{"name": "broken", "jql": "project = TEST AND"}]
+
+    assert boards.check("broken", sample_config) == ("", "")
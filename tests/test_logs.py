@@ -0,0 +1,55 @@
+"""Tests for the log file of the Jira requests."""
+
+import logging
+
+import pytest
+
+from jayrah.utils import logs
+
+
+def test_setup_logging_writes_the_log_file(tmp_path):
+    """The jayrah loggers write to the file, below the level nothing is kept."""
+    path = tmp_path / "state" / "jayrah.log"
+
+    assert logs.setup_logging("warning", path) == path
+    logging.getLogger("jayrah.api").info("GET issue/TEST-1: 200 in 12ms")
+    logging.getLogger("jayrah.api").warning("GET issue/TEST-2: 404 Not Found")
+
+    assert logs.log_file() == path
+    lines = logs.tail(path, 10)
+    assert len(lines) == 1
+    assert lines[0].endswith("WARNING jayrah.api: GET issue/TEST-2: 404 Not Found")
+
+
+def test_setup_logging_replaces_the_previous_file(tmp_path):
+    """Setting up the logging twice doesn't write every line twice."""
+    logs.setup_logging("info", tmp_path / "first.log")
+    logs.setup_logging("info", tmp_path / "second.log")
+    logging.getLogger("jayrah").info("once")
+
+    assert logs.tail(tmp_path / "first.log", 10) == []
+    assert len(logs.tail(tmp_path / "second.log", 10)) == 1
+
+
+def test_setup_logging_rejects_unknown_levels(tmp_path):
+    """A typo in the level is reported rather than logging everything."""
+    with pytest.raises(ValueError, match="debug, info, warning, error"):
+        logs.setup_logging("verbose", tmp_path / "jayrah.log")
+
+
+def test_setup_logging_without_a_writable_directory(tmp_path):
+    """When the file cannot be created jayrah still runs, without a log file."""
+    blocker = tmp_path / "file"
+    blocker.write_text("")
+
+    assert logs.setup_logging("info", blocker / "jayrah.log") is None
+    assert logs.log_file() is None
+    logging.getLogger("jayrah").error("nowhere to go")
+
+
+def test_tail_keeps_the_last_lines(tmp_path):
+    """Only the last lines of the file are read."""
+    path = tmp_path / "jayrah.log"
+    path.write_text("".join(f"line {x}\n" for x in range(10)))
+
+    assert logs.tail(path, 3) == ["line 7", "line 8", "line 9"]
@@ -1,5 +1,6 @@
 import unittest
 
+from jayrah.api.formatters import V3Formatter
 from jayrah.utils.markdown_to_jira import (
     convert,
     convert_v3,
@@ -55,15 +56,13 @@ class TestMarkdownToJira(unittest.TestCase):
 
     def test_nested_lists(self):
         """Test conversion of Markdown nested lists to Jira nested lists"""
-        # Note: The current implementation has issues with deeply nested lists
-        # It incorrectly treats 4+ spaces as code blocks
         markdown = "- Level 1\n  - Level 2\n    - Level 3\n- Back to 1"
-        expected = "* Level 1\n** Level 2\n{code}\n- Level 3\n- Back to 1\n{code}"
+        expected = "* Level 1\n** Level 2\n*** Level 3\n* Back to 1"
         self.assertEqual(convert(markdown), expected)
 
-        # Mixed list types - also has similar issues
+        # Mixed list types keep the markers of their parents
         markdown = "1. Level 1\n   - Level 2\n     1. Level 3\n2. Back to 1"
-        expected = "# Level 1\n** Level 2\n{code}\n 1. Level 3\n2. Back to 1\n{code}"
+        expected = "# Level 1\n#* Level 2\n#*# Level 3\n# Back to 1"
         self.assertEqual(convert(markdown), expected)
 
     def test_task_lists(self):
@@ -84,29 +83,29 @@ class TestMarkdownToJira(unittest.TestCase):
 
     def test_tables(self):
         """Test conversion of Markdown tables to Jira tables"""
-        # Note: The current implementation doesn't properly handle table headers vs separator lines
         markdown = (
             "| Header 1 | Header 2 |\n| -------- | -------- |\n| Cell 1   | Cell 2   |"
         )
-        expected = "|Header 1|Header 2|\n|--------|--------|\n|Cell 1|Cell 2|"
+        expected = "||Header 1||Header 2||\n|Cell 1|Cell 2|"
         self.assertEqual(convert(markdown), expected)
 
     def test_inline_formatting(self):
         """Test conversion of Markdown inline formatting to Jira formatting"""
-        # Bold - note: the current implementation has a bug where ** becomes _ due to regex order
-        self.assertEqual(convert("**Bold text**"), "_Bold text_")
+        # Bold
+        self.assertEqual(convert("**Bold text**"), "*Bold text*")
 
         # Italic
         self.assertEqual(convert("*Italic text*"), "_Italic text_")
 
-        # Bold and italic - current implementation has regex issues
-        self.assertEqual(convert("***Bold and italic***"), "__Bold and italic__")
+        # Bold and italic
+        self.assertEqual(convert("***Bold and italic***"), "*_Bold and italic_*")
 
         # Strikethrough
         self.assertEqual(convert("~~Strikethrough~~"), "-Strikethrough-")
 
-        # Code
+        # Code, its content is not formatted
         self.assertEqual(convert("`code`"), "{{code}}")
+        self.assertEqual(convert("`*args*`"), "{{*args*}}")
 
         # Links
         self.assertEqual(
@@ -120,6 +119,18 @@ class TestMarkdownToJira(unittest.TestCase):
             "!https://example.com/image.png!",
         )
 
+    def test_editor_description(self):
+        """A description written in the editor becomes readable wiki markup"""
+        markdown = (
+            "## Steps\n\nRun **make** with `-j*`:\n\n```sh\nmake -j4\n```\n\n"
+            "> It *fails*\n\n| Step | Result |\n| --- | --- |\n| build | ok |"
+        )
+        expected = (
+            "h2. Steps\n\nRun *make* with {{-j*}}:\n\n{code:bash}\nmake -j4\n{code}\n\n"
+            "{quote}\nIt _fails_\n{quote}\n\n||Step||Result||\n|build|ok|"
+        )
+        self.assertEqual(convert(markdown), expected)
+
 
 class TestMarkdownToADF(unittest.TestCase):
     """Test the Markdown to Atlassian Document Format converter (v3/v4)"""
@@ -284,6 +295,43 @@ def hello():
         self.assertEqual(adf["type"], "doc")
         self.assertGreater(len(adf["content"]), 7)  # At least 7 content blocks
 
+    def test_v3_paragraphs_and_line_breaks(self):
+        """Blank lines separate paragraphs, single newlines are line breaks"""
+        result = convert_v3("first line\nsecond line\n\nnew paragraph")
+
+        self.assertEqual(len(result["content"]), 2)
+        first = result["content"][0]["content"]
+        self.assertEqual(
+            [node["type"] for node in first], ["text", "hardBreak", "text"]
+        )
+        self.assertEqual(first[2]["text"], "second line")
+        self.assertEqual(result["content"][1]["content"][0]["text"], "new paragraph")
+
+    def test_v3_bare_urls_are_links(self):
+        """URLs typed without markdown link syntax become links"""
+        result = convert_v3("see https://example.com/a_b_c.")
+
+        nodes = result["content"][0]["content"]
+        self.assertEqual(nodes[1]["text"], "https://example.com/a_b_c")
+        self.assertEqual(
+            nodes[1]["marks"],
+            [{"type": "link", "attrs": {"href": "https://example.com/a_b_c"}}],
+        )
+        self.assertEqual(nodes[2]["text"], ".")
+
+    def test_v3_comment_with_code_and_list(self):
+        """A comment written in the editor keeps its structure"""
+        formatter = V3Formatter()
+        markdown = "Tried:\n\n- `make`\n- [docs](https://docs)\n\n```sh\nmake\n```"
+
+        body = formatter.format_comment(markdown)["body"]
+
+        self.assertEqual(
+            [block["type"] for block in body["content"]],
+            ["paragraph", "bulletList", "codeBlock"],
+        )
+        self.assertEqual(body["content"][2]["attrs"], {"language": "sh"})
+
 
 if __name__ == "__main__":
     unittest.main()
@@ -9,6 +9,7 @@ from pydantic import AnyUrl
 from jayrah.mcp.server import (
     ServerContext,
     _create_board_resource,
+    _edit_fields,
     _format_issue_details,
     _format_issues_summary,
     _format_transitions,
@@ -67,6 +68,26 @@ class TestHelperFunctions:
         assert resource.description == "Test board description"
         assert resource.mimeType == "application/json"
 
+    def test_edit_fields(self):
+        """Test _edit_fields only keeps the fields given."""
+        fields = _edit_fields(
+            {"ticket": "TEST-1", "summary": "New", "priority": "Major", "labels": []}
+        )
+
+        assert fields == {"summary": "New", "priority": {"name": "Major"}, "labels": []}
+
+    def test_edit_fields_convert_the_markdown_description(self):
+        """Test the Markdown description becomes wiki markup or ADF."""
+        arguments = {"ticket": "TEST-1", "description": "Some **bold** text"}
+
+        wiki = _edit_fields(arguments, "2")["description"]
+        adf = _edit_fields(arguments, "3")["description"]
+
+        assert wiki == "Some *bold* text"
+        assert adf["type"] == "doc"
+        marks = [x.get("marks") for x in adf["content"][0]["content"]]
+        assert [{"type": "strong"}] in marks
+
     def test_create_board_resource_no_description(self):
         """Test _create_board_resource with no description."""
         board = {"name": "test-board"}
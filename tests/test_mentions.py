@@ -0,0 +1,62 @@
+"""Tests for the @mentions of the comments."""
+
+from jayrah.utils import mentions
+
+JANE = mentions.Mention("Jane Doe", account_id="5b10ac", name="jdoe")
+JOHN = mentions.Mention("John", name="jsmith")
+
+
+def test_mention_query_is_the_name_being_typed():
+    """Two letters after an @ starting a word are searched, emails are not."""
+    assert mentions.mention_query("ping @ja") == "ja"
+    assert mentions.mention_query("@jane.d") == "jane.d"
+    assert mentions.mention_query("ping @j") is None
+    assert mentions.mention_query("mail jane@example") is None
+    assert mentions.mention_query("@jane done") is None
+
+
+def test_mention_from_user_search():
+    """Cloud users have an accountId, Server ones only a name."""
+    cloud = mentions.Mention.from_user({"accountId": "5b10ac", "displayName": "Jane"})
+    server = mentions.Mention.from_user({"name": "jsmith"})
+
+    assert cloud.wiki == "[~accountid:5b10ac]"
+    assert server.text == "@jsmith"
+    assert server.wiki == "[~jsmith]"
+
+
+def test_to_wiki_replaces_the_picked_mentions():
+    """Longer names win and the @ of unknown users is left alone."""
+    text = "@Jane Doe and @John, not @Bob"
+
+    assert (
+        mentions.to_wiki(text, [JOHN, JANE])
+        == "[~accountid:5b10ac] and [~jsmith], not @Bob"
+    )
+    assert mentions.to_wiki(text, []) == text
+
+
+def test_to_adf_splits_text_nodes_around_mentions():
+    """Text nodes get mention nodes, code keeps the mention as written."""
+    document = {
+        "type": "doc",
+        "version": 1,
+        "content": [
+            {
+                "type": "paragraph",
+                "content": [
+                    {"type": "text", "text": "hi @Jane Doe!"},
+                    {"type": "text", "text": "@Jane Doe", "marks": [{"type": "code"}]},
+                ],
+            }
+        ],
+    }
+
+    paragraph = mentions.to_adf(document, [JANE])["content"][0]
+
+    assert paragraph["content"] == [
+        {"type": "text", "text": "hi "},
+        {"type": "mention", "attrs": {"id": "5b10ac", "text": "@Jane Doe"}},
+        {"type": "text", "text": "!"},
+        {"type": "text", "text": "@Jane Doe", "marks": [{"type": "code"}]},
+    ]
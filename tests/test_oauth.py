@@ -0,0 +1,244 @@
+"""Tests for the OAuth 2.0 (3LO) authentication."""
+
+import json
+import socket
+import stat
+import threading
+import time
+import urllib.error
+import urllib.parse
+import urllib.request
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from jayrah.api import oauth
+from jayrah.api.jira_client import JiraHTTP
+
+SERVER = "https://example.atlassian.net"
+
+
+def make_session(tmp_path, token=None):
+    """Build a session with its token file in tmp_path."""
+    token_file = tmp_path / "oauth_token.json"
+    if token is not None:
+        token_file.write_text(json.dumps({f"client|{SERVER}": token}))
+    return oauth.OAuthSession("client", "secret", SERVER, token_file=token_file)
+
+
+def fake_send(responses):
+    """Answer each sent request with the next JSON payload, recording them."""
+    requests = []
+
+    def send(request, data):
+        requests.append((request, data))
+        return 200, responses.pop(0)
+
+    return send, requests
+
+
+def test_session_requires_client_credentials(tmp_path):
+    """An OAuth config without the app credentials is rejected."""
+    with pytest.raises(oauth.OAuthError):
+        oauth.OAuthSession("", "", SERVER, token_file=tmp_path / "token.json")
+
+
+def test_authorization_url_has_redirect_and_state(tmp_path):
+    """The consent page redirects to the localhost callback with the state."""
+    url = make_session(tmp_path).authorization_url("abc")
+
+    assert url.startswith(oauth.AUTHORIZE_URL)
+    assert "state=abc" in url
+    assert "redirect_uri=http%3A%2F%2F127.0.0.1%3A8976%2Fcallback" in url
+    assert "offline_access" in url
+
+
+def test_authorize_waits_for_the_callback_with_the_state(tmp_path, monkeypatch):
+    """Stray requests to the callback port do not end the consent flow."""
+    with socket.socket() as sock:
+        sock.bind(("127.0.0.1", 0))
+        port = sock.getsockname()[1]
+    session = oauth.OAuthSession(
+        "client", "secret", SERVER, callback_port=port, token_file=tmp_path / "t"
+    )
+    statuses = []
+    visits = []
+
+    def browser(url):
+        state = urllib.parse.parse_qs(urllib.parse.urlparse(url).query)["state"][0]
+
+        def visit():
+            for path in (
+                "/favicon.ico",
+                "/callback?code=stolen&state=other",
+                f"/callback?code=abc&state={state}",
+            ):
+                try:
+                    with urllib.request.urlopen(f"http://127.0.0.1:{port}{path}"):
+                        statuses.append(200)
+                except urllib.error.HTTPError as e:
+                    statuses.append(e.code)
+
+        visits.append(threading.Thread(target=visit))
+        visits[0].start()
+
+    monkeypatch.setattr(oauth.webbrowser, "open", browser)
+    with patch.object(session, "exchange_code") as exchange_code:
+        session.authorize()
+    visits[0].join()
+
+    exchange_code.assert_called_once_with("abc")
+    assert statuses == [404, 404, 200]
+
+
+def test_authorize_gives_up_after_the_timeout(tmp_path, monkeypatch):
+    """Without a callback the flow fails once the timeout expires."""
+    monkeypatch.setattr(oauth, "AUTHORIZE_TIMEOUT", 0.2)
+    monkeypatch.setattr(oauth.webbrowser, "open", lambda url: None)
+    session = oauth.OAuthSession(
+        "client", "secret", SERVER, callback_port=0, token_file=tmp_path / "t"
+    )
+
+    with pytest.raises(oauth.OAuthError, match="no code received"):
+        session.authorize()
+
+
+def test_access_token_does_not_authorize_from_a_worker(tmp_path):
+    """A worker thread asks for the consent flow to run from the terminal."""
+    session = make_session(tmp_path)
+    errors = []
+
+    def worker():
+        try:
+            session.access_token()
+        except oauth.OAuthError as e:
+            errors.append(str(e.message))
+
+    with patch.object(session, "authorize") as authorize:
+        thread = threading.Thread(target=worker)
+        thread.start()
+        thread.join()
+
+    authorize.assert_not_called()
+    assert "jayrah cli list" in errors[0]
+
+
+def test_exchange_code_stores_token_for_the_site(tmp_path):
+    """The tokens and the cloud id of the site are saved, readable by the user only."""
+    session = make_session(tmp_path)
+    session.send, requests = fake_send(
+        [
+            {"access_token": "a1", "refresh_token": "r1", "expires_in": 3600},
+            [
+                {"id": "other", "url": "https://other.atlassian.net"},
+                {"id": "cloud-1", "url": SERVER},
+            ],
+        ]
+    )
+
+    session.exchange_code("the-code")
+
+    (token_request, token_data), (resources_request, _) = requests
+    assert token_request.full_url == oauth.TOKEN_URL
+    assert json.loads(token_data)["code"] == "the-code"
+    assert resources_request.get_header("Authorization") == "Bearer a1"
+    assert session.token["cloud_id"] == "cloud-1"
+    assert session.api_url == "https://api.atlassian.com/ex/jira/cloud-1"
+    assert stat.S_IMODE(session.token_file.stat().st_mode) == 0o600
+    assert make_session(tmp_path).token["refresh_token"] == "r1"
+
+
+def test_access_token_refreshes_when_expired(tmp_path):
+    """An expired token is refreshed and the rotated refresh token kept."""
+    session = make_session(
+        tmp_path,
+        {
+            "access_token": "old",
+            "refresh_token": "r1",
+            "expires_at": time.time() - 10,
+            "cloud_id": "cloud-1",
+        },
+    )
+    session.send, requests = fake_send(
+        [{"access_token": "new", "refresh_token": "r2", "expires_in": 3600}]
+    )
+
+    assert session.access_token() == "new"
+    assert session.access_token() == "new"
+
+    assert len(requests) == 1
+    assert json.loads(requests[0][1])["refresh_token"] == "r1"
+    assert session.token["refresh_token"] == "r2"
+
+
+def test_jira_client_goes_through_the_api_gateway(tmp_path):
+    """With OAuth the client talks to the gateway with a fresh bearer token."""
+    token_file = tmp_path / "oauth_token.json"
+    token_file.write_text(
+        json.dumps(
+            {
+                f"client|{SERVER}": {
+                    "access_token": "a1",
+                    "expires_at": time.time() + 3600,
+                    "cloud_id": "cloud-1",
+                }
+            }
+        )
+    )
+    config = {
+        "jira_server": SERVER,
+        "oauth_client_id": "client",
+        "oauth_client_secret": "secret",
+        "oauth_token_file": token_file,
+        "no_cache": True,
+    }
+
+    client = JiraHTTP(config, api_version="3", auth_method="oauth")
+
+    assert client.base_url == "https://api.atlassian.com/ex/jira/cloud-1/rest/api/3"
+    assert client.headers["Authorization"] == "Bearer a1"
+    assert client.agile.request_handler.base_url.startswith(
+        "https://api.atlassian.com/ex/jira/cloud-1/"
+    )
+
+
+def test_jira_client_refreshes_through_its_opener(tmp_path):
+    """The token requests go through the proxy and timeouts of the client."""
+    token_file = tmp_path / "oauth_token.json"
+    token_file.write_text(
+        json.dumps(
+            {
+                f"client|{SERVER}": {
+                    "access_token": "old",
+                    "refresh_token": "r1",
+                    "expires_at": time.time() - 10,
+                    "cloud_id": "cloud-1",
+                }
+            }
+        )
+    )
+    config = {
+        "jira_server": SERVER,
+        "oauth_client_id": "client",
+        "oauth_client_secret": "secret",
+        "oauth_token_file": token_file,
+        "no_cache": True,
+        "proxy": "http://proxy.example.com:3128",
+        "connect_timeout": 7,
+    }
+    response = MagicMock()
+    response.status = 200
+    response.read.return_value = json.dumps(
+        {"access_token": "new", "expires_in": 3600}
+    ).encode()
+    response.__enter__.return_value = response
+
+    with patch("urllib.request.OpenerDirector.open", return_value=response) as opened:
+        client = JiraHTTP(config, api_version="3", auth_method="oauth")
+
+    request = opened.call_args.args[0]
+    assert request.full_url == oauth.TOKEN_URL
+    assert request.get_header("User-agent").startswith("jayrah/")
+    assert opened.call_args.kwargs["timeout"] == 7
+    assert client.headers["Authorization"] == "Bearer new"
+    assert client.request_handler.base_url.startswith("https://api.atlassian.com/")
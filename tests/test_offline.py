@@ -0,0 +1,36 @@
+"""Tests for the offline issue store."""
+
+from jayrah.utils.offline import OfflineStore
+
+
+def test_offline_store_round_trip(tmp_path):
+    """Saved board results and issues are read back with their timestamp."""
+    store = OfflineStore({"cache_dir": tmp_path})
+    issues = [{"key": "TEST-1", "fields": {"summary": "Hello"}}]
+
+    store.save_issues("project = TEST", "updated", issues)
+    store.save_issue(issues[0])
+
+    saved_issues, saved_at = store.load_issues("project = TEST", "updated")
+    assert saved_issues == issues
+    assert saved_at > 0
+    assert store.load_issue("TEST-1")[0] == issues[0]
+
+
+def test_offline_store_misses_unknown_entries(tmp_path):
+    """Queries and issues never saved are not found."""
+    store = OfflineStore({"cache_dir": tmp_path})
+    store.save_issues("project = TEST", "updated", [])
+
+    assert store.load_issues("project = TEST", "created") is None
+    assert store.load_issue("TEST-2") is None
+
+
+def test_offline_store_keeps_latest_result(tmp_path):
+    """A newer fetch replaces the saved result and survives a new store."""
+    OfflineStore({"cache_dir": tmp_path}).save_issues("jql", None, [{"key": "A"}])
+    OfflineStore({"cache_dir": tmp_path}).save_issues("jql", None, [{"key": "B"}])
+
+    assert OfflineStore({"cache_dir": tmp_path}).load_issues("jql", None)[0] == [
+        {"key": "B"}
+    ]
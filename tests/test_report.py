@@ -0,0 +1,90 @@
+"""Tests for the standup report of the issues I worked on."""
+
+import datetime
+from unittest.mock import MagicMock
+
+import pytest
+
+from jayrah.utils import report
+
+SERVER = "https://jira.example.com"
+NOW = datetime.datetime(2026, 10, 14, 9, 0, tzinfo=datetime.timezone.utc)
+
+
+def make_issue(key, summary, status="In Progress"):
+    return {"key": key, "fields": {"summary": summary, "status": {"name": status}}}
+
+
+def comment(author, created, body):
+    return {"author": {"displayName": author}, "created": created, "body": body}
+
+
+def test_parse_window():
+    """Named windows and day or week counts give a number of days."""
+    assert report.parse_window("yesterday") == 1
+    assert report.parse_window("Week") == 7
+    assert report.parse_window("3d") == 3
+    assert report.parse_window("2w") == 14
+    with pytest.raises(ValueError):
+        report.parse_window("last month")
+
+
+def test_standup_report_keeps_my_recent_events():
+    """Only my events of the window are reported, grouped by status."""
+    jira = MagicMock()
+    jira.verify_auth.return_value = {"displayName": "Jane Doe"}
+    issues_client = MagicMock()
+    issues_client.list_issues.return_value = [
+        make_issue("TEST-1", "Fix login"),
+        make_issue("TEST-2", "Old work", "Done"),
+        make_issue("TEST-3", "Write docs", "Done"),
+    ]
+    comments = {
+        "TEST-1": [
+            comment("Jane Doe", "2026-10-13T15:00:00.000+0000", "Found it\nmore"),
+            comment("John Roe", "2026-10-13T16:00:00.000+0000", "Thanks"),
+        ],
+        "TEST-2": [comment("Jane Doe", "2026-10-01T10:00:00.000+0000", "Stale")],
+        "TEST-3": [],
+    }
+    jira.get_issue_comments.side_effect = lambda key, **kwargs: {
+        "comments": comments[key]
+    }
+    histories = {
+        "TEST-3": [
+            {
+                "author": {"displayName": "Jane Doe"},
+                "created": "2026-10-14T08:00:00.000+0000",
+                "items": [
+                    {"field": "status", "fromString": "New", "toString": "Done"}
+                ],
+            }
+        ]
+    }
+    jira.get_issue_changelog.side_effect = lambda key, **kwargs: histories.get(
+        key, []
+    )
+
+    text = report.standup_report(jira, issues_client, 1, SERVER, now=NOW)
+
+    assert text == (
+        "# Standup since Tuesday 13 October\n"
+        "\n"
+        "## In Progress\n"
+        "\n"
+        f"- [TEST-1]({SERVER}/browse/TEST-1) Fix login\n"
+        "  - commented: Found it\n"
+        "\n"
+        "## Done\n"
+        "\n"
+        f"- [TEST-3]({SERVER}/browse/TEST-3) Write docs\n"
+        "  - moved New → Done\n"
+    )
+    assert "updated >= -1d" in issues_client.list_issues.call_args.args[0]
+
+
+def test_render_standup_without_events():
+    """An empty window says so rather than showing no section at all."""
+    text = report.render_standup([], NOW)
+
+    assert text.endswith("Nothing done on any issue.\n")
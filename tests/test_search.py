@@ -98,3 +98,18 @@ def test_format_search_terms():
     assert result == expected, f"Expected {expected}, got {result}"
 
     log("All format_search_terms tests passed!")
+
+
+def test_board_overrides():
+    """The overrides of the board, the first one without a name"""
+    config = {
+        "boards": [
+            {"name": "mine", "jql": "assignee = currentUser()", "limit": 10},
+            {"name": "team", "jql": "project = TEST", "columns": ["summary"]},
+        ]
+    }
+
+    assert boards.overrides(None, config) == {"limit": 10}
+    assert boards.overrides("team", config) == {"columns": ["summary"]}
+    assert boards.overrides("unknown", config) == {}
+    assert boards.overrides(None, {"boards": []}) == {}
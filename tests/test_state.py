@@ -0,0 +1,97 @@
+"""Tests for the state kept between sessions."""
+
+import datetime
+
+from jayrah.config import defaults
+from jayrah.utils.state import StateStore
+
+
+def test_toggle_pin_is_kept_across_sessions(tmp_path):
+    """Pinned issues are saved in the order they were pinned."""
+    config = {"cache_dir": tmp_path, "jira_server": "https://jira.example.com"}
+    store = StateStore(config)
+
+    assert store.toggle_pin("TEST-2") is True
+    assert store.toggle_pin("TEST-1") is True
+
+    assert StateStore(config).pinned_issues() == ["TEST-2", "TEST-1"]
+    assert store.toggle_pin("TEST-2") is False
+    assert StateStore(config).pinned_issues() == ["TEST-1"]
+
+
+def test_state_is_kept_by_jira_server(tmp_path):
+    """Profiles on another Jira instance have their own pinned issues."""
+    StateStore({"cache_dir": tmp_path, "jira_server": "https://a"}).toggle_pin("A-1")
+
+    other = StateStore({"cache_dir": tmp_path, "jira_server": "https://b"})
+
+    assert other.pinned_issues() == []
+
+
+def test_corrupted_state_file_is_ignored(tmp_path):
+    """A state file that isn't JSON reads as empty."""
+    (tmp_path / "state.json").write_text("not json", encoding="utf-8")
+
+    assert StateStore({"cache_dir": tmp_path}).pinned_issues() == []
+
+
+
+def test_record_view_keeps_the_latest_view_first(tmp_path):
+    """Viewing an issue again moves it to the top instead of listing it twice."""
+    store = StateStore({"cache_dir": tmp_path})
+    when = datetime.datetime(2026, 10, 14, 9, 30, tzinfo=datetime.timezone.utc)
+
+    store.record_view("TEST-1", "First", when)
+    store.record_view("TEST-2", "Second", when)
+    store.record_view("TEST-1", "First", when)
+
+    assert [x["key"] for x in store.history()] == ["TEST-1", "TEST-2"]
+    assert store.history()[0]["viewed"] == "2026-10-14T09:30:00+00:00"
+
+
+def test_history_is_bounded(tmp_path, monkeypatch):
+    """Only the most recent views are kept."""
+    monkeypatch.setattr(defaults, "HISTORY_SIZE", 2)
+    store = StateStore({"cache_dir": tmp_path})
+
+    for key in ("TEST-1", "TEST-2", "TEST-3"):
+        store.record_view(key, "")
+
+    assert [x["key"] for x in store.history()] == ["TEST-3", "TEST-2"]
+
+
+def test_recent_labels_come_first(tmp_path, monkeypatch):
+    """Labels set again move to the top, only the most recent are kept."""
+    monkeypatch.setattr(defaults, "RECENT_LABELS", 3)
+    store = StateStore({"cache_dir": tmp_path})
+
+    store.record_labels(["backend", "urgent"])
+    store.record_labels(["frontend", "backend"])
+
+    assert store.recent_labels() == ["frontend", "backend", "urgent"]
+    store.record_labels(["docs"])
+    assert store.recent_labels() == ["docs", "frontend", "backend"]
+
+
+def test_comment_drafts_are_kept_until_dropped(tmp_path):
+    """A draft is kept by issue across sessions, saving it empty drops it."""
+    StateStore({"cache_dir": tmp_path}).save_comment_draft("TEST-1", "WIP\nmore")
+
+    store = StateStore({"cache_dir": tmp_path})
+    assert store.comment_draft("TEST-1") == "WIP\nmore"
+    assert store.comment_draft("TEST-2") == ""
+    store.save_comment_draft("TEST-1", "")
+    assert store.comment_draft("TEST-1") == ""
+
+
+def test_session_is_restored_by_the_next_store(tmp_path):
+    """The last session is read back as it was saved, empty at first."""
+    config = {"cache_dir": tmp_path, "jira_server": "https://jira.example.com"}
+    assert StateStore(config).session() == {}
+
+    StateStore(config).save_session({"command": "myboard", "filter_text": "login"})
+
+    assert StateStore(config).session() == {
+        "command": "myboard",
+        "filter_text": "login",
+    }
@@ -1,6 +1,27 @@
 """Tests for Textual issue browser app behavior."""
 
-from jayrah.ui.tui.app import IssueBrowserApp
+import datetime
+import threading
+from collections import deque
+from functools import partial
+from types import SimpleNamespace
+from unittest.mock import MagicMock, call, patch
+
+from jayrah.config import defaults
+from jayrah.ui.shared_helpers import fuzzy_match, parse_filter
+from jayrah.ui.tui.app import IssueBrowserApp, run_textual_browser
+from jayrah.ui.tui.helpers import (
+    board_stats,
+    filter_issues_by_text,
+    get_issue_epic,
+    group_issues,
+    sort_issues,
+    visible_columns,
+)
+from jayrah.ui.tui.messages import MessageLog
+from jayrah.ui.tui.tabs import TabSet
+from jayrah.ui.tui.undo import Change, UndoStack
+from jayrah.ui.tui.workers import JiraWorkerPool
 
 
 class DummyApp:
@@ -23,6 +44,36 @@ def test_row_selected_confirms_in_auto_choose_mode():
     assert app.confirm_calls == 1
 
 
+def test_board_switch_answered_late_is_dropped():
+    """A board answering after the one switched to next doesn't replace it."""
+    pool = JiraWorkerPool(lambda callback, *args: callback(*args))
+    app = MagicMock(config={}, issues_generation=0)
+    app.fetch_issues.return_value = []
+    for name in ("_submit_issues_request", "_change_board_worker"):
+        setattr(app, name, partial(getattr(IssueBrowserApp, name), app))
+    slow = threading.Event()
+
+    def check(board_name, _config):
+        if board_name == "slow":
+            slow.wait(5)
+        return f"project = {board_name.upper()}", board_name
+
+    futures = []
+    app.worker_pool = SimpleNamespace(
+        submit=lambda *args, **kwargs: futures.append(pool.submit(*args, **kwargs))
+    )
+    with patch("jayrah.ui.boards.check", check):
+        IssueBrowserApp.change_board(app, "slow")  # type: ignore[arg-type]
+        IssueBrowserApp.change_board(app, "fast")  # type: ignore[arg-type]
+        futures[1].result()
+        slow.set()
+        futures[0].result()
+    pool.shutdown()
+
+    assert (app.command, app.jql, app.order_by) == ("fast", "project = FAST", "fast")
+    app._update_issues_after_board_change.assert_called_once_with([], "fast")
+
+
 def test_row_selected_does_not_confirm_outside_auto_choose_mode():
     """Pressing Enter on a row should not auto-confirm outside choose mode."""
     app = DummyApp(auto_choose=False)
@@ -30,3 +81,1094 @@ def test_row_selected_does_not_confirm_outside_auto_choose_mode():
     IssueBrowserApp._handle_row_selected(app, object())  # type: ignore[arg-type]
 
     assert app.confirm_calls == 0
+
+
+def test_run_textual_browser_disables_mouse_when_configured():
+    """The no_mouse option runs the app without mouse capture."""
+    with patch("jayrah.ui.tui.app.IssueBrowserApp") as mock_app:
+        mock_app.return_value.run.return_value = "TEST-1"
+        mock_app.return_value.no_issues_found = False
+        result = run_textual_browser([], {"no_mouse": True}, "board", "jql", "")
+
+    mock_app.return_value.run.assert_called_once_with(mouse=False)
+    assert result == "TEST-1"
+
+
+def test_refresh_flag_wins_over_the_interval_of_every_board():
+    """--refresh keeps its interval across boards, 0 turning the reload off."""
+    app = SimpleNamespace(
+        auto_reload_timer=None,
+        refresh_override=60,
+        board_overrides={"refresh_interval": 300},
+        config={"auto_reload": 120},
+        set_interval=MagicMock(),
+        auto_reload_issues=MagicMock(),
+    )
+
+    IssueBrowserApp.start_auto_reload(app)  # type: ignore[arg-type]
+    app.set_interval.assert_called_once_with(60.0, app.auto_reload_issues)
+
+    app.set_interval.reset_mock()
+    app.auto_reload_timer, app.refresh_override = None, 0
+    IssueBrowserApp.start_auto_reload(app)  # type: ignore[arg-type]
+    app.set_interval.assert_not_called()
+
+    app.refresh_override = None
+    IssueBrowserApp.start_auto_reload(app)  # type: ignore[arg-type]
+    app.set_interval.assert_called_once_with(300.0, app.auto_reload_issues)
+
+
+def test_horizontal_layout_css_follows_the_issues_width():
+    """The widths of the side by side panes come from HORIZONTAL_ISSUES_WIDTH."""
+    width = defaults.HORIZONTAL_ISSUES_WIDTH
+
+    assert f"width: {width}%;" in IssueBrowserApp.CSS
+    assert f"width: {100 - width}%;" in IssueBrowserApp.CSS
+    assert "{issues_width}" not in IssueBrowserApp.CSS
+
+
+def test_run_textual_browser_reports_when_no_issues_found():
+    """An empty initial load exits the app and calls back the caller."""
+    on_no_issues = MagicMock()
+    with patch("jayrah.ui.tui.app.IssueBrowserApp") as mock_app:
+        mock_app.return_value.no_issues_found = True
+        result = run_textual_browser(
+            None, {}, "board", "jql", "", on_no_issues=on_no_issues
+        )
+
+    assert mock_app.call_args.args[0] is None
+    on_no_issues.assert_called_once_with()
+    assert result is None
+
+
+class LoadingApp(DummyApp):
+    """App stub recording what happens once the initial issues are loaded."""
+
+    def __init__(self, auto_choose: bool = False):
+        super().__init__(auto_choose)
+        self.issues: list = []
+        self.issues_loading = True
+        self.no_issues_found = False
+        self.exited = False
+        self.filters: list = []
+        self.first_selected = False
+        self.filter_text = ""
+        self.restored_issue: str | None = None
+        self.table = MagicMock(rows={})
+        self.query_one = lambda *args: self.table
+
+    def exit(self) -> None:
+        self.exited = True
+
+    def apply_fuzzy_filter(self, text: str) -> None:
+        self.filters.append(text)
+
+    def _select_first_issue(self) -> None:
+        self.first_selected = True
+
+    def _select_restored_issue(self) -> None:
+        IssueBrowserApp._select_restored_issue(self)  # type: ignore[arg-type]
+
+
+def test_issues_loaded_restore_the_last_session():
+    """The filter and the issue selected in the last session come back."""
+    app = LoadingApp()
+    app.filter_text, app.restored_issue = "login", "TEST-2"
+    app.table.rows = {"TEST-1": None, "TEST-2": None}
+    app.table.get_row_index.return_value = 1
+
+    issues = [make_issue("TEST-1"), make_issue("TEST-2")]
+    IssueBrowserApp._on_issues_loaded(app, issues)  # type: ignore[arg-type]
+
+    assert app.filters == ["login"]
+    app.table.move_cursor.assert_called_once_with(row=1)
+    assert app.restored_issue is None
+
+
+def test_session_is_saved_when_the_browser_closes():
+    """The query and view are saved for the next start, not in choose mode."""
+    app = SimpleNamespace(
+        command="myboard",
+        jql="project = TEST",
+        order_by="updated",
+        filter_text="login",
+        sort_column="priority",
+        sort_reverse=True,
+        group_by=None,
+        selected_issue="TEST-2",
+        auto_choose=False,
+        worker_pool=MagicMock(),
+        jayrah_obj=MagicMock(),
+    )
+    app.session_state = lambda: IssueBrowserApp.session_state(app)  # type: ignore[arg-type]
+
+    IssueBrowserApp.on_unmount(app)  # type: ignore[arg-type]
+
+    saved = app.jayrah_obj.state_store.save_session.call_args.args[0]
+    assert saved["command"] == "myboard"
+    assert (saved["sort_column"], saved["selected_issue"]) == ("priority", "TEST-2")
+
+
+def test_issues_loaded_fills_table():
+    """Issues loaded after startup are shown in the table."""
+    app = LoadingApp()
+
+    IssueBrowserApp._on_issues_loaded(app, [{"key": "TEST-1"}])  # type: ignore[arg-type]
+
+    assert app.issues == [{"key": "TEST-1"}]
+    assert app.filters == [""]
+    assert app.first_selected
+    assert not app.issues_loading
+    assert not app.exited
+
+
+def test_issues_loaded_exits_when_empty():
+    """An empty initial load exits the app instead of showing an empty table."""
+    app = LoadingApp()
+
+    IssueBrowserApp._on_issues_loaded(app, [])  # type: ignore[arg-type]
+
+    assert app.exited
+    assert app.no_issues_found
+    assert not app.filters
+
+
+def make_issue(key, summary="Summary", **fields):
+    """Build a minimal issue as returned by the search API."""
+    return {
+        "key": key,
+        "fields": {
+            "summary": summary,
+            "issuetype": {"name": "Story"},
+            **fields,
+        },
+    }
+
+
+class GroupingApp(DummyApp):
+    """App stub with a fake issues table for the epic grouping code."""
+
+    def __init__(self, config: dict | None = None):
+        super().__init__(auto_choose=False)
+        self.config = config or {}
+        self.group_by = "epic"
+        self.folded_groups: set[str] = set()
+        self.table = MagicMock()
+        self.rows: list = []
+        self.table.clear.side_effect = self.rows.clear
+        self.table.add_row.side_effect = lambda *row, key: self.rows.append(
+            (key, row)
+        )
+        self.marked_issues: set[str] = set()
+        self.updated_issues: set[str] = set()
+        self.filter_text = ""
+        self.board_overrides: dict = {}
+        self.terminal_width: int | None = None
+
+    def _row_data(self, issue: dict) -> tuple:
+        return IssueBrowserApp._row_data(self, issue)  # type: ignore[arg-type]
+
+    def _board_columns(self) -> list[str]:
+        return IssueBrowserApp._board_columns(self)  # type: ignore[arg-type]
+
+    def _shown_columns(self) -> list[str]:
+        return IssueBrowserApp._shown_columns(self)  # type: ignore[arg-type]
+
+    @property
+    def compact(self) -> bool:
+        return IssueBrowserApp.compact.fget(self)  # type: ignore[attr-defined]
+
+    def _highlight_match(self, cell):
+        return IssueBrowserApp._highlight_match(self, cell)  # type: ignore[arg-type]
+
+
+def test_get_issue_epic_from_parent_or_epic_link():
+    """The epic comes from an Epic parent on Cloud or the Epic Link on Server."""
+    epic_parent = {
+        "key": "TEST-1",
+        "fields": {"summary": "Big epic", "issuetype": {"name": "Epic"}},
+    }
+    task_parent = {
+        "key": "TEST-2",
+        "fields": {"summary": "A story", "issuetype": {"name": "Story"}},
+    }
+
+    assert get_issue_epic(make_issue("TEST-3", parent=epic_parent)) == (
+        "TEST-1",
+        "Big epic",
+    )
+    assert get_issue_epic(make_issue("TEST-3", parent=task_parent)) == ("", "")
+    assert get_issue_epic(
+        make_issue("TEST-3", customfield_1="TEST-9"), "customfield_1"
+    ) == ("TEST-9", "")
+
+
+@patch("jayrah.ui.tui.app.get_row_data_for_issue", return_value=("row",))
+def test_epic_group_rows(_mock_row_data):
+    """Issues are listed under their epic header, without an epic last."""
+    app = GroupingApp({"epic_link_field": "customfield_1"})
+    app.folded_groups = {"TEST-8"}
+    issues = [
+        make_issue("TEST-1"),
+        make_issue("TEST-2", customfield_1="TEST-9"),
+        make_issue("TEST-3", customfield_1="TEST-8"),
+        make_issue("TEST-9", "The epic"),
+    ]
+
+    IssueBrowserApp._add_group_rows(app, app.table, issues)  # type: ignore[arg-type]
+
+    assert [key for key, _ in app.rows] == [
+        "group:TEST-9",
+        "TEST-2",
+        "group:TEST-8",
+        "group:",
+        "TEST-1",
+        "TEST-9",
+    ]
+    assert app.rows[0][1][:3] == ("▼", "", "TEST-9 The epic (1)")
+    assert app.rows[2][1][:3] == ("▶", "", "TEST-8 (1)")
+    assert app.rows[3][1][2] == "No epic (2)"
+
+
+def test_group_issues_by_status_and_assignee():
+    """Statuses go from To Do to Done, unassigned issues come last."""
+    todo = {"name": "New", "statusCategory": {"key": "new"}}
+    done = {"name": "Closed", "statusCategory": {"key": "done"}}
+    issues = [
+        make_issue("TEST-1", status=done, assignee={"key": "bob"}),
+        make_issue("TEST-2", status=todo, assignee=None),
+        make_issue("TEST-3", status=done, assignee={"key": "al", "displayName": "Al"}),
+    ]
+
+    by_status = group_issues(issues, "status")
+    by_assignee = group_issues(issues, "assignee")
+
+    assert [(key, len(members)) for key, _, members in by_status] == [
+        ("New", 1),
+        ("Closed", 2),
+    ]
+    assert [(key, label) for key, label, _ in by_assignee] == [
+        ("al", "Al"),
+        ("bob", "bob"),
+        ("", "Unassigned"),
+    ]
+
+
+def test_group_issues_by_status_category():
+    """Custom statuses of the same category share a group."""
+    doing = {"key": "indeterminate", "name": "In Progress"}
+    issues = [
+        make_issue("TEST-1", status={"name": "Review", "statusCategory": doing}),
+        make_issue("TEST-2", status={"name": "Closed"}),
+        make_issue("TEST-3", status={"name": "Coding", "statusCategory": doing}),
+    ]
+
+    groups = group_issues(issues, "category")
+
+    assert [(label, len(members)) for _, label, members in groups] == [
+        ("In Progress", 2),
+        ("No status", 1),
+    ]
+
+
+@patch("jayrah.ui.tui.app.get_row_data_for_issue")
+def test_row_data_colors_status_by_category(mock_row_data):
+    """The status cell takes the color of its category, whatever its name."""
+    mock_row_data.return_value = ("S", "TEST-1", "Summary", "Review", "Major")
+    app = GroupingApp()
+    status = {"name": "Review", "statusCategory": {"key": "done"}}
+
+    row = app._row_data(make_issue("TEST-1", status=status))
+
+    assert (str(row[3]), row[3].style) == ("Review", "green")
+
+
+@patch("jayrah.ui.tui.app.get_row_data_for_issue")
+def test_row_data_shows_the_board_columns(mock_row_data):
+    """The columns of a board follow the type and the ticket, in its order."""
+    mock_row_data.return_value = (
+        "S",
+        "TEST-1",
+        "Summary",
+        "New",
+        "Major",
+        "Jane",
+        "John",
+        "1d",
+        "2h",
+    )
+    app = GroupingApp()
+    app.board_overrides = {"columns": ["updated", "summary"]}
+
+    row = app._row_data(make_issue("TEST-1", status={"name": "New"}))
+
+    assert row == ("S", "TEST-1", "2h", "Summary")
+
+
+@patch("jayrah.ui.tui.app.get_row_data_for_issue")
+def test_row_data_fits_the_terminal_width(mock_row_data):
+    """Narrow terminals drop the low priority columns, then go compact."""
+    mock_row_data.return_value = (
+        "S",
+        "TEST-1",
+        "Summary",
+        "New",
+        "Major",
+        "Jane",
+        "John",
+        "1d",
+        "2h",
+    )
+    app = GroupingApp()
+    app.terminal_width = 100
+
+    row = app._row_data(make_issue("TEST-1", status={"name": "New"}))
+    assert row == ("S", "TEST-1", "Summary", "New", "Major", "Jane", "2h")
+
+    app.terminal_width = 50
+    row = app._row_data(make_issue("TEST-1", status={"name": "New"}))
+    assert row[:2] == ("S", "TEST-1")
+    assert row[2].plain == "Summary  New"
+    assert len(row) == 3
+
+
+def test_fuzzy_filter_ranks_the_best_matches_first():
+    """Fuzzy mode keeps issues with the letters in order, tightest first."""
+    issues = [
+        make_issue("TEST-1", summary="Log in as a guest", status={"name": "New"}),
+        make_issue("TEST-2", summary="Login bug", status={"name": "New"}),
+        make_issue("TEST-3", summary="Docs", status={"name": "New"}),
+    ]
+
+    fuzzy = filter_issues_by_text(issues, "lgnbug", "fuzzy")
+    substring = filter_issues_by_text(issues, "lgnbug")
+
+    assert [x["key"] for x in fuzzy] == ["TEST-2"]
+    assert substring == []
+    assert [x["key"] for x in filter_issues_by_text(issues, "login", "fuzzy")] == [
+        "TEST-2",
+        "TEST-1",
+    ]
+
+
+def test_filter_field_expressions():
+    """field:value words keep the issues with that field containing the value."""
+    alice = {"displayName": "Alice Doe", "emailAddress": "alice@example.com"}
+    issues = [
+        make_issue(
+            "TEST-1",
+            status={"name": "In Progress"},
+            assignee=alice,
+            labels=["backend"],
+        ),
+        make_issue("TEST-2", status={"name": "In Progress"}, labels=["frontend"]),
+        make_issue("TEST-3", summary="Login", status={"name": "In Review"}),
+    ]
+
+    def keys(text):
+        return [x["key"] for x in filter_issues_by_text(issues, text)]
+
+    assert keys("status:progress assignee:alice label:back") == ["TEST-1"]
+    assert keys("status:progress") == ["TEST-1", "TEST-2"]
+    assert keys('status:"in review" login') == ["TEST-3"]
+    assert keys("assignee:none") == ["TEST-2", "TEST-3"]
+    assert parse_filter("status:done foo:bar fix") == (
+        [("status", "done")],
+        "foo:bar fix",
+    )
+
+
+def test_board_stats_counts_and_ages():
+    """Stats count the issues by field and age the unresolved ones."""
+    done = {"name": "Done", "statusCategory": {"key": "done"}}
+    issues = [
+        make_issue(
+            "TEST-1",
+            status={"name": "New"},
+            assignee={"displayName": "Alice"},
+            created="2026-10-01T00:00:00.000+0000",
+        ),
+        make_issue(
+            "TEST-2",
+            status={"name": "New"},
+            priority={"name": "High"},
+            created="2026-10-09T00:00:00.000+0000",
+        ),
+        make_issue("TEST-3", status=done, created="2026-01-01T00:00:00.000+0000"),
+    ]
+    now = datetime.datetime(2026, 10, 11, tzinfo=datetime.timezone.utc)
+
+    stats = board_stats(issues, now)
+
+    assert stats["status"] == [("New", 2), ("Done", 1)]
+    assert stats["assignee"] == [("Unassigned", 2), ("Alice", 1)]
+    assert stats["priority"] == [("None", 2), ("High", 1)]
+    assert stats["oldest"]["key"] == "TEST-1"
+    assert stats["oldest_age"] == datetime.timedelta(days=10)
+    assert stats["average_age"] == datetime.timedelta(days=6)
+
+
+def test_fuzzy_match_keeps_the_shortest_window():
+    """The letters are matched in the tightest window, not the first one."""
+    match = fuzzy_match("ab", "a--xab")
+
+    assert match is not None
+    assert match[1] == [4, 5]
+    assert fuzzy_match("ba", "ab") is None
+
+
+@patch("jayrah.ui.tui.app.get_row_data_for_issue")
+def test_row_data_underlines_filter_matches(mock_row_data):
+    """The letters matching the filter are underlined in the cells."""
+    mock_row_data.return_value = ("S", "TEST-1", "Login bug", "New", "Major")
+    app = GroupingApp({"filter_mode": "fuzzy"})
+    app.filter_text = "lbug"
+
+    row = app._row_data(make_issue("TEST-1", status={"name": "New"}))
+
+    underlined = [span.start for span in row[2].spans]
+    assert underlined == [0, 6, 7, 8]
+    assert row[4] == "Major"
+
+
+def test_cycle_group_goes_back_to_the_plain_list():
+    """g moves through the groupings then back to no grouping."""
+    app = PagingApp(None)
+    app.group_by, app.folded_groups = "epic", {"TEST-1"}
+
+    IssueBrowserApp.action_cycle_group(app)  # type: ignore[arg-type]
+    assert app.group_by is None
+    assert not app.folded_groups
+
+    IssueBrowserApp.action_cycle_group(app)  # type: ignore[arg-type]
+    assert app.group_by == "status"
+    assert app.table.border_title == "Issues (20), grouped by status"
+
+
+def test_row_selected_on_epic_row_toggles_fold():
+    """Enter on an epic header folds it instead of confirming the selection."""
+    app = DummyApp(auto_choose=True)
+    app.toggle_group_fold = MagicMock()  # type: ignore[attr-defined]
+    event = MagicMock()
+    event.row_key.value = "group:TEST-9"
+
+    IssueBrowserApp._handle_row_selected(app, event)  # type: ignore[arg-type]
+
+    app.toggle_group_fold.assert_called_once_with("TEST-9")  # type: ignore[attr-defined]
+    assert app.confirm_calls == 0
+
+
+class BulkApp(DummyApp):
+    """App stub running bulk actions inline."""
+
+    def __init__(self):
+        super().__init__(auto_choose=False)
+        self.worker_pool = JiraWorkerPool(lambda callback, *args: callback(*args))
+        self.jayrah_obj = MagicMock()
+        self.sub_title = ""
+        self.notify = MagicMock()
+        self.set_marked_issues = MagicMock()
+        self.action_reload = MagicMock()
+
+
+def run_bulk(app, issue_keys, func):
+    """Run a bulk action and wait for every request to finish."""
+    futures = []
+    submit = app.worker_pool.submit
+    app.worker_pool.submit = lambda *args, **kwargs: futures.append(
+        submit(*args, **kwargs)
+    )
+    IssueBrowserApp.run_bulk_action(app, "Bulk", issue_keys, func)  # type: ignore[arg-type]
+    for future in futures:
+        future.result()
+
+
+def test_bulk_action_reports_failures_and_keeps_them_marked():
+    """Every issue is processed, failed ones are reported and stay marked."""
+    app = BulkApp()
+
+    def transition(issue_key):
+        if issue_key == "TEST-2":
+            raise ValueError("no 'Done' transition")
+
+    run_bulk(app, ["TEST-1", "TEST-2", "TEST-3"], transition)
+
+    message = app.notify.call_args.args[0]
+    assert "✅ 2 succeeded" in message
+    assert "❌ 1 failed" in message
+    assert "TEST-2: no 'Done' transition" in message
+    assert app.notify.call_args.kwargs["severity"] == "warning"
+    app.set_marked_issues.assert_called_once_with({"TEST-2"})
+    app.action_reload.assert_called_once_with()
+    assert app.sub_title == ""
+
+
+def test_bulk_transition_matches_transition_name():
+    """The bulk transition looks the transition up by name on every issue."""
+    app = BulkApp()
+    jira = app.jayrah_obj.jira
+    jira.get_transitions.return_value = {
+        "transitions": [{"id": "21", "name": "Start Progress"}]
+    }
+
+    IssueBrowserApp.bulk_transition(app, "TEST-1", "start progress")  # type: ignore[arg-type]
+
+    jira.transition_issue.assert_called_once_with("TEST-1", "21")
+
+
+def test_bulk_add_label_keeps_existing_labels():
+    """The bulk label action appends to the labels already on the issue."""
+    app = BulkApp()
+    jira = app.jayrah_obj.jira
+    jira.get_issue.return_value = {"fields": {"labels": ["backend"]}}
+
+    IssueBrowserApp.bulk_add_label(app, "TEST-1", "urgent")  # type: ignore[arg-type]
+
+    jira.update_issue.assert_called_once_with(
+        "TEST-1", {"labels": ["backend", "urgent"]}
+    )
+
+
+class FetchApp(DummyApp):
+    """App stub for the offline aware issue fetching."""
+
+    def __init__(self, config: dict):
+        super().__init__(auto_choose=False)
+        self.config = config
+        self.jayrah_obj = MagicMock()
+        self.offline_saved_at = None
+        self.board_overrides: dict = {}
+
+
+def test_fetch_issues_saves_online_results():
+    """Issues fetched online are saved for offline use."""
+    app = FetchApp({})
+    app.jayrah_obj.issues_client.iter_issue_pages.return_value = iter(
+        [([{"key": "TEST-1"}], 1)]
+    )
+
+    issues = IssueBrowserApp.fetch_issues(app, "jql", "updated")  # type: ignore[arg-type]
+
+    assert issues == [{"key": "TEST-1"}]
+    app.jayrah_obj.offline_store.save_issues.assert_called_once_with(
+        "jql", "updated", [{"key": "TEST-1"}]
+    )
+
+
+def test_fetch_issues_reports_pages_until_the_last():
+    """Every page but the last one is reported with the issues loaded so far."""
+    app = FetchApp({})
+    app.jayrah_obj.issues_client.iter_issue_pages.return_value = iter(
+        [([{"key": "A"}], 3), ([{"key": "B"}], 3), ([{"key": "C"}], 3)]
+    )
+    on_page = MagicMock()
+
+    issues = IssueBrowserApp.fetch_issues(app, "jql", "updated", on_page=on_page)  # type: ignore[arg-type]
+
+    assert [issue["key"] for issue in issues] == ["A", "B", "C"]
+    assert on_page.call_args_list == [
+        call([{"key": "A"}], 3),
+        call([{"key": "A"}, {"key": "B"}], 3),
+    ]
+    app.jayrah_obj.offline_store.save_issues.assert_called_once_with(
+        "jql", "updated", issues
+    )
+
+
+def test_fetch_issues_offline_reads_saved_results():
+    """Offline the saved issues are returned without asking Jira."""
+    app = FetchApp({"offline": True})
+    app.jayrah_obj.offline_store.load_issues.return_value = ([{"key": "A"}], 42.0)
+
+    issues = IssueBrowserApp.fetch_issues(app, "jql", "updated")  # type: ignore[arg-type]
+
+    assert issues == [{"key": "A"}]
+    assert app.offline_saved_at == 42.0
+    app.jayrah_obj.issues_client.iter_issue_pages.assert_not_called()
+
+
+class PagingApp(BulkApp):
+    """App stub with a partially loaded issue list."""
+
+    def __init__(self, pages, row_count=20):
+        super().__init__()
+        self.issues = [make_issue(f"TEST-{i}") for i in range(row_count)]
+        self.issue_pages = pages
+        self.issues_total = 30
+        self.loading_more = False
+        self.issues_generation = 0
+        self.filter_text = ""
+        self.sort_column, self.sort_reverse = None, False
+        self.group_by = None
+        self.spinner = ""
+        self.jql, self.order_by = "jql", "updated"
+        self.tabs = TabSet(self)
+        self.table = MagicMock(row_count=row_count)
+        self.query_one = lambda *args: self.table
+        self.apply_fuzzy_filter = MagicMock()
+        for name in ("_update_issues_title", "_tabs_title", "load_more_issues"):
+            method = getattr(IssueBrowserApp, name)
+            setattr(self, name, lambda *args, method=method: method(self, *args))
+
+
+def load_more(app, cursor_row):
+    """Move the cursor and wait for a next page request to finish."""
+    futures = []
+    submit = app.worker_pool.submit
+    app.worker_pool.submit = lambda *args, **kwargs: futures.append(
+        submit(*args, **kwargs)
+    )
+    app.load_more_issues(cursor_row)
+    app.worker_pool.submit = submit
+    for future in futures:
+        future.result()
+    return futures
+
+
+def test_load_more_issues_waits_for_the_end_of_the_list():
+    """The next page is only requested close to the last loaded row."""
+    app = PagingApp(iter([([make_issue("TEST-20")], 30)]))
+
+    assert not load_more(app, 5)
+    assert len(app.issues) == 20
+
+    assert load_more(app, 15)
+    assert app.issues[-1]["key"] == "TEST-20"
+    assert app.issue_pages is not None
+    assert app.table.border_title == "Issues (21/30)"
+    app.apply_fuzzy_filter.assert_called_once_with("", notify=False)
+
+
+def test_load_more_issues_stops_after_the_last_page():
+    """Once every issue is loaded no more pages are requested."""
+    app = PagingApp(iter([([make_issue(f"TEST-{i}") for i in range(20, 30)], 30)]))
+
+    load_more(app, 19)
+
+    assert len(app.issues) == 30
+    assert app.issue_pages is None
+    assert app.table.border_title == "Issues (30)"
+    assert not load_more(app, 29)
+    app.jayrah_obj.offline_store.save_issues.assert_called_once_with(
+        "jql", "updated", app.issues
+    )
+
+
+def test_sort_issues_by_column():
+    """Loaded issues sort by key number, priority id and assignee, None last."""
+    issues = [
+        make_issue("TEST-10", priority={"id": "3"}, assignee=None),
+        make_issue("TEST-9", priority={"id": "1"}, assignee={"key": "bob"}),
+        make_issue("TEST-2", priority={"id": "2"}, assignee={"key": "alice"}),
+    ]
+
+    def keys(column, reverse=False):
+        return [x["key"] for x in sort_issues(issues, column, reverse)]
+
+    assert keys("key") == ["TEST-2", "TEST-9", "TEST-10"]
+    assert keys("priority") == ["TEST-9", "TEST-2", "TEST-10"]
+    assert keys("assignee") == ["TEST-2", "TEST-9", "TEST-10"]
+    assert keys(None) == ["TEST-10", "TEST-9", "TEST-2"]
+    assert keys("key", reverse=True) == ["TEST-10", "TEST-9", "TEST-2"]
+
+
+def test_cycle_sort_goes_back_to_the_server_order():
+    """z moves through the sort columns then back to no sort."""
+    app = PagingApp(None)
+    app._apply_sort = lambda: IssueBrowserApp._apply_sort(app)  # type: ignore
+
+    IssueBrowserApp.action_cycle_sort(app)  # type: ignore[arg-type]
+    assert app.sort_column == "key"
+    assert app.table.border_title == "Issues (20) by key ↑"
+
+    app.sort_column = "assignee"
+    IssueBrowserApp.action_cycle_sort(app)  # type: ignore[arg-type]
+    assert app.sort_column is None
+    assert app.table.border_title == "Issues (20)"
+    app.table.clear.assert_called()
+
+
+def test_auto_reload_highlights_new_and_moved_issues():
+    """Issues new to the list or with another status are highlighted."""
+    app = PagingApp(None, row_count=2)
+    app.config, app.issues_loading = {}, False
+    app.updated_issues = set()
+    for issue in app.issues:
+        issue["fields"]["status"] = {"name": "New"}
+    new_issues = [
+        make_issue("TEST-0", status={"name": "New"}),
+        make_issue("TEST-1", status={"name": "Done"}),
+        make_issue("TEST-5", status={"name": "New"}),
+    ]
+    app.jayrah_obj.offline_store = MagicMock()
+    app.fetch_issues = MagicMock(return_value=new_issues)
+    app._show_changed_issues = partial(IssueBrowserApp._show_changed_issues, app)
+
+    futures = []
+    submit = app.worker_pool.submit
+    app.worker_pool.submit = lambda *args, **kwargs: futures.append(
+        submit(*args, **kwargs)
+    )
+    IssueBrowserApp.auto_reload_issues(app)  # type: ignore[arg-type]
+    futures[0].result()
+
+    assert app.updated_issues == {"TEST-1", "TEST-5"}
+    assert app.issues == new_issues
+    app.notify.assert_called_once_with("🔔 2 issues updated since last view")
+
+
+def test_run_query_keeps_history_most_recent_last():
+    """Queries run again move to the end of the prompt history."""
+    app = BulkApp()
+    app.query_history = ["project = A", "project = B"]
+    app._submit_issues_request = MagicMock()
+
+    IssueBrowserApp.run_query(app, "project = A")  # type: ignore[arg-type]
+
+    assert app.query_history == ["project = B", "project = A"]
+    app._submit_issues_request.assert_called_once()
+
+
+def test_save_board_writes_config_and_updates_boards(tmp_path):
+    """The current query is saved to the config file and offered as a board."""
+    app = BulkApp()
+    app.config = {"config_file": tmp_path / "config.yaml", "boards": []}
+    app.command, app.jql, app.order_by = "query", "project = TEST", "updated"
+
+    IssueBrowserApp.save_board(app, "test")  # type: ignore[arg-type]
+
+    board = {"name": "test", "jql": "project = TEST", "order_by": "updated"}
+    assert app.config["boards"] == [board]
+    assert app.command == "test"
+    assert "test" in (tmp_path / "config.yaml").read_text()
+
+
+def test_save_board_with_another_query(tmp_path):
+    """A query given to save_board is saved instead of the current one."""
+    app = BulkApp()
+    app.config = {"config_file": tmp_path / "config.yaml", "boards": []}
+    app.command, app.jql, app.order_by = "query", "project = TEST", "updated"
+
+    IssueBrowserApp.save_board(app, "one", "project = ONE")  # type: ignore
+
+    assert app.config["boards"][0]["jql"] == "project = ONE"
+
+
+def test_export_issues_writes_filtered_issues(tmp_path):
+    """Only the issues left by the fuzzy filter are exported."""
+    app = BulkApp()
+    app.config = {"export_columns": ["key", "summary"]}
+    app.issues = [
+        {"key": key, "fields": {"summary": summary, "status": {"name": "New"}}}
+        for key, summary in (("TEST-1", "Fix login"), ("TEST-2", "Add docs"))
+    ]
+    app.filter_text = "login"
+    path = tmp_path / "report.csv"
+
+    IssueBrowserApp.export_issues(app, str(path))  # type: ignore[arg-type]
+
+    assert path.read_text().splitlines() == ["key,summary", "TEST-1,Fix login"]
+    app.notify.assert_called_once_with(f"📤 Exported 1 issues to {path}")
+
+
+def test_choose_multi_returns_the_marked_issues_in_list_order():
+    """Enter in choose multi mode exits with the marked issues."""
+    app = BulkApp()
+    app.auto_choose = True
+    app.choose_multi = True
+    app.exit = MagicMock()
+    app.issues = [make_issue(f"TEST-{x}") for x in range(3)]
+    app.marked_issues = {"TEST-2", "TEST-0"}
+    app.selected_issue = "TEST-1"
+
+    IssueBrowserApp.action_confirm_selection(app)  # type: ignore[arg-type]
+    app.exit.assert_called_once_with(["TEST-0", "TEST-2"])
+
+    app.exit.reset_mock()
+    app.marked_issues = set()
+    IssueBrowserApp.action_confirm_selection(app)  # type: ignore[arg-type]
+    app.exit.assert_called_once_with(["TEST-1"])
+
+
+@patch("webbrowser.open")
+def test_open_visible_issues_asks_first(mock_open):
+    """O opens the filtered issues, capped, once pressed a second time."""
+    app = BulkApp()
+    app.config = {"jira_server": "https://jira.example.com"}
+    app.issues = [make_issue(f"TEST-{x}") for x in range(30)]
+    app.filter_text = ""
+    app.pending_open = None
+
+    IssueBrowserApp.action_open_visible_issues(app)  # type: ignore[arg-type]
+
+    mock_open.assert_not_called()
+    app.notify.assert_called_once_with(
+        "Open the first 20 of 30 issues in the browser? Press O again to confirm"
+    )
+
+    IssueBrowserApp.action_open_visible_issues(app)  # type: ignore[arg-type]
+
+    assert mock_open.call_count == 20
+    mock_open.assert_called_with("https://jira.example.com/browse/TEST-19")
+    assert app.pending_open is None
+
+
+@patch("webbrowser.open")
+def test_open_search_adds_the_order(mock_open):
+    """The query of the list opens as a Jira search with its order."""
+    app = BulkApp()
+    app.config = {"jira_server": "https://jira.example.com"}
+    app.jql = "project = TEST"
+    app.order_by = "updated"
+
+    IssueBrowserApp.action_open_search(app)  # type: ignore[arg-type]
+
+    mock_open.assert_called_once_with(
+        "https://jira.example.com/issues/"
+        "?jql=project%20%3D%20TEST%20ORDER%20BY%20updated"
+    )
+
+
+@patch("jayrah.ui.tui.actions.clipboard.copy_to_clipboard", return_value=False)
+def test_copy_branch_falls_back_to_osc52(_mock_copy):
+    """Without a clipboard tool the text goes through the terminal."""
+    app = BulkApp()
+    app.copy_to_clipboard = MagicMock()
+    app.selected_issue = "TEST-1"
+    app.issues = [make_issue("TEST-1", "Fix the login page")]
+    app.copy_text = partial(IssueBrowserApp.copy_text, app)
+    app._with_selected_summary = partial(IssueBrowserApp._with_selected_summary, app)
+
+    IssueBrowserApp.action_copy_branch(app)  # type: ignore[arg-type]
+
+    app.copy_to_clipboard.assert_called_once_with("TEST-1-fix-the-login-page")
+    app.notify.assert_called_once_with("📋 Copied branch: TEST-1-fix-the-login-page")
+
+
+def test_toggle_watch_stops_watching_and_refreshes_details():
+    """Watching an issue already watched removes the watcher."""
+    app = BulkApp()
+    app.selected_issue = "TEST-1"
+    app.config = {}
+    jira = app.jayrah_obj.jira
+    jira.get_watchers.return_value = {"isWatching": True, "watchCount": 2}
+    detail_panel = MagicMock(ticket="TEST-1")
+    app.query_one = MagicMock(return_value=detail_panel)
+    app._submit_issue_toggle = partial(IssueBrowserApp._submit_issue_toggle, app)
+
+    IssueBrowserApp.action_toggle_watch(app)  # type: ignore[arg-type]
+
+    jira.remove_watcher.assert_called_once_with("TEST-1")
+    jira.add_watcher.assert_not_called()
+    app.notify.assert_called_once_with("🙈 Stopped watching TEST-1")
+    detail_panel.update_issue.assert_called_once_with("TEST-1", {}, use_cache=False)
+
+
+def test_undo_asks_first_then_reverts_the_latest_change():
+    """The first press names the change, the second sends the old values."""
+    app = BulkApp()
+    app.config = {}
+    app.undo_stack = UndoStack()
+    app.undo_pending = None
+    app.undo_stack.push(Change("TEST-1", "labels of TEST-1", {"labels": ["old"]}))
+    app.query_one = MagicMock(return_value=MagicMock(ticket="TEST-2"))
+    futures = []
+    submit = app.worker_pool.submit
+    app.worker_pool.submit = lambda *args, **kwargs: futures.append(
+        submit(*args, **kwargs)
+    )
+
+    IssueBrowserApp.action_undo(app)  # type: ignore[arg-type]
+    assert not futures
+    app.notify.assert_called_with("Undo the labels of TEST-1? Press Ctrl+Z again")
+
+    IssueBrowserApp.action_undo(app)  # type: ignore[arg-type]
+    futures[0].result()
+    app.jayrah_obj.jira.update_issue.assert_called_once_with(
+        "TEST-1", {"labels": ["old"]}
+    )
+    app.notify.assert_called_with("↩️ Reverted the labels of TEST-1")
+    assert len(app.undo_stack) == 0
+
+    IssueBrowserApp.action_undo(app)  # type: ignore[arg-type]
+    app.notify.assert_called_with("Nothing to undo", severity="warning")
+
+
+def test_view_messages_lists_the_notifications_newest_first():
+    """The messages popup gets every notification, even the expired ones."""
+    app = BulkApp()
+    app.messages = MessageLog()
+    app.push_screen = MagicMock()
+
+    IssueBrowserApp.action_view_messages(app)  # type: ignore[arg-type]
+    app.notify.assert_called_once_with("No messages yet", severity="warning")
+
+    app.messages.add("Loading issues...")
+    app.messages.add("Error updating labels", "error")
+    IssueBrowserApp.action_view_messages(app)  # type: ignore[arg-type]
+
+    screen = app.push_screen.call_args.args[0]
+    assert [x.text for x in screen.messages] == [
+        "Error updating labels",
+        "Loading issues...",
+    ]
+
+
+def test_spinner_turns_in_the_titles_while_requests_run():
+    """The pane titles show the spinner until the requests are done."""
+    app = PagingApp(pages=None)
+    app.issue_pages = None
+    app.spinner_tick = 0
+    detail_panel = MagicMock(ticket="TEST-1", loading=True)
+    bottom_panel = MagicMock()
+    panels = {"#bottom-panel": bottom_panel, "#issues-table": app.table}
+    app.query_one = lambda selector, *args: panels.get(selector, detail_panel)
+    app.update_pane_titles = partial(IssueBrowserApp.update_pane_titles, app)
+    app.worker_pool = MagicMock(pending=1)
+
+    IssueBrowserApp.tick_spinner(app)  # type: ignore[arg-type]
+    assert app.table.border_title == "Issues (20) ⠙"
+    assert bottom_panel.border_title == "⠙ Loading TEST-1"
+
+    app.worker_pool.pending = 0
+    IssueBrowserApp.tick_spinner(app)  # type: ignore[arg-type]
+    assert app.table.border_title == "Issues (20)"
+    assert bottom_panel.border_title == ""
+
+
+def test_visible_columns_drop_the_low_priority_ones_first():
+    """The board order is kept, only the columns that do not fit go."""
+    columns = ["updated", "summary", "reporter", "status"]
+
+    assert visible_columns(columns, None) == columns
+    assert visible_columns(columns, 200) == columns
+    assert visible_columns(columns, 75) == ["updated", "summary", "status"]
+    assert visible_columns(columns, 60) == ["summary"]
+
+
+def test_follow_link_offers_the_links_of_the_details():
+    """F lists the links of the issue shown, clicked links use the browser option."""
+    app = BulkApp()
+    app.push_screen = MagicMock()
+    detail_panel = MagicMock(ticket="TEST-1")
+    detail_panel.ticket_cache = {
+        "TEST-1": "# [TEST-1](https://jira/browse/TEST-1): Fix\n\nSee <https://docs>"
+    }
+    app.query_one = MagicMock(return_value=detail_panel)
+
+    IssueBrowserApp.action_follow_link(app)  # type: ignore[arg-type]
+
+    screen = app.push_screen.call_args.args[0]
+    assert screen.links == [
+        ("TEST-1", "https://jira/browse/TEST-1"),
+        ("https://docs", "https://docs"),
+    ]
+
+    detail_panel.ticket_cache = {}
+    IssueBrowserApp.action_follow_link(app)  # type: ignore[arg-type]
+    app.notify.assert_called_with("No links in the issue details", severity="warning")
+
+    app.config = {"browser": "firefox"}
+    with patch("jayrah.ui.tui.app.utils.browser_open") as browser_open:
+        IssueBrowserApp.open_url(app, "https://docs")  # type: ignore[arg-type]
+    browser_open.assert_called_once_with("https://docs", {"browser": "firefox"})
+
+    with patch("jayrah.ui.tui.app.utils.browser_open") as browser_open:
+        browser_open.side_effect = FileNotFoundError("No such file: 'firefox'")
+        IssueBrowserApp.open_url(app, "https://docs")  # type: ignore[arg-type]
+    app.notify.assert_called_with(
+        "Error opening https://docs: No such file: 'firefox'", severity="error"
+    )
+
+
+def test_jump_to_reference_keeps_a_stack_to_come_back():
+    """Ctrl+G shows the referenced issue, Ctrl+T returns to the previous one."""
+    app = BulkApp()
+    app.selected_issue = "TEST-1"
+    app.reference_stack = deque()
+    app.push_screen = MagicMock()
+    app.jump_to_issue = MagicMock()
+    app.jump_to_reference = partial(IssueBrowserApp.jump_to_reference, app)
+    detail_panel = MagicMock(ticket="TEST-1")
+    detail_panel.ticket_cache = {"TEST-1": "# TEST-1: Fix\n\nBroken by OTHER-7"}
+    app.query_one = MagicMock(return_value=detail_panel)
+
+    IssueBrowserApp.action_jump_to_reference(app)  # type: ignore[arg-type]
+    app.jump_to_issue.assert_called_once_with("OTHER-7")
+    assert list(app.reference_stack) == ["TEST-1"]
+
+    detail_panel.ticket_cache["TEST-1"] += " and OTHER-8"
+    IssueBrowserApp.action_jump_to_reference(app)  # type: ignore[arg-type]
+    assert app.push_screen.call_args.args[0].links == [
+        ("OTHER-7", "OTHER-7"),
+        ("OTHER-8", "OTHER-8"),
+    ]
+
+    IssueBrowserApp.action_reference_back(app)  # type: ignore[arg-type]
+    app.jump_to_issue.assert_called_with("TEST-1")
+    IssueBrowserApp.action_reference_back(app)  # type: ignore[arg-type]
+    app.notify.assert_called_with("No issue to go back to", severity="warning")
+
+
+def test_switching_tabs_shows_the_issues_of_the_tab():
+    """The table is rebuilt for the tab, a tab left while loading reloads."""
+    app = BulkApp()
+    app.command, app.jql = "myissues", "assignee = currentUser()"
+    app.issues = [make_issue("TEST-1")]
+    app.selected_issue, app.config = "TEST-1", {}
+    app.issues_generation = 0
+    app.start_auto_reload = MagicMock()
+    app.relayout_table = MagicMock()
+    app._update_issues_title = MagicMock()
+    app.action_reload = MagicMock()
+    app.query_one = MagicMock()
+    app.tabs = TabSet(app)
+    app.show_tab = partial(IssueBrowserApp.show_tab, app)
+
+    IssueBrowserApp.action_new_tab(app)  # type: ignore[arg-type]
+    app.notify.assert_called_with("Opened tab 2, press b or / to pick its issues")
+    app.issues = []
+
+    IssueBrowserApp.action_switch_tab(app, 0)  # type: ignore[arg-type]
+    assert [x["key"] for x in app.issues] == ["TEST-1"]
+    assert app.issues_generation == 2
+    app.relayout_table.assert_called()
+    app.action_reload.assert_not_called()
+
+    IssueBrowserApp.action_switch_tab(app, 1)  # type: ignore[arg-type]
+    app.action_reload.assert_called_once()
+
+
+def test_startup_layout_sets_the_classes_and_theme():
+    """The horizontal layout and zoom are app classes, unknown themes warn."""
+    app = MagicMock(
+        config={"layout": "horizontal", "zoom": "detail", "theme": "nord"},
+        available_themes={"nord": None, "textual-dark": None},
+    )
+
+    IssueBrowserApp.apply_startup_layout(app)  # type: ignore[arg-type]
+
+    assert app.set_class.call_args_list == [
+        call(True, "horizontal"),
+        call(False, "zoom-issues"),
+        call(True, "zoom-detail"),
+    ]
+    assert app.theme == "nord"
+
+    app.config["theme"] = "pink"
+    IssueBrowserApp.apply_startup_layout(app)  # type: ignore[arg-type]
+    app.notify.assert_called_once_with(
+        "Unknown theme pink, the themes are nord, textual-dark", severity="warning"
+    )
+
+
+def test_horizontal_layout_fits_the_columns_in_the_issues_pane():
+    """Beside the details the issues get part of the width only."""
+    app = GroupingApp({"layout": "horizontal"})
+    app.board_overrides = {"columns": list(defaults.ISSUE_COLUMNS)}
+    app.terminal_width = 200
+
+    assert len(IssueBrowserApp._shown_columns(app)) < len(defaults.ISSUE_COLUMNS)  # type: ignore[arg-type]
+
+    app.config["zoom"] = "issues"
+    assert IssueBrowserApp._shown_columns(app) == list(defaults.ISSUE_COLUMNS)  # type: ignore[arg-type]
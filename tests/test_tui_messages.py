@@ -0,0 +1,24 @@
+"""Tests for the notifications kept by the issue browser."""
+
+from jayrah.config import defaults
+from jayrah.ui.tui.messages import MessageLog, notify_timeout
+
+
+def test_message_log_keeps_the_latest_newest_first():
+    """Once full the oldest notifications are dropped."""
+    log = MessageLog(size=2)
+    log.add("Loading issues...")
+    log.add("Could not fetch TEST-1", "error")
+    log.add("Done", "information")
+
+    assert [(x.text, x.severity) for x in log.recent()] == [
+        ("Done", "information"),
+        ("Could not fetch TEST-1", "error"),
+    ]
+    assert len(log) == 2
+
+
+def test_errors_stay_longer_than_information():
+    assert notify_timeout("error") > notify_timeout("warning")
+    assert notify_timeout("warning") > notify_timeout("information")
+    assert notify_timeout("unknown") == defaults.NOTIFY_TIMEOUTS["information"]
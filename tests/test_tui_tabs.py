@@ -0,0 +1,59 @@
+"""Tests for the tabs of the issue browser."""
+
+from types import SimpleNamespace
+
+from jayrah.ui.tui.tabs import TabSet, tab_title
+
+
+def make_app(**state):
+    """An app with the state of a board browsed."""
+    app = SimpleNamespace(
+        command="myissues",
+        jql="assignee = currentUser()",
+        issues=[{"key": "TEST-1"}],
+        filter_text="",
+        marked_issues=set(),
+    )
+    for name, value in state.items():
+        setattr(app, name, value)
+    return app
+
+
+def test_tabs_keep_their_own_query_and_view():
+    """A tab comes back with its issues, filter and marks as left."""
+    app = make_app()
+    tabs = TabSet(app)
+
+    assert tabs.open(app) == 1
+    app.command, app.jql = "query", "project = TEAM AND status = Triage"
+    app.issues = [{"key": "TEAM-1"}, {"key": "TEAM-2"}]
+    app.marked_issues.add("TEAM-1")
+
+    assert tabs.switch(app, 0)
+    assert (app.command, app.issues, app.marked_issues) == (
+        "myissues",
+        [{"key": "TEST-1"}],
+        set(),
+    )
+    app.filter_text = "login"
+    assert tabs.titles(app) == ["myissues", "project = TEAM AND …"]
+
+    assert tabs.switch(app, 1)
+    assert (app.jql, app.marked_issues) == (
+        "project = TEAM AND status = Triage",
+        {"TEAM-1"},
+    )
+    assert not tabs.switch(app, 1)
+    assert not tabs.switch(app, 5)
+
+
+def test_closing_a_tab_shows_its_neighbour():
+    app = make_app()
+    tabs = TabSet(app)
+    tabs.open(app)
+    app.command = "triage"
+
+    assert tabs.close(app)
+    assert (len(tabs), tabs.active, app.command) == (1, 0, "myissues")
+    assert not tabs.close(app)
+    assert tab_title("query", None) == "query"
@@ -0,0 +1,54 @@
+"""Tests for the changes the issue browser can undo."""
+
+from unittest.mock import MagicMock
+
+import pytest
+
+from jayrah.ui.tui.undo import Change, UndoStack, revert
+
+
+def test_undo_stack_keeps_the_latest_changes():
+    """Changes come back most recent first, the oldest are forgotten."""
+    stack = UndoStack(size=2)
+    for key in ("TEST-1", "TEST-2", "TEST-3"):
+        stack.push(Change(key, f"title of {key}", {"summary": key}))
+
+    assert [x.issue_key for x in stack.changes] == ["TEST-2", "TEST-3"]
+    assert stack.last() is stack.pop()
+    assert stack.pop().issue_key == "TEST-2"  # type: ignore[union-attr]
+    assert stack.pop() is None
+
+
+def test_revert_sends_the_previous_field_values():
+    jira = MagicMock()
+
+    revert(jira, Change("TEST-1", "labels of TEST-1", {"labels": ["a", "b"]}))
+
+    jira.update_issue.assert_called_once_with("TEST-1", {"labels": ["a", "b"]})
+
+
+def test_revert_transitions_back_to_the_previous_status():
+    """The transition leading to the old status is looked up fresh."""
+    jira = MagicMock()
+    jira.get_transitions.return_value = {
+        "transitions": [
+            {"id": "21", "to": {"name": "Done"}},
+            {"id": "11", "to": {"name": "To Do"}},
+        ]
+    }
+
+    revert(jira, Change("TEST-1", "transition of TEST-1", status="To Do"))
+
+    jira.get_transitions.assert_called_once_with("TEST-1", use_cache=False)
+    jira.transition_issue.assert_called_once_with("TEST-1", "11")
+
+
+def test_revert_fails_without_a_transition_back():
+    jira = MagicMock()
+    jira.get_transitions.return_value = {
+        "transitions": [{"id": "21", "to": {"name": "Done"}}]
+    }
+
+    with pytest.raises(ValueError, match="goes back to 'Review'"):
+        revert(jira, Change("TEST-1", "transition of TEST-1", status="Review"))
+    jira.transition_issue.assert_not_called()
@@ -0,0 +1,880 @@
+"""Tests for Textual issue browser views."""
+
+import asyncio
+from functools import partial
+from types import SimpleNamespace
+from unittest.mock import MagicMock, call, patch
+
+from jayrah.api import exceptions as jira_exceptions
+from jayrah.api import models
+from jayrah.api.formatters import create_formatter
+from jayrah.ui.tui.views import (
+    ActivityScreen,
+    AddCommentScreen,
+    AttachmentsScreen,
+    CloneIssueScreen,
+    ComponentsEditScreen,
+    CreateIssueScreen,
+    FixVersionsEditScreen,
+    IssueDetailPanel,
+    JqlQueryScreen,
+    KanbanScreen,
+    LabelsEditScreen,
+    LabelSuggester,
+    MultiSelectScreen,
+    PriorityEditScreen,
+    ProjectsScreen,
+    SingleSelectScreen,
+    apply_transition,
+    comment_body,
+)
+from jayrah.ui.tui.helpers import kanban_column, kanban_columns, label_completion
+from jayrah.ui.tui.workers import JiraWorkerPool
+from jayrah.utils import mentions
+
+
+class InlinePool:
+    """Worker pool stand-in running the requests as soon as they are submitted."""
+
+    def submit(self, func, *args, on_result=None, on_error=None, is_stale=None, **kw):
+        if is_stale is not None and is_stale():
+            return
+        try:
+            result = func(*args, **kw)
+        except Exception as exc:  # pylint: disable=broad-exception-caught
+            if on_error is None:
+                raise
+            on_error(exc)
+            return
+        if on_result is not None:
+            on_result(result)
+
+
+def make_parent(**kwargs):
+    """A mocked browser app running the Jira requests inline."""
+    return MagicMock(worker_pool=InlinePool(), **kwargs)
+
+
+def make_panel(jira, ticket=None, config=None):
+    """Build a minimal detail panel stand-in around a mocked Jira client."""
+    panel = SimpleNamespace(
+        jayrah_obj=SimpleNamespace(
+            jira=jira, offline_store=MagicMock(), state_store=MagicMock()
+        ),
+        config=config or {},
+        ticket=ticket,
+        ticket_cache={},
+        issue_data_cache={},
+        transitions_cache={},
+        changelog_cache={},
+        query_one=MagicMock(),
+        app=SimpleNamespace(
+            worker_pool=JiraWorkerPool(lambda callback, *args: callback(*args)),
+            log=MagicMock(),
+        ),
+        log=lambda *args, **kwargs: None,
+        _update_markdown=MagicMock(),
+    )
+    for name in (
+        "_is_stale",
+        "_fetch_issue",
+        "_build_content",
+        "_on_changelog_loaded",
+        "_on_issue_loaded",
+        "_on_issue_error",
+        "_record_view",
+    ):
+        method = getattr(IssueDetailPanel, name)
+        setattr(panel, name, lambda *args, method=method: method(panel, *args))
+    return panel
+
+
+def prefetch(panel, ticket, use_cache=True):
+    """Run the detail prefetch and wait for all its requests to finish."""
+    futures = IssueDetailPanel._prefetch_issue(panel, ticket, panel.config, use_cache)  # type: ignore[arg-type]
+    for future in futures:
+        future.result()
+
+
+@patch("jayrah.ui.tui.views.issue_view.build_issue")
+def test_prefetch_issue_caches_issue_and_transitions(mock_build):
+    """Selecting an issue fetches details and transitions in one go."""
+    mock_build.return_value = ("header", "body")
+    jira = MagicMock()
+    jira.get_issue.return_value = {"key": "TEST-1", "fields": {}}
+    jira.get_transitions.return_value = {"transitions": [{"id": "11"}]}
+    panel = make_panel(jira, ticket="TEST-1")
+
+    prefetch(panel, "TEST-1", use_cache=False)
+
+    jira.get_issue.assert_called_once_with("TEST-1", fields=None, use_cache=False)
+    jira.get_transitions.assert_called_once_with("TEST-1", use_cache=False)
+    assert panel.issue_data_cache["TEST-1"] == {"key": "TEST-1", "fields": {}}
+    assert panel.transitions_cache["TEST-1"] == {"transitions": [{"id": "11"}]}
+    assert panel.ticket_cache["TEST-1"] == "header\nbody"
+    panel._update_markdown.assert_called_once()
+    panel.jayrah_obj.offline_store.save_issue.assert_called_once_with(
+        {"key": "TEST-1", "fields": {}}
+    )
+    panel.jayrah_obj.state_store.record_view.assert_called_once_with("TEST-1", "")
+
+
+@patch("jayrah.ui.tui.views.issue_view.build_issue")
+def test_prefetch_issue_adds_the_time_in_status(mock_build):
+    """The changelog fetched along the issue adds the time in each status."""
+    mock_build.return_value = ("header", "body")
+    jira = MagicMock()
+    jira.get_issue.return_value = {
+        "key": "TEST-1",
+        "fields": {
+            "created": "2026-10-01T09:00:00.000+0000",
+            "status": {"name": "Done"},
+        },
+    }
+    jira.get_issue_changelog.return_value = [
+        {
+            "created": "2026-10-02T09:00:00.000+0000",
+            "items": [{"field": "status", "fromString": "New", "toString": "Done"}],
+        }
+    ]
+    panel = make_panel(jira, ticket="TEST-1")
+
+    prefetch(panel, "TEST-1", use_cache=False)
+
+    jira.get_issue_changelog.assert_called_once_with("TEST-1", False)
+    content = panel.ticket_cache["TEST-1"]
+    assert content.startswith("header\n\n## ⏱️ Time in status\n\n* New: 1d 0h\n")
+    assert content.endswith("body")
+
+
+@patch("jayrah.ui.tui.views.issue_view.build_issue")
+def test_prefetch_issue_offline_uses_saved_copy(mock_build):
+    """Offline the issue comes from the offline store with a stale warning."""
+    mock_build.return_value = ("header", "body")
+    jira = MagicMock()
+    panel = make_panel(jira, ticket="TEST-1", config={"offline": True})
+    panel.jayrah_obj.offline_store.load_issue.return_value = (
+        {"key": "TEST-1", "fields": {}},
+        1700000000.0,
+    )
+
+    prefetch(panel, "TEST-1")
+
+    jira.get_issue.assert_not_called()
+    jira.get_transitions.assert_not_called()
+    assert panel.ticket_cache["TEST-1"].startswith("> ⚠️ Offline")
+
+
+@patch("jayrah.ui.tui.views.issue_view.build_issue")
+def test_prefetch_issue_tolerates_transitions_failure(mock_build):
+    """A transitions error should not prevent the issue from being shown."""
+    mock_build.return_value = ("header", "body")
+    jira = MagicMock()
+    jira.get_issue.return_value = {"key": "TEST-1", "fields": {}}
+    jira.get_transitions.side_effect = jira_exceptions.JiraAPIError(
+        "boom", "issue/TEST-1/transitions", 500, ""
+    )
+    panel = make_panel(jira, ticket="TEST-1")
+    panel.transitions_cache["TEST-1"] = {"transitions": []}
+
+    prefetch(panel, "TEST-1")
+
+    assert panel.issue_data_cache["TEST-1"]["key"] == "TEST-1"
+    assert "TEST-1" not in panel.transitions_cache
+
+
+@patch("jayrah.ui.tui.views.issue_view.build_issue")
+def test_prefetch_issue_drops_stale_result(mock_build):
+    """A result for an issue that is no longer selected is not painted."""
+    mock_build.return_value = ("header", "body")
+    jira = MagicMock()
+    jira.get_transitions.return_value = {"transitions": []}
+    panel = make_panel(jira, ticket="TEST-1")
+
+    def get_issue(*args, **kwargs):
+        # The user moves to another row while the request is in flight
+        panel.ticket = "TEST-2"
+        return {"key": "TEST-1", "fields": {}}
+
+    jira.get_issue.side_effect = get_issue
+
+    prefetch(panel, "TEST-1")
+
+    panel._update_markdown.assert_not_called()
+    assert "TEST-1" in panel.ticket_cache
+
+
+def make_labels_screen(labels_input):
+    """Build a labels editor stand-in for TEST-1 with the input given."""
+    screen = SimpleNamespace(
+        issue_key="TEST-1",
+        current_labels=[],
+        query_one=MagicMock(return_value=MagicMock(value=labels_input)),
+        safe_pop_screen=MagicMock(),
+        _parent=make_parent(),
+    )
+    screen._labels_updated = partial(LabelsEditScreen._labels_updated, screen)
+    return screen
+
+
+def test_labels_are_updated_on_the_worker_pool():
+    """The labels typed are sent and the success notified."""
+    screen = make_labels_screen("bug, ui")
+
+    LabelsEditScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    screen._parent.jayrah_obj.jira.update_issue.assert_called_once_with(
+        "TEST-1", {"labels": ["bug", "ui"]}
+    )
+    screen._parent.notify.assert_called_once_with("✅ Labels updated: bug, ui")
+    screen.safe_pop_screen.assert_called_once()
+
+
+def test_labels_update_error_is_notified():
+    """A failed update is reported instead of claiming success."""
+    screen = make_labels_screen("bug")
+    screen._parent.jayrah_obj.jira.update_issue.side_effect = (
+        jira_exceptions.JiraAPIError("Server error", "issue/TEST-1", 500, "")
+    )
+
+    LabelsEditScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    message = screen._parent.notify.call_args.args[0]
+    assert message.startswith("Error updating labels: ")
+    assert screen._parent.notify.call_args.kwargs == {"severity": "error"}
+
+
+def test_prefetch_issue_skips_requests_when_already_stale():
+    """Requests picked up after the selection moved on do no network work."""
+    jira = MagicMock()
+    panel = make_panel(jira, ticket="TEST-2")
+
+    prefetch(panel, "TEST-1")
+
+    jira.get_issue.assert_not_called()
+    jira.get_transitions.assert_not_called()
+    panel._update_markdown.assert_not_called()
+
+
+def make_create_screen(values, issue_types=None, create_meta=None):
+    """Build a create issue form stand-in returning the given field values."""
+    screen = SimpleNamespace(
+        PROJECT_KEY_RE=CreateIssueScreen.PROJECT_KEY_RE,
+        SUMMARY_MAX_LENGTH=CreateIssueScreen.SUMMARY_MAX_LENGTH,
+        HELP_TEXT=CreateIssueScreen.HELP_TEXT,
+        FORM_FIELDS=CreateIssueScreen.FORM_FIELDS,
+        issue_types=issue_types or [],
+        create_meta=create_meta or {},
+        create_meta_for=("TEST", "Story") if create_meta else None,
+        create_meta_loading=None,
+        confirming=False,
+        query_one=MagicMock(),
+        safe_pop_screen=MagicMock(),
+        _popped=False,
+        _parent=make_parent(),
+        _get_values=lambda: values,
+    )
+    screen._parent.jayrah_obj.jira.get_create_meta.return_value = {}
+    for name in (
+        "action_apply",
+        "validate",
+        "_reset_confirmation",
+        "_load_create_meta",
+        "_create_meta_loaded",
+        "_create_meta_failed",
+        "_issue_created",
+        "_allowed_name",
+    ):
+        method = getattr(CreateIssueScreen, name)
+        setattr(screen, name, lambda *args, method=method: method(screen, *args))
+    return screen
+
+
+def create_values(**overrides):
+    """Valid create form values."""
+    return {
+        "project": "TEST",
+        "issuetype": "Story",
+        "summary": "A new story",
+        "description": "",
+        "labels": ["backend"],
+        "priority": "",
+        "components": [],
+        **overrides,
+    }
+
+
+def test_create_issue_validation():
+    """The form reports every invalid field."""
+    screen = make_create_screen({}, issue_types=["Story", "Bug"])
+
+    assert CreateIssueScreen.validate(screen, create_values()) == []  # type: ignore[arg-type]
+    errors = CreateIssueScreen.validate(
+        screen,  # type: ignore[arg-type]
+        create_values(
+            project="te st", issuetype="Epicc", summary="", labels=["two words"]
+        ),
+    )
+    assert errors == [
+        "Project should be a project key like PROJ",
+        "Unknown issue type: Epicc",
+        "Summary is required",
+        "Labels cannot contain spaces",
+    ]
+
+
+CREATE_META = {
+    "priority": {"name": "Priority", "allowedValues": [{"name": "Major"}]},
+    "components": {
+        "name": "Component/s",
+        "allowedValues": [{"name": "backend"}, {"name": "ui"}],
+    },
+    "customfield_10001": {"name": "Team", "required": True},
+    "summary": {"name": "Summary", "required": True},
+}
+
+
+def test_create_issue_validation_uses_create_meta():
+    """Priorities and components must be allowed, required fields known."""
+    screen = make_create_screen({}, create_meta=CREATE_META)
+
+    errors = CreateIssueScreen.validate(
+        screen,  # type: ignore[arg-type]
+        create_values(priority="Blocker", components=["UI", "db"]),
+    )
+
+    assert errors == [
+        "Unknown priority: Blocker",
+        "Unknown components: db",
+        "Team is required",
+    ]
+
+
+def test_create_issue_sends_allowed_names():
+    """Values typed in another case are sent with the name Jira knows."""
+    meta = {k: v for k, v in CREATE_META.items() if k != "customfield_10001"}
+    screen = make_create_screen(
+        create_values(priority="major", components=["UI"]), create_meta=meta
+    )
+    screen.confirming = True
+
+    CreateIssueScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    kwargs = screen._parent.jayrah_obj.jira.create_issue.call_args.kwargs
+    assert kwargs["priority"] == "Major"
+    assert kwargs["components"] == ["ui"]
+    screen._parent.jayrah_obj.jira.get_create_meta.assert_not_called()
+
+
+def test_create_issue_asks_for_confirmation_first():
+    """The first Ctrl+S only asks for confirmation, the second one creates."""
+    screen = make_create_screen(create_values())
+    jira = screen._parent.jayrah_obj.jira
+    jira.create_issue.return_value = {"key": "TEST-42"}
+
+    CreateIssueScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    assert screen.confirming
+    jira.create_issue.assert_not_called()
+
+    CreateIssueScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    jira.create_issue.assert_called_once_with(
+        issuetype="Story",
+        summary="A new story",
+        description=None,
+        priority=None,
+        labels=["backend"],
+        components=[],
+        extra_fields={"project": {"key": "TEST"}},
+    )
+    screen._parent.notify.assert_called_with("✅ Created TEST-42")
+    screen._parent.action_reload.assert_called_once_with()
+
+
+def test_create_issue_invalid_form_is_not_submitted():
+    """Validation errors are shown and nothing is sent to Jira."""
+    screen = make_create_screen(create_values(summary=""))
+
+    CreateIssueScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    assert not screen.confirming
+    screen._parent.jayrah_obj.jira.create_issue.assert_not_called()
+    assert screen._parent.notify.call_args.kwargs["severity"] == "error"
+
+
+def make_clone_screen(project, summary):
+    """Build a clone form stand-in for TEST-1 with the given inputs."""
+    widgets = {
+        "#clone-project": MagicMock(value=project),
+        "#clone-summary": MagicMock(value=summary),
+    }
+    screen = SimpleNamespace(
+        issue_key="TEST-1",
+        query_one=lambda selector, *args: widgets[selector],
+        safe_pop_screen=MagicMock(),
+        _parent=make_parent(),
+    )
+    screen._cloned = partial(CloneIssueScreen._cloned, screen)  # type: ignore
+    return screen
+
+
+def test_clone_issue_into_another_project():
+    """The copy is created in the project typed, with the summary typed."""
+    screen = make_clone_screen(" new ", "Copy of the story")
+    jira = screen._parent.jayrah_obj.jira
+    jira.clone_issue.return_value = {"key": "NEW-7"}
+
+    CloneIssueScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    jira.clone_issue.assert_called_once_with(
+        "TEST-1",
+        overrides={"project": {"key": "NEW"}, "summary": "Copy of the story"},
+    )
+    screen._parent.notify.assert_called_once_with("✅ Cloned TEST-1 to NEW-7")
+    screen._parent.action_reload.assert_called_once_with()
+
+
+def test_clone_issue_needs_a_project_key():
+    """An invalid project key is reported and nothing is cloned."""
+    screen = make_clone_screen("not a key", "Copy")
+
+    CloneIssueScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    screen._parent.jayrah_obj.jira.clone_issue.assert_not_called()
+    screen.safe_pop_screen.assert_not_called()
+
+
+def make_picker_screen(selected=None):
+    """Build a picker stand-in for TEST-1."""
+    screen = SimpleNamespace(
+        issue_key="TEST-1",
+        selected=list(selected or []),
+        safe_pop_screen=MagicMock(),
+        _parent=make_parent(),
+    )
+    for screen_class, name in (
+        (ComponentsEditScreen, "_components_updated"),
+        (FixVersionsEditScreen, "_update_fix_versions"),
+        (FixVersionsEditScreen, "_fix_versions_updated"),
+        (PriorityEditScreen, "_priority_updated"),
+    ):
+        method = getattr(screen_class, name)
+        setattr(screen, name, lambda *args, method=method: method(screen, *args))
+    return screen
+
+
+def test_components_picker_options_come_from_editmeta():
+    """The editmeta allowed values are offered before the project components."""
+    screen = make_picker_screen()
+    jira = screen._parent.jayrah_obj.jira
+    jira.get_edit_meta.return_value = {
+        "components": {"allowedValues": [{"name": "backend"}, {"name": "UI"}]}
+    }
+
+    assert ComponentsEditScreen.load_options(screen) == ["backend", "UI"]  # type: ignore[arg-type]
+    jira.list_project_components.assert_not_called()
+
+    jira.get_edit_meta.return_value = {}
+    jira.list_project_components.return_value = ["api"]
+
+    assert ComponentsEditScreen.load_options(screen) == ["api"]  # type: ignore[arg-type]
+    jira.list_project_components.assert_called_once_with("TEST")
+
+
+def test_picker_keeps_the_current_values_when_the_options_fail():
+    """A failed fetch is reported, the current values can still be unpicked."""
+    table = MagicMock()
+    screen = make_picker_screen(["UI"])
+    screen.OPTIONS_ERROR = ComponentsEditScreen.OPTIONS_ERROR
+    screen.query_one = lambda *args: table
+    screen._popped = False
+    for name in ("load_options", "show_options", "_options_failed", "_mark"):
+        method = getattr(ComponentsEditScreen, name)
+        setattr(screen, name, lambda *args, method=method: method(screen, *args))
+    screen._parent.jayrah_obj.jira.get_edit_meta.side_effect = (
+        jira_exceptions.JiraAPIError("Server error", "editmeta", 500, "")
+    )
+
+    ComponentsEditScreen.on_mount(screen)  # type: ignore[arg-type]
+
+    assert screen.options == ["UI"]
+    table.add_row.assert_called_once_with("[x]", "UI", key="UI")
+    assert table.loading is False
+    message = screen._parent.notify.call_args.args[0]
+    assert message.startswith("Could not fetch components: ")
+
+
+def test_components_picker_sends_the_selection():
+    """The picked components replace the ones of the issue."""
+    screen = make_picker_screen()
+    screen.current_components = ["UI"]
+
+    ComponentsEditScreen.apply_selection(screen, ["UI", "backend"])  # type: ignore[arg-type]
+
+    screen._parent.jayrah_obj.jira.update_issue.assert_called_once_with(
+        "TEST-1", {"components": [{"name": "UI"}, {"name": "backend"}]}
+    )
+    change = screen._parent.undo_stack.push.call_args.args[0]
+    assert change.fields == {"components": [{"name": "UI"}]}
+    screen.safe_pop_screen.assert_called_once_with()
+
+
+def test_fix_versions_picker_only_sends_the_changes():
+    """Unchanged versions are left alone, others are added or removed."""
+    screen = make_picker_screen()
+    screen.current_versions = ["1.0", "1.1"]
+    jira = screen._parent.jayrah_obj.jira
+
+    FixVersionsEditScreen.apply_selection(screen, ["1.1", "2.0"])  # type: ignore[arg-type]
+
+    assert jira.update_issue_fix_versions.call_args_list == [
+        call("TEST-1", ["2.0"], "add"),
+        call("TEST-1", ["1.0"], "remove"),
+    ]
+    screen._parent.notify.assert_called_with("✅ Fix versions updated: 1.1, 2.0")
+
+
+def test_priority_picker_falls_back_to_the_server_priorities():
+    """Issues without a priority in their editmeta get every priority."""
+    screen = make_picker_screen()
+    jira = screen._parent.jayrah_obj.jira
+    jira.get_edit_meta.return_value = {}
+    jira.list_priorities.return_value = ["Blocker", "Major"]
+
+    assert PriorityEditScreen.load_options(screen) == ["Blocker", "Major"]  # type: ignore[arg-type]
+
+    PriorityEditScreen.apply_selection(screen, ["Blocker"])  # type: ignore[arg-type]
+
+    jira.update_issue_priority.assert_called_once_with("TEST-1", "Blocker")
+    screen._parent.notify.assert_called_with("✅ Priority updated: Blocker")
+
+
+def test_single_select_applies_the_value_under_the_cursor():
+    """Enter picks the highlighted value whatever is marked."""
+    screen = SimpleNamespace(
+        options=["Blocker", "Major", "Minor"],
+        selected=["Major"],
+        query_one=lambda *args: MagicMock(cursor_row=2),
+        apply_selection=MagicMock(),
+    )
+
+    SingleSelectScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    screen.apply_selection.assert_called_once_with(["Minor"])
+
+
+def test_multi_select_toggles_the_value_under_the_cursor():
+    """Space toggles a value and the selection is applied in list order."""
+    table = MagicMock(cursor_row=2)
+    screen = SimpleNamespace(
+        options=["api", "backend", "ui"],
+        selected=["backend"],
+        query_one=lambda *args: table,
+        apply_selection=MagicMock(),
+    )
+    screen._mark = lambda option: MultiSelectScreen._mark(screen, option)  # type: ignore[arg-type]
+
+    MultiSelectScreen.action_toggle(screen)  # type: ignore[arg-type]
+    table.update_cell.assert_called_once_with("ui", "mark", "[x]")
+    table.cursor_row = 1
+    MultiSelectScreen.action_toggle(screen)  # type: ignore[arg-type]
+    MultiSelectScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    screen.apply_selection.assert_called_once_with(["ui"])
+
+
+def make_query_screen(query, history=None):
+    """Build a JQL query prompt stand-in with the given input."""
+    widgets = {"#query-text": MagicMock(value=query), "#query-help": MagicMock()}
+    screen = SimpleNamespace(
+        HELP_TEXT=JqlQueryScreen.HELP_TEXT,
+        history=history or [],
+        history_index=len(history or []),
+        draft=query,
+        query_one=lambda selector, *args: widgets[selector],
+        safe_pop_screen=MagicMock(),
+        _parent=MagicMock(),
+    )
+    method = JqlQueryScreen._show_history_entry
+    screen._show_history_entry = lambda *args: method(screen, *args)  # type: ignore[arg-type]
+    return screen
+
+
+def test_projects_save_runs_and_offers_the_project_board():
+    """s shows the unresolved issues of the project and asks for a board name."""
+    screen = SimpleNamespace(
+        projects=[{"key": "ONE", "name": "One"}, {"key": "TEST", "name": "Test"}],
+        query_one=lambda *args: MagicMock(cursor_row=1),
+        safe_pop_screen=MagicMock(),
+        _parent=MagicMock(),
+    )
+    screen.selected_jql = lambda: ProjectsScreen.selected_jql(screen)  # type: ignore
+
+    ProjectsScreen.action_save(screen)  # type: ignore[arg-type]
+
+    query = "project = TEST AND resolution = Unresolved"
+    screen._parent.run_query.assert_called_once_with(query)
+    save_screen = screen._parent.push_screen.call_args.args[0]
+    assert (save_screen.jql_query, save_screen.board_name) == (query, "test")
+
+
+def make_kanban(issues, transitions, status_columns=None):
+    """Build a kanban view stand-in with its cursor on the first row."""
+    screen = SimpleNamespace(
+        _parent=make_parent(),
+        _popped=False,
+        issues=issues,
+        status_columns=status_columns or {},
+        current=0,
+        _table=lambda *args: MagicMock(cursor_row=0),
+        safe_pop_screen=MagicMock(),
+        refresh_columns=MagicMock(),
+        focus_column=MagicMock(),
+    )
+    screen.columns = kanban_columns(screen.status_columns)
+    for name in ("column_issues", "selected_issue", "_move_issue", "_issue_moved"):
+        method = getattr(KanbanScreen, name)
+        setattr(screen, name, lambda *args, method=method: method(screen, *args))
+    jira = screen._parent.jayrah_obj.jira
+    jira.get_transitions.return_value = {"transitions": transitions}
+    return screen
+
+
+def test_kanban_columns_follow_the_config():
+    """Statuses of the config get their own column before Done."""
+    status_columns = {"In Review": "Review"}
+
+    assert kanban_columns(status_columns) == ["To Do", "In Progress", "Review", "Done"]
+    assert kanban_column({"name": "In Review"}, status_columns) == "Review"
+    assert kanban_column({"statusCategory": {"key": "done"}}) == "Done"
+    assert kanban_column({"name": "Unknown"}) == "To Do"
+
+
+@patch("jayrah.ui.tui.views.apply_transition")
+def test_kanban_moves_the_issue_to_the_next_column(mock_apply):
+    """The first transition leading to the next column is applied."""
+    in_progress = {"name": "Doing", "statusCategory": {"key": "indeterminate"}}
+    transitions = [
+        {"id": "1", "to": {"name": "Closed", "statusCategory": {"key": "done"}}},
+        {"id": "2", "to": in_progress},
+    ]
+    issue = {"key": "TEST-1", "fields": {"status": {"name": "New"}}}
+    screen = make_kanban([issue], transitions)
+
+    KanbanScreen.move_issue(screen, 1)  # type: ignore[arg-type]
+
+    mock_apply.assert_called_once()
+    assert mock_apply.call_args.args == (screen._parent, "TEST-1", transitions[1])
+    # The card only moves once the transition went through
+    assert issue["fields"]["status"] == {"name": "New"}
+    mock_apply.call_args.kwargs["on_done"]()
+    assert issue["fields"]["status"] == in_progress
+    screen.focus_column.assert_called_with(1)
+
+
+def test_kanban_warns_without_a_transition_to_the_column():
+    """Nothing is sent when no transition leads to the column."""
+    issue = {"key": "TEST-1", "fields": {"status": {"name": "New"}}}
+    screen = make_kanban([issue], [])
+
+    KanbanScreen.move_issue(screen, 1)  # type: ignore[arg-type]
+
+    screen._parent.jayrah_obj.jira.transition_issue.assert_not_called()
+    screen._parent.notify.assert_called_once_with(
+        "No transition of TEST-1 leads to In Progress", severity="warning"
+    )
+
+
+@patch("jayrah.ui.tui.views.defaults.ACTIVITY_ISSUES", 2)
+def test_activity_covers_the_latest_updated_issues():
+    """Only the most recently updated issues are fetched."""
+    issues = [
+        {"key": "TEST-1", "fields": {"updated": "2024-01-01T00:00:00.000+0000"}},
+        {"key": "TEST-2", "fields": {"updated": "2024-03-01T00:00:00.000+0000"}},
+        {"key": "TEST-3", "fields": {"updated": "2024-02-01T00:00:00.000+0000"}},
+    ]
+
+    screen = ActivityScreen(MagicMock(), issues)
+
+    assert screen.issue_keys == ["TEST-2", "TEST-3"]
+    assert screen.pending == 2
+
+
+def test_jql_query_runs_valid_query():
+    """A valid query closes the prompt and replaces the issue list."""
+    screen = make_query_screen(" project = TEST ")
+
+    JqlQueryScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    screen.safe_pop_screen.assert_called_once_with()
+    screen._parent.run_query.assert_called_once_with("project = TEST")
+
+
+def test_jql_query_reports_syntax_error():
+    """An invalid query stays in the prompt with the parser error shown."""
+    screen = make_query_screen("project = TEST AND")
+
+    JqlQueryScreen.action_apply(screen)  # type: ignore[arg-type]
+
+    screen.safe_pop_screen.assert_not_called()
+    screen._parent.run_query.assert_not_called()
+    message = screen.query_one("#query-help").update.call_args.args[0]
+    assert message.startswith("❌ Expected a field name")
+
+
+def test_jql_query_history_navigation():
+    """Up and down go through earlier queries and back to the one being typed."""
+    screen = make_query_screen("", history=["project = A", "project = B"])
+    query_input = screen.query_one("#query-text")
+    query_input.value = "status = New"
+
+    JqlQueryScreen.action_history_previous(screen)  # type: ignore[arg-type]
+    assert query_input.value == "project = B"
+    JqlQueryScreen.action_history_previous(screen)  # type: ignore[arg-type]
+    JqlQueryScreen.action_history_previous(screen)  # type: ignore[arg-type]
+    assert query_input.value == "project = A"
+
+    JqlQueryScreen.action_history_next(screen)  # type: ignore[arg-type]
+    JqlQueryScreen.action_history_next(screen)  # type: ignore[arg-type]
+    assert query_input.value == "status = New"
+
+
+def test_label_completion_completes_the_last_label():
+    """The label after the last comma is completed, keeping what was typed."""
+    labels = ["Backend", "bug", "frontend"]
+
+    assert label_completion("ba", labels) == "backend"
+    assert label_completion("bug, Fr", labels) == "bug, Frontend"
+    # Labels already set and complete ones are not suggested again
+    assert label_completion("bug, b", labels) == "bug, backend"
+    assert label_completion("bug", labels) is None
+    assert label_completion("bug, ", labels) is None
+
+
+def test_label_suggester_tries_recent_labels_before_jira():
+    """Recent labels complete without asking Jira, excluded labels never do."""
+    suggest = MagicMock(return_value=["release-1", "review"])
+    suggester = LabelSuggester(["urgent"], suggest, excludes="^release-")
+
+    assert asyncio.run(suggester.get_suggestion("ur")) == "urgent"
+    suggest.assert_not_called()
+    assert asyncio.run(suggester.get_suggestion("urgent, re")) == "urgent, review"
+    suggest.assert_called_once_with("re")
+
+
+def test_label_suggester_ignores_jira_errors():
+    """A failing autocomplete gives no suggestion rather than an error."""
+    suggest = MagicMock(side_effect=jira_exceptions.JiraNotFoundError("jql", ""))
+
+    assert asyncio.run(LabelSuggester([], suggest).get_suggestion("x")) is None
+
+
+def test_comment_body_mentions_users_for_the_api_version():
+    """API v2 gets wiki user links, v3 an ADF document with mention nodes."""
+    jane = mentions.Mention("Jane Doe", account_id="5b10ac")
+    v2 = SimpleNamespace(api_version="2", formatter=create_formatter("2"))
+    v3 = SimpleNamespace(api_version="3", formatter=create_formatter("3"))
+
+    assert (
+        comment_body(v2, "**ping** @Jane Doe", [jane]) == "*ping* [~accountid:5b10ac]"
+    )
+    assert comment_body(v3, "ping", []) == "ping"
+    document = comment_body(v3, "ping @Jane Doe", [jane])
+    assert document["type"] == "doc"
+    assert {"type": "mention", "attrs": {"id": "5b10ac", "text": "@Jane Doe"}} in (
+        document["content"][0]["content"]
+    )
+
+
+def test_add_comment_keeps_the_draft_when_it_fails():
+    """A refused comment stays in the drafts, an added one is dropped."""
+    jira = MagicMock(api_version="2")
+    jira.add_comment.side_effect = jira_exceptions.JiraAPIError(
+        "Server error", "comment", 500, ""
+    )
+    state_store = MagicMock()
+    screen = SimpleNamespace(
+        issue_key="TEST-1",
+        config={},
+        mentions=[],
+        on_comment_added=None,
+        _popped=False,
+        _parent=SimpleNamespace(
+            jayrah_obj=SimpleNamespace(jira=jira, state_store=state_store),
+            notify=MagicMock(),
+            worker_pool=InlinePool(),
+        ),
+        query_one=MagicMock(return_value=SimpleNamespace(text="Needs\nmore work")),
+        safe_pop_screen=MagicMock(),
+    )
+    for name in ("_comment_text", "_save_draft", "_comment_added", "_comment_failed"):
+        method = getattr(AddCommentScreen, name)
+        setattr(screen, name, lambda *args, method=method: method(screen, *args))
+
+    AddCommentScreen.action_apply(screen)  # type: ignore[arg-type]
+    state_store.save_comment_draft.assert_called_once_with("TEST-1", "Needs\nmore work")
+    screen.safe_pop_screen.assert_not_called()
+
+    jira.add_comment.side_effect = None
+    AddCommentScreen.action_apply(screen)  # type: ignore[arg-type]
+    state_store.save_comment_draft.assert_called_with("TEST-1", "")
+    screen.safe_pop_screen.assert_called_once()
+
+
+def test_apply_transition_can_be_undone():
+    """The status the issue left is kept to transition back to it."""
+    issue = {"key": "TEST-1", "fields": {"status": {"name": "New"}}}
+    parent = make_parent(issues=[issue])
+    parent.query_one.return_value = MagicMock(ticket=None, issue_data_cache={})
+
+    transition = {"id": "2", "name": "Start", "to": {"name": "Doing"}}
+    apply_transition(parent, "TEST-1", transition)
+
+    parent.jayrah_obj.jira.transition_issue.assert_called_once_with("TEST-1", "2", None)
+    change = parent.undo_stack.push.call_args.args[0]
+    assert (change.issue_key, change.status) == ("TEST-1", "New")
+
+
+def test_attachment_preview_falls_back_to_the_metadata():
+    """Without the images extra or option an image is described, not drawn."""
+    attachment = models.Attachment(
+        "1", "screenshot.png", "image/png", 1536, "Jane Doe", "", "http://x/1"
+    )
+    screen = SimpleNamespace(
+        attachments=[attachment],
+        config={},
+        previewed=None,
+        _popped=False,
+        _selected=lambda: attachment,
+        _parent=MagicMock(),
+        show_message=MagicMock(),
+    )
+    screen.show_preview = lambda a, d: AttachmentsScreen.show_preview(screen, a, d)  # type: ignore[arg-type]
+
+    AttachmentsScreen.action_preview(screen)  # type: ignore[arg-type]
+
+    screen._parent.worker_pool.submit.assert_not_called()
+    text = screen.show_message.call_args.args[0]
+    assert text.startswith("screenshot.png, image/png of 1.5 KB by Jane Doe")
+
+
+@patch("jayrah.ui.tui.views.utils.browser_open")
+def test_attachment_that_cannot_be_opened_is_notified(mock_open):
+    """A browser command that cannot run is a notification, not a crash."""
+    mock_open.side_effect = FileNotFoundError("No such file: 'firefox'")
+    attachment = models.Attachment(
+        "1", "build.log", "text/plain", 10, "Jane Doe", "", "http://x/1"
+    )
+    screen = SimpleNamespace(
+        config={"browser": "firefox"},
+        _selected=lambda: attachment,
+        notify=MagicMock(),
+    )
+
+    AttachmentsScreen.action_open(screen)  # type: ignore[arg-type]
+
+    mock_open.assert_called_once_with("http://x/1", {"browser": "firefox"})
+    screen.notify.assert_called_once_with(
+        "Error opening build.log: No such file: 'firefox'", severity="error"
+    )
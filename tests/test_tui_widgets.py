@@ -0,0 +1,15 @@
+"""Tests for the enhanced TUI input widgets."""
+
+from jayrah.ui.tui.enhanced_widgets import flatten_pasted_text, normalize_pasted_text
+
+
+def test_normalize_pasted_text_line_endings():
+    """Pasted CRLF and CR line endings become plain newlines."""
+    assert normalize_pasted_text("one\r\ntwo\rthree\n") == "one\ntwo\nthree\n"
+
+
+def test_flatten_pasted_text_for_single_line_inputs():
+    """Multi-line pastes are kept whole instead of stopping at the first line."""
+    assert flatten_pasted_text("status = New\r\n  AND\n\nassignee = me ") == (
+        "status = New AND assignee = me"
+    )
@@ -0,0 +1,146 @@
+"""Tests for the shared TUI worker pool."""
+
+import threading
+import time
+
+import pytest
+
+from jayrah.ui.tui.workers import JiraWorkerPool
+
+
+def make_pool():
+    """Build a pool dispatching callbacks inline and recording them."""
+    calls = []
+
+    def dispatch(callback, *args):
+        calls.append(callback)
+        callback(*args)
+
+    return JiraWorkerPool(dispatch, max_workers=2), calls
+
+
+def test_submit_dispatches_result():
+    """Results are handed to on_result through the dispatcher."""
+    pool, calls = make_pool()
+    results = []
+
+    future = pool.submit(lambda a, b=0: a + b, 1, b=2, on_result=results.append)
+    future.result()
+
+    assert results == [3]
+    assert calls == [results.append]
+    pool.shutdown()
+
+
+def test_submit_dispatches_error():
+    """Exceptions are handed to on_error instead of being raised."""
+    pool, _ = make_pool()
+    errors = []
+
+    def fail():
+        raise RuntimeError("boom")
+
+    pool.submit(fail, on_error=errors.append).result()
+
+    assert len(errors) == 1
+    assert str(errors[0]) == "boom"
+    pool.shutdown()
+
+
+def test_submit_without_error_handler_raises():
+    """Without on_error the exception stays on the future."""
+    pool, _ = make_pool()
+
+    def fail():
+        raise RuntimeError("boom")
+
+    with pytest.raises(RuntimeError):
+        pool.submit(fail).result()
+    pool.shutdown()
+
+
+def test_submit_skips_stale_request():
+    """A request already stale when picked up is never run."""
+    pool, calls = make_pool()
+    ran = []
+
+    pool.submit(
+        lambda: ran.append(True), on_result=print, is_stale=lambda: True
+    ).result()
+
+    assert not ran
+    assert not calls
+    pool.shutdown()
+
+
+def test_submit_shares_in_flight_request_with_same_key():
+    """A request already queued under the same key is not sent twice."""
+    pool, _ = make_pool()
+    started, release = threading.Event(), threading.Event()
+    ran = []
+    results = []
+
+    def fetch():
+        started.set()
+        release.wait(5)
+        ran.append(True)
+        return "TEST-1"
+
+    first = pool.submit(fetch, on_result=results.append, key=("issue", "TEST-1"))
+    started.wait(5)
+    second = pool.submit(fetch, on_result=results.append, key=("issue", "TEST-1"))
+    release.set()
+
+    assert second is first
+    first.result()
+    assert ran == [True]
+    assert results == ["TEST-1", "TEST-1"]
+    pool.shutdown()
+
+
+def test_submit_runs_same_key_again_once_done():
+    """A finished request doesn't answer the ones submitted after it."""
+    pool, _ = make_pool()
+    ran = []
+
+    pool.submit(lambda: ran.append(1), key="issue").result()
+    pool.submit(lambda: ran.append(2), key="issue").result()
+
+    assert ran == [1, 2]
+    pool.shutdown()
+
+
+def test_shared_request_skipped_only_when_all_are_stale():
+    """One submission still wanting the result keeps a shared request."""
+    pool, _ = make_pool()
+    release = threading.Event()
+    blocker = pool.submit(release.wait, 5)
+    pool.submit(release.wait, 5)
+    ran = []
+
+    future = pool.submit(lambda: ran.append(True), is_stale=lambda: True, key="k")
+    pool.submit(lambda: ran.append(True), is_stale=lambda: False, key="k")
+    release.set()
+    blocker.result()
+    future.result()
+
+    assert ran == [True]
+    pool.shutdown()
+
+
+def test_pending_counts_the_requests_in_flight():
+    """Shared keyed requests count once, finished ones are not counted."""
+    pool, _ = make_pool()
+    release = threading.Event()
+
+    pool.submit(release.wait)
+    pool.submit(release.wait, key="TEST-1")
+    pool.submit(release.wait, key="TEST-1")
+    assert pool.pending == 2
+
+    release.set()
+    deadline = time.monotonic() + 5
+    while pool.pending and time.monotonic() < deadline:
+        time.sleep(0.01)
+    assert pool.pending == 0
+    pool.shutdown()
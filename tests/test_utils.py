@@ -23,6 +23,23 @@ def test_make_full_url():
         utils.make_full_url("JIRA-123", None)
 
 
+def test_issue_key_from_text():
+    """Keys and browse URLs give the key of the issue."""
+    assert utils.issue_key_from_text("jay-123") == "JAY-123"
+    assert (
+        utils.issue_key_from_text("https://jira.example.com/browse/JAY-123?focus=1")
+        == "JAY-123"
+    )
+    assert utils.issue_key_from_text("my-board") is None
+    assert utils.issue_key_from_text("https://jira.example.com/projects/JAY") is None
+
+
+def test_make_search_url():
+    """The JQL of a search URL is quoted."""
+    url = utils.make_search_url("project = TEST", "https://jira.example.com")
+    assert url == "https://jira.example.com/issues/?jql=project%20%3D%20TEST"
+
+
 @patch("webbrowser.open")
 def test_browser_open_ticket(mock_open):
     """Test opening a ticket in the browser."""
@@ -36,10 +53,22 @@ def test_browser_open_ticket(mock_open):
     utils.browser_open_ticket(None, config)
     mock_open.assert_called_once_with("https://jira.example.com/projects/TEST")
 
-    # Test with exception
+    # The errors are left to the caller, the TUI shows them in a notification
     mock_open.reset_mock()
-    mock_open.side_effect = Exception("Browser error")
-    utils.browser_open_ticket("JIRA-123", config)  # Should not raise exception
+    mock_open.side_effect = OSError("Browser error")
+    with pytest.raises(OSError, match="Browser error"):
+        utils.browser_open_ticket("JIRA-123", config)
+
+
+@patch("subprocess.Popen")
+def test_browser_open_runs_the_browser_command(mock_popen):
+    """The browser option replaces the default browser."""
+    url = "https://jira.example.com/browse/JIRA-123"
+    utils.browser_open(url, {"browser": "firefox --new-tab"})
+    assert mock_popen.call_args.args[0] == ["firefox", "--new-tab", url]
+
+    utils.browser_open(url, {"browser": "open -a Safari %s --background"})
+    assert mock_popen.call_args.args[0] == ["open", "-a", "Safari", url, "--background"]
 
 
 def test_show_time():